@@ -62,6 +62,12 @@ REQUIRED_SYMBOLS=(
   zkvm_bls12_pairing
   zkvm_bls12_map_fp_to_g1
   zkvm_bls12_map_fp2_to_g2
+  zkvm_bls12_hash_to_g1
+  zkvm_bls12_hash_to_g2
+  zkvm_bls_verify
+  zkvm_bls_aggregate_verify
+  zkvm_bls_fast_aggregate_verify
+  zkvm_rsa_verify
   zkvm_blake2f
   zkvm_ripemd160
   zkvm_modexp
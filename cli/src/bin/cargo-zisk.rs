@@ -1,12 +1,15 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{Context, Result};
 use cargo_zisk::commands::{
-    ZiskBuild, ZiskCheckSetup, ZiskClean, ZiskExecute, ZiskNew, ZiskProgramSetup, ZiskProve,
-    ZiskRun, ZiskStats, ZiskToolchain, ZiskUtils, ZiskVerify, ZiskVerifyConstraints, ZiskWrap,
+    ZiskBuild, ZiskCheckSetup, ZiskClean, ZiskExecute, ZiskExport, ZiskNew, ZiskProgramSetup,
+    ZiskProve, ZiskRun, ZiskStats, ZiskToolchain, ZiskUtils, ZiskVerify, ZiskVerifyConstraints,
+    ZiskVk, ZiskWrap,
 };
-use clap::Parser;
+use cargo_zisk::output::{
+    print_error, reserve_stdout, FailureClass, OutputFormat, WithFailureClass,
+};
+use clap::{Parser, Subcommand};
 use zisk_build::ZISK_VERSION_MESSAGE;
 
-// Main enum defining cargo subcommands.
 #[derive(Parser)]
 #[command(
     name = "cargo-zisk",
@@ -15,12 +18,25 @@ use zisk_build::ZISK_VERSION_MESSAGE;
     about = "CLI tool for Zisk",
     long_about = "Cargo Zisk is a command-line tool to manage Zisk projects."
 )]
+pub struct Cli {
+    /// Output format. `json` prints one machine-readable document for execute, prove, stats,
+    /// verify, vk and check-setup, and is rejected by the other commands
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Cargo,
+}
+
+// Main enum defining cargo subcommands.
+#[derive(Subcommand)]
 pub enum Cargo {
     Build(ZiskBuild),
     #[command(hide = true)]
     CheckSetup(ZiskCheckSetup),
     Clean(ZiskClean),
     Execute(ZiskExecute),
+    Export(ZiskExport),
     New(ZiskNew),
     WrapProof(ZiskWrap),
     Prove(ZiskProve),
@@ -33,18 +49,81 @@ pub enum Cargo {
     Verify(ZiskVerify),
     #[command(hide = true)]
     VerifyConstraints(ZiskVerifyConstraints),
+    Vk(ZiskVk),
+}
+
+impl Cargo {
+    fn name(&self) -> &'static str {
+        match self {
+            Cargo::Build(_) => "build",
+            Cargo::CheckSetup(_) => "check-setup",
+            Cargo::Clean(_) => "clean",
+            Cargo::Execute(_) => "execute",
+            Cargo::Export(_) => "export",
+            Cargo::New(_) => "new",
+            Cargo::WrapProof(_) => "wrap-proof",
+            Cargo::Prove(_) => "prove",
+            Cargo::ProgramSetup(_) => "program-setup",
+            Cargo::Run(_) => "run",
+            Cargo::Stats(_) => "stats",
+            Cargo::Toolchain(_) => "toolchain",
+            Cargo::Utils(_) => "utils",
+            Cargo::Verify(_) => "verify",
+            Cargo::VerifyConstraints(_) => "verify-constraints",
+            Cargo::Vk(_) => "vk",
+        }
+    }
+
+    /// Whether the command prints a JSON document with `--format json`
+    fn supports_json(&self) -> bool {
+        matches!(
+            self,
+            Cargo::CheckSetup(_)
+                | Cargo::Execute(_)
+                | Cargo::Prove(_)
+                | Cargo::Stats(_)
+                | Cargo::Verify(_)
+                | Cargo::Vk(_)
+        )
+    }
 }
 
-fn main() -> Result<()> {
+fn main() {
     // Parse command-line arguments and handle errors if they occur.
-    let cargo_args = Cargo::parse();
+    let cli = Cli::parse();
+    let command = cli.command.name();
+
+    if cli.format.is_json() {
+        if let Err(err) = reserve_stdout() {
+            eprintln!("Error: failed to reserve stdout for the JSON output: {err}");
+            std::process::exit(FailureClass::Internal.exit_code());
+        }
+    }
+
+    let result = if cli.format.is_json() && !cli.command.supports_json() {
+        Err::<(), _>(anyhow::anyhow!("--format json is not supported by {command}"))
+            .failure_class(FailureClass::Input)
+    } else {
+        run(cli.command, cli.format)
+    };
 
-    match cargo_args {
+    if let Err(err) = result {
+        if cli.format.is_json() {
+            print_error(command, &err);
+        } else {
+            eprintln!("Error: {err:?}");
+        }
+        std::process::exit(FailureClass::of(&err).exit_code());
+    }
+}
+
+fn run(command: Cargo, format: OutputFormat) -> Result<()> {
+    match command {
         Cargo::Build(cmd) => {
             cmd.run().context("Error executing Build command")?;
         }
         Cargo::CheckSetup(cmd) => {
-            cmd.run().context("Error executing CheckSetup command")?;
+            cmd.run(format).context("Error executing CheckSetup command")?;
         }
         Cargo::Clean(cmd) => {
             cmd.run().context("Error executing Clean command")?;
@@ -53,7 +132,7 @@ fn main() -> Result<()> {
             cmd.run().context("Error executing New command")?;
         }
         Cargo::Prove(mut cmd) => {
-            cmd.run().context("Error executing Prove command")?;
+            cmd.run(format).context("Error executing Prove command")?;
         }
         Cargo::WrapProof(cmd) => {
             cmd.run().context("Error executing WrapProof command")?;
@@ -65,7 +144,7 @@ fn main() -> Result<()> {
             cmd.run().context("Error executing Run command")?;
         }
         Cargo::Stats(mut cmd) => {
-            cmd.run().context("Error executing Stats command")?;
+            cmd.run(format).context("Error executing Stats command")?;
         }
         Cargo::Toolchain(mut cmd) => {
             cmd.run().context("Error executing Toolchain command")?;
@@ -74,14 +153,20 @@ fn main() -> Result<()> {
             cmd.run().context("Error executing Utils command")?;
         }
         Cargo::Execute(mut cmd) => {
-            cmd.run().context("Error executing Execute command")?;
+            cmd.run(format).context("Error executing Execute command")?;
+        }
+        Cargo::Export(cmd) => {
+            cmd.run().context("Error executing Export command")?;
         }
         Cargo::Verify(cmd) => {
-            cmd.run().map_err(|e| anyhow!("Error executing Verify command: {}", e))?;
+            cmd.run(format).context("Error executing Verify command")?;
         }
         Cargo::VerifyConstraints(mut cmd) => {
             cmd.run().context("Error executing VerifyConstraints command")?;
         }
+        Cargo::Vk(cmd) => {
+            cmd.run(format).context("Error executing Vk command")?;
+        }
     }
 
     Ok(())
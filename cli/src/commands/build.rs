@@ -1,6 +1,10 @@
 use anyhow::{anyhow, Context, Result};
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
-use zisk_build::{HELPER_TARGET_SUBDIR, ZISK_TARGET, ZISK_VERSION_MESSAGE};
+use zisk_build::{
+    BuildManifest, BuildOptions, ReproducibleBuild, HELPER_TARGET_SUBDIR, ZISK_TARGET,
+    ZISK_VERSION_MESSAGE,
+};
 
 // Structure representing the 'build' subcommand of cargo.
 #[derive(clap::Args)]
@@ -35,6 +39,15 @@ pub struct ZiskBuild {
     #[arg(short = 'p', long = "package", value_name = "PACKAGE")]
     packages: Vec<String>,
 
+    /// Build reproducibly, with vendored locked dependencies, remapped paths and a normalized
+    /// environment, and write a build manifest with the ELF hashes and program VKs
+    #[arg(long)]
+    reproducible: bool,
+
+    /// Check that the reproducible build matches this build manifest
+    #[arg(long, value_name = "MANIFEST", requires = "reproducible")]
+    verify_manifest: Option<PathBuf>,
+
     /// Toolchain name to use
     #[arg(long, hide = true)]
     toolchain_name: Option<String>,
@@ -49,14 +62,27 @@ impl ZiskBuild {
         } else {
             "zisk"
         };
+        let expected = self.verify_manifest.as_ref().map(BuildManifest::load).transpose()?;
+        let reproducible = if self.reproducible {
+            let program_dir = std::env::current_dir()?;
+            Some(ReproducibleBuild::prepare(&program_dir, toolchain_name, expected.as_ref())?)
+        } else {
+            None
+        };
+
         let mut command = Command::new("cargo");
         command.args([&format!("+{toolchain_name}"), "build"]);
 
-        // Set RUSTFLAGS for target-cpu=zisk, preserving existing flags
-        let flags = std::env::var("RUSTFLAGS").unwrap_or_default();
-        command.env("RUSTFLAGS", flags.trim());
+        match &reproducible {
+            Some(reproducible) => reproducible.configure(&mut command),
+            None => {
+                // Set RUSTFLAGS for target-cpu=zisk, preserving existing flags
+                let flags = std::env::var("RUSTFLAGS").unwrap_or_default();
+                command.env("RUSTFLAGS", flags.trim());
 
-        command.args(["--target-dir", &format!("target/{}", HELPER_TARGET_SUBDIR)]);
+                command.args(["--target-dir", &format!("target/{}", HELPER_TARGET_SUBDIR)]);
+            }
+        }
 
         // Add the feature selection flags
         if let Some(features) = &self.features {
@@ -93,6 +119,50 @@ impl ZiskBuild {
             return Err(anyhow!("Cargo run command failed with status {}", status));
         }
 
+        if let Some(reproducible) = reproducible {
+            self.write_manifest(&reproducible, expected.as_ref())?;
+        }
+
+        Ok(())
+    }
+
+    fn write_manifest(
+        &self,
+        reproducible: &ReproducibleBuild,
+        expected: Option<&BuildManifest>,
+    ) -> Result<()> {
+        let options = BuildOptions {
+            release: self.release,
+            features: self.features.clone(),
+            all_features: self.all_features,
+            no_default_features: self.no_default_features,
+            packages: self.packages.clone(),
+            binaries: self.binaries.clone(),
+        };
+        let manifest = reproducible.manifest(&options)?;
+        let manifest_path = reproducible.manifest_path(self.release);
+        manifest.save(&manifest_path)?;
+
+        for elf in &manifest.elfs {
+            println!("{}: hash {}, program VK {}", elf.name, elf.hash, elf.program_vk);
+        }
+        println!("Build manifest written to {}", manifest_path.display());
+        if manifest.source_dirty {
+            println!("Warning: the sources have uncommitted changes, the build manifest does not identify them");
+        }
+
+        if let (Some(expected), Some(expected_path)) = (expected, &self.verify_manifest) {
+            let differences = manifest.differences(expected);
+            if !differences.is_empty() {
+                return Err(anyhow!(
+                    "Build does not reproduce {}:\n  {}",
+                    expected_path.display(),
+                    differences.join("\n  ")
+                ));
+            }
+            println!("Build reproduces {}", expected_path.display());
+        }
+
         Ok(())
     }
 }
@@ -1,6 +1,7 @@
 // extern crate env_logger;
 use anyhow::Result;
 use colored::Colorize;
+use serde::Serialize;
 use std::path::PathBuf;
 use zisk_build::ZISK_VERSION_MESSAGE;
 use zisk_common::ZiskPaths;
@@ -10,6 +11,8 @@ use fields::Goldilocks;
 use proofman::{check_setup_snark, ProofMan};
 use zisk_prover_backend::setup_logger;
 
+use crate::output::{print_report, FailureClass, OutputFormat, WithFailureClass};
+
 #[derive(clap::Args)]
 #[command(author, about, long_about = None, version = ZISK_VERSION_MESSAGE)]
 /// Check that the proving key is correctly set up
@@ -40,10 +43,20 @@ pub struct ZiskCheckSetup {
     pub verbose: u8,
 }
 
+/// `--format json` document for the check-setup command.
+#[derive(Serialize)]
+struct CheckSetupReport {
+    proving_key: PathBuf,
+    aggregation: bool,
+    proving_key_plonk: Option<PathBuf>,
+}
+
 impl ZiskCheckSetup {
-    pub fn run(&self) -> Result<()> {
-        println!("{} CheckSetup", format!("{: >12}", "Command").bright_green().bold());
-        println!();
+    pub fn run(&self, format: OutputFormat) -> Result<()> {
+        if !format.is_json() {
+            println!("{} CheckSetup", format!("{: >12}", "Command").bright_green().bold());
+            println!();
+        }
 
         setup_logger(self.verbose.into());
 
@@ -52,21 +65,36 @@ impl ZiskCheckSetup {
         #[cfg(feature = "cpu-only")]
         let gpu = false;
 
+        let proving_key = ZiskPaths::get_proving_key(self.proving_key.as_ref());
         ProofMan::<Goldilocks>::check_setup(
-            ZiskPaths::get_proving_key(self.proving_key.as_ref()),
+            proving_key.clone(),
             !self.no_aggregation,
             self.verbose.into(),
             gpu,
         )
-        .map_err(|e| anyhow::anyhow!("Error checking setup: {}", e))?;
-
-        if self.plonk {
-            check_setup_snark::<Goldilocks>(
-                &ZiskPaths::get_proving_key_snark(self.proving_key_plonk.as_ref()),
-                self.verbose.into(),
-                gpu,
-            )
-            .map_err(|e| anyhow::anyhow!("Error checking setup snark: {}", e))?
+        .map_err(|e| anyhow::anyhow!("Error checking setup: {}", e))
+        .failure_class(FailureClass::Setup)?;
+
+        let proving_key_plonk = if self.plonk {
+            let proving_key_plonk =
+                ZiskPaths::get_proving_key_snark(self.proving_key_plonk.as_ref());
+            check_setup_snark::<Goldilocks>(&proving_key_plonk, self.verbose.into(), gpu)
+                .map_err(|e| anyhow::anyhow!("Error checking setup snark: {}", e))
+                .failure_class(FailureClass::Setup)?;
+            Some(proving_key_plonk)
+        } else {
+            None
+        };
+
+        if format.is_json() {
+            return print_report(
+                "check-setup",
+                &CheckSetupReport {
+                    proving_key,
+                    aggregation: !self.no_aggregation,
+                    proving_key_plonk,
+                },
+            );
         }
 
         Ok(())
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use colored::Colorize;
+use serde::Serialize;
 use std::path::PathBuf;
 use tracing::{info, warn};
 use zisk_build::ZISK_VERSION_MESSAGE;
@@ -8,6 +9,9 @@ use zisk_prover_backend::GuestProgram;
 use zisk_prover_backend::{AsmOptions, BackendProverOpts, ExecuteOutput, ProverClientBuilder};
 
 use crate::common::detect_current_project_elf;
+use crate::output::{
+    print_report, publics_hex, CostReport, FailureClass, OutputFormat, WithFailureClass,
+};
 use crate::ux::{print_banner, print_banner_command, print_banner_field, print_execution_summary};
 use zisk_common::io::{StreamSource, ZiskStdin};
 
@@ -61,15 +65,28 @@ pub struct ZiskExecute {
     pub no_auto_setup: bool,
 }
 
+/// `--format json` document for the execute command.
+#[derive(Serialize)]
+struct ExecuteReport<'a> {
+    elf: &'a PathBuf,
+    steps: u64,
+    duration_ms: u64,
+    cost: CostReport,
+    executor_time: &'a ZiskExecutorTime,
+    publics: String,
+    exit_code: Option<i32>,
+}
+
 impl ZiskExecute {
-    pub fn run(&mut self) -> Result<()> {
+    pub fn run(&mut self, format: OutputFormat) -> Result<()> {
         if self.elf.is_none() {
-            self.elf = match detect_current_project_elf()? {
+            self.elf = match detect_current_project_elf().failure_class(FailureClass::Input)? {
                 Some(elf) => Some(elf),
                 None => {
-                    anyhow::bail!(
+                    return Err(anyhow::anyhow!(
                         "No ELF file provided, and could not detect a project ELF in the current directory. Please provide an ELF file with --elf."
-                    );
+                    ))
+                    .failure_class(FailureClass::Input);
                 }
             };
         }
@@ -79,25 +96,28 @@ impl ZiskExecute {
             eprintln!("{}", "Warning: --input is deprecated, use --inputs instead".yellow().bold());
         }
 
-        print_banner();
+        if !format.is_json() {
+            print_banner();
 
-        print_banner_command("Execute");
-        print_banner_field("Elf", self.elf.as_ref().unwrap().display());
+            print_banner_command("Execute");
+            print_banner_field("Elf", self.elf.as_ref().unwrap().display());
 
-        let inputs_str = self.inputs.clone().unwrap_or_else(|| "None".dimmed().to_string());
-        print_banner_field("Input", inputs_str);
+            let inputs_str = self.inputs.clone().unwrap_or_else(|| "None".dimmed().to_string());
+            print_banner_field("Input", inputs_str);
 
-        if let Some(hints) = &self.hints {
-            print_banner_field("Prec. Hints", hints);
+            if let Some(hints) = &self.hints {
+                print_banner_field("Prec. Hints", hints);
+            }
         }
 
-        let stdin = ZiskStdin::from_uri(self.inputs.as_ref())?;
+        let stdin = ZiskStdin::from_uri(self.inputs.as_ref()).failure_class(FailureClass::Input)?;
 
         let hints_stream = match self.hints.as_ref() {
             Some(uri) => {
-                let stream = StreamSource::from_uri(uri)?;
+                let stream = StreamSource::from_uri(uri).failure_class(FailureClass::Input)?;
                 if matches!(stream, StreamSource::Quic(_)) {
-                    anyhow::bail!("QUIC hints source is not supported in CLI mode.");
+                    return Err(anyhow::anyhow!("QUIC hints source is not supported in CLI mode."))
+                        .failure_class(FailureClass::Input);
                 }
                 Some(stream)
             }
@@ -115,6 +135,24 @@ impl ZiskExecute {
 
         let (result, executor_time) =
             if emulator { self.run_emu(stdin)? } else { self.run_asm(stdin, hints_stream)? };
+        if let Some(panic) = result.get_panic() {
+            return Err(anyhow::Error::new(panic.clone())).failure_class(FailureClass::Execution);
+        }
+
+        if format.is_json() {
+            return print_report(
+                "execute",
+                &ExecuteReport {
+                    elf: self.elf.as_ref().unwrap(),
+                    steps: result.get_execution_steps(),
+                    duration_ms: result.get_execution_time(),
+                    cost: result.get_execution_cost_per_type().into(),
+                    executor_time: &executor_time,
+                    publics: publics_hex(result.get_publics()),
+                    exit_code: result.get_exit_code(),
+                },
+            );
+        }
 
         info!("{}", "--- EXECUTE SUMMARY -----------".bright_green().bold());
         print_execution_summary(
@@ -122,6 +160,9 @@ impl ZiskExecute {
             result.get_execution_time(),
             result.get_execution_steps(),
         );
+        if let Some(exit_code) = result.get_exit_code() {
+            info!("Guest exit code: {exit_code}");
+        }
 
         Ok(())
     }
@@ -137,11 +178,14 @@ impl ZiskExecute {
             .emu()
             .witness()
             .with_prover_options(prover_options)
-            .build()?;
-
-        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())?;
-        prover.setup(&guest_program).run()?;
-        let result = prover.execute(&guest_program, stdin)?;
+            .build()
+            .failure_class(FailureClass::Setup)?;
+
+        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())
+            .failure_class(FailureClass::Input)?;
+        prover.setup(&guest_program).run().failure_class(FailureClass::Setup)?;
+        let result =
+            prover.execute(&guest_program, stdin).failure_class(FailureClass::Execution)?;
         let executor_time = prover.get_executor_time()?;
         Ok((result, executor_time))
     }
@@ -177,18 +221,21 @@ impl ZiskExecute {
             .asm()
             .witness()
             .with_prover_options(prover_options)
-            .build()?;
+            .build()
+            .failure_class(FailureClass::Setup)?;
 
-        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())?;
+        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())
+            .failure_class(FailureClass::Input)?;
         if hints_stream.is_some() {
-            prover.setup(&guest_program).with_hints().run()?;
+            prover.setup(&guest_program).with_hints().run().failure_class(FailureClass::Setup)?;
         } else {
-            prover.setup(&guest_program).run()?;
+            prover.setup(&guest_program).run().failure_class(FailureClass::Setup)?;
         }
         if let Some(hints_stream) = hints_stream {
-            prover.register_hints_stream(hints_stream)?;
+            prover.register_hints_stream(hints_stream).failure_class(FailureClass::Input)?;
         }
-        let result = prover.execute(&guest_program, stdin)?;
+        let result =
+            prover.execute(&guest_program, stdin).failure_class(FailureClass::Execution)?;
         let executor_time = prover.get_executor_time()?;
         Ok((result, executor_time))
     }
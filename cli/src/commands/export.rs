@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use zisk_build::ZISK_VERSION_MESSAGE;
+use zisk_common::Proof;
+
+use crate::output::{FailureClass, WithFailureClass};
+
+/// Encoding of an exported proof.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportEncoding {
+    /// Hex ABI-encoded call to `IZiskVerifier.verifySnarkProof`, selector included
+    #[default]
+    Calldata,
+    /// Foundry fixture with the arguments of `verifySnarkProof` and the expected public values
+    /// hash
+    Json,
+    /// Hex proof bytes, the `proofBytes` argument of `verifySnarkProof`
+    Hex,
+}
+
+#[derive(clap::Args)]
+#[command(author, about, long_about = None, version = ZISK_VERSION_MESSAGE)]
+/// Export a SNARK proof for the Solidity verifier.
+pub struct ZiskExport {
+    /// Path to the proof file
+    #[clap(short = 'p', long)]
+    pub proof: String,
+
+    /// Encoding of the exported proof
+    #[clap(short = 'e', long, value_enum, default_value_t = ExportEncoding::Calldata)]
+    pub encoding: ExportEncoding,
+
+    /// Output file, stdout if not set
+    #[clap(short = 'o', long)]
+    pub output: Option<PathBuf>,
+}
+
+impl ZiskExport {
+    pub fn run(&self) -> Result<()> {
+        let proof = Proof::load(&self.proof)
+            .map_err(|e| anyhow::anyhow!("Error loading proof from {}: {}", &self.proof, e))
+            .failure_class(FailureClass::Input)?;
+
+        // Only SNARK proofs can be exported
+        let exported = match self.encoding {
+            ExportEncoding::Calldata => {
+                proof.to_solidity_calldata().map(|calldata| format!("0x{}", hex::encode(calldata)))
+            }
+            ExportEncoding::Json => proof.solidity_fixture().and_then(|fixture| {
+                serde_json::to_string_pretty(&fixture).map_err(anyhow::Error::from)
+            }),
+            ExportEncoding::Hex => {
+                proof.solidity_proof_bytes().map(|bytes| format!("0x{}", hex::encode(bytes)))
+            }
+        }
+        .failure_class(FailureClass::Input)?;
+
+        match &self.output {
+            Some(path) => std::fs::write(path, exported + "\n")
+                .map_err(|e| anyhow::anyhow!("Error writing {}: {}", path.display(), e))?,
+            None => println!("{exported}"),
+        }
+
+        Ok(())
+    }
+}
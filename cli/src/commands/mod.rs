@@ -2,6 +2,7 @@ mod build;
 mod check_setup;
 mod clean;
 mod execute;
+mod export;
 mod new;
 mod program_setup;
 mod prove;
@@ -11,12 +12,14 @@ mod toolchain;
 mod utils;
 mod verify;
 mod verify_constraints;
+mod vk;
 mod wrap;
 
 pub use build::*;
 pub use check_setup::*;
 pub use clean::*;
 pub use execute::*;
+pub use export::*;
 pub use new::*;
 pub use program_setup::*;
 pub use prove::*;
@@ -26,4 +29,5 @@ pub use toolchain::*;
 pub use utils::*;
 pub use verify::*;
 pub use verify_constraints::*;
+pub use vk::*;
 pub use wrap::*;
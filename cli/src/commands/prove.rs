@@ -1,8 +1,13 @@
 use crate::common::detect_current_project_elf;
+use crate::output::{
+    print_report, program_vk_hex, publics_hex, CostReport, FailureClass, OutputFormat,
+    WithFailureClass,
+};
 use crate::ux::{print_banner, print_banner_command, print_banner_field, print_execution_summary};
 use anyhow::Result;
 
 use colored::Colorize;
+use serde::Serialize;
 use std::path::PathBuf;
 use tracing::{info, warn};
 use zisk_build::ZISK_VERSION_MESSAGE;
@@ -110,15 +115,30 @@ pub struct ZiskProve {
     pub number_threads_witness: Option<usize>,
 }
 
+/// `--format json` document for the prove command.
+#[derive(Serialize)]
+struct ProveReport<'a> {
+    elf: &'a PathBuf,
+    proof_kind: Option<ProofKind>,
+    proof_path: Option<PathBuf>,
+    program_vk: Option<Vec<String>>,
+    publics: Option<String>,
+    steps: u64,
+    proving_time_ms: u64,
+    cost: CostReport,
+    executor_time: &'a ZiskExecutorTime,
+}
+
 impl ZiskProve {
-    pub fn run(&mut self) -> Result<()> {
+    pub fn run(&mut self, format: OutputFormat) -> Result<()> {
         if self.elf.is_none() {
-            self.elf = match detect_current_project_elf()? {
+            self.elf = match detect_current_project_elf().failure_class(FailureClass::Input)? {
                 Some(elf) => Some(elf),
                 None => {
-                    anyhow::bail!(
+                    return Err(anyhow::anyhow!(
                         "No ELF file provided, and could not detect a project ELF in the current directory. Please provide an ELF file with --elf."
-                    );
+                    ))
+                    .failure_class(FailureClass::Input);
                 }
             };
         }
@@ -128,21 +148,24 @@ impl ZiskProve {
             eprintln!("{}", "Warning: --input is deprecated, use --inputs instead".yellow().bold());
         }
 
-        print_banner();
+        if !format.is_json() {
+            print_banner();
 
-        print_banner_command("Prove");
+            print_banner_command("Prove");
 
-        print_banner_field("Elf", self.elf.as_ref().unwrap().display());
+            print_banner_field("Elf", self.elf.as_ref().unwrap().display());
 
-        let inputs_str = self.inputs.clone().unwrap_or_else(|| "None".dimmed().to_string());
-        print_banner_field("Input", inputs_str);
+            let inputs_str = self.inputs.clone().unwrap_or_else(|| "None".dimmed().to_string());
+            print_banner_field("Input", inputs_str);
 
-        if let Some(hints) = &self.hints {
-            print_banner_field("Prec. Hints", hints);
+            if let Some(hints) = &self.hints {
+                print_banner_field("Prec. Hints", hints);
+            }
         }
 
         if self.plonk && self.minimal {
-            anyhow::bail!("Minimal proofs are not supported for SNARK generation.");
+            return Err(anyhow::anyhow!("Minimal proofs are not supported for SNARK generation."))
+                .failure_class(FailureClass::Input);
         }
 
         // Build BackendProverOpts once with all configuration
@@ -160,7 +183,7 @@ impl ZiskProve {
             prover_options = prover_options.gpu();
         }
         if self.plonk {
-            prover_options = prover_options.plonk(false);
+            prover_options = prover_options.snark(ProofKind::Plonk, false);
         }
         if let Some(ref path) = self.proving_key {
             prover_options = prover_options.proving_key(path.clone());
@@ -194,13 +217,14 @@ impl ZiskProve {
         }
         prover_options = prover_options.with_asm_options(asm_options);
 
-        let stdin = ZiskStdin::from_uri(self.inputs.as_ref())?;
+        let stdin = ZiskStdin::from_uri(self.inputs.as_ref()).failure_class(FailureClass::Input)?;
 
         let hints_stream = match self.hints.as_ref() {
             Some(uri) => {
-                let stream = StreamSource::from_uri(uri)?;
+                let stream = StreamSource::from_uri(uri).failure_class(FailureClass::Input)?;
                 if matches!(stream, StreamSource::Quic(_)) {
-                    anyhow::bail!("QUIC hints source is not supported in CLI mode.");
+                    return Err(anyhow::anyhow!("QUIC hints source is not supported in CLI mode."))
+                        .failure_class(FailureClass::Input);
                 }
                 Some(stream)
             }
@@ -222,18 +246,46 @@ impl ZiskProve {
             self.run_asm(stdin, hints_stream, prover_options)?
         };
 
-        if !result.get_proof().is_empty() {
-            info!("{}", "--- PROVE SUMMARY ------------------------".bright_green().bold());
-
-            let output_file: PathBuf = match result.get_proof().kind() {
+        let proof = result.get_proof();
+        let output_file: Option<PathBuf> = if proof.is_empty() {
+            None
+        } else {
+            Some(match proof.kind() {
                 ProofKind::VadcopFinal | ProofKind::VadcopFinalMinimal => {
                     self.output.clone().unwrap_or_else(|| PathBuf::from("vadcop_final_proof.bin"))
                 }
                 ProofKind::Plonk => {
                     self.output.clone().unwrap_or_else(|| PathBuf::from("final_plonk_proof.bin"))
                 }
-            };
-            result.save_proof(&output_file)?;
+                ProofKind::Groth16 => {
+                    self.output.clone().unwrap_or_else(|| PathBuf::from("final_groth16_proof.bin"))
+                }
+            })
+        };
+        if let Some(output_file) = &output_file {
+            result.save_proof(output_file)?;
+        }
+
+        if format.is_json() {
+            let has_proof = output_file.is_some();
+            return print_report(
+                "prove",
+                &ProveReport {
+                    elf: self.elf.as_ref().unwrap(),
+                    proof_kind: has_proof.then(|| proof.kind()),
+                    proof_path: output_file,
+                    program_vk: has_proof.then(|| program_vk_hex(&proof.program_vk)),
+                    publics: has_proof.then(|| publics_hex(&proof.publics)),
+                    steps: result.get_execution_steps(),
+                    proving_time_ms: result.get_proving_time(),
+                    cost: result.get_execution_cost_per_type().into(),
+                    executor_time: &executor_time,
+                },
+            );
+        }
+
+        if output_file.is_some() {
+            info!("{}", "--- PROVE SUMMARY ------------------------".bright_green().bold());
             info!("Proof Time: {:.3} seconds", result.get_proving_time() as f64 / 1000.0);
 
             print_execution_summary(
@@ -251,11 +303,15 @@ impl ZiskProve {
         stdin: ZiskStdin,
         prover_options: BackendProverOpts,
     ) -> Result<(ProveOutput, ZiskExecutorTime)> {
-        let prover =
-            ProverClientBuilder::new().emu().with_prover_options(prover_options).build()?;
+        let prover = ProverClientBuilder::new()
+            .emu()
+            .with_prover_options(prover_options)
+            .build()
+            .failure_class(FailureClass::Setup)?;
 
-        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())?;
-        prover.setup(&guest_program).run()?;
+        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())
+            .failure_class(FailureClass::Input)?;
+        prover.setup(&guest_program).run().failure_class(FailureClass::Setup)?;
 
         let mut builder = prover.prove(&guest_program, stdin);
         if self.plonk {
@@ -264,7 +320,7 @@ impl ZiskProve {
         if self.minimal {
             builder = builder.wrap_proof(ProofKind::VadcopFinalMinimal);
         }
-        let result = builder.run()?;
+        let result = builder.run().failure_class(FailureClass::Proving)?;
         let executor_time = prover.get_executor_time()?;
 
         Ok((result, executor_time))
@@ -276,18 +332,22 @@ impl ZiskProve {
         hints_stream: Option<StreamSource>,
         prover_options: BackendProverOpts,
     ) -> Result<(ProveOutput, ZiskExecutorTime)> {
-        let prover =
-            ProverClientBuilder::new().asm().with_prover_options(prover_options).build()?;
-
-        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())?;
+        let prover = ProverClientBuilder::new()
+            .asm()
+            .with_prover_options(prover_options)
+            .build()
+            .failure_class(FailureClass::Setup)?;
+
+        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())
+            .failure_class(FailureClass::Input)?;
         if hints_stream.is_some() {
-            prover.setup(&guest_program).with_hints().run()?;
+            prover.setup(&guest_program).with_hints().run().failure_class(FailureClass::Setup)?;
         } else {
-            prover.setup(&guest_program).run()?;
+            prover.setup(&guest_program).run().failure_class(FailureClass::Setup)?;
         }
 
         if let Some(hints_stream) = hints_stream {
-            prover.register_hints_stream(hints_stream)?;
+            prover.register_hints_stream(hints_stream).failure_class(FailureClass::Input)?;
         }
 
         let mut builder = prover.prove(&guest_program, stdin);
@@ -298,7 +358,7 @@ impl ZiskProve {
             builder = builder.wrap_proof(ProofKind::VadcopFinalMinimal);
         }
 
-        let result = builder.run()?;
+        let result = builder.run().failure_class(FailureClass::Proving)?;
         let executor_time = prover.get_executor_time()?;
 
         Ok((result, executor_time))
@@ -5,14 +5,19 @@ use std::{collections::HashMap, fs, path::PathBuf, time::Instant};
 use tracing::warn;
 use zisk_build::ZISK_VERSION_MESSAGE;
 use zisk_common::io::{StreamSource, ZiskStdin};
-use zisk_common::{ExecutorStatsHandle, Stats};
+use zisk_common::{ExecutorStatsHandle, Stats, ZiskExecutorSummary, ZiskExecutorTime};
 use zisk_pil::*;
 use zisk_prover_backend::GuestProgram;
 use zisk_prover_backend::{AsmOptions, BackendProverOpts, ProverClientBuilder};
 
 use crate::common::detect_current_project_elf;
+use crate::output::{print_report, CostReport, FailureClass, OutputFormat, WithFailureClass};
 use crate::ux::{print_banner, print_banner_command, print_banner_field};
 
+/// Rank, number of processes, witness stats and execution summary of a stats run. The stats and
+/// summary are `None` on inactive ranks.
+type StatsRun = (i32, i32, Option<ExecutorStatsHandle>, Option<ZiskExecutorSummary>);
+
 #[derive(clap::Args)]
 #[command(author, about, long_about = None, version = ZISK_VERSION_MESSAGE)]
 /// Run the program and collect execution statistics
@@ -83,44 +88,88 @@ pub struct ZiskStats {
     pub debug: Option<Option<String>>,
 }
 
+/// `--format json` document for the stats command.
+#[derive(Serialize)]
+struct StatsReport {
+    world_rank: i32,
+    n_processes: i32,
+    /// Execution of this rank, absent for inactive ranks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execution: Option<ExecutionReport>,
+    airs: Vec<AirStatsReport>,
+    total_collect_ms: u64,
+    total_witness_ms: u64,
+}
+
+#[derive(Serialize)]
+struct ExecutionReport {
+    steps: u64,
+    cost: CostReport,
+    executor_time: ZiskExecutorTime,
+}
+
+impl From<ZiskExecutorSummary> for ExecutionReport {
+    fn from(summary: ZiskExecutorSummary) -> Self {
+        Self {
+            steps: summary.steps,
+            cost: (&summary.cost_per_type).into(),
+            executor_time: summary.executor_time,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct AirStatsReport {
+    airgroup_id: usize,
+    air_id: usize,
+    name: String,
+    chunks: usize,
+    collect_ms: u64,
+    witness_ms: u64,
+}
+
 impl ZiskStats {
-    pub fn run(&mut self) -> Result<()> {
+    pub fn run(&mut self, format: OutputFormat) -> Result<()> {
         // Check if the deprecated alias was used
         if std::env::args().any(|arg| arg == "--input") {
             eprintln!("{}", "Warning: --input is deprecated, use --inputs instead".yellow().bold());
         }
 
         if self.elf.is_none() {
-            self.elf = match detect_current_project_elf()? {
+            self.elf = match detect_current_project_elf().failure_class(FailureClass::Input)? {
                 Some(elf) => Some(elf),
                 None => {
-                    anyhow::bail!(
+                    return Err(anyhow::anyhow!(
                         "No ELF file provided, and could not detect a project ELF in the current directory. Please provide an ELF file with --elf."
-                    );
+                    ))
+                    .failure_class(FailureClass::Input);
                 }
             };
         }
 
-        print_banner();
+        if !format.is_json() {
+            print_banner();
 
-        print_banner_command("Stats");
+            print_banner_command("Stats");
 
-        print_banner_field("Elf", self.elf.as_ref().unwrap().display());
+            print_banner_field("Elf", self.elf.as_ref().unwrap().display());
 
-        let inputs_str = self.inputs.clone().unwrap_or_else(|| "None".dimmed().to_string());
-        print_banner_field("Input", inputs_str);
+            let inputs_str = self.inputs.clone().unwrap_or_else(|| "None".dimmed().to_string());
+            print_banner_field("Input", inputs_str);
 
-        if let Some(hints) = &self.hints {
-            print_banner_field("Prec. Hints", hints);
+            if let Some(hints) = &self.hints {
+                print_banner_field("Prec. Hints", hints);
+            }
         }
 
-        let stdin = ZiskStdin::from_uri(self.inputs.as_ref())?;
+        let stdin = ZiskStdin::from_uri(self.inputs.as_ref()).failure_class(FailureClass::Input)?;
 
         let hints_stream = match self.hints.as_ref() {
             Some(uri) => {
-                let stream = StreamSource::from_uri(uri)?;
+                let stream = StreamSource::from_uri(uri).failure_class(FailureClass::Input)?;
                 if matches!(stream, StreamSource::Quic(_)) {
-                    anyhow::bail!("QUIC hints source is not supported in CLI mode.");
+                    return Err(anyhow::anyhow!("QUIC hints source is not supported in CLI mode."))
+                        .failure_class(FailureClass::Input);
                 }
                 Some(stream)
             }
@@ -136,9 +185,33 @@ impl ZiskStats {
             self.emulator
         };
 
-        let (world_rank, n_processes, stats) =
+        let (world_rank, n_processes, stats, summary) =
             if emulator { self.run_emu(stdin)? } else { self.run_asm(stdin, hints_stream)? };
 
+        if format.is_json() {
+            let airs = match &stats {
+                Some(stats) => Self::air_stats_report(
+                    &stats
+                        .get_inner()
+                        .lock()
+                        .map_err(|e| anyhow::anyhow!("Mutex stats lock poisoned: {e}"))?
+                        .witness_stats,
+                ),
+                None => Vec::new(),
+            };
+            return print_report(
+                "stats",
+                &StatsReport {
+                    world_rank,
+                    n_processes,
+                    execution: summary.map(ExecutionReport::from),
+                    total_collect_ms: airs.iter().map(|a| a.collect_ms).sum(),
+                    total_witness_ms: airs.iter().map(|a| a.witness_ms).sum(),
+                    airs,
+                },
+            );
+        }
+
         if world_rank % 2 == 1 {
             std::thread::sleep(std::time::Duration::from_millis(2000));
         }
@@ -163,7 +236,7 @@ impl ZiskStats {
         Ok(())
     }
 
-    pub fn run_emu(&mut self, stdin: ZiskStdin) -> Result<(i32, i32, Option<ExecutorStatsHandle>)> {
+    pub fn run_emu(&mut self, stdin: ZiskStdin) -> Result<StatsRun> {
         let mut prover_options = BackendProverOpts::default();
 
         if !self.no_packed {
@@ -183,25 +256,31 @@ impl ZiskStats {
             .emu()
             .witness()
             .with_prover_options(prover_options)
-            .build()?;
-
-        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())?;
-        prover.setup(&guest_program).run()?;
-
-        prover.stats(
-            &guest_program,
-            stdin,
-            self.debug.clone(),
-            self.minimal_memory,
-            self.mpi_node.map(|n| n as u32),
-        )
+            .build()
+            .failure_class(FailureClass::Setup)?;
+
+        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())
+            .failure_class(FailureClass::Input)?;
+        prover.setup(&guest_program).run().failure_class(FailureClass::Setup)?;
+
+        let (world_rank, n_processes, stats) = prover
+            .stats(
+                &guest_program,
+                stdin,
+                self.debug.clone(),
+                self.minimal_memory,
+                self.mpi_node.map(|n| n as u32),
+            )
+            .failure_class(FailureClass::Execution)?;
+        let summary = stats.as_ref().map(|_| prover.get_execution_summary()).transpose()?;
+        Ok((world_rank, n_processes, stats, summary))
     }
 
     pub fn run_asm(
         &mut self,
         stdin: ZiskStdin,
         hints_stream: Option<StreamSource>,
-    ) -> Result<(i32, i32, Option<ExecutorStatsHandle>)> {
+    ) -> Result<StatsRun> {
         let mut prover_options = BackendProverOpts::default().verbose(self.verbose);
 
         if !self.no_packed {
@@ -237,20 +316,44 @@ impl ZiskStats {
             .asm()
             .witness()
             .with_prover_options(prover_options)
-            .build()?;
+            .build()
+            .failure_class(FailureClass::Setup)?;
 
-        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())?;
+        let guest_program = GuestProgram::from_uri(self.elf.as_ref().unwrap().to_str().unwrap())
+            .failure_class(FailureClass::Input)?;
         if hints_stream.is_some() {
-            prover.setup(&guest_program).with_hints().run()?;
+            prover.setup(&guest_program).with_hints().run().failure_class(FailureClass::Setup)?;
         } else {
-            prover.setup(&guest_program).run()?;
+            prover.setup(&guest_program).run().failure_class(FailureClass::Setup)?;
         }
 
         if let Some(hints_stream) = hints_stream {
-            prover.register_hints_stream(hints_stream)?;
+            prover.register_hints_stream(hints_stream).failure_class(FailureClass::Input)?;
         }
         let mpi_node = self.mpi_node.map(|n| n as u32);
-        prover.stats(&guest_program, stdin, self.debug.clone(), self.minimal_memory, mpi_node)
+        let (world_rank, n_processes, stats) = prover
+            .stats(&guest_program, stdin, self.debug.clone(), self.minimal_memory, mpi_node)
+            .failure_class(FailureClass::Execution)?;
+        let summary = stats.as_ref().map(|_| prover.get_execution_summary()).transpose()?;
+        Ok((world_rank, n_processes, stats, summary))
+    }
+
+    /// Per-instance stats sorted by AIR, for the `--format json` document.
+    fn air_stats_report(air_stats: &HashMap<usize, Stats>) -> Vec<AirStatsReport> {
+        let mut sorted_stats: Vec<&Stats> = air_stats.values().collect();
+        sorted_stats.sort_by_key(|stat| (stat.airgroup_id, stat.air_id));
+
+        sorted_stats
+            .into_iter()
+            .map(|stat| AirStatsReport {
+                airgroup_id: stat.airgroup_id,
+                air_id: stat.air_id,
+                name: Self::air_name(stat.airgroup_id, stat.air_id),
+                chunks: stat.num_chunks,
+                collect_ms: stat.collect_duration,
+                witness_ms: stat.witness_duration as u64,
+            })
+            .collect()
     }
 
     /// Prints stats individually and grouped, with aligned columns.
@@ -1,9 +1,11 @@
 use anyhow::Result;
 use zisk_build::ZISK_VERSION_MESSAGE;
 
+mod check_hints;
 mod clean_cache;
 mod convert_input;
 
+pub use check_hints::ZiskCheckHints;
 pub use clean_cache::ZiskCleanCache;
 pub use convert_input::ZiskConvertInput;
 
@@ -17,6 +19,7 @@ pub struct ZiskUtils {
 
 #[derive(clap::Subcommand)]
 pub enum ZiskUtilsCommand {
+    CheckHints(ZiskCheckHints),
     CleanCache(ZiskCleanCache),
     #[command(hide = true)]
     ConvertInput(ZiskConvertInput),
@@ -25,6 +28,7 @@ pub enum ZiskUtilsCommand {
 impl ZiskUtils {
     pub fn run(&mut self) -> Result<()> {
         match &mut self.command {
+            ZiskUtilsCommand::CheckHints(cmd) => cmd.run(),
             ZiskUtilsCommand::CleanCache(cmd) => cmd.run(),
             ZiskUtilsCommand::ConvertInput(cmd) => cmd.run(),
         }
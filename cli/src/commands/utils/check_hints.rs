@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use colored::Colorize;
+use zisk_build::ZISK_VERSION_MESSAGE;
+use zisk_prover_backend::{setup_logger, GuestProgram, HintHandlers, HintPluginConfig};
+
+use crate::{
+    common::detect_current_project_elf,
+    output::{FailureClass, WithFailureClass},
+    ux::{print_banner, print_banner_command, print_banner_field},
+};
+
+#[derive(clap::Args)]
+#[command(author, about, long_about = None, version = ZISK_VERSION_MESSAGE)]
+/// Check that a precompile hints file matches the precompile calls the guest actually makes
+pub struct ZiskCheckHints {
+    /// Path to the program ELF file. If omitted, the ELF is auto-detected from the current project
+    #[arg(short = 'e', long)]
+    pub elf: Option<PathBuf>,
+
+    /// Precompiles hints file to check, as a path or `file://` URI. Guest inputs are taken from
+    /// its input hints
+    #[arg(long)]
+    pub hints: String,
+
+    /// Serve the custom hint CODE with the plugin library at PATH, as `CODE=PATH`. May be repeated
+    #[arg(long = "hint-plugin", value_name = "CODE=PATH", value_parser = parse_hint_plugin)]
+    pub hint_plugins: Vec<HintPluginConfig>,
+
+    /// Verbosity (-v, -vv)
+    #[arg(short = 'v', long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+}
+
+impl ZiskCheckHints {
+    pub fn run(&mut self) -> Result<()> {
+        setup_logger(self.verbose.into());
+
+        if self.elf.is_none() {
+            self.elf = match detect_current_project_elf().failure_class(FailureClass::Input)? {
+                Some(elf) => Some(elf),
+                None => {
+                    return Err(anyhow::anyhow!(
+                        "No ELF file provided, and could not detect a project ELF in the current directory. Please provide an ELF file with --elf."
+                    ))
+                    .failure_class(FailureClass::Input);
+                }
+            };
+        }
+        let elf = self.elf.as_ref().unwrap();
+        let hints = PathBuf::from(self.hints.strip_prefix("file://").unwrap_or(&self.hints));
+
+        print_banner();
+        print_banner_command("Check Hints");
+        print_banner_field("Elf", elf.display());
+        print_banner_field("Prec. Hints", hints.display());
+        for plugin in &self.hint_plugins {
+            print_banner_field(
+                "Hint Plugin",
+                format!("{:#x} {}", plugin.hint_code, plugin.path.display()),
+            );
+        }
+
+        let handlers = HintHandlers::default()
+            .with_plugins(&self.hint_plugins)
+            .failure_class(FailureClass::Input)?;
+
+        let guest_program =
+            GuestProgram::from_uri(elf.to_str().unwrap()).failure_class(FailureClass::Input)?;
+
+        let report =
+            guest_program.check_hints(&hints, &handlers).failure_class(FailureClass::Input)?;
+
+        println!();
+        match &report.mismatch {
+            None => {
+                println!(
+                    "{}",
+                    format!(
+                        "\u{2713} {} hints match the {} precompile calls of the guest",
+                        report.hints, report.precompiles
+                    )
+                    .bright_green()
+                    .bold()
+                );
+                Ok(())
+            }
+            Some(mismatch) => {
+                println!("{}", "\u{2717} Hints file does not match the guest".bright_red().bold());
+                println!("{mismatch}");
+                Err(anyhow::anyhow!("hint #{} does not match the guest execution", mismatch.index))
+                    .failure_class(FailureClass::Input)
+            }
+        }
+    }
+}
+
+/// Parses a `--hint-plugin` value, `CODE=PATH` with a decimal or `0x` hexadecimal code
+fn parse_hint_plugin(value: &str) -> Result<HintPluginConfig, String> {
+    let (code, path) =
+        value.split_once('=').ok_or_else(|| format!("expected CODE=PATH, found {value:?}"))?;
+    let code = match code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => code.parse(),
+    }
+    .map_err(|e| format!("invalid hint code {code:?}: {e}"))?;
+    if path.is_empty() {
+        return Err(format!("missing plugin path for hint code {code:#x}"));
+    }
+    Ok(HintPluginConfig::new(code, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hint_plugin() {
+        assert_eq!(
+            parse_hint_plugin("0xA000=/opt/libhints.so").unwrap(),
+            HintPluginConfig::new(0xA000, "/opt/libhints.so")
+        );
+        assert_eq!(
+            parse_hint_plugin("40961=lib.so").unwrap(),
+            HintPluginConfig::new(0xA001, "lib.so")
+        );
+        assert!(parse_hint_plugin("0xA000").is_err());
+        assert!(parse_hint_plugin("0xA000=").is_err());
+        assert!(parse_hint_plugin("hint=lib.so").is_err());
+    }
+}
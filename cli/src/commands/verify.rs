@@ -1,9 +1,14 @@
 use anyhow::Result;
 use colored::Colorize;
+use serde::Serialize;
 use zisk_build::ZISK_VERSION_MESSAGE;
 use zisk_common::{Proof, ProofKind};
 use zisk_prover_backend::setup_logger;
 
+use crate::output::{
+    print_report, program_vk_hex, publics_hex, FailureClass, OutputFormat, WithFailureClass,
+};
+
 #[derive(clap::Args)]
 #[command(author, about, long_about = None, version = ZISK_VERSION_MESSAGE)]
 /// Verify a proof
@@ -17,30 +22,61 @@ pub struct ZiskVerify {
     pub verbose: u8,
 }
 
+/// `--format json` document for the verify command.
+#[derive(Serialize)]
+struct VerifyReport<'a> {
+    proof_path: &'a str,
+    proof_kind: ProofKind,
+    program_vk: Vec<String>,
+    publics: String,
+    time_ms: u128,
+}
+
 impl ZiskVerify {
-    pub fn run(&self) -> Result<()> {
+    pub fn run(&self, format: OutputFormat) -> Result<()> {
         setup_logger(self.verbose.into());
 
-        tracing::info!(
-            "{}",
-            format!("{} ZiskVerify", format!("{: >12}", "Command").bright_green().bold())
-        );
-        tracing::info!("");
+        if !format.is_json() {
+            tracing::info!(
+                "{}",
+                format!("{} ZiskVerify", format!("{: >12}", "Command").bright_green().bold())
+            );
+            tracing::info!("");
+        }
 
         let start = std::time::Instant::now();
 
         let proof = Proof::load(&self.proof)
-            .map_err(|e| anyhow::anyhow!("Error loading proof from {}: {}", &self.proof, e))?;
+            .map_err(|e| anyhow::anyhow!("Error loading proof from {}: {}", &self.proof, e))
+            .failure_class(FailureClass::Input)?;
 
         let proof_type = match proof.kind() {
             ProofKind::VadcopFinal | ProofKind::VadcopFinalMinimal => "STARK",
             ProofKind::Plonk => "PLONK",
+            ProofKind::Groth16 => "GROTH16",
         };
 
         let result = proof.verify();
 
         let elapsed = start.elapsed();
 
+        if format.is_json() {
+            // A failed verification is reported through the error document instead.
+            if result.is_ok() {
+                print_report(
+                    "verify",
+                    &VerifyReport {
+                        proof_path: &self.proof,
+                        proof_kind: proof.kind(),
+                        program_vk: program_vk_hex(&proof.program_vk),
+                        publics: publics_hex(&proof.publics),
+                        time_ms: elapsed.as_millis(),
+                    },
+                )?;
+            }
+            return result.failure_class(FailureClass::Verification);
+        }
+
         if result.is_err() {
             tracing::info!(
                 "{}",
@@ -57,6 +93,6 @@ impl ZiskVerify {
         tracing::info!("      time: {} milliseconds", elapsed.as_millis());
         tracing::info!("{}", "----------------------------".bright_green().bold());
 
-        result
+        result.failure_class(FailureClass::Verification)
     }
 }
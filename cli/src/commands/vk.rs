@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use rom_setup::program_vk_from_elf;
+use serde::Serialize;
+use zisk_build::ZISK_VERSION_MESSAGE;
+
+use crate::common::detect_current_project_elf;
+use crate::output::{
+    print_report, program_vk_bytes32, program_vk_hex, FailureClass, OutputFormat, WithFailureClass,
+};
+
+#[derive(clap::Args)]
+#[command(author, about, long_about = None, version = ZISK_VERSION_MESSAGE)]
+/// Compute the verification key of a program, without the proving key
+pub struct ZiskVk {
+    /// Path to the program ELF file. If omitted, the ELF is auto-detected from the current project
+    pub elf: Option<PathBuf>,
+}
+
+/// `--format json` document for the vk command.
+#[derive(Serialize)]
+struct VkReport {
+    elf: PathBuf,
+    program_vk: Vec<String>,
+    program_vk_bytes32: String,
+}
+
+impl ZiskVk {
+    pub fn run(&self, format: OutputFormat) -> Result<()> {
+        let elf_path = match &self.elf {
+            Some(elf) => elf.clone(),
+            None => match detect_current_project_elf()? {
+                Some(elf) => elf,
+                None => {
+                    return Err(anyhow::anyhow!("No ELF file provided, and could not detect a project ELF in the current directory. Please provide an ELF file path."))
+                        .failure_class(FailureClass::Input);
+                }
+            },
+        };
+
+        let elf = std::fs::read(&elf_path)
+            .map_err(|e| anyhow::anyhow!("Error reading ELF file {}: {}", elf_path.display(), e))
+            .failure_class(FailureClass::Input)?;
+
+        let program_vk = program_vk_from_elf(&elf).failure_class(FailureClass::Input)?;
+
+        if format.is_json() {
+            return print_report(
+                "vk",
+                &VkReport {
+                    elf: elf_path,
+                    program_vk: program_vk_hex(&program_vk),
+                    program_vk_bytes32: program_vk_bytes32(&program_vk),
+                },
+            );
+        }
+
+        println!("{}", program_vk_bytes32(&program_vk));
+        Ok(())
+    }
+}
@@ -10,7 +10,7 @@ use crate::ux::{print_banner, print_banner_command};
 
 #[derive(clap::Args)]
 #[command(author, about, long_about = None, version = ZISK_VERSION_MESSAGE)]
-/// Generate a PLONK/minimal proof from a STARK (VADCOP) proof
+/// Generate a PLONK/Groth16/minimal proof from a STARK (VADCOP) proof
 pub struct ZiskWrap {
     /// Path to the STARK (VADCOP) proof file
     #[arg(short = 'p', long)]
@@ -20,18 +20,23 @@ pub struct ZiskWrap {
     #[arg(short = 'k', long)]
     pub proving_key: Option<PathBuf>,
 
-    /// Path to a precomputed PLONK proving key
+    /// Path to a precomputed SNARK proving key, set up for PLONK or Groth16
     #[arg(short = 'w', long)]
     pub proving_key_plonk: Option<PathBuf>,
 
-    /// Smaller STARK proof with reduced size. Mutually exclusive with --plonk
-    #[arg(short = 'c', long, conflicts_with = "plonk")]
+    /// Smaller STARK proof with reduced size. Mutually exclusive with --plonk and --groth16
+    #[arg(short = 'c', long, conflicts_with_all = ["plonk", "groth16"])]
     pub minimal: bool,
 
     /// PLONK proof for on-chain verification via the EVM verifier. Mutually exclusive with --minimal
-    #[arg(long, conflicts_with = "minimal")]
+    #[arg(long, conflicts_with_all = ["minimal", "groth16"])]
     pub plonk: bool,
 
+    /// Groth16 proof for on-chain verification via the EVM verifier, cheaper to verify than
+    /// PLONK. Requires a SNARK proving key set up for Groth16
+    #[arg(long, conflicts_with_all = ["minimal", "plonk"])]
+    pub groth16: bool,
+
     /// Output file path
     #[arg(short = 'o', long)]
     pub output: Option<PathBuf>,
@@ -56,9 +61,19 @@ impl ZiskWrap {
             anyhow::anyhow!("Failed to load Proof from file {}: {}", self.proof.display(), e)
         })?;
 
+        let proof_kind = if self.plonk {
+            ProofKind::Plonk
+        } else if self.groth16 {
+            ProofKind::Groth16
+        } else if self.minimal {
+            ProofKind::VadcopFinalMinimal
+        } else {
+            anyhow::bail!("One of --plonk, --groth16 or --minimal must be specified.");
+        };
+
         let mut prover_options = BackendProverOpts::default().verbose(self.verbose);
-        if self.plonk {
-            prover_options = prover_options.plonk(true);
+        if proof_kind.is_snark() {
+            prover_options = prover_options.snark(proof_kind, true);
         }
         if let Some(ref path) = self.proving_key {
             prover_options = prover_options.proving_key(path.clone());
@@ -74,28 +89,22 @@ impl ZiskWrap {
         let prover =
             ProverClientBuilder::new().emu().with_prover_options(prover_options).build()?;
 
-        let proof_kind = if self.plonk {
-            ProofKind::Plonk
-        } else if self.minimal {
-            ProofKind::VadcopFinalMinimal
-        } else {
-            anyhow::bail!("Either --plonk or --minimal must be specified.");
-        };
-
         let result = prover.wrap_proof(&zisk_proof, proof_kind).run()?;
 
-        let output_file = self.output.clone().unwrap_or_else(|| {
-            if self.plonk {
-                PathBuf::from("vadcop_final_proof_plonk.bin")
-            } else {
-                PathBuf::from("vadcop_final_proof_minimal.bin")
-            }
+        let output_file = self.output.clone().unwrap_or_else(|| match proof_kind {
+            ProofKind::Plonk => PathBuf::from("vadcop_final_proof_plonk.bin"),
+            ProofKind::Groth16 => PathBuf::from("vadcop_final_proof_groth16.bin"),
+            _ => PathBuf::from("vadcop_final_proof_minimal.bin"),
         });
         result.save_proof(&output_file).map_err(|e| {
             anyhow::anyhow!("Failed to save proof to {}: {}", output_file.display(), e)
         })?;
 
-        let kind_label = if self.plonk { "PLONK" } else { "minimal" };
+        let kind_label = match proof_kind {
+            ProofKind::Plonk => "PLONK",
+            ProofKind::Groth16 => "Groth16",
+            _ => "minimal",
+        };
         info!("Final {} proof generated.", kind_label);
 
         Ok(())
@@ -1,5 +1,6 @@
 pub mod commands;
 pub mod common;
+pub mod output;
 pub mod ux;
 
 use anyhow::{Context, Result};
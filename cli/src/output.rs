@@ -0,0 +1,215 @@
+//! Machine-readable output for `cargo-zisk`.
+//!
+//! With `--format json` the `execute`, `prove`, `stats`, `verify`, `vk` and `check-setup`
+//! commands skip their banners and summaries and instead print exactly one JSON document on
+//! stdout when they finish. The other commands reject `--format json` as an `input` failure. Stdout is reserved for that document: logs and any other output of
+//! the process and its children go to stderr. Successful runs produce `{"command": ..., "status": "ok", ...}`
+//! followed by the command-specific fields; failures produce `{"command": ..., "status":
+//! "error", "error": {...}}`.
+//!
+//! Independently of the output format, every failure is tagged with a [`FailureClass`] and the
+//! process exits with that class' stable exit code:
+//!
+//! | Class          | Exit code | Meaning                                               |
+//! |----------------|-----------|-------------------------------------------------------|
+//! | `internal`     | 1         | Unclassified failure                                  |
+//! | (usage)        | 2         | Invalid command line, reported by clap                |
+//! | `input`        | 3         | ELF, inputs, hints or proof file missing or invalid   |
+//! | `setup`        | 4         | Proving key or program setup missing or invalid       |
+//! | `execution`    | 5         | Guest execution failed                                |
+//! | `proving`      | 6         | Proof generation failed                               |
+//! | `verification` | 7         | The proof did not verify                              |
+
+use std::fmt;
+use std::fs::File;
+use std::io::Write;
+use std::os::fd::{AsRawFd, FromRawFd};
+use std::sync::OnceLock;
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use zisk_common::{ProgramVK, PublicValues, StatsCostPerType};
+
+/// Output format selected with the global `--format` flag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-oriented banners and colored summaries.
+    #[default]
+    Text,
+    /// A single JSON document on stdout.
+    Json,
+}
+
+impl OutputFormat {
+    pub fn is_json(self) -> bool {
+        self == OutputFormat::Json
+    }
+}
+
+/// Failure classes reported by `cargo-zisk`, each with a stable exit code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureClass {
+    Internal,
+    Input,
+    Setup,
+    Execution,
+    Proving,
+    Verification,
+}
+
+impl FailureClass {
+    pub fn exit_code(self) -> i32 {
+        match self {
+            FailureClass::Internal => 1,
+            FailureClass::Input => 3,
+            FailureClass::Setup => 4,
+            FailureClass::Execution => 5,
+            FailureClass::Proving => 6,
+            FailureClass::Verification => 7,
+        }
+    }
+
+    /// Class attached to `err` with [`WithFailureClass`], or `Internal` if there is none.
+    pub fn of(err: &anyhow::Error) -> Self {
+        err.downcast_ref::<FailureClass>().copied().unwrap_or(FailureClass::Internal)
+    }
+}
+
+impl fmt::Display for FailureClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            FailureClass::Internal => "internal error",
+            FailureClass::Input => "invalid input",
+            FailureClass::Setup => "setup error",
+            FailureClass::Execution => "execution failed",
+            FailureClass::Proving => "proving failed",
+            FailureClass::Verification => "verification failed",
+        };
+        f.write_str(msg)
+    }
+}
+
+/// Tags an error with a [`FailureClass`] so `main` can pick the exit code.
+pub trait WithFailureClass<T> {
+    fn failure_class(self, class: FailureClass) -> anyhow::Result<T>;
+}
+
+impl<T, E> WithFailureClass<T> for Result<T, E>
+where
+    E: Into<anyhow::Error>,
+{
+    fn failure_class(self, class: FailureClass) -> anyhow::Result<T> {
+        self.map_err(|e| e.into().context(class))
+    }
+}
+
+/// Execution cost, total and broken down per type.
+#[derive(Serialize)]
+pub struct CostReport {
+    pub total: u64,
+    #[serde(flatten)]
+    pub per_type: StatsCostPerType,
+}
+
+impl From<&StatsCostPerType> for CostReport {
+    fn from(cost: &StatsCostPerType) -> Self {
+        Self { total: cost.total_cost(), per_type: cost.clone() }
+    }
+}
+
+/// Program verification key as `0x`-prefixed 64-bit words.
+pub fn program_vk_hex(program_vk: &ProgramVK) -> Vec<String> {
+    program_vk.vk.iter().map(|w| format!("{w:#018x}")).collect()
+}
+
+/// Program verification key as a `0x`-prefixed `bytes32`, its words big-endian as the Solidity
+/// verifier takes it.
+pub fn program_vk_bytes32(program_vk: &ProgramVK) -> String {
+    let words: String = program_vk.vk.iter().map(|w| format!("{w:016x}")).collect();
+    format!("0x{words}")
+}
+
+/// Public outputs as a `0x`-prefixed hex string.
+pub fn publics_hex(publics: &PublicValues) -> String {
+    format!("0x{}", hex::encode(publics.as_bytes()))
+}
+
+/// Original stdout, where the JSON document goes once [`reserve_stdout`] has been called.
+static REPORT_OUT: OnceLock<File> = OnceLock::new();
+
+/// Reserves stdout for the JSON document: from now on, anything else written to stdout, such as
+/// logs, banners or the output of child processes, goes to stderr instead.
+pub fn reserve_stdout() -> std::io::Result<()> {
+    std::io::stdout().flush()?;
+    let stdout = std::io::stdout().as_raw_fd();
+
+    // SAFETY: `dup` and `dup2` only operate on the process descriptor table, and the duplicate
+    // returned by `dup` is owned by the `File` stored below.
+    let report = unsafe { libc::dup(stdout) };
+    if report < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let report = unsafe { File::from_raw_fd(report) };
+    if unsafe { libc::dup2(std::io::stderr().as_raw_fd(), stdout) } < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    let _ = REPORT_OUT.set(report);
+    Ok(())
+}
+
+fn write_document(doc: &Value) {
+    match REPORT_OUT.get() {
+        Some(mut out) => {
+            let _ = writeln!(out, "{doc}");
+        }
+        None => println!("{doc}"),
+    }
+}
+
+/// Print the success document for `command`.
+pub fn print_report(command: &str, report: &impl Serialize) -> anyhow::Result<()> {
+    let mut doc = Map::new();
+    doc.insert("command".to_owned(), json!(command));
+    doc.insert("status".to_owned(), json!("ok"));
+    match serde_json::to_value(report)? {
+        Value::Object(fields) => doc.extend(fields),
+        Value::Null => {}
+        other => anyhow::bail!("report for {command} must serialize to an object, got {other}"),
+    }
+    write_document(&Value::Object(doc));
+    Ok(())
+}
+
+/// Print the failure document for `command`.
+pub fn print_error(command: &str, err: &anyhow::Error) {
+    let class = FailureClass::of(err);
+    let causes: Vec<String> = err.chain().skip(1).map(|c| c.to_string()).collect();
+    let doc = json!({
+        "command": command,
+        "status": "error",
+        "error": {
+            "class": class,
+            "exit_code": class.exit_code(),
+            "message": err.to_string(),
+            "causes": causes,
+        },
+    });
+    write_document(&doc);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failure_class_survives_outer_context() {
+        let err = Err::<(), _>(anyhow::anyhow!("boom"))
+            .failure_class(FailureClass::Proving)
+            .map_err(|e| e.context("Error executing Prove command"))
+            .unwrap_err();
+        assert_eq!(FailureClass::of(&err), FailureClass::Proving);
+        assert_eq!(FailureClass::of(&anyhow::anyhow!("plain")), FailureClass::Internal);
+    }
+}
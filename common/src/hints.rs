@@ -87,6 +87,11 @@ pub const HINT_BLS12_381_G2_MSM: u32 = 0x0406;
 pub const HINT_BLS12_381_PAIRING_CHECK: u32 = 0x040A;
 pub const HINT_BLS12_381_FP_TO_G1: u32 = 0x0410;
 pub const HINT_BLS12_381_FP2_TO_G2: u32 = 0x0411;
+pub const HINT_BLS12_381_HASH_TO_G1: u32 = 0x0412;
+pub const HINT_BLS12_381_HASH_TO_G2: u32 = 0x0413;
+pub const HINT_BLS_VERIFY: u32 = 0x0420;
+pub const HINT_BLS_AGGREGATE_VERIFY: u32 = 0x0421;
+pub const HINT_BLS_FAST_AGGREGATE_VERIFY: u32 = 0x0422;
 
 // Modular exponentiation hint codes
 pub const HINT_MODEXP: u32 = 0x0500;
@@ -103,6 +108,9 @@ pub const HINT_BLAKE2B_COMPRESS: u32 = 0x0800;
 // RIPEMD-160 hint codes
 pub const HINT_RIPEMD160: u32 = 0x0900;
 
+// RSA hint codes
+pub const HINT_RSA_VERIFY: u32 = 0x0A00;
+
 /// Control code variants for stream control.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u32)]
@@ -188,6 +196,16 @@ pub enum BuiltInHint {
     Bls12_381FpToG1 = HINT_BLS12_381_FP_TO_G1,
     /// BLS12-381 map field element to G2.
     Bls12_381Fp2ToG2 = HINT_BLS12_381_FP2_TO_G2,
+    /// BLS12-381 hash message to G1.
+    Bls12_381HashToG1 = HINT_BLS12_381_HASH_TO_G1,
+    /// BLS12-381 hash message to G2.
+    Bls12_381HashToG2 = HINT_BLS12_381_HASH_TO_G2,
+    /// BLS signature verification.
+    BlsVerify = HINT_BLS_VERIFY,
+    /// BLS aggregate signature verification over distinct messages.
+    BlsAggregateVerify = HINT_BLS_AGGREGATE_VERIFY,
+    /// BLS aggregate signature verification over a common message.
+    BlsFastAggregateVerify = HINT_BLS_FAST_AGGREGATE_VERIFY,
 
     // Modular exponentiation hint types.
     /// Modular exponentiation.
@@ -208,6 +226,10 @@ pub enum BuiltInHint {
     // RIPEMD-160 hint types.
     /// RIPEMD-160 hash (pure software implementation, no ZK circuit witness).
     Ripemd160 = HINT_RIPEMD160,
+
+    // RSA hint types.
+    /// RSA PKCS#1 v1.5 or PSS signature verification over a SHA-256 digest.
+    RsaVerify = HINT_RSA_VERIFY,
 }
 
 impl Display for BuiltInHint {
@@ -234,6 +256,11 @@ impl Display for BuiltInHint {
             BuiltInHint::Bls12_381PairingCheck => "BLS12_381_PAIRING_CHECK",
             BuiltInHint::Bls12_381FpToG1 => "BLS12_381_FP_TO_G1",
             BuiltInHint::Bls12_381Fp2ToG2 => "BLS12_381_FP2_TO_G2",
+            BuiltInHint::Bls12_381HashToG1 => "BLS12_381_HASH_TO_G1",
+            BuiltInHint::Bls12_381HashToG2 => "BLS12_381_HASH_TO_G2",
+            BuiltInHint::BlsVerify => "BLS_VERIFY",
+            BuiltInHint::BlsAggregateVerify => "BLS_AGGREGATE_VERIFY",
+            BuiltInHint::BlsFastAggregateVerify => "BLS_FAST_AGGREGATE_VERIFY",
             // Modular Exponentiation Hint
             BuiltInHint::ModExp => "MODEXP",
             // KZG Hint
@@ -244,6 +271,8 @@ impl Display for BuiltInHint {
             BuiltInHint::Blake2bCompress => "BLAKE2B_COMPRESS",
             // RIPEMD-160 Hint
             BuiltInHint::Ripemd160 => "RIPEMD160",
+            // RSA Hint
+            BuiltInHint::RsaVerify => "RSA_VERIFY",
         };
 
         write!(f, "{} ({:#x})", name, *self as u32)
@@ -276,6 +305,11 @@ impl TryFrom<u32> for BuiltInHint {
             HINT_BLS12_381_PAIRING_CHECK => Ok(Self::Bls12_381PairingCheck),
             HINT_BLS12_381_FP_TO_G1 => Ok(Self::Bls12_381FpToG1),
             HINT_BLS12_381_FP2_TO_G2 => Ok(Self::Bls12_381Fp2ToG2),
+            HINT_BLS12_381_HASH_TO_G1 => Ok(Self::Bls12_381HashToG1),
+            HINT_BLS12_381_HASH_TO_G2 => Ok(Self::Bls12_381HashToG2),
+            HINT_BLS_VERIFY => Ok(Self::BlsVerify),
+            HINT_BLS_AGGREGATE_VERIFY => Ok(Self::BlsAggregateVerify),
+            HINT_BLS_FAST_AGGREGATE_VERIFY => Ok(Self::BlsFastAggregateVerify),
             // Modular Exponentiation Hint
             HINT_MODEXP => Ok(Self::ModExp),
             // KZG Hint
@@ -286,6 +320,8 @@ impl TryFrom<u32> for BuiltInHint {
             HINT_BLAKE2B_COMPRESS => Ok(Self::Blake2bCompress),
             // RIPEMD-160 Hint
             HINT_RIPEMD160 => Ok(Self::Ripemd160),
+            // RSA Hint
+            HINT_RSA_VERIFY => Ok(Self::RsaVerify),
             _ => Err(anyhow::anyhow!("Invalid built-in hint code: {:#x}", value)),
         }
     }
@@ -363,6 +399,13 @@ impl HintCode {
             HintCode::BuiltIn(BuiltInHint::Bls12_381PairingCheck) => HINT_BLS12_381_PAIRING_CHECK,
             HintCode::BuiltIn(BuiltInHint::Bls12_381FpToG1) => HINT_BLS12_381_FP_TO_G1,
             HintCode::BuiltIn(BuiltInHint::Bls12_381Fp2ToG2) => HINT_BLS12_381_FP2_TO_G2,
+            HintCode::BuiltIn(BuiltInHint::Bls12_381HashToG1) => HINT_BLS12_381_HASH_TO_G1,
+            HintCode::BuiltIn(BuiltInHint::Bls12_381HashToG2) => HINT_BLS12_381_HASH_TO_G2,
+            HintCode::BuiltIn(BuiltInHint::BlsVerify) => HINT_BLS_VERIFY,
+            HintCode::BuiltIn(BuiltInHint::BlsAggregateVerify) => HINT_BLS_AGGREGATE_VERIFY,
+            HintCode::BuiltIn(BuiltInHint::BlsFastAggregateVerify) => {
+                HINT_BLS_FAST_AGGREGATE_VERIFY
+            }
             // Modular Exponentiation Hint
             HintCode::BuiltIn(BuiltInHint::ModExp) => HINT_MODEXP,
             // KZG Hint
@@ -374,6 +417,8 @@ impl HintCode {
             HintCode::BuiltIn(BuiltInHint::Blake2bCompress) => HINT_BLAKE2B_COMPRESS,
             // RIPEMD-160 Hint
             HintCode::BuiltIn(BuiltInHint::Ripemd160) => HINT_RIPEMD160,
+            // RSA Hint
+            HintCode::BuiltIn(BuiltInHint::RsaVerify) => HINT_RSA_VERIFY,
 
             // Custom Hints
             HintCode::Custom(code) => code,
@@ -0,0 +1,119 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub use zisk_definitions::HOST_ENV_COMMITMENT_OUTPUT_ID;
+
+/// Environment of a guest built with the `host-env` feature of `ziskos`: the time returned by
+/// its clocks, its environment variables and its read-only files.
+///
+/// Set it on the input with [`ZiskStdin::with_host_env`](super::ZiskStdin::with_host_env). The
+/// default environment is at the Unix epoch, without variables or files.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostEnv {
+    unix_time_secs: u64,
+    unix_time_nanos: u32,
+    vars: Vec<(String, String)>,
+    files: Vec<(String, Vec<u8>)>,
+}
+
+impl HostEnv {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the time returned by `ziskos::shims::time`. Times before the Unix epoch are clamped
+    /// to it.
+    #[must_use]
+    pub fn with_time(mut self, time: SystemTime) -> Self {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        self.unix_time_secs = since_epoch.as_secs();
+        self.unix_time_nanos = since_epoch.subsec_nanos();
+        self
+    }
+
+    /// Sets an environment variable, replacing its previous value
+    #[must_use]
+    pub fn with_var(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let key = key.into();
+        self.vars.retain(|(name, _)| *name != key);
+        self.vars.push((key, value.into()));
+        self
+    }
+
+    /// Adds a file at `path`, replacing the previous one. The guest looks it up by this exact
+    /// path.
+    #[must_use]
+    pub fn with_file(mut self, path: impl Into<String>, contents: impl Into<Vec<u8>>) -> Self {
+        let path = path.into();
+        self.files.retain(|(name, _)| *name != path);
+        self.files.push((path, contents.into()));
+        self
+    }
+
+    /// Adds the contents of the host file `host_path` at `path`, see
+    /// [`with_file`](Self::with_file)
+    pub fn with_file_from(
+        self,
+        path: impl Into<String>,
+        host_path: impl AsRef<Path>,
+    ) -> Result<Self> {
+        let contents = std::fs::read(host_path.as_ref())
+            .map_err(|e| anyhow::anyhow!("Failed to read file {:?}: {}", host_path.as_ref(), e))?;
+        Ok(self.with_file(path, contents))
+    }
+
+    /// Encodes the environment as the input item read by the guest
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serde::encode_to_vec(self, bincode::config::standard())
+            .expect("Failed to serialize")
+    }
+
+    /// Commitment of the environment, as written by the guest to the public output slots
+    /// starting at [`HOST_ENV_COMMITMENT_OUTPUT_ID`]: the SHA-256 hash of
+    /// [`to_bytes`](Self::to_bytes)
+    pub fn commitment(&self) -> [u8; 32] {
+        Sha256::digest(self.to_bytes()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_host_env_builders() {
+        let env = HostEnv::new()
+            .with_time(UNIX_EPOCH + Duration::new(1_700_000_000, 5))
+            .with_var("A", "1")
+            .with_var("B", "2")
+            .with_var("A", "3")
+            .with_file("config.toml", "x = 1")
+            .with_file("config.toml", "x = 2");
+
+        assert_eq!(env.unix_time_secs, 1_700_000_000);
+        assert_eq!(env.unix_time_nanos, 5);
+        assert_eq!(env.vars, [("B".into(), "2".into()), ("A".into(), "3".into())]);
+        assert_eq!(env.files, [("config.toml".into(), b"x = 2".to_vec())]);
+
+        // Times before the epoch are clamped to it
+        let before = HostEnv::new().with_time(UNIX_EPOCH - Duration::from_secs(1));
+        assert_eq!(before, HostEnv::new());
+    }
+
+    #[test]
+    fn test_host_env_commitment() {
+        let env = HostEnv::new().with_var("A", "1");
+        let expected: [u8; 32] = Sha256::digest(env.to_bytes()).into();
+        assert_eq!(env.commitment(), expected);
+        assert_ne!(env.commitment(), HostEnv::new().commitment());
+
+        let (decoded, _): (HostEnv, usize) =
+            bincode::serde::decode_from_slice(&env.to_bytes(), bincode::config::standard())
+                .unwrap();
+        assert_eq!(decoded, env);
+    }
+}
@@ -1,3 +1,7 @@
+mod host_env;
+mod public_inputs;
 mod zisk_stdin;
 
+pub use host_env::*;
+pub use public_inputs::*;
 pub use zisk_stdin::*;
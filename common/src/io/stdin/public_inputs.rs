@@ -0,0 +1,37 @@
+//! Public input items and the input commitment computed from them by the guest
+
+use serde::{Deserialize, Serialize};
+use ziskos_hints::input_commitment::{fold_poseidon2, fold_sha256};
+
+pub use zisk_definitions::{INPUT_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_SIZE, PUBLIC_INPUT_FLAG};
+
+/// Hash function the guest folds the public inputs with
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputCommitmentHash {
+    /// SHA-256, with the `input-commitment` feature of `ziskos`
+    #[default]
+    Sha256,
+    /// Poseidon2 over Goldilocks, with the `input-commitment-poseidon2` feature of `ziskos`
+    Poseidon2,
+}
+
+/// Input commitment of the given public input items, in the order the guest reads them.
+///
+/// Starting from 32 zero bytes, each item is folded as `c = sha256(c || sha256(item))` with
+/// SHA-256, or `c = compress(c, hash_bytes(item))` with Poseidon2, by the same functions that the
+/// guest uses. This is the value that the guest commits and that [`PublicValues::input_commitment`] returns.
+///
+/// [`PublicValues::input_commitment`]: crate::PublicValues::input_commitment
+pub fn input_commitment<I, T>(hash: InputCommitmentHash, public_inputs: I) -> [u8; 32]
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let fold = match hash {
+        InputCommitmentHash::Sha256 => fold_sha256,
+        InputCommitmentHash::Poseidon2 => fold_poseidon2,
+    };
+    public_inputs
+        .into_iter()
+        .fold([0u8; INPUT_COMMITMENT_SIZE], |commitment, item| fold(&commitment, item.as_ref()))
+}
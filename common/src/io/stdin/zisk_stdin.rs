@@ -1,12 +1,43 @@
 use anyhow::Result;
 use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
 use std::io::{Cursor, Read};
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
+use super::{input_commitment, HostEnv, InputCommitmentHash, PUBLIC_INPUT_FLAG};
+use crate::{ProgramVK, Proof, ProofBody, PROGRAM_VK_LEN};
+use zisk_verifier::VADCOP_VK_LEN_WORDS;
+
+/// Size in bytes of the seed of the guest random number generator
+pub const RNG_SEED_SIZE: usize = 32;
+
+pub use zisk_definitions::RNG_SEED_COMMITMENT_OUTPUT_ID;
+
+/// Commitment of a guest random number generator seed, as written by the guest to the public
+/// output slots starting at [`RNG_SEED_COMMITMENT_OUTPUT_ID`]: the SHA-256 hash of the seed
+pub fn rng_seed_commitment(seed: &[u8; RNG_SEED_SIZE]) -> [u8; 32] {
+    Sha256::digest(seed).into()
+}
+
+/// Input items consumed by `ziskos` before the program runs, each set at most once and always
+/// emitted in this order, before the items of the program
+#[derive(Default, Clone)]
+struct Frames {
+    rng_seed: Option<Vec<u8>>,
+    host_env: Option<Vec<u8>>,
+}
+
+impl Frames {
+    fn encode(&self) -> Vec<u8> {
+        [&self.rng_seed, &self.host_env].into_iter().flatten().flatten().copied().collect()
+    }
+}
+
 struct Inner {
     data: Mutex<Vec<u8>>,
     cursor: Mutex<Cursor<Vec<u8>>>,
+    frames: Mutex<Frames>,
 }
 
 #[derive(Clone)]
@@ -26,13 +57,35 @@ impl ZiskStdin {
             inner: Arc::new(Inner {
                 data: Mutex::new(Vec::new()),
                 cursor: Mutex::new(Cursor::new(Vec::new())),
+                frames: Mutex::new(Frames::default()),
             }),
         }
     }
 
     pub fn from_vec(data: Vec<u8>) -> Self {
         let cursor = Cursor::new(data.clone());
-        Self { inner: Arc::new(Inner { data: Mutex::new(data), cursor: Mutex::new(cursor) }) }
+        Self {
+            inner: Arc::new(Inner {
+                data: Mutex::new(data),
+                cursor: Mutex::new(cursor),
+                frames: Mutex::new(Frames::default()),
+            }),
+        }
+    }
+
+    /// Returns a copy with its own buffer and read position. Unlike [`Clone::clone`], which
+    /// shares them, writes to the copy do not change `self`.
+    pub fn deep_clone(&self) -> Self {
+        let data = self.inner.data.lock().unwrap().clone();
+        let cursor = self.inner.cursor.lock().unwrap().clone();
+        let frames = self.inner.frames.lock().unwrap().clone();
+        Self {
+            inner: Arc::new(Inner {
+                data: Mutex::new(data),
+                cursor: Mutex::new(cursor),
+                frames: Mutex::new(frames),
+            }),
+        }
     }
 
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
@@ -83,26 +136,156 @@ impl ZiskStdin {
     }
 
     pub fn write_slice(&self, data: &[u8]) {
-        let data_len = data.len();
-        let total_len = 8 + data_len;
-        let padding = (8 - (total_len % 8)) % 8;
-        let len_bytes = data_len.to_le_bytes();
+        self.push_item(&Self::encode_item(data, false));
+    }
 
-        let mut buf = self.inner.data.lock().unwrap();
-        buf.extend_from_slice(&len_bytes);
-        buf.extend_from_slice(data);
-        if padding > 0 {
-            buf.extend_from_slice(&vec![0u8; padding]);
+    /// Writes a Vadcop final proof as an input item, in the layout that
+    /// `ziskos::recursion::verify_proof` reads
+    pub fn write_proof(&self, proof: &Proof) -> Result<()> {
+        self.write_slice(&proof.get_proof_bytes()?);
+        Ok(())
+    }
+
+    /// Writes a program VK as the `ziskos::recursion::ProgramVk` a guest reads with
+    /// `ziskos::io::read`
+    pub fn write_program_vk(&self, program_vk: &ProgramVK) -> Result<()> {
+        let vk: [u64; PROGRAM_VK_LEN] = program_vk.vk.as_slice().try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid program_vk length: expected {}, got {}",
+                PROGRAM_VK_LEN,
+                program_vk.vk.len()
+            )
+        })?;
+        self.write(&vk);
+        Ok(())
+    }
+
+    /// Writes the Vadcop final VK a proof was generated with as the `ziskos::recursion::VadcopVk`
+    /// a guest reads with `ziskos::io::read`
+    pub fn write_vadcop_vk(&self, proof: &Proof) -> Result<()> {
+        let ProofBody::Vadcop { zisk_vk, .. } = &proof.body else {
+            anyhow::bail!("Proof is not a Vadcop final proof");
+        };
+        let vk: [u64; VADCOP_VK_LEN_WORDS] = zisk_vk.as_slice().try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid zisk_vk length: expected {}, got {}",
+                VADCOP_VK_LEN_WORDS,
+                zisk_vk.len()
+            )
+        })?;
+        self.write(&vk);
+        Ok(())
+    }
+
+    /// Writes a serialized value as a public input item, for guests built with the
+    /// `input-commitment` feature of `ziskos`. The guest reads it as any other item and folds it
+    /// into the input commitment, see [`input_commitment`].
+    pub fn write_public<T: Serialize>(&self, data: &T) {
+        let bytes = bincode::serde::encode_to_vec(data, bincode::config::standard())
+            .expect("Failed to serialize");
+        self.write_public_slice(&bytes);
+    }
+
+    /// Writes raw bytes as a public input item, see [`write_public`](Self::write_public)
+    pub fn write_public_slice(&self, data: &[u8]) {
+        self.push_item(&Self::encode_item(data, true));
+    }
+
+    /// Returns the public input items, in order
+    pub fn public_inputs(&self) -> Vec<Vec<u8>> {
+        let data = self.inner.data.lock().unwrap();
+        let mut items = Vec::new();
+        let mut pos = 0;
+        while pos + 8 <= data.len() {
+            let prefix = u64::from_le_bytes(data[pos..pos + 8].try_into().unwrap());
+            let len = (prefix & !PUBLIC_INPUT_FLAG) as usize;
+            let end = (pos + 8 + len).min(data.len());
+            if prefix & PUBLIC_INPUT_FLAG != 0 {
+                items.push(data[pos + 8..end].to_vec());
+            }
+            pos += 8 + len.next_multiple_of(8);
+        }
+        items
+    }
+
+    /// Input commitment that a guest reading all the items commits, see [`input_commitment`]
+    pub fn input_commitment(&self, hash: InputCommitmentHash) -> [u8; 32] {
+        input_commitment(hash, self.public_inputs())
+    }
+
+    fn push_item(&self, item: &[u8]) {
+        self.inner.data.lock().unwrap().extend_from_slice(item);
+        self.inner.cursor.lock().unwrap().get_mut().extend_from_slice(item);
+    }
+
+    /// Sets the seed of the guest random number generator, for guests built with the
+    /// `host-rng-seed` feature of `ziskos`, replacing the previous one. The seed is inserted as
+    /// the first item of the input, which the guest consumes before the program reads its own
+    /// inputs, and its [`rng_seed_commitment`] is committed to the slots at
+    /// [`RNG_SEED_COMMITMENT_OUTPUT_ID`]. The clones of `self` are not changed.
+    pub fn with_rng_seed(self, seed: [u8; RNG_SEED_SIZE]) -> Self {
+        let stdin = self.into_unshared();
+        stdin.set_frame(|frames| frames.rng_seed = Some(Self::encode_item(&seed, false)));
+        stdin
+    }
+
+    /// Sets the environment of the guest, for guests built with the `host-env` feature of
+    /// `ziskos`, replacing the previous one. It is inserted before the items of the program,
+    /// after the RNG seed if any whichever is set first, and its [`HostEnv::commitment`] is
+    /// committed to the slots at [`HOST_ENV_COMMITMENT_OUTPUT_ID`]. The clones of `self` are not
+    /// changed.
+    pub fn with_host_env(self, env: &HostEnv) -> Self {
+        let stdin = self.into_unshared();
+        stdin.set_frame(|frames| frames.host_env = Some(Self::encode_item(&env.to_bytes(), false)));
+        stdin
+    }
+
+    /// Returns `self` if no clone shares its buffer, or a [`deep_clone`](Self::deep_clone)
+    /// otherwise
+    fn into_unshared(self) -> Self {
+        if Arc::strong_count(&self.inner) == 1 {
+            self
+        } else {
+            self.deep_clone()
         }
+    }
+
+    /// Updates the frames and writes them again before the items of the program, keeping the
+    /// read position on the same item of the program. A read position within the frames goes
+    /// back to the first frame.
+    fn set_frame(&self, update: impl FnOnce(&mut Frames)) {
+        let mut frames = self.inner.frames.lock().unwrap();
+        let old_len = frames.encode().len();
+        update(&mut frames);
+        let encoded = frames.encode();
+
+        self.inner.data.lock().unwrap().splice(0..old_len, encoded.iter().copied());
 
         let mut cursor = self.inner.cursor.lock().unwrap();
-        cursor.get_mut().extend_from_slice(&len_bytes);
-        cursor.get_mut().extend_from_slice(data);
-        if padding > 0 {
-            cursor.get_mut().extend_from_slice(&vec![0u8; padding]);
+        let position = cursor.position() as usize;
+        cursor.get_mut().splice(0..old_len, encoded.iter().copied());
+        if position >= old_len && position > 0 {
+            cursor.set_position((position - old_len + encoded.len()) as u64);
+        } else {
+            cursor.set_position(0);
         }
     }
 
+    /// Encodes an input item: its length as a little-endian u64, with [`PUBLIC_INPUT_FLAG`] set
+    /// for public items, followed by the data padded with zeros to a multiple of 8 bytes
+    fn encode_item(data: &[u8], public: bool) -> Vec<u8> {
+        let data_len = data.len();
+        let total_len = 8 + data_len;
+        let padding = (8 - (total_len % 8)) % 8;
+        let prefix = data_len as u64 | if public { PUBLIC_INPUT_FLAG } else { 0 };
+
+        let mut item = Vec::with_capacity(total_len + padding);
+        item.extend_from_slice(&prefix.to_le_bytes());
+        item.extend_from_slice(data);
+        item.resize(total_len + padding, 0);
+        item
+    }
+
     pub fn save(&self, path: &Path) -> Result<()> {
         std::fs::write(path, self.inner.data.lock().unwrap().as_slice())?;
         Ok(())
@@ -119,6 +302,7 @@ impl ZiskStdin {
     }
 
     pub fn clear(&self) {
+        *self.inner.frames.lock().unwrap() = Frames::default();
         self.inner.data.lock().unwrap().clear();
         let mut cursor = self.inner.cursor.lock().unwrap();
         *cursor = Cursor::new(Vec::new());
@@ -128,7 +312,7 @@ impl ZiskStdin {
         let mut cursor = self.inner.cursor.lock().unwrap();
         let mut len_bytes = [0u8; 8];
         cursor.read_exact(&mut len_bytes)?;
-        let len = usize::from_le_bytes(len_bytes);
+        let len = (u64::from_le_bytes(len_bytes) & !PUBLIC_INPUT_FLAG) as usize;
         let mut data = vec![0u8; len];
         cursor.read_exact(&mut data)?;
         let total_len = 8 + len;
@@ -140,3 +324,166 @@ impl ZiskStdin {
         Ok(data)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{
+        HOST_ENV_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_SIZE,
+    };
+    use std::time::{Duration, UNIX_EPOCH};
+
+    /// Serializes the tests that run a guest, as they go through its global input and outputs
+    static GUEST_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Public output bytes of a guest run natively, from `slot` on
+    fn guest_outputs(slot: usize, len: usize) -> Vec<u8> {
+        ziskos::native_outputs()[slot..slot + len / 4]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn test_rng_seed_is_first_item() {
+        let stdin = ZiskStdin::new();
+        stdin.write_slice(&[1, 2, 3]);
+        let stdin = stdin.with_rng_seed([7u8; RNG_SEED_SIZE]);
+
+        assert_eq!(stdin.read_bytes(), vec![7u8; RNG_SEED_SIZE]);
+        assert_eq!(stdin.read_bytes(), vec![1, 2, 3]);
+        assert_eq!(stdin.read_data().len(), 8 + RNG_SEED_SIZE + 16);
+    }
+
+    #[test]
+    fn test_host_env_follows_rng_seed() {
+        let env = HostEnv::new().with_var("CHAIN_ID", "1").with_file("config.toml", "x = 1");
+        let stdin = ZiskStdin::new();
+        stdin.write_slice(&[1, 2, 3]);
+
+        // The frames are in the same order whichever is set first
+        let env_first = stdin.clone().with_host_env(&env).with_rng_seed([7u8; RNG_SEED_SIZE]);
+        let stdin = ZiskStdin::new();
+        stdin.write_slice(&[1, 2, 3]);
+        let seed_first = stdin.with_rng_seed([7u8; RNG_SEED_SIZE]).with_host_env(&env);
+        assert_eq!(env_first.read_data(), seed_first.read_data());
+
+        assert_eq!(seed_first.read_bytes(), vec![7u8; RNG_SEED_SIZE]);
+        assert_eq!(seed_first.read::<HostEnv>().unwrap(), env);
+        assert_eq!(seed_first.read_bytes(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_frames_do_not_change_clones() {
+        let stdin = ZiskStdin::new();
+        stdin.write_slice(&[1, 2, 3]);
+        let clone = stdin.clone();
+
+        let seeded = stdin.with_rng_seed([7u8; RNG_SEED_SIZE]).with_host_env(&HostEnv::new());
+        assert_eq!(clone.read_data().len(), 16);
+        assert_eq!(clone.read_bytes(), vec![1, 2, 3]);
+        assert_eq!(seeded.read_bytes(), vec![7u8; RNG_SEED_SIZE]);
+
+        // A deep clone has its own buffer and read position
+        let copy = seeded.deep_clone();
+        copy.write_slice(&[4]);
+        assert_eq!(copy.read::<HostEnv>().unwrap(), HostEnv::new());
+        assert_eq!(seeded.read_data().len() + 16, copy.read_data().len());
+        assert_eq!(seeded.read::<HostEnv>().unwrap(), HostEnv::new());
+    }
+
+    #[test]
+    fn test_frames_are_replaced() {
+        let stdin = ZiskStdin::new();
+        stdin.write_slice(&[1, 2, 3]);
+        stdin.write_slice(&[4]);
+        let stdin = stdin
+            .with_host_env(&HostEnv::new().with_var("A", "1"))
+            .with_rng_seed([7u8; RNG_SEED_SIZE]);
+        assert_eq!(stdin.read_bytes(), vec![7u8; RNG_SEED_SIZE]);
+        assert_eq!(stdin.read::<HostEnv>().unwrap(), HostEnv::new().with_var("A", "1"));
+        assert_eq!(stdin.read_bytes(), vec![1, 2, 3]);
+
+        // Setting a frame again keeps a single frame of each kind and the read position
+        let env = HostEnv::new().with_var("A", "2");
+        let stdin = stdin.with_host_env(&env).with_rng_seed([8u8; RNG_SEED_SIZE]);
+        assert_eq!(stdin.read_bytes(), vec![4]);
+
+        stdin.rewind();
+        assert_eq!(stdin.read_bytes(), vec![8u8; RNG_SEED_SIZE]);
+        assert_eq!(stdin.read::<HostEnv>().unwrap(), env);
+        assert_eq!(stdin.read_bytes(), vec![1, 2, 3]);
+        assert_eq!(stdin.read_bytes(), vec![4]);
+    }
+
+    #[test]
+    fn test_public_inputs() {
+        let stdin = ZiskStdin::new();
+        stdin.write_slice(&[1, 2, 3]);
+        stdin.write_public_slice(&[4, 5]);
+        stdin.write_public(&7u32);
+
+        assert_eq!(stdin.public_inputs(), vec![vec![4, 5], vec![7]]);
+        assert_eq!(stdin.read_bytes(), vec![1, 2, 3]);
+        assert_eq!(stdin.read_bytes(), vec![4, 5]);
+        assert_eq!(stdin.read::<u32>().unwrap(), 7);
+
+        let first: [u8; 32] =
+            Sha256::digest([[0u8; 32], Sha256::digest([4u8, 5]).into()].concat()).into();
+        let second: [u8; 32] =
+            Sha256::digest([first, Sha256::digest([7u8]).into()].concat()).into();
+        assert_eq!(stdin.input_commitment(InputCommitmentHash::Sha256), second);
+        assert_eq!(input_commitment(InputCommitmentHash::Sha256, Vec::<Vec<u8>>::new()), [0; 32]);
+    }
+
+    /// A guest built with the `input-commitment` feature of `ziskos`, run natively on the input,
+    /// commits the input commitment computed on the host. The guest is also built with
+    /// `host-env`, whose frame is private and so not in the input commitment.
+    #[test]
+    fn test_guest_commits_the_input_commitment() {
+        let _guard = GUEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let stdin = ZiskStdin::new().with_host_env(&HostEnv::new());
+        stdin.write_public(&42u64);
+        stdin.write_slice(b"private");
+        stdin.write_public_slice(&[7u8; 100]);
+
+        ziskos::set_native_input(stdin.read_data());
+        ziskos::zkvm_init();
+        assert_eq!(ziskos::io::read::<u64>(), 42);
+        assert_eq!(ziskos::io::read_input_slice().as_ref(), b"private");
+        assert_eq!(ziskos::io::read_input_slice().as_ref(), [7u8; 100]);
+        ziskos::zkvm_deinit();
+
+        assert_eq!(
+            guest_outputs(INPUT_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_SIZE),
+            stdin.input_commitment(InputCommitmentHash::Sha256)
+        );
+    }
+
+    /// A guest built with the `host-env` feature of `ziskos`, run natively on the input, reads
+    /// the environment set on the host and commits its commitment
+    #[test]
+    fn test_guest_commits_the_host_env() {
+        let _guard = GUEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let env = HostEnv::new()
+            .with_time(UNIX_EPOCH + Duration::new(1_700_000_000, 5))
+            .with_var("CHAIN_ID", "1")
+            .with_file("config.toml", "x = 1");
+        let stdin = ZiskStdin::new().with_host_env(&env);
+        stdin.write(&42u64);
+
+        ziskos::set_native_input(stdin.read_data());
+        ziskos::zkvm_init();
+        let now = ziskos::shims::time::SystemTime::now();
+        assert_eq!(
+            now.duration_since(ziskos::shims::time::UNIX_EPOCH).unwrap(),
+            Duration::new(1_700_000_000, 5)
+        );
+        assert_eq!(ziskos::shims::env::var("CHAIN_ID").as_deref(), Some("1"));
+        assert_eq!(ziskos::shims::fs::read_to_string("config.toml").as_deref(), Some("x = 1"));
+        assert_eq!(ziskos::io::read::<u64>(), 42);
+        ziskos::zkvm_deinit();
+
+        assert_eq!(guest_outputs(HOST_ENV_COMMITMENT_OUTPUT_ID, 32), env.commitment());
+    }
+}
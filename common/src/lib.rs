@@ -11,6 +11,7 @@ mod proof;
 mod proof_log;
 mod regular_counters;
 mod regular_planner;
+mod solidity;
 mod types;
 mod utils;
 
@@ -26,5 +27,6 @@ pub use proof::*;
 pub use proof_log::*;
 pub use regular_counters::*;
 pub use regular_planner::*;
+pub use solidity::*;
 pub use types::*;
 pub use utils::*;
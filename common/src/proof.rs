@@ -1,5 +1,4 @@
 use anyhow::{anyhow, Context, Result};
-use proofman::{verify_snark_proof, SnarkProof, SnarkProtocol};
 use proofman_verifier::VadcopFinalProof;
 use proofman_verifier::{
     expected_vadcop_final_compressed_proof_bytes, expected_vadcop_final_proof_bytes,
@@ -11,6 +10,16 @@ use std::fs::File;
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use crate::io::{
+    HOST_ENV_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_SIZE,
+    RNG_SEED_COMMITMENT_OUTPUT_ID,
+};
+
+use zisk_verifier::{
+    verify_zisk_groth16_proof, verify_zisk_plonk_proof, G1Point, G2Point, Groth16VerifyingKey,
+    PlonkVerifyingKey,
+};
+
 pub use zisk_verifier::{PROGRAM_VK_LEN, ZISK_PUBLICS};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -53,6 +62,14 @@ pub enum ProofKind {
     VadcopFinal,
     VadcopFinalMinimal,
     Plonk,
+    Groth16,
+}
+
+impl ProofKind {
+    /// Whether proofs of this kind are SNARK-wrapped, and need the SNARK proving key
+    pub fn is_snark(&self) -> bool {
+        matches!(self, ProofKind::Plonk | ProofKind::Groth16)
+    }
 }
 
 impl From<i32> for ProofKind {
@@ -60,6 +77,7 @@ impl From<i32> for ProofKind {
         match v {
             1 => ProofKind::VadcopFinalMinimal,
             2 => ProofKind::Plonk,
+            3 => ProofKind::Groth16,
             _ => ProofKind::VadcopFinal,
         }
     }
@@ -71,6 +89,7 @@ impl From<ProofKind> for i32 {
             ProofKind::VadcopFinal => 0,
             ProofKind::VadcopFinalMinimal => 1,
             ProofKind::Plonk => 2,
+            ProofKind::Groth16 => 3,
         }
     }
 }
@@ -134,6 +153,29 @@ impl PlonkVkey {
 
         Ok(())
     }
+
+    /// Converts the snarkJS JSON form into the key of the in-memory Plonk verifier
+    pub fn to_verifying_key(&self) -> Result<PlonkVerifyingKey> {
+        check_vkey_protocol("PlonkVkey", "plonk", &self.protocol, &self.curve)?;
+        let scalar = |name, value| vkey_scalar("PlonkVkey", name, value);
+        let g1 = |name, point| vkey_g1("PlonkVkey", name, point);
+        Ok(PlonkVerifyingKey {
+            n_public: self.n_public as usize,
+            power: self.power,
+            k1: scalar("k1", &self.k1)?,
+            k2: scalar("k2", &self.k2)?,
+            qm: g1("Qm", &self.qm)?,
+            ql: g1("Ql", &self.ql)?,
+            qr: g1("Qr", &self.qr)?,
+            qo: g1("Qo", &self.qo)?,
+            qc: g1("Qc", &self.qc)?,
+            s1: g1("S1", &self.s1)?,
+            s2: g1("S2", &self.s2)?,
+            s3: g1("S3", &self.s3)?,
+            x_2: vkey_g2("PlonkVkey", "X_2", &self.x_2)?,
+            w: scalar("w", &self.w)?,
+        })
+    }
 }
 
 /// Verification key for a Plonk proof: the underlying Vadcop vkey plus the structured Plonk vkey.
@@ -143,6 +185,146 @@ pub struct PlonkVkBlob {
     pub plonk_vkey: PlonkVkey,
 }
 
+/// Groth16 verification key, in the JSON form exported by snarkJS
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Groth16Vkey {
+    pub protocol: String,
+    pub curve: String,
+    #[serde(rename = "nPublic")]
+    pub n_public: u32,
+    pub vk_alpha_1: [String; 3],
+    pub vk_beta_2: [[String; 2]; 3],
+    pub vk_gamma_2: [[String; 2]; 3],
+    pub vk_delta_2: [[String; 2]; 3],
+    pub vk_alphabeta_12: [[[String; 2]; 3]; 2],
+    #[serde(rename = "IC")]
+    pub ic: Vec<[String; 3]>,
+}
+
+impl Groth16Vkey {
+    /// Load Groth16Vkey from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(path.as_ref()).with_context(|| {
+            format!("failed to open file for loading Groth16Vkey: {}", path.as_ref().display())
+        })?;
+        let vkey: Groth16Vkey = serde_json::from_reader(file).with_context(|| {
+            format!("failed to parse Groth16Vkey JSON from {}", path.as_ref().display())
+        })?;
+        Ok(vkey)
+    }
+
+    /// Converts the snarkJS JSON form into the key of the in-memory Groth16 verifier
+    pub fn to_verifying_key(&self) -> Result<Groth16VerifyingKey> {
+        check_vkey_protocol("Groth16Vkey", "groth16", &self.protocol, &self.curve)?;
+        if self.ic.len() != self.n_public as usize + 1 {
+            return Err(anyhow!(
+                "Groth16Vkey has {} IC points for {} public inputs",
+                self.ic.len(),
+                self.n_public
+            ));
+        }
+        let g2 = |name, point| vkey_g2("Groth16Vkey", name, point);
+        Ok(Groth16VerifyingKey {
+            alpha_1: vkey_g1("Groth16Vkey", "vk_alpha_1", &self.vk_alpha_1)?,
+            beta_2: g2("vk_beta_2", &self.vk_beta_2)?,
+            gamma_2: g2("vk_gamma_2", &self.vk_gamma_2)?,
+            delta_2: g2("vk_delta_2", &self.vk_delta_2)?,
+            ic: self.ic.iter().map(|p| vkey_g1("Groth16Vkey", "IC", p)).collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// Verification key for a Groth16 proof: the underlying Vadcop vkey plus the Groth16 vkey.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Groth16VkBlob {
+    pub vadcop_vk: Vec<u64>,
+    pub groth16_vkey: Groth16Vkey,
+}
+
+/// Verification key of a SNARK proving key, for the protocol the key was set up with
+#[derive(Debug, Clone)]
+pub enum SnarkVkey {
+    Plonk(PlonkVkey),
+    Groth16(Groth16Vkey),
+}
+
+impl SnarkVkey {
+    /// Load the snarkJS verification key from a JSON file, of either protocol
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path).with_context(|| {
+            format!("failed to open file for loading SNARK vkey: {}", path.display())
+        })?;
+        let value: serde_json::Value = serde_json::from_reader(file)
+            .with_context(|| format!("failed to parse SNARK vkey JSON from {}", path.display()))?;
+        let protocol = value.get("protocol").and_then(|p| p.as_str()).unwrap_or_default();
+        match protocol {
+            "plonk" => Ok(SnarkVkey::Plonk(serde_json::from_value(value)?)),
+            "groth16" => Ok(SnarkVkey::Groth16(serde_json::from_value(value)?)),
+            _ => Err(anyhow!("unsupported SNARK protocol {:?} in {}", protocol, path.display())),
+        }
+    }
+
+    /// Kind of the proofs generated with this key
+    pub fn proof_kind(&self) -> ProofKind {
+        match self {
+            SnarkVkey::Plonk(_) => ProofKind::Plonk,
+            SnarkVkey::Groth16(_) => ProofKind::Groth16,
+        }
+    }
+
+    /// Body of a proof generated with this key
+    pub fn proof_body(self, proof_bytes: Vec<u8>, vadcop_vk: Vec<u64>) -> ProofBody {
+        match self {
+            SnarkVkey::Plonk(plonk_vkey) => ProofBody::Plonk {
+                proof_bytes,
+                plonk_vk: Box::new(PlonkVkBlob { vadcop_vk, plonk_vkey }),
+            },
+            SnarkVkey::Groth16(groth16_vkey) => ProofBody::Groth16 {
+                proof_bytes,
+                groth16_vk: Box::new(Groth16VkBlob { vadcop_vk, groth16_vkey }),
+            },
+        }
+    }
+}
+
+fn check_vkey_protocol(key: &str, expected: &str, protocol: &str, curve: &str) -> Result<()> {
+    if protocol != expected || curve != "bn128" {
+        return Err(anyhow!("unsupported {key}: protocol {protocol} over {curve}"));
+    }
+    Ok(())
+}
+
+fn vkey_scalar(key: &str, name: &str, value: &str) -> Result<[u8; 32]> {
+    zisk_verifier::parse_decimal(value).ok_or_else(|| anyhow!("invalid {name} in {key}: {value:?}"))
+}
+
+/// G1 point of a snarkJS key, in projective coordinates normalized to z = 1
+fn vkey_g1(key: &str, name: &str, point: &[String; 3]) -> Result<G1Point> {
+    if point[2] != "1" {
+        return Err(anyhow!("{name} in {key} is not normalized"));
+    }
+    let mut out = [0u8; 64];
+    out[..32].copy_from_slice(&vkey_scalar(key, name, &point[0])?);
+    out[32..].copy_from_slice(&vkey_scalar(key, name, &point[1])?);
+    Ok(out)
+}
+
+/// G2 point of a snarkJS key, in projective coordinates normalized to z = 1
+fn vkey_g2(key: &str, name: &str, point: &[[String; 2]; 3]) -> Result<G2Point> {
+    if point[2] != ["1", "0"] {
+        return Err(anyhow!("{name} in {key} is not normalized"));
+    }
+    // Coordinates are `[real, imaginary]`, the verifiers expect `imaginary || real`
+    let mut out = [0u8; 128];
+    for (chunk, value) in
+        out.chunks_exact_mut(32).zip([&point[0][1], &point[0][0], &point[1][1], &point[1][0]])
+    {
+        chunk.copy_from_slice(&vkey_scalar(key, name, value)?);
+    }
+    Ok(out)
+}
+
 #[derive(Default, Debug, Serialize, Deserialize)]
 pub struct PublicValues {
     data: Vec<u8>,
@@ -242,6 +424,11 @@ impl PublicValues {
         Ok(Self { data: data.to_vec(), ptr: AtomicUsize::new(0) })
     }
 
+    /// Raw public output bytes, independent of the reading pointer.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
     /// Reset the reading pointer to the beginning.
     pub fn head(&self) {
         self.ptr.store(0, Ordering::Relaxed);
@@ -294,6 +481,36 @@ impl PublicValues {
             .collect()
     }
 
+    /// Commitment to the public inputs, written by guests built with the `input-commitment`
+    /// feature of `ziskos`. Compare it with [`input_commitment`](crate::io::input_commitment) of
+    /// the public input items to check which inputs a proof used.
+    pub fn input_commitment(&self) -> [u8; INPUT_COMMITMENT_SIZE] {
+        let start = INPUT_COMMITMENT_OUTPUT_ID * 4;
+        self.data[start..start + INPUT_COMMITMENT_SIZE].try_into().unwrap()
+    }
+
+    /// Commitment to the RNG seed, written by guests built with the `host-rng-seed` feature of
+    /// `ziskos`. Compare it with [`rng_seed_commitment`](crate::io::rng_seed_commitment) of the
+    /// seed to check which seed a proof used.
+    pub fn rng_seed_commitment(&self) -> [u8; 32] {
+        let start = RNG_SEED_COMMITMENT_OUTPUT_ID * 4;
+        self.data[start..start + 32].try_into().unwrap()
+    }
+
+    /// Commitment to the host environment, written by guests built with the `host-env` feature
+    /// of `ziskos`. Compare it with [`HostEnv::commitment`](crate::io::HostEnv::commitment) to
+    /// check which time, variables and files a proof used.
+    pub fn host_env_commitment(&self) -> [u8; 32] {
+        let start = HOST_ENV_COMMITMENT_OUTPUT_ID * 4;
+        self.data[start..start + 32].try_into().unwrap()
+    }
+
+    /// SHA-256 hash of the raw public output bytes, as committed by
+    /// `VerifiedProof::commit_publics_hash` in a guest that verifies this proof
+    pub fn hash(&self) -> [u8; 32] {
+        Sha256::digest(&self.data).into()
+    }
+
     pub fn hash_solidity(&self, program_vk: &ProgramVK, vadcop_verkey: &[u64]) -> Vec<u8> {
         let bytes = self.bytes_solidity(program_vk, vadcop_verkey);
 
@@ -322,14 +539,40 @@ impl PublicValues {
     }
 }
 
-/// Kind-tagged proof payload. The Vadcop variant is u64-native; Plonk is byte-shaped.
+/// Magic bytes at the start of a proof in the compact encoding, see [`Proof::to_bytes`].
+pub const PROOF_MAGIC: [u8; 4] = *b"ZKPF";
+
+/// Version of the compact proof encoding written by [`Proof::to_bytes`].
+pub const PROOF_FORMAT_VERSION: u8 = 1;
+
+/// Magic, version and flags
+const PROOF_HEADER_SIZE: usize = PROOF_MAGIC.len() + 2;
+
+/// Flag set when the payload is compressed with zstd
+const PROOF_FLAG_ZSTD: u8 = 1;
+
+/// Compression of the compact proof encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProofCompression {
+    None,
+    #[default]
+    Zstd,
+}
+
+fn compact_config() -> impl bincode::config::Config {
+    bincode::config::standard().with_fixed_int_encoding()
+}
+
+/// Kind-tagged proof payload. The Vadcop variant is u64-native; the SNARK variants are
+/// byte-shaped.
 ///
-/// The Plonk vkey blob is boxed so the enum doesn't carry ~880 bytes of inline Plonk
-/// vkey strings on the Vadcop variant — Vadcop is the common case and most-cloned.
+/// The SNARK vkey blobs are boxed so the enum doesn't carry ~880 bytes of inline vkey
+/// strings on the Vadcop variant — Vadcop is the common case and most-cloned.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ProofBody {
     Vadcop { proof: Vec<u64>, zisk_vk: Vec<u64>, minimal: bool },
     Plonk { proof_bytes: Vec<u8>, plonk_vk: Box<PlonkVkBlob> },
+    Groth16 { proof_bytes: Vec<u8>, groth16_vk: Box<Groth16VkBlob> },
 }
 
 impl Default for ProofBody {
@@ -399,44 +642,26 @@ impl<'a> ZiskVerifyBuilder<'a> {
 
         match &self.proof_with_values.body {
             ProofBody::Plonk { proof_bytes, plonk_vk } => {
-                let pubs = publics.bytes_solidity(program_vk, &plonk_vk.vadcop_vk);
-                let hash = Sha256::digest(&pubs).to_vec();
-
-                let snark_proof = SnarkProof {
-                    proof_bytes: proof_bytes.clone(),
-                    public_bytes: pubs,
-                    public_snark_bytes: hash,
-                    protocol_id: SnarkProtocol::Plonk.protocol_id(),
-                };
-
-                let temp_dir = std::env::temp_dir();
-                // Concurrent verify() calls in one process otherwise race on the tempfile.
-                let unique_id = format!(
-                    "{}_{}",
-                    std::process::id(),
-                    std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .map(|d| d.as_nanos())
-                        .unwrap_or(0)
-                );
-                let temp_file = temp_dir.join(format!("plonk_vkey_{}.json", unique_id));
-
-                let plonk_vkey_json = serde_json::to_vec(&plonk_vk.plonk_vkey)
-                    .context("Failed to serialize PlonkVkey to JSON")?;
-                std::fs::write(&temp_file, &plonk_vkey_json).with_context(|| {
-                    format!("Failed to write PlonkVkey to temporary file: {}", temp_file.display())
-                })?;
-
-                let result = verify_snark_proof(&snark_proof, &temp_file);
-
-                if temp_file.exists() {
-                    std::fs::remove_file(&temp_file).with_context(|| {
-                        format!("Failed to delete temporary file: {}", temp_file.display())
-                    })?;
-                }
-
-                result?;
-                Ok(())
+                let vk = plonk_vk.plonk_vkey.to_verifying_key()?;
+                verify_zisk_plonk_proof(
+                    &vk,
+                    proof_bytes,
+                    &program_vk.vk,
+                    publics.as_bytes(),
+                    &plonk_vk.vadcop_vk,
+                )
+                .map_err(|e| anyhow!("Plonk proof verification failed: {e}"))
+            }
+            ProofBody::Groth16 { proof_bytes, groth16_vk } => {
+                let vk = groth16_vk.groth16_vkey.to_verifying_key()?;
+                verify_zisk_groth16_proof(
+                    &vk,
+                    proof_bytes,
+                    &program_vk.vk,
+                    publics.as_bytes(),
+                    &groth16_vk.vadcop_vk,
+                )
+                .map_err(|e| anyhow!("Groth16 proof verification failed: {e}"))
             }
             ProofBody::Vadcop { proof, zisk_vk, minimal } => {
                 let minimal = *minimal;
@@ -485,6 +710,7 @@ impl Proof {
             ProofBody::Vadcop { minimal: true, .. } => ProofKind::VadcopFinalMinimal,
             ProofBody::Vadcop { minimal: false, .. } => ProofKind::VadcopFinal,
             ProofBody::Plonk { .. } => ProofKind::Plonk,
+            ProofBody::Groth16 { .. } => ProofKind::Groth16,
         }
     }
 
@@ -492,27 +718,100 @@ impl Proof {
     pub fn is_empty(&self) -> bool {
         match &self.body {
             ProofBody::Vadcop { proof, .. } => proof.is_empty(),
-            ProofBody::Plonk { proof_bytes, .. } => proof_bytes.is_empty(),
+            ProofBody::Plonk { proof_bytes, .. } | ProofBody::Groth16 { proof_bytes, .. } => {
+                proof_bytes.is_empty()
+            }
         }
     }
 
+    /// Save the proof in the compact encoding, see [`Proof::to_bytes`].
     pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
-        let mut file = File::create(path.as_ref()).with_context(|| {
-            format!("failed to create file for saving proof: {}", path.as_ref().display())
-        })?;
-        bincode::serde::encode_into_std_write(self, &mut file, bincode::config::standard())
-            .map(|_| ())
-            .map_err(|e| anyhow::anyhow!("Failed to save proof: {}", e))
+        let bytes = self.to_bytes().map_err(|e| anyhow::anyhow!("Failed to save proof: {}", e))?;
+        std::fs::write(path.as_ref(), bytes).with_context(|| {
+            format!("failed to write file for saving proof: {}", path.as_ref().display())
+        })
     }
 
+    /// Load a proof saved with [`Proof::save`], in either the compact or the legacy encoding.
     pub fn load(path: impl AsRef<Path>) -> Result<Self> {
-        let mut file = File::open(path.as_ref()).with_context(|| {
+        let bytes = std::fs::read(path.as_ref()).with_context(|| {
             format!("failed to open file for loading proof: {}", path.as_ref().display())
         })?;
-        let proof: Proof =
-            bincode::serde::decode_from_std_read(&mut file, bincode::config::standard())
-                .map_err(|e| anyhow::anyhow!("Failed to load proof: {}", e))?;
-        Ok(proof)
+        Self::from_bytes(&bytes).map_err(|e| anyhow::anyhow!("Failed to load proof: {}", e))
+    }
+
+    /// Compact encoding of the proof, compressed with zstd.
+    ///
+    /// The encoding starts with [`PROOF_MAGIC`], the format version and a flags byte, followed
+    /// by the proof serialized with fixed-size integers, which suits the Goldilocks limbs of
+    /// Vadcop proofs better than variable-length ones.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.to_bytes_with(ProofCompression::Zstd)
+    }
+
+    /// Compact encoding of the proof with the given compression.
+    pub fn to_bytes_with(&self, compression: ProofCompression) -> Result<Vec<u8>> {
+        let payload = bincode::serde::encode_to_vec(self, compact_config())
+            .map_err(|e| anyhow!("Failed to serialize proof: {}", e))?;
+
+        let mut bytes = Vec::with_capacity(PROOF_HEADER_SIZE + payload.len());
+        bytes.extend_from_slice(&PROOF_MAGIC);
+        bytes.push(PROOF_FORMAT_VERSION);
+        match compression {
+            ProofCompression::None => {
+                bytes.push(0);
+                bytes.extend_from_slice(&payload);
+            }
+            ProofCompression::Zstd => {
+                bytes.push(PROOF_FLAG_ZSTD);
+                zstd::stream::copy_encode(payload.as_slice(), &mut bytes, 0)
+                    .map_err(|e| anyhow!("Failed to compress proof: {}", e))?;
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Legacy bincode encoding of the proof, without header, for readers older than the compact
+    /// encoding of [`Proof::to_bytes`].
+    pub fn to_legacy_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serde::encode_to_vec(self, bincode::config::standard())
+            .map_err(|e| anyhow!("Failed to serialize proof: {}", e))
+    }
+
+    /// Decode a proof from its compact encoding or from the legacy bincode encoding, which has
+    /// no header.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let Some(rest) = bytes.strip_prefix(&PROOF_MAGIC) else {
+            return bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+                .map(|(proof, _)| proof)
+                .map_err(|e| anyhow!("Failed to deserialize proof: {}", e));
+        };
+
+        let [version, flags, payload @ ..] = rest else {
+            return Err(anyhow!("Truncated proof header"));
+        };
+        if *version != PROOF_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Unsupported proof format version {} (supported: {})",
+                version,
+                PROOF_FORMAT_VERSION
+            ));
+        }
+        if flags & !PROOF_FLAG_ZSTD != 0 {
+            return Err(anyhow!("Unsupported proof format flags {:#04x}", flags));
+        }
+
+        let decompressed;
+        let payload = if flags & PROOF_FLAG_ZSTD != 0 {
+            decompressed = zstd::stream::decode_all(payload)
+                .map_err(|e| anyhow!("Failed to decompress proof: {}", e))?;
+            decompressed.as_slice()
+        } else {
+            payload
+        };
+        bincode::serde::decode_from_slice(payload, compact_config())
+            .map(|(proof, _)| proof)
+            .map_err(|e| anyhow!("Failed to deserialize proof: {}", e))
     }
 
     pub fn get_vadcop_final_proof(&self) -> Result<VadcopFinalProof> {
@@ -522,7 +821,9 @@ impl Proof {
                 pubs_u64.extend(self.publics.public_u64());
                 Ok(VadcopFinalProof::new(proof.clone(), pubs_u64, *minimal))
             }
-            ProofBody::Plonk { .. } => Err(anyhow::anyhow!("Proof is not a Vadcop final proof")),
+            ProofBody::Plonk { .. } | ProofBody::Groth16 { .. } => {
+                Err(anyhow::anyhow!("Proof is not a Vadcop final proof"))
+            }
         }
     }
 
@@ -558,7 +859,7 @@ impl Proof {
 
                 Ok(words)
             }
-            ProofBody::Plonk { .. } => Err(anyhow!(
+            ProofBody::Plonk { .. } | ProofBody::Groth16 { .. } => Err(anyhow!(
                 "Proof not suitable for get_proof_u64. Only VadcopFinal and VadcopFinalMinimal proofs are supported."
             )),
         }
@@ -689,6 +990,98 @@ mod tests {
         assert!(result.is_err(), "expected Err for malformed proof, got {:?}", result);
     }
 
+    fn plonk_vkey() -> PlonkVkey {
+        let g1 = || ["1".to_string(), "2".to_string(), "1".to_string()];
+        let fq2 = |real: &str, imaginary: &str| [real.to_string(), imaginary.to_string()];
+        PlonkVkey {
+            protocol: "plonk".to_string(),
+            curve: "bn128".to_string(),
+            n_public: 1,
+            power: 3,
+            k1: "2".to_string(),
+            k2: "3".to_string(),
+            qm: g1(),
+            ql: g1(),
+            qr: g1(),
+            qo: g1(),
+            qc: g1(),
+            s1: g1(),
+            s2: g1(),
+            s3: g1(),
+            x_2: [fq2("10", "11"), fq2("12", "13"), fq2("1", "0")],
+            w: "5".to_string(),
+        }
+    }
+
+    #[test]
+    fn plonk_vkey_to_verifying_key() {
+        let vk = plonk_vkey().to_verifying_key().unwrap();
+        assert_eq!((vk.n_public, vk.power, vk.k1[31], vk.w[31]), (1, 3, 2, 5));
+        assert_eq!((vk.qm[31], vk.qm[63]), (1, 2));
+        // G2 coordinates as `imaginary || real`
+        assert_eq!([vk.x_2[31], vk.x_2[63], vk.x_2[95], vk.x_2[127]], [11, 10, 13, 12]);
+
+        let mut projective = plonk_vkey();
+        projective.qc[2] = "2".to_string();
+        assert!(projective.to_verifying_key().is_err());
+
+        let mut invalid = plonk_vkey();
+        invalid.k1 = "0x2".to_string();
+        assert!(invalid.to_verifying_key().is_err());
+    }
+
+    #[test]
+    fn groth16_vkey_to_verifying_key() {
+        let g1 = |x: &str| [x.to_string(), "2".to_string(), "1".to_string()];
+        let g2 = || {
+            let fq2 = |real: &str, imaginary: &str| [real.to_string(), imaginary.to_string()];
+            [fq2("10", "11"), fq2("12", "13"), fq2("1", "0")]
+        };
+        let mut vkey = Groth16Vkey {
+            protocol: "groth16".to_string(),
+            curve: "bn128".to_string(),
+            n_public: 1,
+            vk_alpha_1: g1("1"),
+            vk_beta_2: g2(),
+            vk_gamma_2: g2(),
+            vk_delta_2: g2(),
+            vk_alphabeta_12: [g2(), g2()],
+            ic: vec![g1("3"), g1("4")],
+        };
+
+        let vk = vkey.to_verifying_key().unwrap();
+        assert_eq!((vk.alpha_1[31], vk.alpha_1[63]), (1, 2));
+        assert_eq!(
+            [vk.delta_2[31], vk.delta_2[63], vk.delta_2[95], vk.delta_2[127]],
+            [11, 10, 13, 12]
+        );
+        assert_eq!(vk.ic.iter().map(|p| p[31]).collect::<Vec<_>>(), vec![3, 4]);
+
+        vkey.ic.pop();
+        assert!(vkey.to_verifying_key().is_err());
+
+        vkey.protocol = "plonk".to_string();
+        assert!(vkey.to_verifying_key().is_err());
+    }
+
+    #[test]
+    fn verify_returns_err_for_malformed_plonk() {
+        let result = Proof::new(
+            ProofBody::Plonk {
+                proof_bytes: vec![0u8; 24 * 32 - 1],
+                plonk_vk: Box::new(PlonkVkBlob {
+                    vadcop_vk: vec![0u64; PROGRAM_VK_LEN],
+                    plonk_vkey: plonk_vkey(),
+                }),
+            },
+            PublicValues::new_empty(),
+            ProgramVK::new_empty(),
+        )
+        .verify();
+
+        assert!(result.is_err(), "expected Err for malformed proof, got {:?}", result);
+    }
+
     #[test]
     fn proof_save_load_roundtrip_vadcop() {
         let tmp = std::env::temp_dir().join(format!("proof_roundtrip_{}.bin", std::process::id()));
@@ -713,11 +1106,78 @@ mod tests {
                 assert_eq!(zisk_vk, vec![10, 20, 30, 40]);
                 assert!(minimal);
             }
-            ProofBody::Plonk { .. } => panic!("expected Vadcop body after roundtrip"),
+            ProofBody::Plonk { .. } | ProofBody::Groth16 { .. } => {
+                panic!("expected Vadcop body after roundtrip")
+            }
         }
         assert_eq!(loaded.program_vk.vk, vec![7, 8, 9, 10]);
     }
 
+    fn vadcop_proof(words: usize) -> Proof {
+        Proof::new(
+            ProofBody::Vadcop {
+                proof: (0..words as u64).map(|i| i % 7).collect(),
+                zisk_vk: vec![10, 20, 30, 40],
+                minimal: false,
+            },
+            PublicValues::new_empty(),
+            ProgramVK::new_from_publics(&[7, 8, 9, 10]),
+        )
+    }
+
+    fn vadcop_words(proof: &Proof) -> Vec<u64> {
+        match &proof.body {
+            ProofBody::Vadcop { proof, .. } => proof.clone(),
+            ProofBody::Plonk { .. } | ProofBody::Groth16 { .. } => panic!("expected Vadcop body"),
+        }
+    }
+
+    #[test]
+    fn proof_bytes_roundtrip_compact() {
+        let original = vadcop_proof(4096);
+        let plain = original.to_bytes_with(ProofCompression::None).unwrap();
+        let compressed = original.to_bytes().unwrap();
+
+        assert_eq!(plain[..4], PROOF_MAGIC);
+        assert_eq!(plain[4..6], [PROOF_FORMAT_VERSION, 0]);
+        assert_eq!(compressed[4..6], [PROOF_FORMAT_VERSION, PROOF_FLAG_ZSTD]);
+        assert!(compressed.len() < plain.len() / 10);
+
+        for bytes in [plain, compressed] {
+            let decoded = Proof::from_bytes(&bytes).unwrap();
+            assert_eq!(vadcop_words(&decoded), vadcop_words(&original));
+            assert_eq!(decoded.program_vk.vk, original.program_vk.vk);
+        }
+    }
+
+    #[test]
+    fn proof_from_bytes_reads_legacy_bincode() {
+        let original = vadcop_proof(16);
+        let legacy = original.to_legacy_bytes().unwrap();
+        assert_eq!(
+            legacy,
+            bincode::serde::encode_to_vec(&original, bincode::config::standard()).unwrap()
+        );
+        assert!(!legacy.starts_with(&PROOF_MAGIC));
+
+        let decoded = Proof::from_bytes(&legacy).unwrap();
+        assert_eq!(vadcop_words(&decoded), vadcop_words(&original));
+        assert_eq!(decoded.program_vk.vk, original.program_vk.vk);
+    }
+
+    #[test]
+    fn proof_from_bytes_rejects_unknown_format() {
+        let mut bytes = vadcop_proof(16).to_bytes().unwrap();
+        bytes[4] = PROOF_FORMAT_VERSION + 1;
+        assert!(Proof::from_bytes(&bytes).is_err());
+
+        bytes[4] = PROOF_FORMAT_VERSION;
+        bytes[5] = 0x80;
+        assert!(Proof::from_bytes(&bytes).is_err());
+
+        assert!(Proof::from_bytes(&PROOF_MAGIC).is_err());
+    }
+
     #[test]
     fn proof_kind_derivation() {
         let vadcop = Proof::new(
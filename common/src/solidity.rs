@@ -0,0 +1,217 @@
+//! Solidity encoding of SNARK-wrapped proofs.
+//!
+//! [`Proof::to_solidity_calldata`] builds the call to `IZiskVerifier.verifySnarkProof` of the
+//! contracts in `zisk-contracts`, and [`SolidityFixture`] holds the same arguments as hex
+//! strings, for Foundry tests that read them with `vm.parseJson`.
+
+use alloy_sol_types::{sol, SolCall};
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use zisk_verifier::zisk_snark_public_input;
+
+use crate::{Proof, ProofBody, ProofKind, PROGRAM_VK_LEN};
+
+sol! {
+    interface IZiskVerifier {
+        function verifySnarkProof(
+            bytes32 programVK,
+            bytes32 rootCVadcopFinal,
+            bytes publicValues,
+            bytes proofBytes
+        ) external view;
+    }
+}
+
+/// Arguments of `verifySnarkProof` for a proof, plus the digest the contract computes from them
+/// with `hashPublicValues`. All values are `0x`-prefixed hex strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolidityFixture {
+    pub proof_kind: ProofKind,
+    #[serde(rename = "programVK")]
+    pub program_vk: String,
+    pub root_c_vadcop_final: String,
+    pub public_values: String,
+    pub proof: String,
+    pub public_values_digest: String,
+}
+
+impl SolidityFixture {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path.as_ref(), json).with_context(|| {
+            format!("failed to write Solidity fixture: {}", path.as_ref().display())
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path.as_ref()).with_context(|| {
+            format!("failed to read Solidity fixture: {}", path.as_ref().display())
+        })?;
+        serde_json::from_str(&json).map_err(|e| anyhow!("Failed to parse Solidity fixture: {}", e))
+    }
+
+    /// ABI-encoded call to `IZiskVerifier.verifySnarkProof` with the fixture arguments.
+    pub fn calldata(&self) -> Result<Vec<u8>> {
+        let call = IZiskVerifier::verifySnarkProofCall {
+            programVK: hex_decode_32("programVK", &self.program_vk)?.into(),
+            rootCVadcopFinal: hex_decode_32("rootCVadcopFinal", &self.root_c_vadcop_final)?.into(),
+            publicValues: hex_decode("publicValues", &self.public_values)?.into(),
+            proofBytes: hex_decode("proof", &self.proof)?.into(),
+        };
+        Ok(call.abi_encode())
+    }
+}
+
+impl Proof {
+    /// Proof bytes as passed to the Solidity verifier, `proofBytes` of `verifySnarkProof`.
+    pub fn solidity_proof_bytes(&self) -> Result<&[u8]> {
+        self.snark_parts().map(|(proof_bytes, _)| proof_bytes)
+    }
+
+    /// ABI-encoded call to `IZiskVerifier.verifySnarkProof`, selector included.
+    pub fn to_solidity_calldata(&self) -> Result<Vec<u8>> {
+        let (proof_bytes, vadcop_vk) = self.snark_parts()?;
+        let call = IZiskVerifier::verifySnarkProofCall {
+            programVK: words_to_bytes32(&self.program_vk.vk)?.into(),
+            rootCVadcopFinal: words_to_bytes32(vadcop_vk)?.into(),
+            publicValues: self.publics.as_bytes().to_vec().into(),
+            proofBytes: proof_bytes.to_vec().into(),
+        };
+        Ok(call.abi_encode())
+    }
+
+    /// Foundry fixture with the arguments of `verifySnarkProof` for this proof.
+    pub fn solidity_fixture(&self) -> Result<SolidityFixture> {
+        let (proof_bytes, vadcop_vk) = self.snark_parts()?;
+        let digest =
+            zisk_snark_public_input(&self.program_vk.vk, self.publics.as_bytes(), vadcop_vk);
+        Ok(SolidityFixture {
+            proof_kind: self.kind(),
+            program_vk: hex_0x(&words_to_bytes32(&self.program_vk.vk)?),
+            root_c_vadcop_final: hex_0x(&words_to_bytes32(vadcop_vk)?),
+            public_values: hex_0x(self.publics.as_bytes()),
+            proof: hex_0x(proof_bytes),
+            public_values_digest: hex_0x(&digest),
+        })
+    }
+
+    fn snark_parts(&self) -> Result<(&[u8], &[u64])> {
+        match &self.body {
+            ProofBody::Plonk { proof_bytes, plonk_vk } => Ok((proof_bytes, &plonk_vk.vadcop_vk)),
+            ProofBody::Groth16 { proof_bytes, groth16_vk } => {
+                Ok((proof_bytes, &groth16_vk.vadcop_vk))
+            }
+            ProofBody::Vadcop { .. } => Err(anyhow!(
+                "Only SNARK proofs can be verified on-chain, wrap the {:?} proof first",
+                self.kind()
+            )),
+        }
+    }
+}
+
+/// Verification key words packed big-endian, as the contracts expect them.
+fn words_to_bytes32(words: &[u64]) -> Result<[u8; 32]> {
+    if words.len() != PROGRAM_VK_LEN {
+        return Err(anyhow!(
+            "Invalid verification key length: expected {}, got {}",
+            PROGRAM_VK_LEN,
+            words.len()
+        ));
+    }
+    let mut out = [0u8; 32];
+    for (chunk, word) in out.chunks_exact_mut(8).zip(words) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    Ok(out)
+}
+
+fn hex_0x(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn hex_decode(name: &str, value: &str) -> Result<Vec<u8>> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| anyhow!("Invalid hex in fixture field {}: {}", name, e))
+}
+
+fn hex_decode_32(name: &str, value: &str) -> Result<[u8; 32]> {
+    hex_decode(name, value)?
+        .try_into()
+        .map_err(|_| anyhow!("Fixture field {} is not 32 bytes long", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Groth16VkBlob, Groth16Vkey, ProgramVK, PublicValues, ZISK_PUBLICS};
+
+    fn groth16_proof() -> Proof {
+        let vkey: Groth16Vkey = serde_json::from_value(serde_json::json!({
+            "protocol": "groth16",
+            "curve": "bn128",
+            "nPublic": 1,
+            "vk_alpha_1": ["1", "2", "1"],
+            "vk_beta_2": [["1", "0"], ["1", "0"], ["1", "0"]],
+            "vk_gamma_2": [["1", "0"], ["1", "0"], ["1", "0"]],
+            "vk_delta_2": [["1", "0"], ["1", "0"], ["1", "0"]],
+            "vk_alphabeta_12": [
+                [["1", "0"], ["1", "0"], ["1", "0"]],
+                [["1", "0"], ["1", "0"], ["1", "0"]]
+            ],
+            "IC": [["1", "2", "1"], ["1", "2", "1"]]
+        }))
+        .unwrap();
+        Proof::new(
+            ProofBody::Groth16 {
+                proof_bytes: (0..=255).collect(),
+                groth16_vk: Box::new(Groth16VkBlob {
+                    vadcop_vk: vec![5, 6, 7, 8],
+                    groth16_vkey: vkey,
+                }),
+            },
+            PublicValues::new_from_u64(&[9; PROGRAM_VK_LEN + ZISK_PUBLICS]),
+            ProgramVK { vk: vec![1, 2, 3, 4] },
+        )
+    }
+
+    #[test]
+    fn solidity_calldata_encodes_verify_call() {
+        let proof = groth16_proof();
+        let calldata = proof.to_solidity_calldata().unwrap();
+        assert_eq!(calldata[..4], IZiskVerifier::verifySnarkProofCall::SELECTOR);
+
+        let call = IZiskVerifier::verifySnarkProofCall::abi_decode(&calldata).unwrap();
+        assert_eq!(call.programVK.0[..8], 1u64.to_be_bytes());
+        assert_eq!(call.rootCVadcopFinal.0[24..], 8u64.to_be_bytes());
+        assert_eq!(call.publicValues.as_ref(), proof.publics.as_bytes());
+        assert_eq!(call.proofBytes.as_ref(), proof.solidity_proof_bytes().unwrap());
+    }
+
+    #[test]
+    fn solidity_fixture_matches_calldata() {
+        let proof = groth16_proof();
+        let fixture = proof.solidity_fixture().unwrap();
+        let call =
+            IZiskVerifier::verifySnarkProofCall::abi_decode(&proof.to_solidity_calldata().unwrap())
+                .unwrap();
+        assert_eq!(fixture.program_vk, hex_0x(call.programVK.as_ref()));
+        assert_eq!(fixture.root_c_vadcop_final, hex_0x(call.rootCVadcopFinal.as_ref()));
+        assert_eq!(fixture.public_values, hex_0x(&call.publicValues));
+        assert_eq!(fixture.proof, hex_0x(&call.proofBytes));
+        assert_eq!(fixture.calldata().unwrap(), proof.to_solidity_calldata().unwrap());
+
+        let json = serde_json::to_value(&fixture).unwrap();
+        assert!(json.get("programVK").is_some());
+        assert!(json.get("rootCVadcopFinal").is_some());
+        assert!(json.get("publicValuesDigest").is_some());
+    }
+
+    #[test]
+    fn solidity_export_rejects_vadcop_proofs() {
+        let proof = Proof::default();
+        assert!(proof.to_solidity_calldata().is_err());
+        assert!(proof.solidity_fixture().is_err());
+    }
+}
@@ -158,6 +158,9 @@ pub struct ZiskExecutorSummary {
     pub steps: u64,
     pub executor_time: ZiskExecutorTime,
     pub cost_per_type: StatsCostPerType,
+    /// Exit code of the guest program, if it completed without an error
+    #[serde(default)]
+    pub exit_code: Option<i32>,
 }
 
 impl ZiskExecutorSummary {
@@ -165,8 +168,9 @@ impl ZiskExecutorSummary {
         executed_steps: u64,
         execution_time: ZiskExecutorTime,
         cost_per_type: StatsCostPerType,
+        exit_code: Option<i32>,
     ) -> Self {
-        Self { steps: executed_steps, executor_time: execution_time, cost_per_type }
+        Self { steps: executed_steps, executor_time: execution_time, cost_per_type, exit_code }
     }
 }
 
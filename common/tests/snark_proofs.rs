@@ -0,0 +1,154 @@
+//! SNARK-wrapped proofs in `tests/fixtures`, checked with `Proof::verify`.
+//!
+//! Each fixture holds the verification key and proof in the JSON form exported by snarkJS, and
+//! the public input of the proof in `public.json`. The proofs are of a small circuit that exposes
+//! `zisk_snark_public_input` of the program VK, public outputs and Vadcop VK below, so they verify
+//! as ZisK proofs of these values. `solidity.json` is their export for the Solidity verifiers,
+//! run in `solidity_verifier.rs`.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use zisk_common::{
+    Groth16VkBlob, Groth16Vkey, PlonkVkBlob, PlonkVkey, ProgramVK, Proof, ProofBody, PublicValues,
+    SolidityFixture, PROGRAM_VK_LEN, ZISK_PUBLICS,
+};
+use zisk_verifier::{parse_decimal, zisk_snark_public_input};
+
+const PROGRAM_VK: [u64; PROGRAM_VK_LEN] =
+    [0x123456789abcdef0, 0xfedcba9876543210, 0x0123456789abcdef, 0x9e3779b97f4a7c15];
+const VADCOP_VK: [u64; 4] =
+    [0x5555555555555555, 0xaaaaaaaaaaaaaaaa, 0x3333333333333333, 0xcccccccccccccccc];
+
+fn fixture(protocol: &str, name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(protocol).join(name)
+}
+
+fn read_json(path: &Path) -> Value {
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn scalar(value: &Value) -> [u8; 32] {
+    parse_decimal(value.as_str().unwrap()).unwrap()
+}
+
+fn publics() -> PublicValues {
+    let words: Vec<u64> =
+        PROGRAM_VK.into_iter().chain((0..ZISK_PUBLICS as u64).map(|i| i * 7 + 1)).collect();
+    PublicValues::new_from_u64(&words)
+}
+
+/// Checks that the public input of the fixture binds the values of this test
+fn check_public_input(protocol: &str) {
+    let public = read_json(&fixture(protocol, "public.json"));
+    assert_eq!(
+        scalar(&public[0]),
+        zisk_snark_public_input(&PROGRAM_VK, publics().as_bytes(), &VADCOP_VK)
+    );
+}
+
+/// Plonk proof as the `uint256[24]` argument of the Solidity verifier
+fn plonk_proof_bytes(proof: &Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for point in ["A", "B", "C", "Z", "T1", "T2", "T3", "Wxi", "Wxiw"] {
+        assert_eq!(proof[point][2], "1", "{point} is not normalized");
+        bytes.extend(scalar(&proof[point][0]));
+        bytes.extend(scalar(&proof[point][1]));
+    }
+    for eval in ["eval_a", "eval_b", "eval_c", "eval_s1", "eval_s2", "eval_zw"] {
+        bytes.extend(scalar(&proof[eval]));
+    }
+    bytes
+}
+
+fn plonk_proof() -> Proof {
+    let plonk_vkey = PlonkVkey::load(fixture("plonk", "final.verkey.json")).unwrap();
+    let proof_bytes = plonk_proof_bytes(&read_json(&fixture("plonk", "proof.json")));
+    let body = ProofBody::Plonk {
+        proof_bytes,
+        plonk_vk: Box::new(PlonkVkBlob { vadcop_vk: VADCOP_VK.to_vec(), plonk_vkey }),
+    };
+    Proof::new(body, publics(), ProgramVK { vk: PROGRAM_VK.to_vec() })
+}
+
+/// Groth16 proof as the `(pA, pB, pC)` arguments of the Solidity verifier
+fn groth16_proof_bytes(proof: &Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for point in ["pi_a", "pi_c"] {
+        assert_eq!(proof[point][2], "1", "{point} is not normalized");
+    }
+    assert_eq!(proof["pi_b"][2], serde_json::json!(["1", "0"]), "pi_b is not normalized");
+    bytes.extend(scalar(&proof["pi_a"][0]));
+    bytes.extend(scalar(&proof["pi_a"][1]));
+    // Coordinates of `pB` are `[real, imaginary]`, the verifier takes the imaginary part first
+    for coordinate in 0..2 {
+        bytes.extend(scalar(&proof["pi_b"][coordinate][1]));
+        bytes.extend(scalar(&proof["pi_b"][coordinate][0]));
+    }
+    bytes.extend(scalar(&proof["pi_c"][0]));
+    bytes.extend(scalar(&proof["pi_c"][1]));
+    bytes
+}
+
+fn groth16_proof() -> Proof {
+    let groth16_vkey = Groth16Vkey::load(fixture("groth16", "verification_key.json")).unwrap();
+    let proof_bytes = groth16_proof_bytes(&read_json(&fixture("groth16", "proof.json")));
+    let body = ProofBody::Groth16 {
+        proof_bytes,
+        groth16_vk: Box::new(Groth16VkBlob { vadcop_vk: VADCOP_VK.to_vec(), groth16_vkey }),
+    };
+    Proof::new(body, publics(), ProgramVK { vk: PROGRAM_VK.to_vec() })
+}
+
+#[test]
+fn test_plonk_fixture_verifies() {
+    check_public_input("plonk");
+    plonk_proof().verify().unwrap();
+}
+
+#[test]
+fn test_plonk_fixture_rejects_other_values() {
+    let proof = plonk_proof();
+
+    let other_publics = PublicValues::new_from_u64(&[0; PROGRAM_VK_LEN + ZISK_PUBLICS]);
+    assert!(proof.with_publics(&other_publics).verify().is_err());
+
+    let other_program_vk = ProgramVK { vk: vec![1, 2, 3, 4] };
+    assert!(proof.with_program_vk(&other_program_vk).verify().is_err());
+
+    let mut other_setup = proof.clone();
+    let ProofBody::Plonk { plonk_vk, .. } = &mut other_setup.body else { unreachable!() };
+    plonk_vk.vadcop_vk[0] ^= 1;
+    assert!(other_setup.verify().is_err());
+}
+
+#[test]
+fn test_groth16_fixture_verifies() {
+    check_public_input("groth16");
+    groth16_proof().verify().unwrap();
+}
+
+#[test]
+fn test_groth16_fixture_rejects_other_values() {
+    let proof = groth16_proof();
+
+    let other_publics = PublicValues::new_from_u64(&[0; PROGRAM_VK_LEN + ZISK_PUBLICS]);
+    assert!(proof.with_publics(&other_publics).verify().is_err());
+
+    let other_program_vk = ProgramVK { vk: vec![1, 2, 3, 4] };
+    assert!(proof.with_program_vk(&other_program_vk).verify().is_err());
+
+    let mut other_setup = proof.clone();
+    let ProofBody::Groth16 { groth16_vk, .. } = &mut other_setup.body else { unreachable!() };
+    groth16_vk.vadcop_vk[0] ^= 1;
+    assert!(other_setup.verify().is_err());
+}
+
+#[test]
+fn test_solidity_fixtures_match_export() {
+    for (protocol, proof) in [("plonk", plonk_proof()), ("groth16", groth16_proof())] {
+        let solidity = SolidityFixture::load(fixture(protocol, "solidity.json")).unwrap();
+        assert_eq!(proof.solidity_fixture().unwrap(), solidity);
+        assert_eq!(proof.to_solidity_calldata().unwrap(), solidity.calldata().unwrap());
+    }
+}
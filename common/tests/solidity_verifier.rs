@@ -0,0 +1,178 @@
+//! Round trip of the Solidity fixtures in `tests/fixtures` against the contracts in
+//! `zisk-contracts`, executed in an in-process EVM. The contracts are compiled with the `solc`
+//! found in `PATH`; the tests are skipped if there is none.
+//!
+//! The fixtures are real proofs of the keys in `tests/fixtures`. The contracts are compiled with
+//! the verifiers exported for the fixture keys, `tests/fixtures/plonk/PlonkVerifier.sol` and
+//! `tests/fixtures/groth16/Groth16Verifier.sol`, in place of the ones of the production setups.
+//! `snark_proofs.rs` checks that the fixtures are the export of the proofs.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use alloy_sol_types::{sol, SolCall, SolError};
+use revm::db::{CacheDB, EmptyDB};
+use revm::primitives::{Address, ExecutionResult, Output, TxKind};
+use revm::Evm;
+use zisk_common::SolidityFixture;
+
+sol! {
+    function hashPublicValues(
+        bytes32 programVK,
+        bytes32 rootCVadcopFinal,
+        bytes publicValues
+    ) external pure returns (uint256);
+
+    error InvalidProof();
+}
+
+const CALLER: Address = Address::repeat_byte(0x11);
+
+fn fixture(protocol: &str) -> SolidityFixture {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(protocol);
+    SolidityFixture::load(path.join("solidity.json")).unwrap()
+}
+
+/// Copy of `zisk-contracts` with the verifiers of the fixture keys, one per deployment as the
+/// tests run concurrently
+fn fixture_contracts() -> PathBuf {
+    static COPIES: AtomicUsize = AtomicUsize::new(0);
+    let copy = COPIES.fetch_add(1, Ordering::Relaxed);
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = std::env::temp_dir().join(format!("zisk-contracts-{}-{copy}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for entry in std::fs::read_dir(manifest_dir.join("../zisk-contracts")).unwrap() {
+        let path = entry.unwrap().path();
+        std::fs::copy(&path, dir.join(path.file_name().unwrap())).unwrap();
+    }
+    for verifier in ["plonk/PlonkVerifier.sol", "groth16/Groth16Verifier.sol"] {
+        let fixture = manifest_dir.join("tests/fixtures").join(verifier);
+        std::fs::copy(&fixture, dir.join(fixture.file_name().unwrap())).unwrap();
+    }
+    dir
+}
+
+/// Runtime of a deployed Zisk verifier contract
+struct Verifier {
+    evm: Evm<'static, (), CacheDB<EmptyDB>>,
+    address: Address,
+}
+
+impl Verifier {
+    /// Deploys `ZiskVerifier` for Plonk fixtures or `ZiskGroth16Verifier` for Groth16 ones
+    fn deploy(protocol: &str) -> Option<Self> {
+        let contract = match protocol {
+            "plonk" => "ZiskVerifier",
+            "groth16" => "ZiskGroth16Verifier",
+            _ => unreachable!(),
+        };
+        let output = match Command::new("solc")
+            .args(["--combined-json", "bin", "--optimize", &format!("{contract}.sol")])
+            .current_dir(fixture_contracts())
+            .output()
+        {
+            Ok(output) => output,
+            Err(_) => {
+                eprintln!("skipping: solc not found in PATH");
+                return None;
+            }
+        };
+        assert!(
+            output.status.success(),
+            "solc failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let bin = json["contracts"][format!("{contract}.sol:{contract}")]["bin"]
+            .as_str()
+            .unwrap_or_else(|| panic!("{contract} bytecode in solc output"));
+
+        let mut evm = Evm::builder()
+            .with_db(CacheDB::new(EmptyDB::default()))
+            .modify_cfg_env(|cfg| cfg.limit_contract_code_size = Some(usize::MAX))
+            .modify_tx_env(|tx| {
+                tx.caller = CALLER;
+                tx.transact_to = TxKind::Create;
+                tx.data = hex::decode(bin).unwrap().into();
+            })
+            .build();
+        let address = match evm.transact_commit().unwrap() {
+            ExecutionResult::Success { output: Output::Create(_, Some(address)), .. } => address,
+            result => panic!("{contract} deployment failed: {result:?}"),
+        };
+        Some(Self { evm, address })
+    }
+
+    fn call(&mut self, calldata: Vec<u8>) -> ExecutionResult {
+        let tx = self.evm.tx_mut();
+        tx.transact_to = TxKind::Call(self.address);
+        tx.data = calldata.into();
+        self.evm.transact().unwrap().result
+    }
+}
+
+fn decode(value: &str) -> Vec<u8> {
+    hex::decode(value.trim_start_matches("0x")).unwrap()
+}
+
+fn decode_32(value: &str) -> [u8; 32] {
+    decode(value).try_into().unwrap()
+}
+
+fn check_digest(protocol: &str) {
+    let Some(mut verifier) = Verifier::deploy(protocol) else { return };
+    let fixture = fixture(protocol);
+
+    let call = hashPublicValuesCall {
+        programVK: decode_32(&fixture.program_vk).into(),
+        rootCVadcopFinal: decode_32(&fixture.root_c_vadcop_final).into(),
+        publicValues: decode(&fixture.public_values).into(),
+    };
+    let output = match verifier.call(call.abi_encode()) {
+        ExecutionResult::Success { output: Output::Call(output), .. } => output,
+        result => panic!("hashPublicValues failed: {result:?}"),
+    };
+    assert_eq!(&output[..], decode_32(&fixture.public_values_digest));
+}
+
+fn check_verifies(protocol: &str) {
+    let Some(mut verifier) = Verifier::deploy(protocol) else { return };
+    let fixture = fixture(protocol);
+
+    match verifier.call(fixture.calldata().unwrap()) {
+        ExecutionResult::Success { .. } => {}
+        result => panic!("{protocol} fixture was not verified: {result:?}"),
+    }
+
+    // Other public values than those of the proof are rejected
+    let mut other_publics = fixture.clone();
+    other_publics.public_values = format!("0x{}", hex::encode([0u8; 256]));
+    match verifier.call(other_publics.calldata().unwrap()) {
+        ExecutionResult::Revert { output, .. } => {
+            assert_eq!(&output[..], InvalidProof::SELECTOR);
+        }
+        result => panic!("expected InvalidProof, got {result:?}"),
+    }
+}
+
+#[test]
+fn contract_digest_matches_plonk_fixture() {
+    check_digest("plonk");
+}
+
+#[test]
+fn contract_digest_matches_groth16_fixture() {
+    check_digest("groth16");
+}
+
+#[test]
+fn contract_verifies_plonk_fixture() {
+    check_verifies("plonk");
+}
+
+#[test]
+fn contract_verifies_groth16_fixture() {
+    check_verifies("groth16");
+}
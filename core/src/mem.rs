@@ -34,6 +34,7 @@
 //! `| Contains system address.`
 //! `| The first 256 bytes contain 32 8-byte registers`
 //! `| The address UART_ADDR is used as a stdout at addr = 0xa0000200`
+//! `| The address UART_ERR_ADDR is used as a stderr at addr = 0xa0000208`
 //! `| The first float register is at         FREG_FIRST = 0xa0001000`
 //! `| The first CSR register is at             CSR_ADDR = 0xa0008000`
 //! `|`
@@ -90,7 +91,8 @@
 //! * The lower addresses of this region is used to store 32 registers of 8 bytes each, i.e. 256
 //!   bytes in total.  These registers are the equivalent to the RISC-V registers.
 //! * Any data of exactly 1-byte length written to UART_ADDR will be sent to the standard output of
-//!   the system.
+//!   the system, and any data of exactly 1-byte length written to UART_ERR_ADDR will be sent to
+//!   the standard error of the system.
 //! * The second RW memory region going from `OUTPUT_ADDR` to `AVAILABLE_MEM_ADDR` is reserved to
 //!   copy the output data during the program execution.
 //! * The third RW memory region going from `AVAILABLE_MEM_ADDR` onwards can be used during the
@@ -143,6 +145,8 @@ pub const FLOAT_LIB_SP: u64 = 0xc0000000 - 16; // 0xbffffff0
 pub const ARCH_ID_ZISK: u64 = 0xFFFEEEE;
 /// UART memory address; single bytes written here will be copied to the standard output
 pub const UART_ADDR: u64 = SYS_ADDR + 0x200;
+/// UART error memory address; single bytes written here will be copied to the standard error
+pub const UART_ERR_ADDR: u64 = SYS_ADDR + 0x208;
 /// Extra parameters of repcompiles are stored in fixed memory area (256 bytes => 32 parameters)
 pub const EXTRA_PARAMS_ADDR: u64 = SYS_ADDR + 0x0F00;
 /// Float registers first address
@@ -589,9 +593,13 @@ impl Mem {
         // Call write_silent to perform the real work
         self.write_silent(addr, val, width);
 
-        // Log to console bytes written to UART address
-        if (addr == UART_ADDR) && (width == 1) {
-            print!("{}", String::from(val as u8 as char));
+        // Log to console bytes written to UART addresses
+        if width == 1 {
+            if addr == UART_ADDR {
+                print!("{}", String::from(val as u8 as char));
+            } else if addr == UART_ERR_ADDR {
+                eprint!("{}", String::from(val as u8 as char));
+            }
         }
     }
 
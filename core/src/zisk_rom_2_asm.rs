@@ -9,7 +9,7 @@ use crate::{
     zisk_ops::ZiskOp, AsmGenerationMethod, ZiskInst, ZiskRom, EXTRA_PARAMS_ADDR,
     FLOAT_LIB_ROM_ADDR, FREE_INPUT_ADDR, INPUT_ADDR, M64, P2_32, ROM_ADDR, ROM_ADDR_MAX, ROM_ENTRY,
     SRC_C, SRC_IMM, SRC_IND, SRC_MEM, SRC_REG, SRC_STEP, STORE_IND, STORE_MEM, STORE_NONE,
-    STORE_REG,
+    STORE_REG, UART_ADDR, UART_ERR_ADDR,
 };
 
 // Regs rax, rcx, rdx, rdi, rsi, rsp, and r8-r11 are caller-save, not saved across function calls.
@@ -630,6 +630,7 @@ impl ZiskRom2Asm {
         *code += ".section .text\n";
         *code += ".extern print_abcflag\n";
         *code += ".extern print_char\n";
+        *code += ".extern print_err_char\n";
         *code += ".extern print_step\n";
         *code += ".extern opcode_keccak\n";
         *code += ".extern opcode_sha256\n";
@@ -3262,43 +3263,22 @@ impl ZiskRom2Asm {
                                     );
                                 }
                                 if ctx.log_output {
-                                    *code += &format!(
-                                        "\tmov {}, 0xa0000200 {}\n",
-                                        REG_FLAG,
-                                        ctx.comment_str("width=1: aux = UART")
-                                    );
-                                    *code += &format!(
-                                        "\tcmp {}, {} {}\n",
-                                        REG_ADDRESS,
-                                        REG_FLAG,
-                                        ctx.comment_str(
-                                            "width=1: if address = USART then print char"
-                                        )
+                                    Self::print_uart_char(
+                                        &mut ctx,
+                                        code,
+                                        instruction,
+                                        UART_ADDR,
+                                        "_print_char",
+                                        "uart",
                                     );
-                                    *code += &format!(
-                                        "\tjne pc_{:x}_store_c_not_uart {}\n",
-                                        ctx.pc,
-                                        ctx.comment_str("width=1: continue")
+                                    Self::print_uart_char(
+                                        &mut ctx,
+                                        code,
+                                        instruction,
+                                        UART_ERR_ADDR,
+                                        "_print_err_char",
+                                        "uart_err",
                                     );
-                                    if instruction.store_pc {
-                                        *code += &format!(
-                                            "\tmov dil, 0x{:x} {}\n",
-                                            (ctx.pc as i64 + instruction.jmp_offset2) as u64 as u8,
-                                            ctx.comment_str("width=1: rdi = value")
-                                        );
-                                    } else {
-                                        *code += &format!(
-                                            "\tmov dil, {} {}\n",
-                                            REG_C_B,
-                                            ctx.comment_str("width=1: rdi = c")
-                                        );
-                                    }
-                                    Self::push_internal_registers(&mut ctx, code, false);
-                                    //Self::assert_rsp_is_aligned(&mut ctx, code);
-                                    *code += "\tcall _print_char\n";
-                                    Self::pop_internal_registers(&mut ctx, code, false);
-                                    //Self::assert_rsp_is_aligned(&mut ctx, code);
-                                    *code += &format!("pc_{:x}_store_c_not_uart:\n", ctx.pc);
                                 }
                             }
                             _ => panic!(
@@ -9373,6 +9353,51 @@ impl ZiskRom2Asm {
         *code += &format!("\tmov rsp, {} {}\n", ctx.mem_rsp, ctx.comment_str("restore rsp"));
     }
 
+    /// Generates the code that calls `print_function` with the stored byte if the 1-byte store
+    /// address is `uart_addr`
+    fn print_uart_char(
+        ctx: &mut ZiskAsmContext,
+        code: &mut String,
+        instruction: &ZiskInst,
+        uart_addr: u64,
+        print_function: &str,
+        label: &str,
+    ) {
+        *code += &format!(
+            "\tmov {}, 0x{:x} {}\n",
+            REG_FLAG,
+            uart_addr,
+            ctx.comment_str("width=1: aux = UART")
+        );
+        *code += &format!(
+            "\tcmp {}, {} {}\n",
+            REG_ADDRESS,
+            REG_FLAG,
+            ctx.comment_str("width=1: if address = UART then print char")
+        );
+        *code += &format!(
+            "\tjne pc_{:x}_store_c_not_{} {}\n",
+            ctx.pc,
+            label,
+            ctx.comment_str("width=1: continue")
+        );
+        if instruction.store_pc {
+            *code += &format!(
+                "\tmov dil, 0x{:x} {}\n",
+                (ctx.pc as i64 + instruction.jmp_offset2) as u64 as u8,
+                ctx.comment_str("width=1: rdi = value")
+            );
+        } else {
+            *code += &format!("\tmov dil, {} {}\n", REG_C_B, ctx.comment_str("width=1: rdi = c"));
+        }
+        Self::push_internal_registers(ctx, code, false);
+        //Self::assert_rsp_is_aligned(ctx, code);
+        *code += &format!("\tcall {print_function}\n");
+        Self::pop_internal_registers(ctx, code, false);
+        //Self::assert_rsp_is_aligned(ctx, code);
+        *code += &format!("pc_{:x}_store_c_not_{}:\n", ctx.pc, label);
+    }
+
     fn push_internal_registers(ctx: &mut ZiskAsmContext, code: &mut String, extra_8: bool) {
         *code += "\tpush rax\n";
         *code += "\tpush rcx\n";
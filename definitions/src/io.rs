@@ -0,0 +1,31 @@
+//! Encoding of the guest input items and layout of the public output slots written by `ziskos`,
+//! shared by the guest and the host tools that build its input or read its outputs
+//!
+//! The 64 public output slots are 32-bit words. Programs commit their outputs from slot 0 on,
+//! while the slots from [`HOST_ENV_COMMITMENT_OUTPUT_ID`] on are reserved for the features of
+//! `ziskos` that commit to them:
+//!
+//! | Slots  | Content                | `ziskos` feature    |
+//! |--------|------------------------|---------------------|
+//! | 39..47 | Host env commitment    | `host-env`          |
+//! | 47..55 | RNG seed commitment    | `host-rng-seed`     |
+//! | 55..63 | Input commitment       | `input-commitment`  |
+//! | 63     | Exit code              | `commit-exit-code`  |
+
+/// Bit of the length prefix of an input item set when the item is public
+pub const PUBLIC_INPUT_FLAG: u64 = 1 << 63;
+
+/// First public output slot of the host environment commitment, which takes 8 slots
+pub const HOST_ENV_COMMITMENT_OUTPUT_ID: usize = 39;
+
+/// First public output slot of the RNG seed commitment, which takes 8 slots
+pub const RNG_SEED_COMMITMENT_OUTPUT_ID: usize = 47;
+
+/// First public output slot of the input commitment, which takes 8 slots
+pub const INPUT_COMMITMENT_OUTPUT_ID: usize = 55;
+
+/// Size in bytes of the input commitment
+pub const INPUT_COMMITMENT_SIZE: usize = 32;
+
+/// Public output slot of the exit code
+pub const EXIT_CODE_OUTPUT_ID: usize = 63;
@@ -8,3 +8,6 @@ pub use profile::*;
 
 mod labels;
 pub use labels::*;
+
+mod io;
+pub use io::*;
@@ -142,6 +142,7 @@ impl From<WorkerRegisterResponseDto> for WorkerRegisterResponse {
             }),
             directive: None,
             setup_program: None,
+            compact_proofs: true,
         }
     }
 }
@@ -329,6 +330,7 @@ impl From<ExecuteTaskResponse> for ExecuteTaskResponseDto {
                     instances: exec_data.instances,
                     executed_steps: exec_data.executed_steps,
                     publics,
+                    exit_code: exec_data.exit_code,
                     zisk_executor_time: ZiskExecutorTimeDto {
                         task_received_time: zisk_execution_time.task_received_time,
                         total_duration: zisk_execution_time.total_duration,
@@ -54,7 +54,7 @@ pub struct LaunchProofResponseDto {
 }
 
 pub struct LaunchWrapRequestDto {
-    pub proof_data: Vec<u8>, // bincode-encoded Proof
+    pub proof_data: Vec<u8>, // Proof::to_legacy_bytes
     pub proof_dest: i32,     // ProofKind value
 }
 
@@ -241,6 +241,7 @@ pub struct ExecutionResultDataDto {
     pub executed_steps: u64,
     pub zisk_executor_time: ZiskExecutorTimeDto,
     pub publics: Vec<u64>,
+    pub exit_code: Option<i32>,
 }
 
 pub struct AggParamsDto {
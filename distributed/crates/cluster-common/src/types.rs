@@ -477,6 +477,7 @@ pub struct ExecutionResult {
     pub zisk_executor_time: ZiskExecutorTime,
     pub task_received_time: Option<chrono::DateTime<chrono::Utc>>,
     pub public_outputs: Vec<u8>,
+    pub exit_code: Option<i32>,
 }
 
 #[derive(Debug, Clone)]
@@ -1,10 +1,12 @@
 //! Domain types shared across coordinator and SDK.
 //!
 //! These types are the canonical representation of coordinator API concepts.
+//! They also serialize to JSON for the HTTP gateway; byte payloads are hex-encoded.
 
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Convert a [`Duration`] into a future deadline.
@@ -15,11 +17,14 @@ pub fn deadline_from_now(d: Duration) -> DateTime<Utc> {
     Utc::now().checked_add_signed(chrono_dur).unwrap_or(DateTime::<Utc>::MAX_UTC)
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct RegisterGuestProgramRequestDto {
     /// The ELF bytes of the guest program to register.
+    #[serde(with = "hex_bytes")]
     pub zisk_elf: Vec<u8>,
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct RegisterGuestProgramResponseDto {
     /// blake3 content hash of zisk_elf
     pub hash_id: String,
@@ -27,16 +32,18 @@ pub struct RegisterGuestProgramResponseDto {
 
 /// Result of [`submit_job`] — the coordinator-assigned job ID plus any
 /// stream URIs allocated by the coordinator for auto-negotiated transports.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubmitJobResult {
     pub job_id: Uuid,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DomainProofKind {
     Stark,
     StarkMinimal,
     Plonk,
+    Groth16,
 }
 
 impl From<zisk_common::ProofKind> for DomainProofKind {
@@ -45,41 +52,50 @@ impl From<zisk_common::ProofKind> for DomainProofKind {
             zisk_common::ProofKind::VadcopFinal => DomainProofKind::Stark,
             zisk_common::ProofKind::VadcopFinalMinimal => DomainProofKind::StarkMinimal,
             zisk_common::ProofKind::Plonk => DomainProofKind::Plonk,
+            zisk_common::ProofKind::Groth16 => DomainProofKind::Groth16,
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DomainJobPhase {
     Contributions,
     Prove,
     Aggregate,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainInputChunk {
+    #[serde(with = "hex_bytes")]
     pub data: Vec<u8>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DomainInputKind {
     Inline(DomainInputChunk),
     StreamUri(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainProof {
     pub proof_id: Uuid,
     pub hash_id: String,
+    #[serde(with = "hex_bytes")]
     pub verification_key: Vec<u8>,
     pub proof_kind: DomainProofKind,
+    /// `zisk_common::Proof` encoded with `Proof::to_legacy_bytes`.
+    #[serde(with = "hex_bytes")]
     pub data: Vec<u8>,
+    #[serde(with = "hex_bytes")]
     pub public_inputs: Vec<u8>,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum DomainJobKind {
     Setup(DomainSetupRequest),
     Prove(DomainProveRequest),
@@ -89,20 +105,20 @@ pub enum DomainJobKind {
 
 /// Optional compute capacity hint attached to a job request.
 /// When absent the coordinator applies its configured defaults.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainComputeConstraints {
     pub requested: u32,
     pub minimum: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainSetupRequest {
     pub hash_id: String,
     pub program_name: String,
     pub with_hints: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainProveRequest {
     pub hash_id: String,
     pub input: DomainInputKind,
@@ -111,14 +127,14 @@ pub struct DomainProveRequest {
     pub proof_dest: DomainProofKind,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainWrapRequest {
     pub proof: DomainProof,
     pub proof_dest: DomainProofKind,
     pub wrap_timeout: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainExecuteRequest {
     pub hash_id: String,
     pub input: DomainInputKind,
@@ -126,7 +142,7 @@ pub struct DomainExecuteRequest {
     pub execute_timeout: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DomainExecutionStats {
     pub steps: u64,
     pub duration_nanos: u64,
@@ -138,15 +154,30 @@ pub struct DomainExecutionStats {
     pub other_cost: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum DomainJobKindResponse {
-    Setup { vk: Vec<u8> },
-    Prove { proof: DomainProof, stats: DomainExecutionStats },
+    Setup {
+        #[serde(with = "hex_bytes")]
+        vk: Vec<u8>,
+    },
+    Prove {
+        proof: DomainProof,
+        stats: DomainExecutionStats,
+    },
     Wrap(DomainProof),
-    Execute { stats: DomainExecutionStats, public_outputs: Vec<u8> },
+    Execute {
+        stats: DomainExecutionStats,
+        #[serde(with = "hex_bytes")]
+        public_outputs: Vec<u8>,
+        /// Exit code of the guest program, if reported by the worker
+        #[serde(default)]
+        exit_code: Option<i32>,
+    },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "detail", rename_all = "snake_case")]
 pub enum DomainJobStatus {
     Queued,
     Running(Option<DomainJobPhase>),
@@ -162,7 +193,8 @@ impl DomainJobStatus {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum DomainJobFailure {
     Timeout { phase: Option<DomainJobPhase>, limit: Duration },
     Input { reason: String },
@@ -171,7 +203,8 @@ pub enum DomainJobFailure {
     Cancelled,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
 pub enum DomainJobEvent {
     Queued(DomainJobEventQueued),
     Started(DomainJobEventStarted),
@@ -182,45 +215,48 @@ pub enum DomainJobEvent {
     Failed(DomainJobEventFailed),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainJobEventQueued {
     pub job_id: Uuid,
     pub timestamp: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainJobEventStarted {
     pub job_id: Uuid,
     pub timestamp: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainJobEventProgress {
     pub job_id: Uuid,
     pub phase: DomainJobPhase,
     pub timestamp: DateTime<Utc>,
+    /// Number of workers taking part in the phase, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workers: Option<u32>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainJobEventWaitingForInput {
     pub job_id: Uuid,
     pub timestamp: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainJobEventCompleted {
     pub job_id: Uuid,
     pub result: DomainJobKindResponse,
     pub timestamp: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainJobEventCancelled {
     pub job_id: Uuid,
     pub timestamp: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomainJobEventFailed {
     pub job_id: Uuid,
     pub failure: DomainJobFailure,
@@ -236,10 +272,24 @@ pub enum TerminalStatus {
 }
 
 /// Returned by the coordinator `wait_job_result` long-poll.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct WaitResult {
     pub job_id: Uuid,
     pub job_status: DomainJobStatus,
     /// Present only when `job_status` is [`DomainJobStatus::Completed`].
     pub result: Option<DomainJobKindResponse>,
 }
+
+/// Serde adapter that encodes byte payloads as lowercase hex strings.
+mod hex_bytes {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s.strip_prefix("0x").unwrap_or(&s)).map_err(serde::de::Error::custom)
+    }
+}
@@ -80,6 +80,7 @@ impl From<DomainProofKind> for ProofKind {
             DomainProofKind::Stark => ProofKind::Stark,
             DomainProofKind::StarkMinimal => ProofKind::StarkMinimal,
             DomainProofKind::Plonk => ProofKind::Plonk,
+            DomainProofKind::Groth16 => ProofKind::Groth16,
         }
     }
 }
@@ -92,6 +93,7 @@ impl TryFrom<i32> for DomainProofKind {
             ProofKind::Stark => Ok(DomainProofKind::Stark),
             ProofKind::StarkMinimal => Ok(DomainProofKind::StarkMinimal),
             ProofKind::Plonk => Ok(DomainProofKind::Plonk),
+            ProofKind::Groth16 => Ok(DomainProofKind::Groth16),
             _ => Err(value),
         }
     }
@@ -315,8 +317,12 @@ impl From<DomainJobKindResponse> for JobKindResponse {
             DomainJobKindResponse::Wrap(proof) => {
                 Kind::Wrap(WrapResponse { proof: Some(proof.into()) })
             }
-            DomainJobKindResponse::Execute { stats, public_outputs } => {
-                Kind::Execute(ExecuteResponse { stats: Some(stats.into()), public_outputs })
+            DomainJobKindResponse::Execute { stats, public_outputs, exit_code } => {
+                Kind::Execute(ExecuteResponse {
+                    stats: Some(stats.into()),
+                    public_outputs,
+                    exit_code,
+                })
             }
         };
         JobKindResponse { kind: Some(kind) }
@@ -403,6 +409,7 @@ impl From<DomainJobEvent> for JobEvent {
                 job_id: e.job_id.to_string(),
                 phase: JobPhase::from(e.phase).into(),
                 timestamp: Some(datetime_to_ts(e.timestamp)),
+                workers: e.workers,
             }),
             DomainJobEvent::WaitingForInput(e) => Event::WaitingForInput(JobEventWaitingForInput {
                 job_id: e.job_id.to_string(),
@@ -536,7 +543,11 @@ impl TryFrom<JobKindResponse> for DomainJobKindResponse {
             }
             Kind::Execute(r) => {
                 let stats = r.stats.map(DomainExecutionStats::from).unwrap_or_default();
-                Ok(DomainJobKindResponse::Execute { stats, public_outputs: r.public_outputs })
+                Ok(DomainJobKindResponse::Execute {
+                    stats,
+                    public_outputs: r.public_outputs,
+                    exit_code: r.exit_code,
+                })
             }
         }
     }
@@ -560,6 +571,7 @@ impl TryFrom<JobEvent> for DomainJobEvent {
                 job_id: parse_uuid(&e.job_id).map_err(|e| format!("{e}"))?,
                 phase: DomainJobPhase::try_from(e.phase())?,
                 timestamp: e.timestamp.and_then(ts_to_datetime).unwrap_or_else(chrono::Utc::now),
+                workers: e.workers,
             })),
             Event::WaitingForInput(e) => {
                 Ok(DomainJobEvent::WaitingForInput(DomainJobEventWaitingForInput {
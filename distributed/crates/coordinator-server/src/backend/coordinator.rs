@@ -79,8 +79,12 @@ fn coord_result_to_domain(result: CoordinatorJobResult, hash_id: &str) -> Domain
             proof: make_proof(hash_id.to_string(), proof_bytes),
             stats: coord_stats_to_domain(stats),
         },
-        CoordinatorJobResult::Execute { stats, public_outputs } => {
-            DomainJobKindResponse::Execute { stats: coord_stats_to_domain(stats), public_outputs }
+        CoordinatorJobResult::Execute { stats, public_outputs, exit_code } => {
+            DomainJobKindResponse::Execute {
+                stats: coord_stats_to_domain(stats),
+                public_outputs,
+                exit_code,
+            }
         }
         CoordinatorJobResult::Wrap { proof_bytes } => {
             DomainJobKindResponse::Wrap(make_proof(hash_id.to_string(), proof_bytes))
@@ -112,11 +116,12 @@ fn coord_event_to_domain(
         CoordinatorJobEvent::Started => {
             Some(DomainJobEvent::Started(DomainJobEventStarted { job_id, timestamp: ts }))
         }
-        CoordinatorJobEvent::Progress(phase) => {
+        CoordinatorJobEvent::Progress { phase, workers } => {
             Some(DomainJobEvent::Progress(DomainJobEventProgress {
                 job_id,
                 phase: coord_phase_to_domain(&phase),
                 timestamp: ts,
+                workers: Some(workers),
             }))
         }
         CoordinatorJobEvent::WaitingForInput => {
@@ -210,23 +215,29 @@ fn is_terminal(event: &CoordinatorJobEvent) -> bool {
 
 /// Synthesize domain events the watcher missed before subscribing.
 ///
-/// Queued and Started fire atomically at job creation, before `submit_job`
-/// returns. Any client calling `watch_job` after submission has always missed
-/// them. For jobs already past Contributions, the phase-transition Progress
-/// events are also synthesized. The terminal event itself is NOT synthesized
+/// Queued, Started and Progress(Contributions) fire atomically at job creation,
+/// before `submit_job` returns. Any client calling `watch_job` after submission
+/// has always missed them. For jobs already past Contributions, the
+/// phase-transition Progress events are also synthesized. The terminal event itself is NOT synthesized
 /// here — callers should fetch the stashed real event via
 /// `Coordinator::get_terminal_event` and append it separately.
 fn catchup_events(state: &JobState, job_id: Uuid) -> Vec<DomainJobEvent> {
     let ts = Utc::now();
     let queued = DomainJobEvent::Queued(DomainJobEventQueued { job_id, timestamp: ts });
     let started = DomainJobEvent::Started(DomainJobEventStarted { job_id, timestamp: ts });
-    let progress =
-        |phase| DomainJobEvent::Progress(DomainJobEventProgress { job_id, phase, timestamp: ts });
+    let progress = |phase| {
+        DomainJobEvent::Progress(DomainJobEventProgress {
+            job_id,
+            phase,
+            timestamp: ts,
+            workers: None,
+        })
+    };
 
     match state {
         JobState::Created => vec![queued],
         JobState::Running(phase) => {
-            let mut events = vec![queued, started];
+            let mut events = vec![queued, started, progress(DomainJobPhase::Contributions)];
             // Synthesize Progress events for phases already past.
             // Progress(Prove) fires when Prove starts; Progress(Aggregate) when Aggregate starts.
             match phase {
@@ -272,6 +283,7 @@ impl BackendService for CoordinatorBackend {
                 let proof_type = match r.proof_dest {
                     DomainProofKind::StarkMinimal => ProofKind::VadcopFinalMinimal,
                     DomainProofKind::Plonk => ProofKind::Plonk,
+                    DomainProofKind::Groth16 => ProofKind::Groth16,
                     _ => ProofKind::VadcopFinal,
                 };
                 let hints_mode = domain_hints_to_dto(&r.hints);
@@ -324,6 +336,7 @@ impl BackendService for CoordinatorBackend {
                 let proof_dest = match r.proof_dest {
                     DomainProofKind::StarkMinimal => 1,
                     DomainProofKind::Plonk => 2,
+                    DomainProofKind::Groth16 => 3,
                     DomainProofKind::Stark => 1,
                 };
                 let response = self
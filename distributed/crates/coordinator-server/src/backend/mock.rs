@@ -7,11 +7,11 @@
 //! ## Job lifecycle (mock timing)
 //!
 //! ```text
-//! t=0ms   Queued          → JobEvent::Queued
-//! t=20ms  Running         → JobEvent::Started
-//! t=40ms  (Prove only)    → JobEvent::Progress(Contributions)
-//! t=80ms  (Prove only)    → JobEvent::Progress(Prove)
-//! t=2s    Completed       → JobEvent::Completed
+//! t=0ms   Queued          → DomainJobEvent::Queued
+//! t=20ms  Running         → DomainJobEvent::Started
+//! t=40ms  (Prove only)    → DomainJobEvent::Progress (phase Contributions)
+//! t=80ms  (Prove only)    → DomainJobEvent::Progress (phase Prove)
+//! t=2s    Completed       → DomainJobEvent::Completed
 //! ```
 
 use std::collections::{HashMap, HashSet};
@@ -252,6 +252,7 @@ impl MockBackend {
                         job_id,
                         phase: DomainJobPhase::Contributions,
                         timestamp: Utc::now(),
+                        workers: Some(1),
                     }),
                 )
                 .await;
@@ -277,6 +278,7 @@ impl MockBackend {
                         job_id,
                         phase: DomainJobPhase::Prove,
                         timestamp: Utc::now(),
+                        workers: Some(1),
                     }),
                 )
                 .await;
@@ -565,6 +567,7 @@ fn synthesize_result(kind: &DomainJobKind) -> DomainJobKindResponse {
         DomainJobKind::Execute(_) => DomainJobKindResponse::Execute {
             stats: DomainExecutionStats::default(),
             public_outputs: vec![],
+            exit_code: Some(0),
         },
         DomainJobKind::Prove(req) => DomainJobKindResponse::Prove {
             proof: DomainProof {
@@ -600,6 +603,7 @@ fn synthesize_terminal_event(
             result: result.unwrap_or(DomainJobKindResponse::Execute {
                 stats: DomainExecutionStats::default(),
                 public_outputs: vec![],
+                exit_code: Some(0),
             }),
             timestamp: now,
         }),
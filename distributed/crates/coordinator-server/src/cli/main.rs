@@ -55,6 +55,15 @@ struct Args {
     )]
     metrics_port: Option<u16>,
 
+    /// Enable the HTTP/JSON gateway on this port.
+    #[arg(
+        long,
+        env = "ZISK_COORDINATOR_HTTP_PORT",
+        value_name = "PORT",
+        help = "Enable the HTTP/JSON gateway on this port (disabled by default)"
+    )]
+    http_port: Option<u16>,
+
     /// Override the log level.
     #[arg(
         long,
@@ -74,6 +83,7 @@ async fn main() -> Result<()> {
         args.api_port,
         args.cluster_port,
         args.metrics_port,
+        args.http_port,
         args.log_level,
     )?;
 
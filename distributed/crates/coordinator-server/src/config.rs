@@ -3,7 +3,7 @@
 //! Load order (later entries override earlier):
 //! 1. Built-in defaults
 //! 2. TOML file (path from `--config` or `ZISK_COORDINATOR_CONFIG`)
-//! 3. CLI flags / env vars: --api-port, --cluster-port, --metrics-port, --log-level
+//! 3. CLI flags / env vars: --api-port, --cluster-port, --metrics-port, --http-port, --log-level
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -14,6 +14,7 @@ pub struct Config {
     pub service: ServiceConfig,
     pub server: ServerConfig,
     pub metrics: MetricsConfig,
+    pub http: HttpConfig,
     pub logging: LoggingConfig,
     pub backend: BackendConfig,
     pub coordinator: CoordinatorConfig,
@@ -40,6 +41,14 @@ pub struct MetricsConfig {
     pub port: u16,
 }
 
+/// Optional HTTP/JSON gateway serving the same API as the gRPC server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpConfig {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendConfig {
     pub mode: BackendMode,
@@ -67,6 +76,7 @@ impl Config {
         api_port: Option<u16>,
         cluster_port: Option<u16>,
         metrics_port: Option<u16>,
+        http_port: Option<u16>,
         log_level: Option<String>,
     ) -> Result<Self> {
         let mut builder = config::Config::builder()
@@ -82,6 +92,10 @@ impl Config {
             .set_default("metrics.enabled", true)?
             .set_default("metrics.host", "0.0.0.0")?
             .set_default("metrics.port", 9090)?
+            // http gateway
+            .set_default("http.enabled", false)?
+            .set_default("http.host", "0.0.0.0")?
+            .set_default("http.port", 7080)?
             // logging
             .set_default("logging.level", "info")?
             .set_default("logging.format", "pretty")?
@@ -113,6 +127,10 @@ impl Config {
         if let Some(p) = metrics_port {
             builder = builder.set_override("metrics.port", p)?;
         }
+        // Passing an HTTP port also turns the gateway on.
+        if let Some(p) = http_port {
+            builder = builder.set_override("http.port", p)?.set_override("http.enabled", true)?;
+        }
         if let Some(level) = log_level {
             builder = builder.set_override("logging.level", level)?;
         }
@@ -127,6 +145,10 @@ impl Config {
     pub fn metrics_addr(&self) -> String {
         format!("{}:{}", self.metrics.host, self.metrics.port)
     }
+
+    pub fn http_addr(&self) -> String {
+        format!("{}:{}", self.http.host, self.http.port)
+    }
 }
 
 /// Returns the well-known coordinator config file paths, ordered from least to most specific.
@@ -157,36 +179,45 @@ mod tests {
 
     #[test]
     fn defaults_load_without_file() {
-        let cfg = Config::load(None, None, None, None, None).unwrap();
+        let cfg = Config::load(None, None, None, None, None, None).unwrap();
         assert_eq!(cfg.server.host, "0.0.0.0");
         assert_eq!(cfg.server.port, 7000);
         assert_eq!(cfg.coordinator.port, 50051);
         assert_eq!(cfg.metrics.port, 9090);
+        assert!(!cfg.http.enabled);
+        assert_eq!(cfg.http.port, 7080);
         assert_eq!(cfg.backend.mode, BackendMode::Coordinator);
         assert_eq!(cfg.service.version, env!("CARGO_PKG_VERSION"));
     }
 
     #[test]
     fn cli_api_port_override() {
-        let cfg = Config::load(None, Some(8080), None, None, None).unwrap();
+        let cfg = Config::load(None, Some(8080), None, None, None, None).unwrap();
         assert_eq!(cfg.server.port, 8080);
     }
 
     #[test]
     fn cli_cluster_port_override() {
-        let cfg = Config::load(None, None, Some(50100), None, None).unwrap();
+        let cfg = Config::load(None, None, Some(50100), None, None, None).unwrap();
         assert_eq!(cfg.coordinator.port, 50100);
     }
 
     #[test]
     fn cli_metrics_port_override() {
-        let cfg = Config::load(None, None, None, Some(9999), None).unwrap();
+        let cfg = Config::load(None, None, None, Some(9999), None, None).unwrap();
         assert_eq!(cfg.metrics.port, 9999);
     }
 
+    #[test]
+    fn cli_http_port_override_enables_gateway() {
+        let cfg = Config::load(None, None, None, None, Some(8081), None).unwrap();
+        assert!(cfg.http.enabled);
+        assert_eq!(cfg.http_addr(), "0.0.0.0:8081");
+    }
+
     #[test]
     fn grpc_addr_format() {
-        let cfg = Config::load(None, Some(9000), None, None, None).unwrap();
+        let cfg = Config::load(None, Some(9000), None, None, None, None).unwrap();
         assert_eq!(cfg.grpc_addr(), "0.0.0.0:9000");
     }
 }
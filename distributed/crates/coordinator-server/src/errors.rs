@@ -1,4 +1,4 @@
-//! API error types with stable API error codes and gRPC status mapping.
+//! API error types with stable API error codes and gRPC / HTTP status mapping.
 //!
 //! Every [`ApiError`] variant maps to a stable numeric code matching the
 //! API reference in `book/developer/coordinator_api.md`. Clients that embed the
@@ -9,6 +9,7 @@
 //! "internal server error" message is returned — no server internals leak to
 //! clients.
 
+use axum::http::StatusCode;
 use tonic::{Code, Status};
 use uuid::Uuid;
 
@@ -74,7 +75,7 @@ impl ApiError {
         }
     }
 
-    fn tonic_code(&self) -> Code {
+    pub(crate) fn tonic_code(&self) -> Code {
         match self {
             Self::JobNotFound(_) | Self::ProgramNotFound(_) | Self::ProgramNotSetup(_) => {
                 Code::NotFound
@@ -86,25 +87,41 @@ impl ApiError {
             Self::Internal(_) => Code::Internal,
         }
     }
-}
 
-impl From<ApiError> for Status {
-    fn from(err: ApiError) -> Self {
-        let code = err.tonic_code();
+    pub(crate) fn http_status(&self) -> StatusCode {
+        match self {
+            Self::JobNotFound(_) | Self::ProgramNotFound(_) | Self::ProgramNotSetup(_) => {
+                StatusCode::NOT_FOUND
+            }
+            Self::InvalidJobState { .. } => StatusCode::CONFLICT,
+            Self::InvalidProofConversion { .. } => StatusCode::BAD_REQUEST,
+            Self::ClusterUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
 
-        // For internal errors, log the real cause server-side and return a
-        // generic message — never expose internal detail to callers.
-        if let ApiError::Internal(ref detail) = err {
+    /// Message safe to return to callers, whatever the transport.
+    ///
+    /// For internal errors, logs the real cause server-side and returns a
+    /// generic message — never expose internal detail to callers.
+    pub(crate) fn client_message(&self) -> String {
+        if let ApiError::Internal(ref detail) = self {
             tracing::error!(
-                error_code = err.code(),
-                error_name = err.name(),
+                error_code = self.code(),
+                error_name = self.name(),
                 detail,
                 "internal coordinator error"
             );
-            return Status::new(code, "An internal error occurred");
+            return "An internal error occurred".to_owned();
         }
 
-        Status::new(code, err.to_string())
+        self.to_string()
+    }
+}
+
+impl From<ApiError> for Status {
+    fn from(err: ApiError) -> Self {
+        Status::new(err.tonic_code(), err.client_message())
     }
 }
 
@@ -150,4 +167,24 @@ mod tests {
         assert_eq!(Status::from(ApiError::ProgramNotFound("h".into())).code(), Code::NotFound);
         assert_eq!(Status::from(ApiError::JobNotFound(Uuid::new_v4())).code(), Code::NotFound);
     }
+
+    #[test]
+    fn http_status_mapping() {
+        assert_eq!(ApiError::JobNotFound(Uuid::new_v4()).http_status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            ApiError::InvalidJobState { reason: "x".into() }.http_status(),
+            StatusCode::CONFLICT
+        );
+        assert_eq!(
+            ApiError::ClusterUnavailable { reason: "test" }.http_status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(ApiError::Internal("x".into()).http_status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn internal_error_client_message_is_generic() {
+        let msg = ApiError::Internal("secret detail".into()).client_message();
+        assert!(!msg.contains("secret detail"));
+    }
 }
@@ -5,7 +5,6 @@
 //! at the wire boundary, and call-level observability. All business logic lives
 //! in [`crate::handler::CoordinatorHandler`].
 
-use std::borrow::Cow;
 use std::pin::Pin;
 use std::time::{Duration, Instant};
 
@@ -24,7 +23,7 @@ use zisk_coordinator_api::dto::RegisterGuestProgramRequestDto;
 
 const WAIT_TIMEOUT_DEFAULT_SECS: u32 = 5;
 const WAIT_TIMEOUT_MIN_SECS: u32 = 1;
-const WAIT_TIMEOUT_MAX_SECS: u32 = 3600;
+pub(crate) const WAIT_TIMEOUT_MAX_SECS: u32 = 3600;
 
 pub type WatchJobStream = Pin<Box<dyn Stream<Item = Result<JobEvent, Status>> + Send + 'static>>;
 
@@ -46,15 +45,7 @@ impl<B: BackendService> GrpcAdapter<B> {
             Err(s) => s.code().description(),
         };
 
-        let method_str: Cow<'static, str> = Cow::Borrowed(method);
-        metrics::counter!(
-            "coordinator_requests_total",
-            "method" => method_str.clone(),
-            "status" => status_label
-        )
-        .increment(1);
-        metrics::histogram!("coordinator_request_duration_seconds", "method" => method_str)
-            .record(elapsed_secs);
+        crate::metrics::record_request("grpc", method, status_label, elapsed_secs);
 
         match result {
             Ok(()) => {
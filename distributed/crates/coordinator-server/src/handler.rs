@@ -2,7 +2,7 @@
 //!
 //! [`CoordinatorHandler`] contains all business logic, operating exclusively on
 //! domain types. Transport adapters ([`crate::grpc::GrpcAdapter`],
-//! own the format-conversion layer and delegate here.
+//! [`crate::http::HttpAdapter`]) own the format-conversion layer and delegate here.
 
 use std::sync::Arc;
 use std::time::Duration;
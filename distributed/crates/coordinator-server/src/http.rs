@@ -0,0 +1,389 @@
+//! HTTP/JSON transport adapter.
+//!
+//! [`HttpAdapter`] exposes the [`CoordinatorHandler`] operations over plain
+//! HTTP for clients that can't speak gRPC (web dashboards, non-Rust services).
+//! Like [`crate::grpc::GrpcAdapter`], it only converts at the wire boundary and
+//! records call-level observability; all business logic lives in the handler.
+//!
+//! Request and response bodies are the JSON form of the domain DTOs in
+//! `zisk_coordinator_api::dto`. Byte payloads are hex-encoded.
+//!
+//! ## Routes
+//!
+//! | Method | Path | Operation |
+//! |--------|------|-----------|
+//! | `POST` | `/v1/programs` | `RegisterGuestProgram` — raw ELF bytes as the request body |
+//! | `POST` | `/v1/jobs` | `JobRequest` — `DomainJobKind` JSON |
+//! | `GET` | `/v1/jobs/{job_id}` | `WaitJobResult` — `?timeout_seconds=N` long-polls (default: no wait) |
+//! | `GET` | `/v1/jobs/{job_id}/events` | `WatchJob` as server-sent events |
+//! | `GET` | `/v1/jobs/{job_id}/proof` | Proof download — `?format=compact` (default), `bincode` (legacy) or `json` |
+//! | `POST` | `/v1/jobs/{job_id}/input` | `PushJobInput` — raw bytes as the request body |
+//! | `POST` | `/v1/jobs/{job_id}/hints` | `PushJobHintsInput` — raw bytes as the request body |
+//! | `POST` | `/v1/jobs/{job_id}/cancel` | `CancelJob` |
+//!
+//! Errors are returned as `{"code": 1001, "name": "JOB_NOT_FOUND", "message": "..."}`
+//! using the stable codes from [`crate::errors::codes`].
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::body::Bytes;
+use axum::extract::{DefaultBodyLimit, Path, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+use tracing::{error, info};
+use uuid::Uuid;
+use zisk_common::Proof;
+
+use crate::backend::{
+    BackendService, DomainInputChunk, DomainJobEvent, DomainJobKind, DomainJobKindResponse,
+    InputChunkStream, RegisterGuestProgramRequestDto, RegisterGuestProgramResponseDto,
+    SubmitJobResult, WaitResult,
+};
+use crate::errors::ApiError;
+use crate::grpc::WAIT_TIMEOUT_MAX_SECS;
+use crate::handler::CoordinatorHandler;
+use crate::server::MAX_MESSAGE_SIZE;
+
+type SharedHandler<B> = Arc<CoordinatorHandler<B>>;
+
+pub struct HttpAdapter<B: BackendService> {
+    handler: SharedHandler<B>,
+}
+
+impl<B: BackendService> HttpAdapter<B> {
+    pub fn new(handler: CoordinatorHandler<B>) -> Self {
+        Self { handler: Arc::new(handler) }
+    }
+
+    /// Build the axum router serving every route of the gateway.
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/v1/programs", post(register_guest_program::<B>))
+            .route("/v1/jobs", post(job_request::<B>))
+            .route("/v1/jobs/{job_id}", get(wait_job_result::<B>))
+            .route("/v1/jobs/{job_id}/events", get(watch_job::<B>))
+            .route("/v1/jobs/{job_id}/proof", get(download_proof::<B>))
+            .route("/v1/jobs/{job_id}/input", post(push_job_input::<B>))
+            .route("/v1/jobs/{job_id}/hints", post(push_job_hints_input::<B>))
+            .route("/v1/jobs/{job_id}/cancel", post(cancel_job::<B>))
+            .layer(DefaultBodyLimit::max(MAX_MESSAGE_SIZE))
+            .with_state(self.handler)
+    }
+}
+
+// ── Errors ───────────────────────────────────────────────────────────────────
+
+/// Error returned by an HTTP route: either a handler error or a request
+/// rejected at the wire boundary before reaching the handler.
+#[derive(Debug)]
+pub enum HttpError {
+    Api(ApiError),
+    InvalidArgument(String),
+}
+
+impl From<ApiError> for HttpError {
+    fn from(err: ApiError) -> Self {
+        Self::Api(err)
+    }
+}
+
+impl HttpError {
+    /// Outcome label for metrics — the same label set the gRPC adapter uses.
+    fn status_label(&self) -> &'static str {
+        match self {
+            Self::Api(e) => e.tonic_code().description(),
+            Self::InvalidArgument(_) => Code::InvalidArgument.description(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<u32>,
+    name: &'static str,
+    message: String,
+}
+
+impl From<&ApiError> for ErrorBody {
+    fn from(err: &ApiError) -> Self {
+        Self { code: Some(err.code()), name: err.name(), message: err.client_message() }
+    }
+}
+
+impl IntoResponse for HttpError {
+    fn into_response(self) -> Response {
+        let (status, body) = match self {
+            Self::Api(err) => (err.http_status(), ErrorBody::from(&err)),
+            Self::InvalidArgument(message) => (
+                StatusCode::BAD_REQUEST,
+                ErrorBody { code: None, name: "INVALID_ARGUMENT", message },
+            ),
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+type HttpResult<T> = Result<T, HttpError>;
+
+fn log_call(method: &'static str, start: Instant, result: Result<(), &HttpError>) {
+    let elapsed_secs = start.elapsed().as_secs_f64();
+    let elapsed_ms = (elapsed_secs * 1_000.0) as u128;
+
+    let status_label = match result {
+        Ok(()) => "ok",
+        Err(e) => e.status_label(),
+    };
+    crate::metrics::record_request("http", method, status_label, elapsed_secs);
+
+    match result {
+        Ok(()) => tracing::debug!(method, elapsed_ms, "HTTP call OK"),
+        Err(HttpError::Api(ApiError::Internal(_))) => {
+            error!(method, elapsed_ms, "HTTP call failed (internal)")
+        }
+        Err(e) => info!(method, elapsed_ms, error = ?e, "HTTP call failed"),
+    }
+}
+
+fn parse_uuid(s: &str) -> HttpResult<Uuid> {
+    Uuid::parse_str(s).map_err(|_| HttpError::InvalidArgument(format!("invalid UUID: {s}")))
+}
+
+fn single_chunk(body: Bytes) -> InputChunkStream {
+    Box::pin(futures::stream::once(async move { Ok(DomainInputChunk { data: body.to_vec() }) }))
+}
+
+// ── Routes ───────────────────────────────────────────────────────────────────
+
+async fn register_guest_program<B: BackendService>(
+    State(handler): State<SharedHandler<B>>,
+    body: Bytes,
+) -> HttpResult<Json<RegisterGuestProgramResponseDto>> {
+    let start = Instant::now();
+
+    let result = if body.is_empty() {
+        Err(HttpError::InvalidArgument("request body must contain the ELF bytes".into()))
+    } else {
+        handler
+            .register_guest_program(RegisterGuestProgramRequestDto { zisk_elf: body.to_vec() })
+            .await
+            .map(Json)
+            .map_err(HttpError::from)
+    };
+
+    log_call("RegisterGuestProgram", start, result.as_ref().map(|_| ()));
+    result
+}
+
+async fn job_request<B: BackendService>(
+    State(handler): State<SharedHandler<B>>,
+    body: Bytes,
+) -> HttpResult<Json<SubmitJobResult>> {
+    let start = Instant::now();
+
+    let result = match serde_json::from_slice::<DomainJobKind>(&body) {
+        Ok(kind) => handler.submit_job(kind).await.map(Json).map_err(HttpError::from),
+        Err(e) => Err(HttpError::InvalidArgument(format!("invalid job request: {e}"))),
+    };
+
+    log_call("JobRequest", start, result.as_ref().map(|_| ()));
+    result
+}
+
+#[derive(Debug, Deserialize)]
+struct WaitQuery {
+    timeout_seconds: Option<u32>,
+}
+
+async fn wait_job_result<B: BackendService>(
+    State(handler): State<SharedHandler<B>>,
+    Path(job_id): Path<String>,
+    Query(query): Query<WaitQuery>,
+) -> HttpResult<Json<WaitResult>> {
+    let start = Instant::now();
+
+    // Unlike gRPC, a plain GET is a status poll: no wait unless asked for.
+    let timeout_secs = query.timeout_seconds.unwrap_or(0).min(WAIT_TIMEOUT_MAX_SECS);
+    let result = match parse_uuid(&job_id) {
+        Ok(job_id) => handler
+            .wait_job_result(job_id, Duration::from_secs(timeout_secs as u64))
+            .await
+            .map(Json)
+            .map_err(HttpError::from),
+        Err(e) => Err(e),
+    };
+
+    log_call("WaitJobResult", start, result.as_ref().map(|_| ()));
+    result
+}
+
+fn event_name(event: &DomainJobEvent) -> &'static str {
+    match event {
+        DomainJobEvent::Queued(_) => "queued",
+        DomainJobEvent::Started(_) => "started",
+        DomainJobEvent::Progress(_) => "progress",
+        DomainJobEvent::WaitingForInput(_) => "waiting_for_input",
+        DomainJobEvent::Completed(_) => "completed",
+        DomainJobEvent::Cancelled(_) => "cancelled",
+        DomainJobEvent::Failed(_) => "failed",
+    }
+}
+
+async fn watch_job<B: BackendService>(
+    State(handler): State<SharedHandler<B>>,
+    Path(job_id): Path<String>,
+) -> HttpResult<Sse<impl Stream<Item = Result<Event, axum::Error>>>> {
+    let start = Instant::now();
+
+    let result = match parse_uuid(&job_id) {
+        Ok(job_id) => handler
+            .watch_job(job_id)
+            .await
+            .map(|stream| {
+                let events = stream.map(|item| match item {
+                    Ok(event) => Event::default().event(event_name(&event)).json_data(&event),
+                    Err(err) => Event::default().event("error").json_data(ErrorBody::from(&err)),
+                });
+                Sse::new(events).keep_alive(KeepAlive::default())
+            })
+            .map_err(HttpError::from),
+        Err(e) => Err(e),
+    };
+
+    log_call("WatchJob", start, result.as_ref().map(|_| ()));
+    result
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ProofFormat {
+    /// The compact encoding of `zisk_common::Proof`, as written by `Proof::save`.
+    #[default]
+    Compact,
+    /// The legacy bincode encoding of `zisk_common::Proof`, see `Proof::to_legacy_bytes`.
+    Bincode,
+    /// The `DomainProof` DTO as JSON, with the legacy encoded proof hex-encoded.
+    Json,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProofQuery {
+    #[serde(default)]
+    format: ProofFormat,
+}
+
+async fn download_proof<B: BackendService>(
+    State(handler): State<SharedHandler<B>>,
+    Path(job_id): Path<String>,
+    Query(query): Query<ProofQuery>,
+) -> HttpResult<Response> {
+    let start = Instant::now();
+
+    let result = match parse_uuid(&job_id) {
+        Ok(job_id) => handler
+            .wait_job_result(job_id, Duration::ZERO)
+            .await
+            .and_then(|wait| match wait.result {
+                Some(DomainJobKindResponse::Prove { proof, .. })
+                | Some(DomainJobKindResponse::Wrap(proof)) => Ok(proof),
+                Some(_) => Err(ApiError::InvalidJobState {
+                    reason: "job result does not contain a proof".into(),
+                }),
+                None => Err(ApiError::InvalidJobState {
+                    reason: format!("job has no result yet (status: {:?})", wait.job_status),
+                }),
+            })
+            .and_then(|proof| match query.format {
+                ProofFormat::Compact => Proof::from_bytes(&proof.data)
+                    .and_then(|proof| proof.to_bytes())
+                    .map(octet_stream)
+                    .map_err(|e| ApiError::Internal(format!("failed to re-encode proof: {e}"))),
+                ProofFormat::Bincode => Proof::from_bytes(&proof.data)
+                    .and_then(|proof| proof.to_legacy_bytes())
+                    .map(octet_stream)
+                    .map_err(|e| ApiError::Internal(format!("failed to re-encode proof: {e}"))),
+                ProofFormat::Json => Ok(Json(proof).into_response()),
+            })
+            .map_err(HttpError::from),
+        Err(e) => Err(e),
+    };
+
+    log_call("DownloadProof", start, result.as_ref().map(|_| ()));
+    result
+}
+
+fn octet_stream(bytes: Vec<u8>) -> Response {
+    ([(header::CONTENT_TYPE, "application/octet-stream")], bytes).into_response()
+}
+
+async fn push_job_input<B: BackendService>(
+    State(handler): State<SharedHandler<B>>,
+    Path(job_id): Path<String>,
+    body: Bytes,
+) -> HttpResult<StatusCode> {
+    let start = Instant::now();
+
+    let result = match parse_uuid(&job_id) {
+        Ok(job_id) => handler
+            .push_job_input(job_id, single_chunk(body))
+            .await
+            .map(|()| StatusCode::NO_CONTENT)
+            .map_err(HttpError::from),
+        Err(e) => Err(e),
+    };
+
+    log_call("PushJobInput", start, result.as_ref().map(|_| ()));
+    result
+}
+
+async fn push_job_hints_input<B: BackendService>(
+    State(handler): State<SharedHandler<B>>,
+    Path(job_id): Path<String>,
+    body: Bytes,
+) -> HttpResult<StatusCode> {
+    let start = Instant::now();
+
+    let result = match parse_uuid(&job_id) {
+        Ok(job_id) => handler
+            .push_job_hints_input(job_id, single_chunk(body))
+            .await
+            .map(|()| StatusCode::NO_CONTENT)
+            .map_err(HttpError::from),
+        Err(e) => Err(e),
+    };
+
+    log_call("PushJobHintsInput", start, result.as_ref().map(|_| ()));
+    result
+}
+
+#[derive(Debug, Serialize)]
+struct CancelJobResponse {
+    job_id: Uuid,
+    cancelled: bool,
+}
+
+async fn cancel_job<B: BackendService>(
+    State(handler): State<SharedHandler<B>>,
+    Path(job_id): Path<String>,
+) -> HttpResult<Json<CancelJobResponse>> {
+    let start = Instant::now();
+
+    let result = match parse_uuid(&job_id) {
+        Ok(job_id) => handler
+            .cancel_job(job_id)
+            .await
+            .map(|cancelled| Json(CancelJobResponse { job_id, cancelled }))
+            .map_err(HttpError::from),
+        Err(e) => Err(e),
+    };
+
+    log_call("CancelJob", start, result.as_ref().map(|_| ()));
+    result
+}
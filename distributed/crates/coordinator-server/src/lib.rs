@@ -3,7 +3,8 @@
 //! # Overview
 //!
 //! This crate implements the coordinator API defined in
-//! `book/developer/coordinator_api.md`. It exposes the `ZiskCoordinatorApi` gRPC service.
+//! `book/developer/coordinator_api.md`. It exposes the `ZiskCoordinatorApi` gRPC service
+//! and, optionally, the same operations as an HTTP/JSON gateway ([`http::HttpAdapter`]).
 //!
 //! # Backend
 //!
@@ -18,6 +19,7 @@ pub mod config;
 pub mod errors;
 pub mod grpc;
 pub mod handler;
+pub mod http;
 pub mod metrics;
 pub mod server;
 pub mod shutdown;
@@ -29,4 +31,5 @@ pub use config::Config as CoordinatorServerConfig;
 pub use errors::{ApiError, ApiResult};
 pub use grpc::GrpcAdapter;
 pub use handler::CoordinatorHandler;
+pub use http::HttpAdapter;
 pub use server::CoordinatorServer;
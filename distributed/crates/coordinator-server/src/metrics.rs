@@ -4,8 +4,8 @@
 //!
 //! | Name | Type | Labels | Description |
 //! |------|------|--------|-------------|
-//! | `coordinator_requests_total` | Counter | `method`, `status` | gRPC calls by method and outcome |
-//! | `coordinator_request_duration_seconds` | Histogram | `method` | gRPC call latency |
+//! | `coordinator_requests_total` | Counter | `transport`, `method`, `status` | API calls by transport (`grpc`/`http`), method and outcome |
+//! | `coordinator_request_duration_seconds` | Histogram | `transport`, `method` | API call latency |
 //! | `coordinator_active_jobs` | Gauge | — | Currently active (non-terminal) jobs |
 //! | `coordinator_jobs_total` | Counter | `kind`, `outcome` | Jobs by kind and final outcome |
 //! | `coordinator_registered_programs_total` | Gauge | — | Registered guest programs |
@@ -21,6 +21,7 @@
 //! - `GET /metrics` → Prometheus text format
 //! - `GET /health`  → `200 OK` (liveness probe)
 
+use std::borrow::Cow;
 use std::sync::OnceLock;
 
 use anyhow::Result;
@@ -55,11 +56,11 @@ pub fn install_prometheus() -> Result<()> {
 fn register_descriptions() {
     metrics::describe_counter!(
         "coordinator_requests_total",
-        "Total gRPC calls by method and outcome (ok/error)"
+        "Total API calls by transport, method and outcome (ok/error)"
     );
     metrics::describe_histogram!(
         "coordinator_request_duration_seconds",
-        "gRPC call latency in seconds"
+        "API call latency in seconds"
     );
     metrics::describe_gauge!(
         "coordinator_active_jobs",
@@ -87,6 +88,30 @@ fn register_descriptions() {
     );
 }
 
+/// Record one API call. Shared by the gRPC and HTTP adapters so both transports
+/// report under the same metric names and `method` / `status` labels.
+pub(crate) fn record_request(
+    transport: &'static str,
+    method: &'static str,
+    status: &'static str,
+    elapsed_secs: f64,
+) {
+    let method_str: Cow<'static, str> = Cow::Borrowed(method);
+    metrics::counter!(
+        "coordinator_requests_total",
+        "transport" => transport,
+        "method" => method_str.clone(),
+        "status" => status
+    )
+    .increment(1);
+    metrics::histogram!(
+        "coordinator_request_duration_seconds",
+        "transport" => transport,
+        "method" => method_str
+    )
+    .record(elapsed_secs);
+}
+
 /// Start the HTTP server that serves `/metrics` and `/health`.
 /// No-ops if `cfg.enabled` is false.
 /// The server stops accepting new connections when `cancel` is cancelled.
@@ -1,24 +1,27 @@
-//! Coordinator gRPC server — builds the tonic server and wires all components.
+//! Coordinator server — builds the tonic server, the optional HTTP gateway, and wires all
+//! components.
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Result;
+use tokio::net::TcpListener;
 use tokio_util::sync::CancellationToken;
 use tonic::transport::Server;
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
 use crate::backend::BackendService;
 use crate::config::Config as CoordinatorServerConfig;
 use crate::grpc::GrpcAdapter;
 use crate::handler::CoordinatorHandler;
+use crate::http::HttpAdapter;
 use crate::metrics;
 use crate::proto::zisk_coordinator_api_server::ZiskCoordinatorApiServer;
 use crate::shutdown::shutdown_signal;
 
 /// Maximum inbound/outbound message size. Must be at least as large as
-/// `DomainInputKind::MAX_INLINE_BYTES` in coordinator-api.
-const MAX_MESSAGE_SIZE: usize = 128 * 1024 * 1024; // 128 MB
+/// `DomainInputKind::MAX_INLINE_BYTES` in coordinator-api. Also bounds HTTP request bodies.
+pub(crate) const MAX_MESSAGE_SIZE: usize = 128 * 1024 * 1024; // 128 MB
 
 pub struct CoordinatorServer<B: BackendService> {
     config: CoordinatorServerConfig,
@@ -34,6 +37,10 @@ impl<B: BackendService> CoordinatorServer<B> {
     pub async fn run(self) -> Result<()> {
         metrics::start(&self.config.metrics, self.cancel.clone()).await?;
 
+        if self.config.http.enabled {
+            self.start_http().await?;
+        }
+
         let addr = self.config.grpc_addr().parse()?;
         let service = GrpcAdapter::new(CoordinatorHandler::new(Arc::clone(&self.backend)));
         let shutdown_secs = self.config.server.shutdown_timeout_seconds;
@@ -87,4 +94,26 @@ impl<B: BackendService> CoordinatorServer<B> {
 
         Ok(())
     }
+
+    /// Bind the HTTP/JSON gateway and serve it in the background. It shares the
+    /// backend with the gRPC service and stops when the cancel token fires.
+    async fn start_http(&self) -> Result<()> {
+        let addr = self.config.http_addr();
+        // Bind up front so a port conflict fails startup instead of being logged later.
+        let listener = TcpListener::bind(&addr).await?;
+        let router = HttpAdapter::new(CoordinatorHandler::new(Arc::clone(&self.backend))).router();
+        let cancel = self.cancel.clone();
+
+        info!("HTTP gateway listening on http://{addr}");
+
+        tokio::spawn(async move {
+            if let Err(e) =
+                axum::serve(listener, router).with_graceful_shutdown(cancel.cancelled_owned()).await
+            {
+                error!("HTTP gateway exited: {e:#}");
+            }
+        });
+
+        Ok(())
+    }
 }
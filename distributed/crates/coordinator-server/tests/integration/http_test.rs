@@ -0,0 +1,188 @@
+//! Integration tests for the HTTP/JSON gateway.
+//!
+//! Each test serves `HttpAdapter` on a random local port backed by
+//! `MockBackend` and exercises the routes with a real HTTP client.
+
+use std::sync::Arc;
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+
+use zisk_coordinator_server::{backend::mock::MockBackend, CoordinatorHandler, HttpAdapter};
+
+// ── Test helpers ──────────────────────────────────────────────────────────────
+
+/// Start the HTTP gateway on a random local port and return its base URL.
+async fn start_test_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let backend = Arc::new(MockBackend::default());
+    let router = HttpAdapter::new(CoordinatorHandler::new(backend)).router();
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    format!("http://{addr}")
+}
+
+fn dummy_elf() -> Vec<u8> {
+    vec![0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x01, 0x02, 0x03]
+}
+
+async fn register_program(client: &reqwest::Client, base: &str) -> String {
+    let resp = client.post(format!("{base}/v1/programs")).body(dummy_elf()).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.unwrap();
+    body["hash_id"].as_str().unwrap().to_owned()
+}
+
+async fn submit_prove(client: &reqwest::Client, base: &str, hash_id: &str) -> String {
+    let request = json!({
+        "kind": "prove",
+        "hash_id": hash_id,
+        "input": { "inline": { "data": "010203" } },
+        "hints": null,
+        "proof_timeout": null,
+        "proof_dest": "stark",
+    });
+    let resp = client.post(format!("{base}/v1/jobs")).json(&request).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = resp.json().await.unwrap();
+    body["job_id"].as_str().unwrap().to_owned()
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn prove_job_status_and_proof_download() {
+    let base = start_test_server().await;
+    let client = reqwest::Client::new();
+    let hash_id = register_program(&client, &base).await;
+    let job_id = submit_prove(&client, &base, &hash_id).await;
+
+    // Long-poll until Completed (mock completes after ~2 s)
+    let status: Value = client
+        .get(format!("{base}/v1/jobs/{job_id}?timeout_seconds=5"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(status["job_status"]["status"], "completed");
+    assert_eq!(status["result"]["kind"], "prove");
+
+    let resp = client.get(format!("{base}/v1/jobs/{job_id}/proof")).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers()["content-type"], "application/octet-stream");
+    let bincode_bytes = resp.bytes().await.unwrap();
+    assert!(!bincode_bytes.is_empty());
+
+    let proof: Value = client
+        .get(format!("{base}/v1/jobs/{job_id}/proof?format=json"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(proof["hash_id"], hash_id.as_str());
+    assert_eq!(proof["data"], hex::encode(&bincode_bytes).as_str());
+}
+
+#[tokio::test]
+async fn events_stream_ends_with_completed() {
+    let base = start_test_server().await;
+    let client = reqwest::Client::new();
+    let hash_id = register_program(&client, &base).await;
+    let job_id = submit_prove(&client, &base, &hash_id).await;
+
+    let resp = client.get(format!("{base}/v1/jobs/{job_id}/events")).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers()["content-type"], "text/event-stream");
+
+    // The stream closes after the terminal event.
+    let body = resp.text().await.unwrap();
+    assert!(body.contains("event: queued"), "expected queued event in {body}");
+    assert!(body.contains("event: started"), "expected started event in {body}");
+    assert!(body.contains("event: completed"), "expected completed event in {body}");
+}
+
+#[tokio::test]
+async fn proof_download_before_completion_is_conflict() {
+    let base = start_test_server().await;
+    let client = reqwest::Client::new();
+    let hash_id = register_program(&client, &base).await;
+    let job_id = submit_prove(&client, &base, &hash_id).await;
+
+    let resp = client.get(format!("{base}/v1/jobs/{job_id}/proof")).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["code"], 1004);
+    assert_eq!(body["name"], "INVALID_JOB_STATE");
+}
+
+#[tokio::test]
+async fn cancel_running_job_returns_true() {
+    let base = start_test_server().await;
+    let client = reqwest::Client::new();
+    let hash_id = register_program(&client, &base).await;
+    let job_id = submit_prove(&client, &base, &hash_id).await;
+
+    let body: Value = client
+        .post(format!("{base}/v1/jobs/{job_id}/cancel"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["cancelled"], true);
+
+    let body: Value = client
+        .post(format!("{base}/v1/jobs/{job_id}/cancel"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["cancelled"], false, "second cancel must return false (already terminal)");
+}
+
+#[tokio::test]
+async fn job_not_found_returns_404() {
+    let base = start_test_server().await;
+    let client = reqwest::Client::new();
+    let fake_id = uuid::Uuid::new_v4();
+
+    let resp = client.get(format!("{base}/v1/jobs/{fake_id}")).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["code"], 1001);
+}
+
+#[tokio::test]
+async fn malformed_requests_return_400() {
+    let base = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client.get(format!("{base}/v1/jobs/not-a-uuid")).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = client.post(format!("{base}/v1/programs")).send().await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = client
+        .post(format!("{base}/v1/jobs"))
+        .json(&json!({ "kind": "teleport" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["name"], "INVALID_ARGUMENT");
+}
@@ -501,6 +501,14 @@ impl Coordinator {
         self.alloc_job_events(&job_id).await;
         self.fire_job_event(&job_id, CoordinatorJobEvent::Queued).await;
         self.fire_job_event(&job_id, CoordinatorJobEvent::Started).await;
+        self.fire_job_event(
+            &job_id,
+            CoordinatorJobEvent::Progress {
+                phase: JobPhase::Contributions,
+                workers: active_workers.len() as u32,
+            },
+        )
+        .await;
 
         // Increment `coordinator_active_jobs` BEFORE dispatch: even if dispatch
         // fails, the job is already in `self.jobs` map and a later monitor
@@ -640,10 +648,7 @@ impl Coordinator {
         let duration_ms = job.duration_ms.unwrap_or(0);
         let job_state = job.state.clone();
         let executed_steps = job.executed_steps;
-        let proof_data = job
-            .proof
-            .as_ref()
-            .and_then(|p| bincode::serde::encode_to_vec(p, bincode::config::standard()).ok());
+        let proof_data = job.proof.as_ref().and_then(|p| p.to_legacy_bytes().ok());
 
         tokio::spawn(async move {
             const MAX_RETRIES: usize = 10;
@@ -922,8 +927,11 @@ impl Coordinator {
                     )
                     .await?;
 
-                self.fire_job_event(&job_id, CoordinatorJobEvent::Progress(JobPhase::Aggregate))
-                    .await;
+                self.fire_job_event(
+                    &job_id,
+                    CoordinatorJobEvent::Progress { phase: JobPhase::Aggregate, workers: 1 },
+                )
+                .await;
 
                 info!(
                     "[Phase3] Assigned worker {} as aggregator for job {}",
@@ -1442,6 +1450,7 @@ mod tests {
                         task_received_time: 0.0,
                     },
                     publics: vec![],
+                    exit_code: None,
                 },
             )),
             worker_in_recovery: false,
@@ -468,7 +468,14 @@ impl Coordinator {
 
         drop(job); // Release jobs lock early
 
-        self.fire_job_event(&job_id, CoordinatorJobEvent::Progress(JobPhase::Prove)).await;
+        self.fire_job_event(
+            &job_id,
+            CoordinatorJobEvent::Progress {
+                phase: JobPhase::Prove,
+                workers: active_workers.len() as u32,
+            },
+        )
+        .await;
 
         // Start Phase2 for all workers
         self.start_prove(&job_id, &active_workers, challenges_dto).await?;
@@ -648,13 +655,13 @@ impl Coordinator {
 
         let exec_stats = exec_stats_from_job(&job);
 
-        let public_outputs = job
+        let (public_outputs, exit_code) = job
             .results
             .get(&JobPhase::Execution)
             .and_then(|m| m.values().next())
             .and_then(|r| {
                 if let JobResultData::Execution(ref e) = r.data {
-                    Some(e.public_outputs.clone())
+                    Some((e.public_outputs.clone(), e.exit_code))
                 } else {
                     None
                 }
@@ -669,6 +676,7 @@ impl Coordinator {
             CoordinatorJobEvent::Completed(CoordinatorJobResult::Execute {
                 stats: exec_stats,
                 public_outputs,
+                exit_code,
             }),
         )
         .await;
@@ -843,6 +851,7 @@ impl Coordinator {
                     zisk_executor_time,
                     task_received_time,
                     public_outputs: Self::publics_u64_to_bytes(&exec_data.publics),
+                    exit_code: exec_data.exit_code,
                 }))
             }
             _ => {
@@ -17,7 +17,8 @@ impl Coordinator {
     /// Launch a wrap job: compress/reduce an existing vadcop proof to minimal or SNARK format.
     ///
     /// Selects any single idle worker, sends a WRAP task to it, and returns the job ID.
-    /// The proof data must be a bincode-encoded `Proof`.
+    /// The proof data must be a `Proof` in the legacy bincode encoding, which is forwarded
+    /// as is to the worker.
     pub async fn launch_wrap(
         &self,
         request: LaunchWrapRequestDto,
@@ -119,12 +120,7 @@ impl Coordinator {
             ));
         };
 
-        let zisk_proof = bincode::serde::decode_from_slice::<Proof, _>(
-            &wrap_result.proof_data,
-            bincode::config::standard(),
-        )
-        .map(|(v, _)| v)
-        .map_err(|e| {
+        let zisk_proof = Proof::from_bytes(&wrap_result.proof_data).map_err(|e| {
             CoordinatorError::Internal(format!("Failed to deserialize wrap proof: {}", e))
         })?;
         job.proof = Some(zisk_proof);
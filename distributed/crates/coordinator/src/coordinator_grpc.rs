@@ -233,6 +233,7 @@ impl CoordinatorGrpc {
                 },
                 directive,
                 setup_program,
+                compact_proofs: true,
             })),
         })
     }
@@ -5,7 +5,8 @@ use zisk_cluster_common::JobPhase;
 pub enum CoordinatorJobEvent {
     Queued,
     Started,
-    Progress(JobPhase),
+    /// The job entered a phase, run by the given number of workers.
+    Progress { phase: JobPhase, workers: u32 },
     WaitingForInput,
     Completed(CoordinatorJobResult),
     Failed(String),
@@ -17,7 +18,7 @@ pub enum CoordinatorJobEvent {
 pub enum CoordinatorJobResult {
     Setup { vk: Vec<u8> },
     Prove { proof_bytes: Vec<u8>, stats: CoordinatorExecutionStats },
-    Execute { stats: CoordinatorExecutionStats, public_outputs: Vec<u8> },
+    Execute { stats: CoordinatorExecutionStats, public_outputs: Vec<u8>, exit_code: Option<i32> },
     Wrap { proof_bytes: Vec<u8> },
 }
 
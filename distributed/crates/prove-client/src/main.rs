@@ -112,7 +112,10 @@ fn parse_proof_kind(s: &str) -> Result<DomainProofKind, String> {
         "stark" => Ok(DomainProofKind::Stark),
         "stark-minimal" | "stark_minimal" | "minimal" => Ok(DomainProofKind::StarkMinimal),
         "plonk" => Ok(DomainProofKind::Plonk),
-        other => Err(format!("unknown proof kind '{other}'; use stark, stark-minimal, or plonk")),
+        "groth16" => Ok(DomainProofKind::Groth16),
+        other => Err(format!(
+            "unknown proof kind '{other}'; use stark, stark-minimal, plonk, or groth16"
+        )),
     }
 }
 
@@ -296,10 +299,15 @@ fn run_execute(
 
     info!("Waiting for execution …");
     match job.wait(None)? {
-        TerminalStatus::Completed(DomainJobKindResponse::Execute { stats, public_outputs }) => {
+        TerminalStatus::Completed(DomainJobKindResponse::Execute {
+            stats,
+            public_outputs,
+            exit_code,
+        }) => {
             info!(
                 steps = stats.steps,
                 duration_ms = stats.duration_nanos / 1_000_000,
+                exit_code = ?exit_code,
                 "Execute completed."
             );
 
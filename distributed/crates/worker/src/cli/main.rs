@@ -131,6 +131,7 @@ async fn main() -> Result<()> {
         preload_plonk: cli.preload_plonk,
         gpu,
         plonk: cli.plonk,
+        hint_plugins: worker_config.worker.hint_plugins.clone(),
     };
 
     let prover_config = ProverConfig::load(prover_config_dto)?;
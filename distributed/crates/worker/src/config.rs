@@ -1,4 +1,5 @@
 use anyhow::Result;
+use precompiles_hints::HintPluginConfig;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use zisk_cluster_common::Environment;
@@ -34,6 +35,10 @@ pub struct WorkerConfig {
 
     /// This is the path where the worker will look for input files to process. By default, it is the current directory.
     pub inputs_folder: PathBuf,
+
+    /// Plugin libraries serving custom hints, registered by hint code
+    #[serde(default)]
+    pub hint_plugins: Vec<HintPluginConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -141,4 +146,5 @@ pub struct ProverServiceConfigDto {
     pub gpu: bool,
     pub plonk: bool,
     pub preload_plonk: bool,
+    pub hint_plugins: Vec<HintPluginConfig>,
 }
@@ -12,11 +12,11 @@ use zisk_cluster_common::{HintsSourceDto, StreamDataDto, StreamMessageKind};
 use zisk_cluster_common::{JobId, PartitionInfo};
 use zisk_common::io::{StreamSource, ZiskStdin};
 use zisk_common::{ProgramVK, Proof, ProofKind, SetupKey, ZiskExecutorTime, ZiskPaths};
-use zisk_prover_backend::GuestProgram;
 use zisk_prover_backend::{
     Asm, AsmOptions, BackendProverOpts, Emu, ProverClientBuilder, ProverEngine, ZiskBackend,
     ZiskProver,
 };
+use zisk_prover_backend::{GuestProgram, HintPluginConfig};
 
 use crate::stream_ordering::StreamOrderingActor;
 use crate::worker_node::run_recovery;
@@ -82,7 +82,8 @@ pub enum ComputationResult {
     Execution {
         job_id: JobId,
         success: bool,
-        result: Result<(WitnessInfo, ZiskExecutorTime, u64, u64)>, // (witness_info, exec_time, instances, executed_steps)
+        // (witness_info, exec_time, instances, executed_steps, exit_code)
+        result: Result<(WitnessInfo, ZiskExecutorTime, u64, u64, Option<i32>)>,
         task_received_time: Option<chrono::DateTime<chrono::Utc>>,
     },
     /// Partial contribution with challenges
@@ -195,6 +196,9 @@ pub struct ProverConfig {
 
     /// Maximum witness buffers stored in memory
     pub max_witness_stored: Option<usize>,
+
+    /// Plugin libraries serving custom hints
+    pub hint_plugins: Vec<HintPluginConfig>,
 }
 
 impl ProverConfig {
@@ -233,6 +237,7 @@ impl ProverConfig {
             max_witness_stored: prover_service_config.max_witness_stored,
             plonk: prover_service_config.plonk,
             preload_plonk,
+            hint_plugins: prover_service_config.hint_plugins,
         })
     }
 }
@@ -290,7 +295,7 @@ impl<T: ZiskBackend + 'static> Worker<T> {
             }
             prover_options = prover_options
                 .proving_key_plonk(prover_config.proving_key_snark.clone().unwrap())
-                .plonk(prover_config.preload_plonk);
+                .snark(ProofKind::Plonk, prover_config.preload_plonk);
         }
 
         if prover_config.minimal_memory {
@@ -340,7 +345,7 @@ impl<T: ZiskBackend + 'static> Worker<T> {
             }
             prover_options = prover_options
                 .proving_key_plonk(prover_config.proving_key_snark.clone().unwrap())
-                .plonk(prover_config.preload_plonk);
+                .snark(ProofKind::Plonk, prover_config.preload_plonk);
         }
 
         if prover_config.minimal_memory {
@@ -368,6 +373,9 @@ impl<T: ZiskBackend + 'static> Worker<T> {
             asm_options = asm_options.asm_out_file();
         }
         asm_options = asm_options.is_distributed();
+        for plugin in &prover_config.hint_plugins {
+            asm_options = asm_options.hint_plugin(plugin.hint_code, plugin.path.clone());
+        }
         prover_options = prover_options.with_asm_options(asm_options);
 
         let prover = Arc::new(
@@ -828,7 +836,7 @@ impl<T: ZiskBackend + 'static> Worker<T> {
                         .unwrap_or_else(|_| (WitnessInfo::default(), ZiskExecutorTime::default()));
 
                     let computation = match result {
-                        Ok((num_instances, publics)) => {
+                        Ok((num_instances, publics, exit_code)) => {
                             let instances = num_instances as u64;
                             let executed_steps = prover.executed_steps();
                             job.blocking_lock().instances = instances;
@@ -841,7 +849,13 @@ impl<T: ZiskBackend + 'static> Worker<T> {
                             ComputationResult::Execution {
                                 job_id: job_id.clone(),
                                 success: true,
-                                result: Ok((wi, zisk_execution_time, instances, executed_steps)),
+                                result: Ok((
+                                    wi,
+                                    zisk_execution_time,
+                                    instances,
+                                    executed_steps,
+                                    exit_code,
+                                )),
                                 task_received_time,
                             }
                         }
@@ -943,7 +957,7 @@ impl<T: ZiskBackend + 'static> Worker<T> {
         hints_source: HintsSourceDto,
         partition_info: PartitionInfo,
         guest_program: &GuestProgram,
-    ) -> Result<(usize, Vec<u64>)> {
+    ) -> Result<(usize, Vec<u64>, Option<i32>)> {
         let stdin = match input_source {
             InputSourceDto::InputPath(inputs_uri) => ZiskStdin::from_file(inputs_uri)?,
             InputSourceDto::InputData(input_data) => ZiskStdin::from_vec(input_data),
@@ -976,6 +990,9 @@ impl<T: ZiskBackend + 'static> Worker<T> {
         )?;
 
         let result = prover.execute(guest_program, stdin)?;
+        if let Some(panic) = result.get_panic() {
+            return Err(anyhow::Error::new(panic.clone()));
+        }
 
         let num_instances = prover.get_execution_info()?.0.total_instances;
 
@@ -987,33 +1004,34 @@ impl<T: ZiskBackend + 'static> Worker<T> {
         // stale broadcasts queued behind a previous cancel/failure.
         prover.cluster_barrier();
 
-        Ok((num_instances, publics_u64))
+        Ok((num_instances, publics_u64, result.get_exit_code()))
     }
 
     /// Wrap an existing vadcop proof into a minimal or SNARK proof.
-    /// `proof_data` is a bincode-encoded `Proof`.
-    /// Returns the bincode-encoded wrapped `Proof`.
+    /// `proof_data` is a `Proof` in either encoding, see `Proof::from_bytes`.
+    /// Returns the wrapped `Proof`, in the compact encoding if `compact` is set, or in the
+    /// legacy bincode encoding otherwise.
     pub fn execute_wrap_task(
         prover: &ZiskProver<T>,
         proof_data: Vec<u8>,
         proof_dest: i32,
+        compact: bool,
     ) -> Result<Vec<u8>> {
         let proof_kind = match proof_dest {
             1 => ProofKind::VadcopFinalMinimal,
             2 => ProofKind::Plonk,
+            3 => ProofKind::Groth16,
             _ => anyhow::bail!("Unsupported proof_dest for wrap: {}", proof_dest),
         };
 
-        let proof: Proof =
-            bincode::serde::decode_from_slice(&proof_data, bincode::config::standard())
-                .map(|(v, _)| v)
-                .map_err(|e| anyhow::anyhow!("Failed to deserialize proof for wrap: {}", e))?;
+        let proof = Proof::from_bytes(&proof_data)
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize proof for wrap: {}", e))?;
 
         let result = prover.wrap_proof(&proof, proof_kind).run()?;
 
         let wrapped = result.get_proof();
 
-        let result_bytes = bincode::serde::encode_to_vec(wrapped, bincode::config::standard())
+        let result_bytes = if compact { wrapped.to_bytes() } else { wrapped.to_legacy_bytes() }
             .map_err(|e| anyhow::anyhow!("Failed to serialize wrapped proof: {}", e))?;
 
         Ok(result_bytes)
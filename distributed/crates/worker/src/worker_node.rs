@@ -135,11 +135,14 @@ impl<T: ZiskBackend + 'static> WorkerNodeMpi<T> {
 pub struct WorkerNodeGrpc<T: ZiskBackend + 'static> {
     worker_config: WorkerServiceConfig,
     worker: Worker<T>,
+    /// Whether the coordinator decodes proofs in the compact encoding, as advertised in its
+    /// registration response. Older coordinators only decode the legacy bincode encoding.
+    compact_proofs: bool,
 }
 
 impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
     pub async fn new(worker_config: WorkerServiceConfig, worker: Worker<T>) -> Result<Self> {
-        Ok(Self { worker_config, worker })
+        Ok(Self { worker_config, worker, compact_proofs: false })
     }
 
     pub fn world_rank(&self) -> i32 {
@@ -553,7 +556,7 @@ impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
         &mut self,
         job_id: JobId,
         success: bool,
-        result: Result<(WitnessInfo, ZiskExecutorTime, u64, u64)>,
+        result: Result<(WitnessInfo, ZiskExecutorTime, u64, u64, Option<i32>)>,
         message_sender: &mpsc::UnboundedSender<WorkerMessage>,
         loop_tx: &LoopEventSender,
         task_received_time: Option<chrono::DateTime<chrono::Utc>>,
@@ -577,11 +580,11 @@ impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
                         "Inconsistent state: operation reported success but returned Err result"
                     ));
                 }
-                ((WitnessInfo::default(), ZiskExecutorTime::default(), 0, 0), e.to_string())
+                ((WitnessInfo::default(), ZiskExecutorTime::default(), 0, 0, None), e.to_string())
             }
         };
 
-        let (witness_info, zisk_exec_time, instances, executed_steps) = result_data;
+        let (witness_info, zisk_exec_time, instances, executed_steps, exit_code) = result_data;
 
         let witness_info_msg = WitnessExecInfo {
             witness_time: witness_info.witness_time,
@@ -610,6 +613,7 @@ impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
             executed_steps,
             zisk_execution_time: Some(zisk_execution_time),
             witness_info: Some(witness_info_msg),
+            exit_code,
         }));
 
         let worker_in_recovery = !success && self.owns_recovery_for(&job_id).await;
@@ -733,7 +737,6 @@ impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
                     reset_current_job = !final_proof.is_empty();
 
                     let proof_data = if !final_proof.is_empty() {
-                        let is_plonk = proof_type == ProofKind::Plonk;
                         let flat_proof: Vec<u64> = final_proof.into_iter().flatten().collect();
                         let minimal = proof_type == ProofKind::VadcopFinalMinimal;
                         let verkey = self.worker.get_vadcop_vk(minimal).unwrap_or_else(|e| {
@@ -742,18 +745,18 @@ impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
                         });
                         match Proof::new_from_vadcop_proof(&flat_proof, minimal, verkey) {
                             Ok(zisk_proof) => {
-                                let final_proof: Proof = if is_plonk {
+                                let final_proof: Proof = if proof_type.is_snark() {
                                     match self
                                         .worker
                                         .prover_arc()
-                                        .wrap_proof(&zisk_proof, ProofKind::Plonk)
+                                        .wrap_proof(&zisk_proof, proof_type)
                                         .run()
                                     {
                                         Ok(wrapped) => wrapped.get_proof().clone(),
                                         Err(e) => {
                                             error!(
-                                                "Failed to wrap Plonk proof for {}: {}",
-                                                job_id, e
+                                                "Failed to wrap {:?} proof for {}: {}",
+                                                proof_type, job_id, e
                                             );
                                             zisk_proof
                                         }
@@ -761,10 +764,11 @@ impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
                                 } else {
                                     zisk_proof
                                 };
-                                bincode::serde::encode_to_vec(
-                                    &final_proof,
-                                    bincode::config::standard(),
-                                )
+                                if self.compact_proofs {
+                                    final_proof.to_bytes()
+                                } else {
+                                    final_proof.to_legacy_bytes()
+                                }
                                 .unwrap_or_default()
                             }
                             Err(e) => {
@@ -910,6 +914,7 @@ impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
             coordinator_message::Payload::RegisterResponse(response) => {
                 if response.accepted {
                     info!("Registration accepted: {}", response.message);
+                    self.compact_proofs = response.compact_proofs;
 
                     // `clear_current_job` detaches any in-flight `spawn_blocking`;
                     // if we then accept a new dispatch, `prepare_for_new_job`'s
@@ -1572,11 +1577,12 @@ impl<T: ZiskBackend + 'static> WorkerNodeGrpc<T> {
         let proof_dest = wrap_params.proof_dest;
 
         let prover = self.worker.prover_arc();
+        let compact = self.compact_proofs;
         let worker_id_str = self.worker_config.worker.worker_id.as_string();
         let job_id_str = job_id.as_string();
 
         let (success, result_data, error_message) = tokio::task::spawn_blocking(move || {
-            match Worker::<T>::execute_wrap_task(&prover, proof_data, proof_dest) {
+            match Worker::<T>::execute_wrap_task(&prover, proof_data, proof_dest, compact) {
                 Ok(wrapped_bytes) => (
                     true,
                     Some(ResultData::WrapResult(zisk_cluster_api::WrapResult {
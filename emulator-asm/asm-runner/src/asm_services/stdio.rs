@@ -126,9 +126,13 @@ impl StdioService {
         let stdout = child.stdout.take().context("Failed to open stdout for stdio service")?;
         let mut stderr = child.stderr.take().context("Failed to open stderr for stdio service")?;
 
+        // Stdout carries the service protocol, so the guest output, including the guest stderr
+        // stream, arrives through stderr and is forwarded to ours
         let stderr_drain = thread::spawn(move || {
             let mut chunk = [0u8; 4096];
-            while matches!(stderr.read(&mut chunk), Ok(n) if n > 0) {}
+            while let Ok(n @ 1..) = stderr.read(&mut chunk) {
+                let _ = std::io::stderr().write_all(&chunk[..n]);
+            }
         });
 
         Ok(StdioHandle { stdin, stdout, _stderr_drain: stderr_drain, child })
@@ -461,6 +461,15 @@ extern int _print_char(uint64_t param)
     return 0;
 }
 
+extern int _print_err_char(uint64_t param)
+{
+    printed_chars_counter++;
+    char c = param;
+    fputc(c, stderr);
+    fflush(stderr);
+    return 0;
+}
+
 uint64_t print_step_counter = 0;
 extern int _print_step(uint64_t step)
 {
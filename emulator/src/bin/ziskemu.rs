@@ -1,7 +1,7 @@
 use clap::Parser;
 use std::{fmt::Write, process};
 use zisk_common::EmuTrace;
-use ziskemu::{EmuOptions, Emulator, ZiskEmulator};
+use ziskemu::{EmuOptions, Emulator, ZiskEmulator, ZiskEmulatorErr};
 
 fn main() {
     // Create a emulator options instance based on arguments or default values
@@ -27,6 +27,10 @@ fn main() {
             });
             // print!("Result: 0x{}", hex_string);
         }
+        Err(ZiskEmulatorErr::GuestPanic(panic)) => {
+            eprintln!("{panic}");
+            process::exit(1);
+        }
         Err(e) => {
             eprintln!("Error during emulation: {e:?}");
             process::exit(1);
@@ -1,23 +1,33 @@
 //! Disassembly writer module
-//! Generates objdump-like output with execution counts
+//! Generates objdump-like output with execution counts, optionally interleaved with the source
+//! lines of the instructions
 
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufWriter, Result, Write};
 
-use crate::ElfSymbolReader;
+use crate::{ElfSymbolReader, SourceLines};
 use zisk_core::{ZiskInst, ZiskRom};
 
 pub struct DisasmWriter {
     file: BufWriter<File>,
     pc_histogram: HashMap<u64, u64>,
     symbols: Option<ElfSymbolReader>,
+    source_lines: Option<SourceLines>,
+    /// Lines of the source files read so far, `None` if the file could not be read
+    source_files: HashMap<String, Option<Vec<String>>>,
 }
 
 impl DisasmWriter {
     pub fn new(path: &str) -> Result<Self> {
         let file = File::create(path)?;
-        Ok(Self { file: BufWriter::new(file), pc_histogram: HashMap::new(), symbols: None })
+        Ok(Self {
+            file: BufWriter::new(file),
+            pc_histogram: HashMap::new(),
+            symbols: None,
+            source_lines: None,
+            source_files: HashMap::new(),
+        })
     }
 
     pub fn set_pc_histogram(&mut self, histogram: HashMap<u64, u64>) {
@@ -28,6 +38,10 @@ impl DisasmWriter {
         self.symbols = Some(symbols);
     }
 
+    pub fn set_source_lines(&mut self, source_lines: SourceLines) {
+        self.source_lines = Some(source_lines);
+    }
+
     pub fn write_header(&mut self, title: &str) -> Result<()> {
         writeln!(&mut self.file)?;
         writeln!(&mut self.file, "Disassembly with execution counts:")?;
@@ -94,6 +108,7 @@ impl DisasmWriter {
         }
 
         // Second pass: generate disassembly
+        let mut previous_chain = None;
         for (idx, pc) in rom.sorted_pc_list.iter().enumerate() {
             // Check if this PC is a function entry point
             if let Some(ref symbols) = self.symbols {
@@ -101,6 +116,7 @@ impl DisasmWriter {
                     // Write function header
                     writeln!(&mut self.file)?;
                     writeln!(&mut self.file, "{:016x} <{}>:", pc, sym.name)?;
+                    previous_chain = None;
                 }
             }
 
@@ -117,6 +133,13 @@ impl DisasmWriter {
                 }
             }
 
+            // Write the source line when it changes
+            let chain = self.source_lines.as_ref().and_then(|lines| lines.chain_id(*pc));
+            if chain.is_some() && chain != previous_chain {
+                self.write_source_line(*pc)?;
+            }
+            previous_chain = chain;
+
             let inst = rom.get_instruction(*pc);
             let exec_count = self.pc_histogram.get(pc).unwrap_or(&0);
 
@@ -174,6 +197,41 @@ impl DisasmWriter {
         Ok(())
     }
 
+    /// Writes the source line of the instruction at `pc`, followed by the call sites it was
+    /// inlined at
+    fn write_source_line(&mut self, pc: u64) -> Result<()> {
+        let Some(source_lines) = &self.source_lines else {
+            return Ok(());
+        };
+        for (depth, frame) in source_lines.frames(pc).iter().enumerate() {
+            let Some(location) = &frame.location else {
+                continue;
+            };
+            let function = frame.function.as_deref().unwrap_or("??");
+            if depth == 0 {
+                let text = self
+                    .source_files
+                    .entry(location.file.clone())
+                    .or_insert_with(|| {
+                        std::fs::read_to_string(&location.file)
+                            .ok()
+                            .map(|source| source.lines().map(str::to_string).collect())
+                    })
+                    .as_ref()
+                    .and_then(|lines| lines.get((location.line as usize).wrapping_sub(1)));
+                match text {
+                    Some(text) => {
+                        writeln!(&mut self.file, "  ; {location} <{function}>: {}", text.trim())?
+                    }
+                    None => writeln!(&mut self.file, "  ; {location} <{function}>")?,
+                }
+            } else {
+                writeln!(&mut self.file, "  ;   inlined at {location} <{function}>")?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn flush(&mut self) -> Result<()> {
         self.file.flush()
     }
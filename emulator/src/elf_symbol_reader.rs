@@ -52,6 +52,14 @@ impl ElfSymbolReader {
         }
     }
 
+    /// Loads the function symbols of an ELF already in memory
+    pub fn load_from_bytes(&mut self, data: &[u8]) -> Result<()> {
+        let obj = object::File::parse(data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        self.parse_symbols(&obj);
+        Ok(())
+    }
+
     pub fn get_symbols_from_file(&mut self, path: &str, symbols: &[&str]) -> Result<Vec<u64>> {
         let file = File::open(path)?;
         let mmap = unsafe { Mmap::map(&file)? };
@@ -133,4 +141,9 @@ impl ElfSymbolReader {
     pub fn get_symbol_at_address(&self, address: u64) -> Option<&SymbolInfo> {
         self.functions.iter().find(|s| s.address == address)
     }
+
+    /// Returns the function symbol whose code contains the given address, if any
+    pub fn get_symbol_containing(&self, address: u64) -> Option<&SymbolInfo> {
+        self.functions.iter().find(|s| address >= s.address && address < s.address + s.size.max(1))
+    }
 }
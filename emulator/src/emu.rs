@@ -1,7 +1,10 @@
 use std::borrow::Cow;
 use std::mem;
 
-use crate::{ElfSymbolReader, EmuContext, EmuOptions, EmuRegTrace, ParEmuOptions};
+use crate::{
+    ElfSymbolReader, EmuContext, EmuOptions, EmuRegTrace, GuestPanic, PanicTracker, ParEmuOptions,
+    SourceLines,
+};
 use fields::PrimeField64;
 use mem_common::MemHelpers;
 use riscv::RiscVRegisters;
@@ -1473,6 +1476,28 @@ impl<'a> Emu<'a> {
         }
     }
 
+    /// Runs the whole program following the guest output and call stack, and returns the guest
+    /// panic if the execution ends with an error. The backtrace is not symbolized.
+    pub fn run_capturing_panic(
+        &mut self,
+        inputs: Vec<u8>,
+        options: &EmuOptions,
+    ) -> Option<GuestPanic> {
+        self.ctx = self.create_emu_context(inputs, options);
+
+        let mut tracker = PanicTracker::default();
+        // The failing instruction is the last one executed, the pc already points past it
+        let mut last_pc = self.ctx.inst_ctx.pc;
+        while !self.ctx.inst_ctx.end && (self.ctx.inst_ctx.step < options.max_steps) {
+            last_pc = self.ctx.inst_ctx.pc;
+            let instruction = self.rom.get_instruction(last_pc);
+            self.step_fast();
+            tracker.on_step(instruction, last_pc, &self.ctx.inst_ctx);
+        }
+
+        self.ctx.inst_ctx.error.then(|| tracker.into_panic(last_pc, self.ctx.inst_ctx.step))
+    }
+
     #[inline(always)]
     pub fn step_fast_with_progress(&mut self) {
         let instruction = self.rom.get_instruction(self.ctx.inst_ctx.pc);
@@ -1610,6 +1635,23 @@ impl<'a> Emu<'a> {
                     count, roi_count
                 );
 
+                if options.source_lines {
+                    match SourceLines::load_from_file(elf_file, &self.rom.sorted_pc_list) {
+                        Ok(source_lines) if source_lines.is_empty() => eprintln!(
+                            "Warning: --source-lines specified but the ELF has no DWARF line information"
+                        ),
+                        Ok(source_lines) => {
+                            println!(
+                                "Loaded source lines for {} of {} instructions",
+                                source_lines.resolved_pcs(),
+                                self.rom.sorted_pc_list.len()
+                            );
+                            self.ctx.stats.set_source_lines(source_lines);
+                        }
+                        Err(e) => eprintln!("Error loading source lines: {}", e),
+                    }
+                }
+
                 // Setup call tracking if requested
                 if options.track_calls > 0 {
                     if roi_count > 0 {
@@ -2691,6 +2733,16 @@ impl<'a> Emu<'a> {
         self.ctx.inst_ctx.end
     }
 
+    /// Returns true if the program ended with an error, e.g. a guest panic
+    pub fn failed(&self) -> bool {
+        self.ctx.inst_ctx.error
+    }
+
+    /// Returns the exit code of the program, if it completed without an error
+    pub fn exit_code(&self) -> Option<i32> {
+        self.ctx.exit_code()
+    }
+
     /// Returns the number of executed steps
     pub fn number_of_steps(&self) -> u64 {
         self.ctx.inst_ctx.step
@@ -1,6 +1,8 @@
 use crate::{EmuOptions, Stats};
 use zisk_common::EmuTrace;
-use zisk_core::{InstContext, INPUT_ADDR, RAM_ADDR, RAM_SIZE, REGS_IN_MAIN_TOTAL_NUMBER};
+use zisk_core::{
+    InstContext, Mem, INPUT_ADDR, RAM_ADDR, RAM_SIZE, REGS_IN_MAIN_TOTAL_NUMBER, REG_A0,
+};
 
 /// ZisK emulator context data container, storing the state of the emulation
 pub struct EmuContext {
@@ -54,6 +56,13 @@ impl EmuContext {
 
         ctx
     }
+
+    /// Returns the exit code of the program, i.e. the value left in `a0` when it halted, if it
+    /// completed without an error
+    pub fn exit_code(&self) -> Option<i32> {
+        (self.inst_ctx.end && !self.inst_ctx.error)
+            .then(|| self.inst_ctx.regs[Mem::address_to_register_index(REG_A0)] as i32)
+    }
 }
 
 impl Default for EmuContext {
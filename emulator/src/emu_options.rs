@@ -94,6 +94,12 @@ pub struct EmuOptions {
     /// Load function names and symbols from the ELF file.
     #[clap(short = 'S', long, value_name = "READ_SYMBOLS", default_value = "false")]
     pub read_symbols: bool,
+    /// Attribute steps and cost to source lines using the DWARF debug information of the ELF,
+    /// including inlined frames. Adds the top source lines to the report, interleaves source
+    /// lines in the disassembly and adds per-line samples to the profiler output.
+    /// Requires options: -S -X
+    #[clap(long, value_name = "SOURCE_LINES", default_value = "false")]
+    pub source_lines: bool,
     /// Set the number of top Regions of Interest (ROI) to display.
     /// Requires options: -S -X
     #[clap(short = 'T', long, value_name = "TOP_ROI", default_value = "25")]
@@ -195,6 +201,7 @@ impl Default for EmuOptions {
             generate_minimal_traces: false,
             store_op_output: None,
             read_symbols: false,
+            source_lines: false,
             roi_callers: 10,
             top_roi: 25,
             top_roi_detail: false,
@@ -247,6 +254,7 @@ impl fmt::Display for EmuOptions {
         writeln!(f, "LOG_STEP: {:?}", self.log_step)?;
         writeln!(f, "MINIMAL_TRACES: {:?}", self.generate_minimal_traces)?;
         writeln!(f, "READ_SYMBOLS: {:?}", self.read_symbols)?;
+        writeln!(f, "SOURCE_LINES: {:?}", self.source_lines)?;
         writeln!(f, "TOP_ROI: {:?}", self.top_roi)?;
         writeln!(f, "ROI_CALLERS: {:?}", self.roi_callers)?;
         writeln!(f, "TOP_ROI_DETAIL: {:?}", self.top_roi_detail)?;
@@ -15,17 +15,21 @@
 //!             Emu::run()
 //! ```
 
-use crate::{Emu, EmuOptions, ErrWrongArguments, ParEmuOptions, ZiskEmulatorErr};
+use crate::{
+    ElfSymbolReader, Emu, EmuOptions, ErrWrongArguments, GuestPanic, ParEmuOptions, SourceLines,
+    ZiskEmulatorErr,
+};
 
 use data_bus::DataBusTrait;
 use fields::PrimeField;
 use std::{
     fs,
     path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
     time::Instant,
 };
 use sysinfo::System;
-use zisk_common::EmuTrace;
+use zisk_common::{BusId, EmuTrace};
 use zisk_core::{Riscv2zisk, ZiskRom};
 
 pub trait Emulator {
@@ -132,6 +136,26 @@ impl ZiskEmulator {
             return Err(ZiskEmulatorErr::EmulationNoCompleted);
         }
 
+        // Replay a failed execution to report the guest panic
+        if emu.failed() {
+            let mut symbols = ElfSymbolReader::new();
+            let mut source_lines = None;
+            if let Some(elf) = &options.elf {
+                if symbols.load_from_file(elf, &[]).is_ok() && options.source_lines {
+                    source_lines = SourceLines::load_from_file(elf, &rom.sorted_pc_list).ok();
+                }
+            }
+            if let Some(mut panic) = Self::capture_guest_panic(rom, inputs, options) {
+                panic.symbolize(&symbols, source_lines.as_ref());
+                return Err(ZiskEmulatorErr::GuestPanic(Box::new(panic)));
+            }
+        }
+
+        // Report a non-zero exit code of the guest program
+        if let Some(exit_code) = emu.exit_code().filter(|exit_code| *exit_code != 0) {
+            eprintln!("Guest program exited with code {exit_code}");
+        }
+
         // Store the duration of the emulation process as a difference vs. the start time
         let duration = start.elapsed();
 
@@ -185,6 +209,17 @@ impl ZiskEmulator {
         Ok(output)
     }
 
+    /// Replays the execution of a Zisk rom to capture the guest panic that made it fail.
+    /// Returns `None` if the execution does not fail; the backtrace is not symbolized.
+    pub fn capture_guest_panic(
+        rom: &ZiskRom,
+        inputs: &[u8],
+        options: &EmuOptions,
+    ) -> Option<GuestPanic> {
+        let options = EmuOptions { max_steps: options.max_steps, ..EmuOptions::default() };
+        Emu::new(rom).run_capturing_panic(inputs.to_owned(), &options)
+    }
+
     /// EXECUTE phase
     /// First phase of the witness computation
     /// 8 threads in waterfall (# threads to be re-calibrated after memory reads refactor)
@@ -197,6 +232,7 @@ impl ZiskEmulator {
     ) -> Result<Vec<EmuTrace>, ZiskEmulatorErr> {
         let mut minimal_traces = vec![Vec::new(); num_threads];
 
+        let failed = AtomicBool::new(false);
         minimal_traces.par_iter_mut().enumerate().for_each(|(thread_id, emu_trace)| {
             let par_emu_options =
                 ParEmuOptions::new(num_threads, thread_id, options.chunk_size.unwrap() as usize);
@@ -211,9 +247,19 @@ impl ZiskEmulator {
                 // return Err(ZiskEmulatorErr::EmulationNoCompleted);
             }
 
+            if emu.failed() {
+                failed.store(true, Ordering::Relaxed);
+            }
+
             *emu_trace = result;
         });
 
+        if failed.load(Ordering::Relaxed) {
+            let panic = Self::capture_guest_panic(rom, inputs, options)
+                .ok_or(ZiskEmulatorErr::EmulationNoCompleted)?;
+            return Err(ZiskEmulatorErr::GuestPanic(Box::new(panic)));
+        }
+
         let capacity = minimal_traces.iter().map(|trace| trace.len()).sum::<usize>();
         let mut vec_traces = Vec::with_capacity(capacity);
         for i in 0..capacity {
@@ -226,6 +272,19 @@ impl ZiskEmulator {
         Ok(vec_traces)
     }
 
+    /// Returns the exit code of an execution from its minimal traces, by replaying the last chunk.
+    /// Returns `None` if the execution did not complete or completed with an error.
+    pub fn exit_code(rom: &ZiskRom, min_traces: &[EmuTrace]) -> Option<i32> {
+        let last_chunk = min_traces.len().checked_sub(1)?;
+        if !min_traces[last_chunk].end {
+            return None;
+        }
+
+        let mut emu = Emu::new(rom);
+        emu.process_emu_traces(min_traces, last_chunk, &mut DiscardBus);
+        emu.exit_code()
+    }
+
     /// COUNT phase
     /// Second phase of the witness computation
     /// Executes in parallel the different blocks of wc
@@ -293,6 +352,21 @@ impl ZiskEmulator {
     }
 }
 
+/// Data bus that discards all the data, to replay chunks only for their final state
+struct DiscardBus;
+
+impl DataBusTrait<u64, ()> for DiscardBus {
+    fn write_to_bus(&mut self, _bus_id: BusId, _data: &[u64], _data_ext: &[u64]) -> bool {
+        true
+    }
+
+    fn on_close(&mut self) {}
+
+    fn into_devices(self, _execute_on_close: bool) -> Vec<(Option<usize>, Option<()>)> {
+        Vec::new()
+    }
+}
+
 impl Emulator for ZiskEmulator {
     /// Implement the emulate method of the Emulator trait for ZiskEmulator
     fn emulate(
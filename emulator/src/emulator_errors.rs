@@ -1,11 +1,14 @@
 use std::{error::Error, fmt};
 
+use crate::GuestPanic;
+
 #[derive(Debug)]
 pub enum ZiskEmulatorErr {
     WrongArguments(ErrWrongArguments),
     AddressOutOfRange(u64),
     EmulationNoCompleted,
     Unknown(String),
+    GuestPanic(Box<GuestPanic>),
 }
 
 #[derive(Debug)]
@@ -32,6 +35,7 @@ impl fmt::Display for ZiskEmulatorErr {
             }
             ZiskEmulatorErr::EmulationNoCompleted => write!(f, "Emulation not completed"),
             ZiskEmulatorErr::Unknown(code) => write!(f, "Error code {code}"),
+            ZiskEmulatorErr::GuestPanic(_) => write!(f, "Guest program failed"),
         }
     }
 }
@@ -43,6 +47,7 @@ impl Error for ZiskEmulatorErr {
             ZiskEmulatorErr::AddressOutOfRange(_) => None,
             ZiskEmulatorErr::EmulationNoCompleted => None,
             ZiskEmulatorErr::Unknown(_) => None,
+            ZiskEmulatorErr::GuestPanic(panic) => Some(panic.as_ref()),
         }
     }
 }
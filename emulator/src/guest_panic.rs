@@ -0,0 +1,234 @@
+//! Guest panic reports
+//!
+//! When a guest program panics, its panic handler prints the panic message and then aborts, which
+//! the emulator sees as an execution ending with the error flag set. [`PanicTracker`] follows the
+//! execution to keep the last bytes written to the UART and the call sites of the active calls,
+//! so that the failure can be reported as a [`GuestPanic`] with its message, location and
+//! backtrace instead of a bare error flag.
+
+use std::fmt;
+
+use zisk_core::{InstContext, ZiskInst, SRC_REG, STORE_IND, UART_ADDR, UART_ERR_ADDR};
+
+use crate::{ElfSymbolReader, SourceLines, SourceLocation};
+
+/// Number of trailing guest output bytes kept to extract the panic message
+const OUTPUT_TAIL_SIZE: usize = 4096;
+
+/// Maximum number of call sites kept; deeper calls drop the outermost ones
+const MAX_CALL_DEPTH: usize = 1024;
+
+/// Prefix of the panic messages printed by the Rust standard library panic handler
+const PANICKED_AT: &str = "panicked at ";
+
+/// Source location reported by the guest panic handler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl fmt::Display for PanicLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// One frame of the backtrace of a guest panic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BacktraceFrame {
+    /// Failing instruction for the innermost frame, call instruction for the others
+    pub pc: u64,
+    /// Function containing `pc`, once symbolized
+    pub function: Option<String>,
+    /// Source line of `pc`, once symbolized with DWARF line information
+    pub location: Option<SourceLocation>,
+}
+
+impl fmt::Display for BacktraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:08x}", self.pc)?;
+        if let Some(function) = &self.function {
+            write!(f, " {function}")?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, " ({location})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Guest program panic, as captured by the emulator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuestPanic {
+    /// Panic message, or the last line printed by the guest if it was not a Rust panic
+    pub message: String,
+    /// Location reported by the panic handler
+    pub location: Option<PanicLocation>,
+    /// Call stack at the failing instruction, innermost frame first
+    pub backtrace: Vec<BacktraceFrame>,
+    /// Step at which the execution failed
+    pub step: u64,
+}
+
+impl GuestPanic {
+    /// Builds a guest panic from the output printed by the guest before failing
+    pub fn from_output(output: &[u8], backtrace: Vec<BacktraceFrame>, step: u64) -> Self {
+        let output = String::from_utf8_lossy(output);
+        let (message, location) = Self::parse_panic_message(&output);
+        Self { message, location, backtrace, step }
+    }
+
+    /// Extracts the message and location of the last panic printed in `output`, with the format
+    /// of the Rust standard library panic handler:
+    ///
+    /// ```text
+    /// thread '<unnamed>' panicked at src/main.rs:15:14:
+    /// message
+    /// note: ...
+    /// ```
+    ///
+    /// If there is no such panic, the last line printed is used as the message.
+    fn parse_panic_message(output: &str) -> (String, Option<PanicLocation>) {
+        let Some(start) = output.rfind(PANICKED_AT) else {
+            let message = output.lines().rev().find(|line| !line.trim().is_empty());
+            return (
+                message.map_or_else(|| "guest program failed".to_string(), |m| m.to_string()),
+                None,
+            );
+        };
+        let report = &output[start + PANICKED_AT.len()..];
+        let (header, message) = report.split_once('\n').unwrap_or((report, ""));
+        let location = Self::parse_location(header.trim_end().trim_end_matches(':'));
+        let message = message
+            .lines()
+            .take_while(|line| !line.starts_with("note: "))
+            .collect::<Vec<_>>()
+            .join("\n");
+        (message.trim_end().to_string(), location)
+    }
+
+    /// Parses `file:line:column`, where the file may contain colons
+    fn parse_location(text: &str) -> Option<PanicLocation> {
+        let mut parts = text.rsplitn(3, ':');
+        let column = parts.next()?.parse().ok()?;
+        let line = parts.next()?.parse().ok()?;
+        let file = parts.next()?.to_string();
+        Some(PanicLocation { file, line, column })
+    }
+
+    /// Fills the function names of the backtrace with the ELF symbols, and the source lines if
+    /// the DWARF line information is available
+    pub fn symbolize(&mut self, symbols: &ElfSymbolReader, source_lines: Option<&SourceLines>) {
+        for frame in self.backtrace.iter_mut() {
+            if let Some(symbol) = symbols.get_symbol_containing(frame.pc) {
+                frame.function = Some(symbol.name.clone());
+            }
+            if let Some(location) = source_lines.and_then(|lines| lines.location(frame.pc)) {
+                frame.location = Some(location.clone());
+            }
+        }
+    }
+}
+
+impl fmt::Display for GuestPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "guest panicked")?;
+        if let Some(location) = &self.location {
+            write!(f, " at {location}")?;
+        }
+        write!(f, " (step {}): {}", self.step, self.message)?;
+        if !self.backtrace.is_empty() {
+            write!(f, "\nbacktrace:")?;
+            for (index, frame) in self.backtrace.iter().enumerate() {
+                write!(f, "\n  {index:>3}: {frame}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for GuestPanic {}
+
+/// Follows an execution to build a [`GuestPanic`] if it fails
+#[derive(Debug, Default)]
+pub struct PanicTracker {
+    /// Last bytes written by the guest to the stdout and stderr UARTs, interleaved
+    output: Vec<u8>,
+    /// PC of the call instructions of the active calls, outermost first
+    call_sites: Vec<u64>,
+}
+
+impl PanicTracker {
+    /// Called after executing `instruction` at `pc`, with the context it left behind
+    #[inline(always)]
+    pub fn on_step(&mut self, instruction: &ZiskInst, pc: u64, inst_ctx: &InstContext) {
+        if instruction.store == STORE_IND && instruction.ind_width == 1 && !instruction.store_use_sp
+        {
+            let addr = (instruction.store_offset + inst_ctx.a as i64) as u64;
+            if addr == UART_ADDR || addr == UART_ERR_ADDR {
+                if self.output.len() == OUTPUT_TAIL_SIZE {
+                    self.output.drain(..OUTPUT_TAIL_SIZE / 2);
+                }
+                self.output.push(inst_ctx.c as u8);
+            }
+        }
+
+        // Same call and return patterns used by the statistics call stack
+        let is_jmp = instruction.set_pc
+            || (instruction.op == 0
+                && (instruction.jmp_offset1 > 4 || instruction.jmp_offset1 < 0));
+        if !is_jmp {
+            return;
+        }
+        if instruction.store_pc {
+            if self.call_sites.len() == MAX_CALL_DEPTH {
+                self.call_sites.remove(0);
+            }
+            self.call_sites.push(pc);
+        } else if instruction.set_pc
+            && instruction.b_src == SRC_REG
+            && instruction.b_offset_imm0 == 1
+        {
+            self.call_sites.pop();
+        }
+    }
+
+    /// Builds the guest panic of an execution that failed at `pc` and `step`
+    pub fn into_panic(self, pc: u64, step: u64) -> GuestPanic {
+        let backtrace = std::iter::once(pc)
+            .chain(self.call_sites.into_iter().rev())
+            .map(|pc| BacktraceFrame { pc, function: None, location: None })
+            .collect();
+        GuestPanic::from_output(&self.output, backtrace, step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_std_panic() {
+        let output = b"hello\nthread '<unnamed>' panicked at src/main.rs:15:14:\n\
+            Intentional panic\nsecond line\nnote: run with `RUST_BACKTRACE=1`\n";
+        let panic = GuestPanic::from_output(output, Vec::new(), 7);
+        assert_eq!(panic.message, "Intentional panic\nsecond line");
+        assert_eq!(
+            panic.location,
+            Some(PanicLocation { file: "src/main.rs".to_string(), line: 15, column: 14 })
+        );
+        assert_eq!(panic.step, 7);
+    }
+
+    #[test]
+    fn test_parse_without_std_panic() {
+        let panic = GuestPanic::from_output(b"starting\nout of gas\n\n", Vec::new(), 0);
+        assert_eq!(panic.message, "out of gas");
+        assert_eq!(panic.location, None);
+
+        let panic = GuestPanic::from_output(b"", Vec::new(), 0);
+        assert_eq!(panic.message, "guest program failed");
+    }
+}
@@ -23,6 +23,9 @@ mod emu_reg_trace;
 mod emu_segment;
 mod emulator;
 mod emulator_errors;
+mod guest_panic;
+mod precompile_log;
+mod source_lines;
 pub mod stats;
 
 pub use disasm::*;
@@ -36,4 +39,7 @@ pub use emu_reg_trace::*;
 pub use emu_segment::*;
 pub use emulator::*;
 pub use emulator_errors::*;
+pub use guest_panic::*;
+pub use precompile_log::*;
+pub use source_lines::*;
 pub use stats::*;
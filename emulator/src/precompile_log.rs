@@ -0,0 +1,135 @@
+//! Records the results of the precompiled operations and free-input calls executed by the guest.
+//!
+//! When a guest is built with precompile hints, the native side replays every precompile syscall
+//! and fcall and streams their results to the prover, which consumes them in the same order the
+//! guest executes the matching instructions. [`Emu::run_precompile_log`] runs the emulator and
+//! captures, for every such instruction, the words the hints stream is expected to contain:
+//!
+//! * For precompiled operations, the output written back to memory, in the same order the
+//!   `syscall_*` functions push it with `hints` enabled.
+//! * For fcalls, the result size followed by the result words.
+
+use zisk_core::{zisk_ops::ZiskOp, InstContext};
+use ziskos_hints::zisklib::FCALL_INPUT_READY_ID;
+
+use crate::{Emu, EmuOptions};
+
+/// Output words produced by one precompiled operation or fcall
+#[derive(Debug, Clone)]
+pub struct PrecompileRecord {
+    /// Step at which the instruction was executed
+    pub step: u64,
+    /// Program counter of the instruction
+    pub pc: u64,
+    /// Operation code
+    pub op: u8,
+    /// Words the hints stream must provide for this instruction
+    pub words: Vec<u64>,
+}
+
+impl PrecompileRecord {
+    /// Human-readable name of the operation
+    pub fn op_name(&self) -> &'static str {
+        ZiskOp::try_from_code(self.op).map(|op| op.name()).unwrap_or("unknown")
+    }
+}
+
+/// Where a precompiled operation leaves its output
+enum OutputLocation {
+    /// `size` words starting at the address in `b`
+    Direct(usize),
+    /// `size` words starting at the address stored in parameter `index` of the table at `b`
+    Param(u64, usize),
+}
+
+impl Emu<'_> {
+    /// Runs the whole program and returns the results of every precompiled operation and fcall,
+    /// in execution order
+    pub fn run_precompile_log(
+        &mut self,
+        inputs: Vec<u8>,
+        options: &EmuOptions,
+    ) -> Vec<PrecompileRecord> {
+        self.ctx = self.create_emu_context(inputs, options);
+
+        let mut records = Vec::new();
+        while !self.ctx.inst_ctx.end && (self.ctx.inst_ctx.step < options.max_steps) {
+            let pc = self.ctx.inst_ctx.pc;
+            let step = self.ctx.inst_ctx.step;
+            let op = self.rom.get_instruction(pc).op;
+
+            self.step_fast();
+
+            if let Some(words) = Self::precompile_output(op, &self.ctx.inst_ctx) {
+                records.push(PrecompileRecord { step, pc, op, words });
+            }
+        }
+
+        if self.ctx.inst_ctx.error {
+            eprintln!(
+                "Emu::run_precompile_log() finished with error at step={} pc=0x{:x}",
+                self.ctx.inst_ctx.step, self.ctx.inst_ctx.pc
+            );
+        }
+
+        records
+    }
+
+    /// Returns the output of operation `op` once it has been executed, or `None` if the operation
+    /// does not feed the hints stream
+    fn precompile_output(op: u8, ctx: &InstContext) -> Option<Vec<u64>> {
+        use OutputLocation::*;
+
+        let location = match op {
+            ZiskOp::FCALL => {
+                if ctx.a == FCALL_INPUT_READY_ID as u64 {
+                    return None;
+                }
+                let size = ctx.fcall.result_size as usize;
+                let mut words = Vec::with_capacity(size + 1);
+                words.push(ctx.fcall.result_size);
+                words.extend_from_slice(&ctx.fcall.result[..size]);
+                return Some(words);
+            }
+            ZiskOp::ADD256 => {
+                let mut words = Self::read_output(ctx, Param(3, 4));
+                words.push(ctx.c);
+                return Some(words);
+            }
+            ZiskOp::ARITH256 => {
+                let mut words = Self::read_output(ctx, Param(3, 4));
+                words.extend(Self::read_output(ctx, Param(4, 4)));
+                return Some(words);
+            }
+            ZiskOp::KECCAK => Direct(25),
+            ZiskOp::POSEIDON2 => Direct(16),
+            ZiskOp::SHA256 => Param(0, 4),
+            ZiskOp::BLAKE2 => Param(1, 16),
+            ZiskOp::ARITH256_MOD => Param(4, 4),
+            ZiskOp::ARITH384_MOD => Param(4, 6),
+            ZiskOp::SECP256K1_ADD
+            | ZiskOp::SECP256R1_ADD
+            | ZiskOp::BN254_CURVE_ADD
+            | ZiskOp::BN254_COMPLEX_ADD
+            | ZiskOp::BN254_COMPLEX_SUB
+            | ZiskOp::BN254_COMPLEX_MUL => Param(0, 8),
+            ZiskOp::SECP256K1_DBL | ZiskOp::SECP256R1_DBL | ZiskOp::BN254_CURVE_DBL => Direct(8),
+            ZiskOp::BLS12_381_CURVE_ADD
+            | ZiskOp::BLS12_381_COMPLEX_ADD
+            | ZiskOp::BLS12_381_COMPLEX_SUB
+            | ZiskOp::BLS12_381_COMPLEX_MUL => Param(0, 12),
+            ZiskOp::BLS12_381_CURVE_DBL => Direct(12),
+            _ => return None,
+        };
+
+        Some(Self::read_output(ctx, location))
+    }
+
+    fn read_output(ctx: &InstContext, location: OutputLocation) -> Vec<u64> {
+        let (address, size) = match location {
+            OutputLocation::Direct(size) => (ctx.b, size),
+            OutputLocation::Param(index, size) => (ctx.mem.read(ctx.b + 8 * index, 8), size),
+        };
+        (0..size as u64).map(|i| ctx.mem.read(address + 8 * i, 8)).collect()
+    }
+}
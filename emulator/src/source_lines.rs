@@ -0,0 +1,215 @@
+//! Source line attribution from the DWARF debug information of the ELF
+//!
+//! Every ROM instruction is resolved once, when the ELF is loaded, to the chain of source frames
+//! that produced it: the innermost frame holds the source line of the instruction itself, and
+//! each following frame the line where the previous one was inlined, up to the function that
+//! contains the instruction. Identical chains are shared, so callers can detect a change of
+//! source line by comparing chain ids.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{Error, ErrorKind, Result};
+
+/// Source file and line
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+/// One level of the inline chain of an instruction
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceFrame {
+    /// Demangled name of the function this frame belongs to
+    pub function: Option<String>,
+    /// Source line executed in this frame
+    pub location: Option<SourceLocation>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SourceLines {
+    /// Distinct inline chains, innermost frame first
+    chains: Vec<Vec<SourceFrame>>,
+    /// Chain id of each resolved PC
+    pc_chain: HashMap<u64, usize>,
+}
+
+impl SourceLines {
+    /// Loads the DWARF line tables of the ELF at `path` and resolves the given PCs
+    pub fn load_from_file(path: &str, pcs: &[u64]) -> Result<Self> {
+        let loader = addr2line::Loader::new(path)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut source_lines = Self::default();
+        let mut chain_ids: HashMap<Vec<SourceFrame>, usize> = HashMap::new();
+        for pc in pcs {
+            let chain = Self::find_frames(&loader, *pc);
+            if chain.iter().all(|frame| frame.location.is_none()) {
+                continue;
+            }
+            let id = *chain_ids.entry(chain).or_insert_with_key(|chain| {
+                source_lines.chains.push(chain.clone());
+                source_lines.chains.len() - 1
+            });
+            source_lines.pc_chain.insert(*pc, id);
+        }
+        Ok(source_lines)
+    }
+
+    fn find_frames(loader: &addr2line::Loader, pc: u64) -> Vec<SourceFrame> {
+        let mut chain = Vec::new();
+        let Ok(mut frames) = loader.find_frames(pc) else {
+            return chain;
+        };
+        while let Ok(Some(frame)) = frames.next() {
+            let function = frame
+                .function
+                .as_ref()
+                .and_then(|name| name.raw_name().ok())
+                .map(|name| symbolic_demangle::demangle(&name).into_owned());
+            let location = frame.location.and_then(|location| {
+                Some(SourceLocation { file: location.file?.to_string(), line: location.line? })
+            });
+            chain.push(SourceFrame { function, location });
+        }
+        chain
+    }
+
+    /// Returns true if no instruction could be resolved to a source line
+    pub fn is_empty(&self) -> bool {
+        self.pc_chain.is_empty()
+    }
+
+    /// Number of instructions resolved to a source line
+    pub fn resolved_pcs(&self) -> usize {
+        self.pc_chain.len()
+    }
+
+    /// Returns the id of the inline chain of the instruction at `pc`
+    pub fn chain_id(&self, pc: u64) -> Option<usize> {
+        self.pc_chain.get(&pc).copied()
+    }
+
+    /// Returns the inline chain with the given id, innermost frame first
+    pub fn chain(&self, id: usize) -> &[SourceFrame] {
+        &self.chains[id]
+    }
+
+    /// Returns the inline chain of the instruction at `pc`, innermost frame first
+    pub fn frames(&self, pc: u64) -> &[SourceFrame] {
+        self.chain_id(pc).map(|id| self.chain(id)).unwrap_or(&[])
+    }
+
+    /// Returns the source line of the instruction at `pc`
+    pub fn location(&self, pc: u64) -> Option<&SourceLocation> {
+        self.frames(pc).iter().find_map(|frame| frame.location.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object::{Object, ObjectSymbol};
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    /// Source of the test ELF, `double` is inlined into `fixture_entry`
+    const FIXTURE_SOURCE: &str = "#[inline(always)]
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+#[no_mangle]
+pub extern \"C\" fn fixture_entry(x: u64) -> u64 {
+    double(x) + 1
+}
+";
+
+    /// Builds the test ELF with debug information and returns its path and the PCs of
+    /// `fixture_entry`
+    fn build_fixture(name: &str) -> (PathBuf, Vec<u64>) {
+        let dir =
+            std::env::temp_dir().join(format!("zisk-source-lines-{}-{name}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("dwarf_fixture.rs");
+        std::fs::write(&source, FIXTURE_SOURCE).unwrap();
+
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let status = Command::new(rustc)
+            .args(["--edition", "2021", "--crate-type", "cdylib", "--crate-name", "dwarf_fixture"])
+            .args(["-C", "debuginfo=2", "-C", "opt-level=0", "-C", "overflow-checks=off"])
+            .arg("--out-dir")
+            .arg(&dir)
+            .arg(&source)
+            .status()
+            .expect("Failed to run rustc");
+        assert!(status.success(), "Failed to build the DWARF test ELF");
+
+        let elf = dir.join(format!(
+            "{}dwarf_fixture{}",
+            std::env::consts::DLL_PREFIX,
+            std::env::consts::DLL_SUFFIX
+        ));
+        let data = std::fs::read(&elf).unwrap();
+        let file = object::File::parse(data.as_slice()).unwrap();
+        let entry = file.symbols().find(|symbol| symbol.name() == Ok("fixture_entry")).unwrap();
+        (elf, (entry.address()..entry.address() + entry.size()).collect())
+    }
+
+    #[test]
+    fn test_resolves_pcs_to_source_lines() {
+        let (elf, pcs) = build_fixture("lines");
+        let source_lines = SourceLines::load_from_file(elf.to_str().unwrap(), &pcs).unwrap();
+        assert!(!source_lines.is_empty());
+
+        // The first instruction of a function belongs to the line of its signature
+        let entry = source_lines.location(pcs[0]).unwrap();
+        assert!(entry.file.ends_with("dwarf_fixture.rs"), "{entry}");
+        assert_eq!(entry.line, 7);
+        assert_eq!(source_lines.frames(pcs[0])[0].function.as_deref(), Some("fixture_entry"));
+
+        // The inlined body is attributed to `double`, inlined at the call line of `fixture_entry`
+        let inlined = pcs
+            .iter()
+            .map(|pc| source_lines.frames(*pc))
+            .find(|frames| frames.len() == 2)
+            .expect("no instruction of the inlined function");
+        assert_eq!(inlined[0].function.as_deref(), Some("dwarf_fixture::double"));
+        assert_eq!(inlined[0].location.as_ref().unwrap().line, 3);
+        assert_eq!(inlined[1].function.as_deref(), Some("fixture_entry"));
+        assert_eq!(inlined[1].location.as_ref().unwrap().line, 8);
+    }
+
+    #[test]
+    fn test_instructions_of_a_line_share_their_chain() {
+        let (elf, pcs) = build_fixture("chains");
+        let source_lines = SourceLines::load_from_file(elf.to_str().unwrap(), &pcs).unwrap();
+
+        let ids: Vec<usize> = pcs.iter().filter_map(|pc| source_lines.chain_id(*pc)).collect();
+        assert_eq!(ids.len(), source_lines.resolved_pcs());
+        let mut distinct = ids.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert!(distinct.len() < ids.len(), "instructions of the same line must share a chain");
+        for id in ids {
+            let location = source_lines.chain(id)[0].location.as_ref().unwrap();
+            assert!([3, 7, 8, 9].contains(&location.line), "{location}");
+        }
+
+        // Addresses outside the resolved PCs have no source line
+        assert_eq!(source_lines.chain_id(0), None);
+        assert!(source_lines.frames(0).is_empty());
+        assert_eq!(source_lines.location(0), None);
+    }
+
+    #[test]
+    fn test_missing_elf_fails_to_load() {
+        assert!(SourceLines::load_from_file("/nonexistent/program.elf", &[0x1000]).is_err());
+    }
+}
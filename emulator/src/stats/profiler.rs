@@ -10,7 +10,33 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
-use crate::RegionsOfInterest;
+use crate::{RegionsOfInterest, SourceFrame};
+
+/// Frame of an entry of the stack table
+#[derive(Debug, Clone, Copy)]
+enum StackFrame {
+    /// Function of the ROI with this index
+    Roi(usize),
+    /// Source line, index into line_frames
+    Line(usize),
+}
+
+/// Function a source line frame belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum LineFunc {
+    /// Function of the ROI with this index
+    Roi(usize),
+    /// Function inlined into a ROI, index into inlined_funcs
+    Inlined(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct LineFrame {
+    func: LineFunc,
+    /// Index into source_files
+    file: Option<usize>,
+    line: u32,
+}
 
 /// Profiler for tracking call paths and building a call hierarchy tree
 #[derive(Debug)]
@@ -21,11 +47,21 @@ pub struct CallPathProfiler {
     prefix_table: HashMap<String, usize>,
     /// Stack of indices into call_stack_table representing current call path
     prefix_stack: Vec<usize>,
-    /// Table of call stack entries: (roi_index, parent_index)
-    stack_table: Vec<(usize, Option<usize>)>,
+    /// Table of call stack entries: (frame, parent_index)
+    stack_table: Vec<(StackFrame, Option<usize>)>,
     /// Samples, first element metric (e.g., cost), second element index into stack_table
     samples: Vec<(u64, usize)>,
     ram_usage: Vec<u64>,
+    /// Maps (parent_index, line frame) to their index in the stack_table
+    line_stack_table: HashMap<(Option<usize>, usize), usize>,
+    line_frames: Vec<LineFrame>,
+    line_frames_map: HashMap<LineFrame, usize>,
+    inlined_funcs: Vec<String>,
+    inlined_funcs_map: HashMap<String, usize>,
+    source_files: Vec<String>,
+    source_files_map: HashMap<String, usize>,
+    /// Call path entry and inline chain of the last source line sample
+    last_line_sample: Option<(usize, usize)>,
 }
 
 impl Default for CallPathProfiler {
@@ -44,6 +80,14 @@ impl CallPathProfiler {
             prefix_stack: Vec::with_capacity(1024),
             samples: Vec::with_capacity(128 * 1024),
             ram_usage: Vec::with_capacity(128 * 1024),
+            line_stack_table: HashMap::new(),
+            line_frames: Vec::new(),
+            line_frames_map: HashMap::new(),
+            inlined_funcs: Vec::new(),
+            inlined_funcs_map: HashMap::new(),
+            source_files: Vec::new(),
+            source_files_map: HashMap::new(),
+            last_line_sample: None,
         }
     }
 
@@ -86,7 +130,7 @@ impl CallPathProfiler {
         } else {
             let new_index = self.stack_table.len();
             self.prefix_table.insert(self.call_path.clone(), new_index);
-            self.stack_table.push((roi_index, self.prefix_stack.last().copied()));
+            self.stack_table.push((StackFrame::Roi(roi_index), self.prefix_stack.last().copied()));
             self.prefix_stack.push(new_index);
         }
     }
@@ -106,12 +150,94 @@ impl CallPathProfiler {
 
     /// Adds a sample cost to the current call path entry
     pub fn add_call_path_sample(&mut self, metric: u64, ram_usage: u64) {
+        self.last_line_sample = None;
         if let Some(&index) = self.prefix_stack.last() {
             self.samples.push((metric, index));
             self.ram_usage.push(ram_usage);
         }
     }
 
+    /// Returns true if entering the inline chain `chain_id` starts a new source line sample
+    pub fn needs_line_sample(&self, chain_id: usize) -> bool {
+        match self.prefix_stack.last() {
+            Some(&top) => self.last_line_sample != Some((top, chain_id)),
+            None => false,
+        }
+    }
+
+    /// Adds a sample for the source line being executed in the current call path entry
+    ///
+    /// `frames` is the inline chain of the line, innermost frame first, and `chain_id` its
+    /// identifier; consecutive samples of the same chain in the same call path entry are merged.
+    /// The outermost frame replaces the current function in the stack and the inlined frames
+    /// are stacked on top of it.
+    pub fn add_line_sample(
+        &mut self,
+        chain_id: usize,
+        frames: &[SourceFrame],
+        metric: u64,
+        ram_usage: u64,
+    ) {
+        let Some(&top) = self.prefix_stack.last() else {
+            return;
+        };
+        if frames.is_empty() || self.last_line_sample == Some((top, chain_id)) {
+            return;
+        }
+        self.last_line_sample = Some((top, chain_id));
+        let (StackFrame::Roi(roi_index), mut prefix) = self.stack_table[top] else {
+            return;
+        };
+
+        let mut index = top;
+        for (depth, frame) in frames.iter().rev().enumerate() {
+            let func = if depth == 0 {
+                LineFunc::Roi(roi_index)
+            } else {
+                let name = frame.function.as_deref().unwrap_or("??");
+                LineFunc::Inlined(Self::intern(
+                    &mut self.inlined_funcs,
+                    &mut self.inlined_funcs_map,
+                    name,
+                ))
+            };
+            let (file, line) = match &frame.location {
+                Some(location) => (
+                    Some(Self::intern(
+                        &mut self.source_files,
+                        &mut self.source_files_map,
+                        &location.file,
+                    )),
+                    location.line,
+                ),
+                None => (None, 0),
+            };
+            let line_frame = LineFrame { func, file, line };
+            let frame_index = *self.line_frames_map.entry(line_frame).or_insert_with(|| {
+                self.line_frames.push(line_frame);
+                self.line_frames.len() - 1
+            });
+            index = *self.line_stack_table.entry((prefix, frame_index)).or_insert_with(|| {
+                self.stack_table.push((StackFrame::Line(frame_index), prefix));
+                self.stack_table.len() - 1
+            });
+            prefix = Some(index);
+        }
+
+        self.samples.push((metric, index));
+        self.ram_usage.push(ram_usage);
+    }
+
+    /// Returns the index of `name` in `names`, adding it if needed
+    fn intern(names: &mut Vec<String>, map: &mut HashMap<String, usize>, name: &str) -> usize {
+        if let Some(&index) = map.get(name) {
+            return index;
+        }
+        names.push(name.to_string());
+        map.insert(name.to_string(), names.len() - 1);
+        names.len() - 1
+    }
+
     /// Updates the call path by replacing the last ROI index
     ///
     /// This is used for tail calls where we need to update the current
@@ -141,10 +267,12 @@ impl CallPathProfiler {
     ///
     /// # Format
     /// The output follows the Firefox Profiler format (version 31, preprocessedProfileVersion 61) with:
-    /// - **shared.stringArray**: ROI names extracted from the rois parameter
-    /// - **shared.frameTable**: 1:1 correspondence with stringArray
+    /// - **shared.stringArray**: ROI names extracted from the rois parameter, followed by the
+    ///   names of inlined functions and source files seen in line samples
+    /// - **shared.funcTable**: ROIs followed by inlined functions
+    /// - **shared.frameTable**: one frame per ROI, followed by one frame per source line
     /// - **shared.stackTable**: Information from self.stack_table where:
-    ///   - `frame`: ROI index
+    ///   - `frame`: ROI index, or source line frame
     ///   - `prefix`: Parent stack index or null
     /// - **threads[0].samples**: Samples where:
     ///   - `timeDeltas`: Time differences between consecutive samples (first is 0.0)
@@ -172,14 +300,50 @@ impl CallPathProfiler {
     /// # Errors
     /// Returns an IO error if the file cannot be created or written
     pub fn save_to_file(&self, filename: &str, rois: &[RegionsOfInterest]) -> std::io::Result<()> {
-        // Build string array from ROI names
-        let string_array: Vec<&str> = rois.iter().map(|roi| roi.name.as_ref()).collect();
+        // Build string array from ROI names, followed by inlined function and source file names
+        let roi_count = rois.len();
+        let mut string_array: Vec<&str> = rois.iter().map(|roi| roi.name.as_ref()).collect();
+        string_array.extend(self.inlined_funcs.iter().map(String::as_str));
+        let files_offset = string_array.len();
+        string_array.extend(self.source_files.iter().map(String::as_str));
+
+        // Functions are the ROIs followed by the inlined functions
+        let func_count = roi_count + self.inlined_funcs.len();
+        let func_index = |func: LineFunc| match func {
+            LineFunc::Roi(index) => index,
+            LineFunc::Inlined(index) => roi_count + index,
+        };
+        let mut func_source = vec![Value::Null; func_count];
+        for frame in &self.line_frames {
+            if let Some(file) = frame.file {
+                let source = &mut func_source[func_index(frame.func)];
+                if source.is_null() {
+                    *source = json!(file);
+                }
+            }
+        }
 
-        // Build frame table (1:1 with string array)
-        let frame_func: Vec<usize> = (0..string_array.len()).collect();
+        // Frames are the ROIs (1:1 with their functions) followed by the source lines
+        let mut frame_func: Vec<usize> = (0..roi_count).collect();
+        frame_func.extend(self.line_frames.iter().map(|frame| func_index(frame.func)));
+        let mut frame_line = vec![Value::Null; roi_count];
+        frame_line.extend(self.line_frames.iter().map(|frame| {
+            if frame.line > 0 {
+                json!(frame.line)
+            } else {
+                Value::Null
+            }
+        }));
 
         // Build stack table from self.stack_table
-        let stack_frame: Vec<usize> = self.stack_table.iter().map(|(frame, _)| *frame).collect();
+        let stack_frame: Vec<usize> = self
+            .stack_table
+            .iter()
+            .map(|(frame, _)| match frame {
+                StackFrame::Roi(index) => *index,
+                StackFrame::Line(index) => roi_count + index,
+            })
+            .collect();
         let stack_prefix: Vec<Option<usize>> =
             self.stack_table.iter().map(|(_, prefix)| *prefix).collect();
 
@@ -261,12 +425,12 @@ impl CallPathProfiler {
             "shared": {
                 "stringArray": string_array,
                 "sources": {
-                    "length": 0,
-                    "filename": Vec::<String>::new(),
-                    "id": Vec::<u32>::new(),
-                    "startLine": Vec::<u32>::new(),
-                    "startColumn": Vec::<u32>::new(),
-                    "sourceMapURL": Vec::<Option<String>>::new()
+                    "length": self.source_files.len(),
+                    "filename": (files_offset..string_array.len()).collect::<Vec<usize>>(),
+                    "id": (0..self.source_files.len()).collect::<Vec<usize>>(),
+                    "startLine": vec![1; self.source_files.len()],
+                    "startColumn": vec![1; self.source_files.len()],
+                    "sourceMapURL": vec![Value::Null; self.source_files.len()]
                 },
                 "stackTable": {
                     "frame": stack_frame,
@@ -281,19 +445,19 @@ impl CallPathProfiler {
                     "func": frame_func.clone(),
                     "nativeSymbol": vec![Value::Null; frame_func.len()],
                     "innerWindowID": vec![0; frame_func.len()],
-                    "line": vec![Value::Null; frame_func.len()],
+                    "line": frame_line,
                     "column": vec![Value::Null; frame_func.len()],
                     "length": frame_func.len()
                 },
                 "funcTable": {
-                    "name": frame_func,
-                    "isJS": vec![false; string_array.len()],
-                    "relevantForJS": vec![false; string_array.len()],
-                    "resource": vec![-1; string_array.len()],
-                    "source": vec![Value::Null; string_array.len()],
-                    "lineNumber": vec![Value::Null; string_array.len()],
-                    "columnNumber": vec![Value::Null; string_array.len()],
-                    "length": string_array.len()
+                    "name": (0..func_count).collect::<Vec<usize>>(),
+                    "isJS": vec![false; func_count],
+                    "relevantForJS": vec![false; func_count],
+                    "resource": vec![-1; func_count],
+                    "source": func_source,
+                    "lineNumber": vec![Value::Null; func_count],
+                    "columnNumber": vec![Value::Null; func_count],
+                    "length": func_count
                 },
                 "resourceTable": {
                     "type": Vec::<u32>::new(),
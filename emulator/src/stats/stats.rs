@@ -28,8 +28,8 @@ use zisk_definitions::{
 use zisk_core::{STORE_IND, UART_ADDR};
 
 use crate::{
-    CallPathProfiler, OpsCosts, RamMonitor, RegionsOfInterest, StatsCosts, StatsCoverageReport,
-    StatsReport, BASE_COST, MAIN_COST, NO_ROI_ID,
+    CallPathProfiler, OpsCosts, RamMonitor, RegionsOfInterest, SourceLines, SourceLocation,
+    StatsCosts, StatsCoverageReport, StatsReport, BASE_COST, MAIN_COST, NO_ROI_ID,
 };
 
 #[derive(Debug, Clone)]
@@ -81,6 +81,24 @@ impl Default for ProfileStats {
     }
 }
 
+/// How a profile tag mark breaks the nesting of the profile tags
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnbalancedProfileTagKind {
+    /// Started and not ended before its enclosing tag ended, or before the program finished
+    NotEnded,
+    /// Ended without a matching start
+    NotStarted,
+}
+
+/// Profile tag mark that breaks the nesting of the profile tags
+#[derive(Debug, Clone)]
+pub struct UnbalancedProfileTag {
+    pub tag: String,
+    /// PC of the offending start or end mark
+    pub pc: u64,
+    pub kind: UnbalancedProfileTagKind,
+}
+
 /// Keeps statistics of the emulator operations
 #[derive(Debug)]
 pub struct Stats {
@@ -126,7 +144,9 @@ pub struct Stats {
     ram_monitor: RamMonitor,
     profile_tags_map: HashMap<String, usize>,
     profile_tags: Vec<ProfileStats>,
-    profile_stack: Vec<(usize, u64)>,
+    /// Open profile tags: (tag index, reference steps or cost, PC of the start mark)
+    profile_stack: Vec<(usize, u64, u64)>,
+    unbalanced_profile_tags: Vec<UnbalancedProfileTag>,
     current_variable_cost: u64,
     #[cfg(feature = "handle_stdout")]
     stdout_data: String,
@@ -137,6 +157,12 @@ pub struct Stats {
     #[cfg(feature = "debug_stats_trace")]
     previous_stack_depth: usize,
     profiler_output: String,
+    /// Source line information of the ROM instructions, loaded from the DWARF of the ELF
+    source_lines: Option<SourceLines>,
+    /// Cost histogram, i.e. cost of all the executions of each PC, kept with source lines
+    pc_costs: HashMap<u64, u64>,
+    /// Total cost at the end of the previous operation
+    previous_total_cost: u64,
 }
 
 impl Default for Stats {
@@ -193,8 +219,12 @@ impl Default for Stats {
             profile_tags_map: HashMap::new(),
             profile_tags: Vec::new(),
             profile_stack: Vec::new(),
+            unbalanced_profile_tags: Vec::new(),
             current_variable_cost: 0,
             profiler_output: "profile.json.gz".to_string(),
+            source_lines: None,
+            pc_costs: HashMap::new(),
+            previous_total_cost: 0,
             #[cfg(feature = "handle_stdout")]
             stdout_data: String::with_capacity(256),
             #[cfg(feature = "handle_stdout")]
@@ -562,6 +592,23 @@ impl Stats {
             }
         }
     }
+    /// Adds a profiler sample when the execution moves to another source line
+    fn check_source_line(&mut self, inst_ctx: &InstContext) {
+        let (Some(source_lines), Some(profiler)) = (&self.source_lines, &mut self.profiler) else {
+            return;
+        };
+        if let Some(chain_id) = source_lines.chain_id(inst_ctx.pc) {
+            if profiler.needs_line_sample(chain_id) {
+                let ram_usage = self.ram_monitor.get_usage(inst_ctx);
+                profiler.add_line_sample(
+                    chain_id,
+                    source_lines.chain(chain_id),
+                    self.costs.total_cost(),
+                    ram_usage,
+                );
+            }
+        }
+    }
     #[cfg(feature = "handle_stdout")]
     pub fn handle_stdout(&mut self) {}
 
@@ -599,6 +646,7 @@ impl Stats {
         #[cfg(feature = "handle_stdout")]
         self.check_stdout(instruction, inst_ctx);
         self.check_roi(inst_ctx);
+        self.check_source_line(inst_ctx);
         #[cfg(feature = "debug_stats_trace")]
         self.debug_stats_trace(pc);
 
@@ -609,30 +657,34 @@ impl Stats {
             let tag = unsafe { std::str::from_utf8_unchecked(bytes) };
             match inst_ctx.b as u8 {
                 PROFILE_START_COST_ID => {
-                    self.start_profile_tag(tag, false);
+                    self.start_profile_tag(tag, false, pc);
                 }
                 PROFILE_START_STEPS_ID => {
-                    self.start_profile_tag(tag, true);
+                    self.start_profile_tag(tag, true, pc);
                 }
                 PROFILE_REPORT_START_COST_ID => {
-                    let id = self.start_profile_tag(tag, false);
+                    let id = self.start_profile_tag(tag, false, pc);
                     self.profile_tags[id].report_cost = true;
                 }
                 PROFILE_REPORT_START_STEPS_ID => {
-                    let id = self.start_profile_tag(tag, true);
+                    let id = self.start_profile_tag(tag, true, pc);
                     self.profile_tags[id].report_steps = true;
                 }
                 PROFILE_END_COST_ID => {
-                    println!("[{tag}] {}", self.end_profile_tag(tag, false));
+                    if let Some(delta) = self.end_profile_tag(tag, false, pc) {
+                        println!("[{tag}] {delta}");
+                    }
                 }
                 PROFILE_END_STEPS_ID => {
-                    println!("[{tag}] {}", self.end_profile_tag(tag, true));
+                    if let Some(delta) = self.end_profile_tag(tag, true, pc) {
+                        println!("[{tag}] {delta}");
+                    }
                 }
                 PROFILE_REPORT_END_COST_ID => {
-                    self.end_profile_tag(tag, false);
+                    self.end_profile_tag(tag, false, pc);
                 }
                 PROFILE_REPORT_END_STEPS_ID => {
-                    self.end_profile_tag(tag, true);
+                    self.end_profile_tag(tag, true, pc);
                 }
                 _ => panic!("Unknown profile mark type: {}", inst_ctx.b),
             }
@@ -660,6 +712,11 @@ impl Stats {
         }
         // Increase the PC histogram entry for this PC
         self.pc_histogram.entry(pc).and_modify(|count| *count += 1).or_insert(1);
+        if self.source_lines.is_some() {
+            let total_cost = self.costs.total_cost();
+            *self.pc_costs.entry(pc).or_insert(0) += total_cost - self.previous_total_cost;
+            self.previous_total_cost = total_cost;
+        }
         self.previous_pc = pc;
         self.previous_verbose = instruction.verbose.clone();
         let is_jmp = instruction.set_pc
@@ -700,44 +757,80 @@ impl Stats {
         }
     }
 
-    fn start_profile_tag(&mut self, tag: &str, use_steps: bool) -> usize {
+    fn start_profile_tag(&mut self, tag: &str, use_steps: bool, pc: u64) -> usize {
         let id = self.profile_tags_map.entry(tag.to_string()).or_insert_with(|| {
             self.profile_tags.push(ProfileStats { tag: tag.to_string(), ..Default::default() });
             self.profile_tags.len() - 1
         });
         if use_steps {
-            self.profile_stack.push((*id, self.costs.steps));
+            self.profile_stack.push((*id, self.costs.steps, pc));
             self.profile_tags[*id].steps_calls += 1;
         } else {
-            self.profile_stack.push((*id, self.costs.total_cost()));
+            self.profile_stack.push((*id, self.costs.total_cost(), pc));
             self.profile_tags[*id].cost_calls += 1;
         }
         *id
     }
-    fn end_profile_tag(&mut self, tag: &str, use_steps: bool) -> u64 {
-        let (rtag, reference) = self.profile_stack.pop().unwrap();
-        if self.profile_tags.len() > rtag {
-            if self.profile_tags[rtag].tag != tag {
-                panic!(
-                    "Profile tag mismatch: expected '{}', got '{}'",
-                    self.profile_tags[rtag].tag, tag
-                );
-            }
-            if use_steps {
-                let delta = self.costs.steps - reference;
-                self.profile_tags[rtag].total_steps += delta;
-                self.profile_tags[rtag].max_steps = self.profile_tags[rtag].max_steps.max(delta);
-                self.profile_tags[rtag].min_steps = self.profile_tags[rtag].min_steps.min(delta);
-                delta
-            } else {
-                let delta = self.costs.total_cost() - reference;
-                self.profile_tags[rtag].total_cost += delta;
-                self.profile_tags[rtag].max_cost = self.profile_tags[rtag].max_cost.max(delta);
-                self.profile_tags[rtag].min_cost = self.profile_tags[rtag].min_cost.min(delta);
-                delta
-            }
+    /// Closes the innermost open region of `tag` and returns its steps or cost. Regions opened
+    /// inside it and still open are recorded as not ended; an end mark without an open region
+    /// is recorded as not started and returns `None`.
+    fn end_profile_tag(&mut self, tag: &str, use_steps: bool, pc: u64) -> Option<u64> {
+        let Some(position) =
+            self.profile_stack.iter().rposition(|(id, _, _)| self.profile_tags[*id].tag == tag)
+        else {
+            self.unbalanced_profile_tags.push(UnbalancedProfileTag {
+                tag: tag.to_string(),
+                pc,
+                kind: UnbalancedProfileTagKind::NotStarted,
+            });
+            return None;
+        };
+        for (id, _, start_pc) in self.profile_stack.drain(position + 1..) {
+            self.unbalanced_profile_tags.push(UnbalancedProfileTag {
+                tag: self.profile_tags[id].tag.clone(),
+                pc: start_pc,
+                kind: UnbalancedProfileTagKind::NotEnded,
+            });
+        }
+
+        let (rtag, reference, _) = self.profile_stack.pop().unwrap();
+        let stats = &mut self.profile_tags[rtag];
+        if use_steps {
+            let delta = self.costs.steps - reference;
+            stats.total_steps += delta;
+            stats.max_steps = stats.max_steps.max(delta);
+            stats.min_steps = stats.min_steps.min(delta);
+            Some(delta)
         } else {
-            panic!("Profile tag index {} not found in profile_tags", rtag);
+            let delta = self.costs.total_cost() - reference;
+            stats.total_cost += delta;
+            stats.max_cost = stats.max_cost.max(delta);
+            stats.min_cost = stats.min_cost.min(delta);
+            Some(delta)
+        }
+    }
+    /// Returns the profile tag marks that break the nesting of the profile tags
+    pub fn unbalanced_profile_tags(&self) -> &[UnbalancedProfileTag] {
+        &self.unbalanced_profile_tags
+    }
+    fn describe_unbalanced_profile_tag(&self, unbalanced: &UnbalancedProfileTag) -> String {
+        let function = self
+            .rois_by_address
+            .range(..=unbalanced.pc as u32)
+            .next_back()
+            .map(|(_, index)| {
+                format!(" in {}", self.format_roi_name(&self.rois[*index as usize].name))
+            })
+            .unwrap_or_default();
+        match unbalanced.kind {
+            UnbalancedProfileTagKind::NotEnded => format!(
+                "'{}' started at 0x{:08x}{function} was never ended",
+                unbalanced.tag, unbalanced.pc
+            ),
+            UnbalancedProfileTagKind::NotStarted => format!(
+                "'{}' ended at 0x{:08x}{function} without a matching start",
+                unbalanced.tag, unbalanced.pc
+            ),
         }
     }
     pub fn set_store_ops(&mut self, store: bool) {
@@ -1007,6 +1100,13 @@ impl Stats {
                 }
                 report.sdk_report_footer();
             }
+            if !self.unbalanced_profile_tags.is_empty() {
+                report.sdk_report_header("UNBALANCED PROFILE TAGS");
+                for unbalanced in self.unbalanced_profile_tags.iter() {
+                    report.sdk_text_line(&self.describe_unbalanced_profile_tag(unbalanced));
+                }
+                report.sdk_report_footer();
+            }
         }
 
         report.output
@@ -1174,6 +1274,12 @@ impl Stats {
             }
         }
 
+        if show_top_functions {
+            if let Some(source_lines) = &self.source_lines {
+                self.report_source_lines(&mut report, source_lines);
+            }
+        }
+
         if show_profile_tags {
             if self.profile_tags.iter().any(|t| t.report_steps) {
                 report.ln();
@@ -1215,6 +1321,13 @@ impl Stats {
                     );
                 }
             }
+            if !self.unbalanced_profile_tags.is_empty() {
+                report.ln();
+                report.title_auto_width("UNBALANCED PROFILE TAGS");
+                for unbalanced in self.unbalanced_profile_tags.iter() {
+                    report.add(&format!("{}\n", self.describe_unbalanced_profile_tag(unbalanced)));
+                }
+            }
         }
 
         if self.top_histogram > 0 {
@@ -1267,6 +1380,60 @@ impl Stats {
 
         report.output
     }
+    /// Reports the steps and cost of the top source lines, and of the lines where the code of
+    /// other functions was inlined
+    fn report_source_lines(&self, report: &mut StatsReport, source_lines: &SourceLines) {
+        // (cost, steps, function) by innermost source line
+        let mut lines: HashMap<&SourceLocation, (u64, u64, Option<&str>)> = HashMap::new();
+        // (cost, steps) by inlined call site
+        let mut call_sites: HashMap<&SourceLocation, (u64, u64)> = HashMap::new();
+        for (pc, steps) in self.pc_histogram.iter() {
+            let cost = self.pc_costs.get(pc).copied().unwrap_or(0);
+            let frames = source_lines.frames(*pc);
+            let Some((innermost, callers)) = frames.split_first() else {
+                continue;
+            };
+            if let Some(location) = &innermost.location {
+                let entry = lines.entry(location).or_insert((0, 0, innermost.function.as_deref()));
+                entry.0 += cost;
+                entry.1 += steps;
+            }
+            let mut seen = HashSet::new();
+            for location in callers.iter().filter_map(|frame| frame.location.as_ref()) {
+                if seen.insert(location) {
+                    let entry = call_sites.entry(location).or_insert((0, 0));
+                    entry.0 += cost;
+                    entry.1 += steps;
+                }
+            }
+        }
+
+        report.title_auto_width("TOP COST SOURCE LINES (COST, % VARIABLE COST, STEPS, LOCATION)");
+        let mut lines: Vec<_> = lines.into_iter().collect();
+        lines.sort_by(|a, b| {
+            b.1 .0.cmp(&a.1 .0).then_with(|| a.0.to_string().cmp(&b.0.to_string()))
+        });
+        for (location, (cost, steps, function)) in lines.iter().take(self.top_rois) {
+            let label = match function {
+                Some(function) => format!("{location} {}", self.format_roi_name(function)),
+                None => location.to_string(),
+            };
+            report.add_top_cost_steps_perc(&label, *cost, *steps);
+        }
+
+        if !call_sites.is_empty() {
+            report.title_auto_width(
+                "TOP COST INLINED CALL SITES (COST, % VARIABLE COST, STEPS, LOCATION)",
+            );
+            let mut call_sites: Vec<_> = call_sites.into_iter().collect();
+            call_sites.sort_by(|a, b| {
+                b.1 .0.cmp(&a.1 .0).then_with(|| a.0.to_string().cmp(&b.0.to_string()))
+            });
+            for (location, (cost, steps)) in call_sites.iter().take(self.top_rois) {
+                report.add_top_cost_steps_perc(&location.to_string(), *cost, *steps);
+            }
+        }
+    }
     pub fn add_roi(&mut self, from_pc: u32, to_pc: u32, name: &str) {
         let roi = RegionsOfInterest::new(self.rois.len(), from_pc, to_pc, name, self.compact_cost);
         let index = self.rois.len() as u32;
@@ -1370,7 +1537,17 @@ impl Stats {
             self.profiler = Some(CallPathProfiler::new());
         }
     }
+    pub fn set_source_lines(&mut self, source_lines: SourceLines) {
+        self.source_lines = Some(source_lines);
+    }
     pub fn on_finish(&mut self, inst_ctx: &InstContext) {
+        for (id, _, start_pc) in self.profile_stack.drain(..) {
+            self.unbalanced_profile_tags.push(UnbalancedProfileTag {
+                tag: self.profile_tags[id].tag.clone(),
+                pc: start_pc,
+                kind: UnbalancedProfileTagKind::NotEnded,
+            });
+        }
         self.ram_monitor.on_finish(inst_ctx);
         let ram_usage = self.ram_monitor.ram_used;
         if let Some(profiler) = &mut self.profiler {
@@ -1392,6 +1569,9 @@ impl Stats {
         if let Some(syms) = symbols {
             disasm_writer.set_symbols(syms);
         }
+        if let Some(source_lines) = &self.source_lines {
+            disasm_writer.set_source_lines(source_lines.clone());
+        }
         disasm_writer.write_header("ZisK Disassembly")?;
         disasm_writer.write_disassembly(rom)?;
         disasm_writer.flush()?;
@@ -1449,3 +1629,78 @@ impl OpStats for Stats {
         self.current_variable_cost = cost;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERIFY_PC: u64 = 0x8000_1000;
+
+    fn stats() -> Stats {
+        let mut stats = Stats::default();
+        stats.add_roi(VERIFY_PC as u32, VERIFY_PC as u32 + 0xfff, "guest::verify");
+        stats
+    }
+
+    #[test]
+    fn test_unbalanced_profile_tags_are_reported() {
+        let mut stats = stats();
+
+        // "inner" is still open when the enclosing "outer" ends
+        stats.start_profile_tag("outer", false, VERIFY_PC);
+        stats.start_profile_tag("inner", true, VERIFY_PC + 0x10);
+        assert!(stats.end_profile_tag("outer", false, VERIFY_PC + 0x20).is_some());
+        // "stray" ends without a start
+        assert_eq!(stats.end_profile_tag("stray", true, VERIFY_PC + 0x30), None);
+        // "open" is still open when the program finishes
+        stats.start_profile_tag("open", false, VERIFY_PC + 0x40);
+        stats.on_finish(&InstContext::default());
+
+        let unbalanced: Vec<_> = stats
+            .unbalanced_profile_tags()
+            .iter()
+            .map(|unbalanced| (unbalanced.tag.as_str(), unbalanced.pc, unbalanced.kind))
+            .collect();
+        assert_eq!(
+            unbalanced,
+            [
+                ("inner", VERIFY_PC + 0x10, UnbalancedProfileTagKind::NotEnded),
+                ("stray", VERIFY_PC + 0x30, UnbalancedProfileTagKind::NotStarted),
+                ("open", VERIFY_PC + 0x40, UnbalancedProfileTagKind::NotEnded),
+            ]
+        );
+
+        let descriptions: Vec<String> = stats
+            .unbalanced_profile_tags()
+            .iter()
+            .map(|unbalanced| stats.describe_unbalanced_profile_tag(unbalanced))
+            .collect();
+        assert_eq!(
+            descriptions,
+            [
+                "'inner' started at 0x80001010 in guest::verify was never ended",
+                "'stray' ended at 0x80001030 in guest::verify without a matching start",
+                "'open' started at 0x80001040 in guest::verify was never ended",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_profile_tags_are_balanced() {
+        let mut stats = stats();
+
+        stats.start_profile_tag("outer", false, VERIFY_PC);
+        stats.start_profile_tag("inner", false, VERIFY_PC + 0x10);
+        stats.costs.steps += 5;
+        assert!(stats.end_profile_tag("inner", false, VERIFY_PC + 0x20).is_some());
+        stats.start_profile_tag("inner", true, VERIFY_PC + 0x10);
+        stats.costs.steps += 3;
+        assert_eq!(stats.end_profile_tag("inner", true, VERIFY_PC + 0x20), Some(3));
+        assert!(stats.end_profile_tag("outer", false, VERIFY_PC + 0x30).is_some());
+        stats.on_finish(&InstContext::default());
+
+        assert!(stats.unbalanced_profile_tags().is_empty());
+        let inner = &stats.profile_tags[stats.profile_tags_map["inner"]];
+        assert_eq!((inner.cost_calls, inner.steps_calls, inner.total_steps), (1, 1, 3));
+    }
+}
@@ -199,6 +199,16 @@ impl StatsReport {
         self.add_top_calls_perc(label, steps, calls, self.step_divisor)
     }
 
+    pub fn add_top_cost_steps_perc(&mut self, label: &str, cost: u64, steps: u64) {
+        self.output += &format!(
+            "{}{:>15} {:6.2}% {:>15} {label}\n",
+            self.identation,
+            self.format_number(cost),
+            cost as f64 / self.cost_divisor,
+            self.format_number(steps)
+        );
+    }
+
     pub fn add_top_step_perc(&mut self, label: &str, cost: u64) {
         self.output += &format!(
             "{}{:>15} {:6.2}% {label}\n",
@@ -477,6 +487,11 @@ impl StatsReport {
         );
     }
 
+    pub fn sdk_text_line(&mut self, text: &str) {
+        let width = self.sdk_width - 6;
+        self.output += &format!("║  {text:<width$}  ║\n");
+    }
+
     /// SDK Report - Header
     pub fn sdk_report_summary_line(&mut self, label: &str, cost: u64) {
         let lw = (self.sdk_width - 6) >> 1;
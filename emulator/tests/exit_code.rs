@@ -0,0 +1,56 @@
+//! Exit codes of a guest program built with the zisk toolchain
+//!
+//! Runs the `panic-guest` of the examples, built with `cargo-zisk build` from `examples/panic`,
+//! or the ELF at `ZISK_PANIC_GUEST_ELF`. The tests are ignored by default, as they need the ELF:
+//! run them with `cargo test -- --ignored`.
+
+use std::path::PathBuf;
+
+use zisk_common::{io::ZiskStdin, EmuTrace};
+use zisk_core::Riscv2zisk;
+use zisk_definitions::EXIT_CODE_OUTPUT_ID;
+use ziskemu::{Emu, EmuOptions};
+
+fn panic_guest_elf() -> Vec<u8> {
+    let candidates = match std::env::var_os("ZISK_PANIC_GUEST_ELF") {
+        Some(path) => vec![PathBuf::from(path)],
+        None => ["release", "debug"]
+            .iter()
+            .map(|profile| {
+                PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                    .join("../examples/target/elf/riscv64ima-zisk-zkvm-elf")
+                    .join(profile)
+                    .join("panic-guest")
+            })
+            .collect(),
+    };
+    candidates
+        .iter()
+        .find_map(|path| std::fs::read(path).ok())
+        .expect("panic-guest is not built, run `cargo-zisk build` in examples/panic")
+}
+
+/// Runs the guest with `input` and returns its exit code and the one committed to the public
+/// outputs, as the guest is built with the `commit-exit-code` feature
+fn exit_code(elf: &[u8], input: u64) -> (Option<i32>, u32) {
+    let rom = Riscv2zisk::new(elf).run().expect("Failed to convert the ELF to a ZisK ROM");
+    let stdin = ZiskStdin::new();
+    stdin.write(&input);
+
+    let mut emu = Emu::new(&rom);
+    emu.run(stdin.read_data(), &EmuOptions::default(), None::<Box<dyn Fn(EmuTrace)>>);
+    assert!(emu.terminated(), "the guest did not finish");
+    (emu.exit_code(), emu.get_output_32()[EXIT_CODE_OUTPUT_ID])
+}
+
+#[test]
+#[ignore = "needs panic-guest built with cargo-zisk build in examples/panic"]
+fn test_process_exit_sets_the_exit_code() {
+    let elf = panic_guest_elf();
+
+    // `std::process::exit(3)` reaches `sys_exit` with the code in its first argument
+    assert_eq!(exit_code(&elf, 3), (Some(3), 3));
+    assert_eq!(exit_code(&elf, 42), (Some(0), 0));
+    // A panic is a failed execution, without exit code
+    assert_eq!(exit_code(&elf, 0).0, None);
+}
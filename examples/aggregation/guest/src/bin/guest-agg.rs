@@ -1,24 +1,35 @@
-// This example program takes a number `n` as input and computes the SHA-256 hash `n` times sequentially.
+// This example program verifies two proofs of the `guest` program and commits the program VK and
+// the Vadcop final VK together with the result of each proof.
 
 // Mark the main function as the entry point for ZisK
 #![no_main]
 ziskos::entrypoint!(main);
 
+use serde::Deserialize;
+use ziskos::recursion::{self, ProgramVk, VadcopVk};
+
+// Public outputs committed by the `guest` program
+#[derive(Deserialize)]
+struct GuestPublics {
+    n: u16,
+    module: u8,
+    b: u32,
+}
+
 fn main() {
+    // Read the VK of the inner program, the VK of its setup and the proofs to verify
+    let program_vk: ProgramVk = ziskos::io::read();
+    let vadcop_vk: VadcopVk = ziskos::io::read();
     let proof1 = ziskos::io::read_input_slice();
     let proof2 = ziskos::io::read_input_slice();
 
-    // Verify the first proof
-    let valid_proof1 =
-        unsafe { ziskos::zisklib::verify_zisk_proof_c(proof1.as_ptr(), proof1.len()) };
-    if !valid_proof1 {
-        panic!("Proof 1 verification failed");
-    }
+    // Verify both proofs against the VKs, panicking if either is not accepted
+    let publics1: GuestPublics = recursion::verify_proof(&proof1, &program_vk, &vadcop_vk);
+    let publics2: GuestPublics = recursion::verify_proof(&proof2, &program_vk, &vadcop_vk);
 
-    // Verify the second proof
-    let valid_proof2 =
-        unsafe { ziskos::zisklib::verify_zisk_proof_c(proof2.as_ptr(), proof2.len()) };
-    if !valid_proof2 {
-        panic!("Proof 2 verification failed");
-    }
+    // Bind this proof to the inner program and setup, and expose the inner results
+    ziskos::io::commit(&program_vk);
+    ziskos::io::commit(&vadcop_vk);
+    ziskos::io::commit(&(publics1.n, publics1.module, publics1.b));
+    ziskos::io::commit(&(publics2.n, publics2.module, publics2.b));
 }
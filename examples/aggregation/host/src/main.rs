@@ -1,6 +1,8 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use zisk_sdk::{load_program, EmbeddedOpts, GuestProgram, ProfilingMode, ProverClient, ZiskStdin};
+use zisk_sdk::{
+    load_program, EmbeddedOpts, GuestProgram, ProfilingMode, ProofBody, ProverClient, ZiskStdin,
+};
 
 #[derive(Serialize, Deserialize)]
 struct GuestPublics {
@@ -77,11 +79,14 @@ async fn main() -> Result<()> {
     println!("Generating second proof for program...");
     let vadcop_result2 = client.prove(&PROGRAM1, stdin2).run()?.await?;
 
-    // Write the proofs, publics, and verification keys to be verified by the guest
+    // Write the program VK, the Vadcop final VK and the proofs to be verified by the guest
+    let program_vk = vadcop_result1.get_program_vk();
     let stdin_aggregation = ZiskStdin::new();
 
-    stdin_aggregation.write_slice(&vadcop_result1.get_proof_bytes()?);
-    stdin_aggregation.write_slice(&vadcop_result2.get_proof_bytes()?);
+    stdin_aggregation.write_program_vk(program_vk)?;
+    stdin_aggregation.write_vadcop_vk(vadcop_result1.get_proof())?;
+    stdin_aggregation.write_proof(vadcop_result1.get_proof())?;
+    stdin_aggregation.write_proof(vadcop_result2.get_proof())?;
 
     println!("Running ZisK Emulator on aggregation program for profiling...");
     zisk_sdk::run(&PROGRAM2, stdin_aggregation.clone(), Some(ProfilingMode::Complete))?;
@@ -90,5 +95,20 @@ async fn main() -> Result<()> {
 
     result_aggregation.verify()?;
 
+    // The aggregation proof is only meaningful for the program and setup VKs it committed
+    let publics = result_aggregation.get_publics();
+    let committed_vk: [u64; 4] = publics.read()?;
+    assert_eq!(committed_vk.as_slice(), program_vk.vk.as_slice(), "unexpected program VK");
+    let committed_vadcop_vk: [u64; 4] = publics.read()?;
+    let ProofBody::Vadcop { zisk_vk, .. } = &vadcop_result1.get_proof().body else {
+        unreachable!("inner proofs are Vadcop final proofs");
+    };
+    assert_eq!(committed_vadcop_vk.as_slice(), zisk_vk.as_slice(), "unexpected Vadcop VK");
+    for _ in 0..2 {
+        let inner: (u16, u8, u32) = publics.read()?;
+        assert_eq!(inner, (n as u16, expected_module, expected_b), "unexpected inner publics");
+    }
+    println!("Aggregation publics OK: program VK {:?}", committed_vk);
+
     Ok(())
 }
@@ -19,12 +19,9 @@ use anyhow::Result;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Notify;
-use zisk_sdk::{load_program, ExecutorKind, GuestProgram, JobEvent, ProverClient, ZiskStream};
-
-/// SDK progress percent emitted when the worker enters the prove phase.
-/// Mirrors `PROGRESS_PROVE` in `sdk/src/job_handle.rs` — the SDK doesn't
-/// expose a phase enum, only `JobEvent::Progress(pct)`.
-const PROGRESS_PROVE: u8 = 75;
+use zisk_sdk::{
+    load_program, ExecutorKind, GuestProgram, JobEvent, JobPhase, ProverClient, ZiskStream,
+};
 
 static PROGRAM: GuestProgram = load_program!("liveness-guest");
 
@@ -162,7 +159,7 @@ async fn run_prove_with_cancel(client: &zisk_sdk::RemoteClient) -> Result<()> {
 }
 
 /// Submit a long-running prove and cancel only after the worker enters the
-/// Prove phase. We listen for `JobEvent::Progress(PROGRESS_PROVE)` instead of
+/// Prove phase. We listen for `JobEvent::phase(JobPhase::Prove)` instead of
 /// timing it: the SDK already pipes phase transitions through this event, so
 /// the cancel lands deterministically inside Prove regardless of how long
 /// Contributions took on the box.
@@ -174,7 +171,7 @@ async fn run_prove_with_cancel_in_prove_phase(client: &zisk_sdk::RemoteClient) -
     let mut handle = client
         .prove(&PROGRAM, input.clone())
         .executor(ExecutorKind::Assembly)
-        .on(JobEvent::Progress(PROGRESS_PROVE), move |_| notify.notify_one())
+        .on(JobEvent::phase(JobPhase::Prove), move |_| notify.notify_one())
         .run()?;
     input.write(&1u64); // mode = long
     input.write(&7u64); // value (seed for the busy-loop)
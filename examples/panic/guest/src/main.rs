@@ -3,6 +3,7 @@
 //   0 -> panic!
 //   1 -> assert! failure
 //   2 -> segfault
+//   3 -> std::process::exit(3)
 //   _ -> normal exit
 
 #![no_main]
@@ -18,6 +19,7 @@ fn main() {
             let ptr = core::ptr::null_mut::<u64>();
             core::ptr::write_volatile(ptr, 0xDEAD);
         },
+        3 => std::process::exit(3),
         _ => println!("No failure triggered with input value: {}", input),
     }
 }
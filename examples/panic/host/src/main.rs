@@ -58,6 +58,16 @@ async fn main() -> Result<()> {
         Err(e) => println!("  err: {e:#}"),
     }
 
+    // Early exit with a code
+    let stdin = ZiskStdin::new();
+    stdin.write(&3u64);
+    println!("Executing with value 3 (exit code 3)...");
+    let outcome: Result<_> = async { client.execute(&PROGRAM, stdin).run()?.await }.await;
+    match outcome {
+        Ok(r) => println!("  ok: exit code {:?}", r.get_exit_code()),
+        Err(e) => println!("  err: {e:#}"),
+    }
+
     // Valid execution again
     let stdin = ZiskStdin::new();
     stdin.write(&99u64);
@@ -4,7 +4,7 @@ use anyhow::Result;
 use asm_runner::{AsmServices, ControlShmem, HintsShmem, InputsShmemWriter};
 #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
 use asm_runner::{MOShMemReader, MTShMemReader, RHShMemReader};
-use precompiles_hints::{HintsProcessor, MpiBroadcastFn};
+use precompiles_hints::{HintHandlers, HintsProcessor, MpiBroadcastFn};
 use zisk_common::io::{StreamSink, StreamSource, ZiskStdin, ZiskStream};
 
 /// Configuration for assembly resources.
@@ -89,6 +89,7 @@ impl AsmSharedResources {
         mpi_broadcast_fn: Option<MpiBroadcastFn>,
         init_rom: bool,
         with_hints: bool,
+        hint_handlers: HintHandlers,
         shm_prefix: &str,
     ) -> Result<Self> {
         #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
@@ -120,7 +121,8 @@ impl AsmSharedResources {
 
             let mut builder =
                 HintsProcessor::builder(hints_shmem, Some(inputs_shmem_writer.clone()))
-                    .enable_stats(verbose_mode != proofman_common::VerboseMode::Info);
+                    .enable_stats(verbose_mode != proofman_common::VerboseMode::Info)
+                    .with_hint_handlers(hint_handlers);
 
             if let Some(broadcast_fn) = mpi_broadcast_fn {
                 builder = builder.with_mpi_broadcast(move |data| broadcast_fn(data));
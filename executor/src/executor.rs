@@ -42,6 +42,7 @@ use zisk_pil::{
     MAIN_AIR_IDS, SPECIFIED_RANGES_AIR_IDS, VIRTUAL_TABLE_0_AIR_IDS, VIRTUAL_TABLE_1_AIR_IDS,
     ZISK_AIRGROUP_ID,
 };
+use ziskemu::{GuestPanic, ZiskEmulator};
 
 use anyhow::Result;
 
@@ -127,6 +128,11 @@ impl<F: PrimeField64> ZiskExecutor<F> {
         (self.state.get_execution_result(), self.state.get_stats())
     }
 
+    /// Takes the guest panic of the last execution, if it failed because of one.
+    pub fn take_guest_panic(&self) -> Option<GuestPanic> {
+        self.rom_executor.take_guest_panic()
+    }
+
     /// Stores statistics to persistent storage.
     pub fn store_stats(&self) {
         self.state.stats.store_stats();
@@ -175,6 +181,9 @@ impl<F: PrimeField64> WitnessComponent<F> for ZiskExecutor<F> {
         let execution_duration = start_partial.elapsed();
         timer_stop_and_log_info!(COMPUTE_MINIMAL_TRACE);
 
+        // Exit code of the guest program, left in a0 at the end of the last chunk
+        let exit_code = ZiskEmulator::exit_code(&zisk_rom, &output.min_traces);
+
         // Phase 2: Plan main instances
         stats_begin!(self.state.stats, &_exec_scope, _main_plan_scope, "MAIN_PLAN", 0);
 
@@ -395,7 +404,7 @@ impl<F: PrimeField64> WitnessComponent<F> for ZiskExecutor<F> {
         };
         // Store the execution result
         let execution_result =
-            ZiskExecutorSummary::new(output.steps, zisk_execution_time, cost_per_type);
+            ZiskExecutorSummary::new(output.steps, zisk_execution_time, cost_per_type, exit_code);
 
         // Store the execution result
         self.state.set_execution_result(execution_result);
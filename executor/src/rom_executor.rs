@@ -5,17 +5,18 @@
 
 use crate::{
     AsmResources, DeviceMetricsList, EmulatorAsm, EmulatorRust, NestedDeviceMetricsList,
-    StaticSMBundle,
+    StaticSMBundle, MAX_NUM_STEPS,
 };
 use arc_swap::ArcSwap;
-use asm_runner::{AsmRunnerMO, AsmRunnerRH};
+use asm_runner::{AsmRunError, AsmRunnerMO, AsmRunnerRH};
 use fields::PrimeField64;
 use proofman_common::ProofCtx;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread::JoinHandle;
 use zisk_common::{io::ZiskStdin, AsmExecutionInfo, EmuTrace, ExecutorStatsHandle, StatsScope};
 use zisk_core::ZiskRom;
+use ziskemu::{EmuOptions, GuestPanic, ZiskEmulator};
 
 use anyhow::Result;
 
@@ -46,6 +47,9 @@ pub struct RomExecutor {
 
     /// Standard input for the ZisK program execution.
     stdin: ArcSwap<ZiskStdin>,
+
+    /// Guest panic of the last failed execution, if any.
+    guest_panic: Mutex<Option<GuestPanic>>,
 }
 
 impl RomExecutor {
@@ -59,6 +63,7 @@ impl RomExecutor {
             emulator_rust: EmulatorRust::new(chunk_size),
             is_asm_execution: AtomicBool::new(false),
             stdin: ArcSwap::from_pointee(ZiskStdin::new()),
+            guest_panic: Mutex::new(None),
         }
     }
 
@@ -120,20 +125,47 @@ impl RomExecutor {
         caller_stats_scope: &StatsScope,
     ) -> Result<RomExecutionOutput> {
         let stdin = self.stdin.load_full();
+        self.take_guest_panic();
+        let result = match self.is_asm_execution.load(Ordering::SeqCst) {
+            true => self.emulator_asm.execute(
+                zisk_rom,
+                &stdin,
+                pctx,
+                sm_bundle,
+                use_hints,
+                stats,
+                caller_stats_scope,
+            ),
+            false => self.emulator_rust.execute(zisk_rom, &stdin, sm_bundle),
+        };
         let (min_traces, main_count, secn_count, handle_mo, handle_rh, steps) =
-            match self.is_asm_execution.load(Ordering::SeqCst) {
-                true => self.emulator_asm.execute(
-                    zisk_rom,
-                    &stdin,
-                    pctx,
-                    sm_bundle,
-                    use_hints,
-                    stats,
-                    caller_stats_scope,
-                )?,
-                false => self.emulator_rust.execute(zisk_rom, &stdin, sm_bundle)?,
-            };
+            result.inspect_err(|e| self.record_guest_panic(e, zisk_rom, &stdin))?;
 
         Ok(RomExecutionOutput { min_traces, main_count, secn_count, handle_mo, handle_rh, steps })
     }
+
+    /// Takes the guest panic of the last failed execution, if it failed because of one.
+    pub fn take_guest_panic(&self) -> Option<GuestPanic> {
+        self.guest_panic.lock().ok()?.take()
+    }
+
+    /// Keeps the guest panic behind a failed execution. The assembly runner only reports an exit
+    /// code, so in that case the execution is replayed with the Rust emulator to capture it.
+    fn record_guest_panic(&self, error: &anyhow::Error, zisk_rom: &ZiskRom, stdin: &ZiskStdin) {
+        let panic = if let Some(panic) = error.chain().find_map(|e| e.downcast_ref::<GuestPanic>())
+        {
+            Some(panic.clone())
+        } else if error
+            .chain()
+            .any(|e| matches!(e.downcast_ref::<AsmRunError>(), Some(AsmRunError::ExitCode(_))))
+        {
+            let options = EmuOptions { max_steps: MAX_NUM_STEPS, ..EmuOptions::default() };
+            ZiskEmulator::capture_guest_panic(zisk_rom, &stdin.read_data(), &options)
+        } else {
+            None
+        };
+        if let Ok(mut guest_panic) = self.guest_panic.lock() {
+            *guest_panic = panic;
+        }
+    }
 }
@@ -1,11 +1,13 @@
 use anyhow::Result;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use zisk_common::{BuiltInHint, HintCode, PrecompileHint};
 use ziskos_hints::handlers::blake2b::blake2b_compress_hint;
 use ziskos_hints::handlers::bls381::{
     bls12_381_fp2_to_g2_hint, bls12_381_fp_to_g1_hint, bls12_381_g1_add_hint,
-    bls12_381_g1_msm_hint, bls12_381_g2_add_hint, bls12_381_g2_msm_hint,
-    bls12_381_pairing_check_hint,
+    bls12_381_g1_msm_hint, bls12_381_g2_add_hint, bls12_381_g2_msm_hint, bls12_381_hash_to_g1_hint,
+    bls12_381_hash_to_g2_hint, bls12_381_pairing_check_hint, bls_aggregate_verify_hint,
+    bls_fast_aggregate_verify_hint, bls_verify_hint,
 };
 use ziskos_hints::handlers::bn254::{
     bn254_g1_add_hint, bn254_g1_mul_hint, bn254_pairing_check_hint,
@@ -14,10 +16,13 @@ use ziskos_hints::handlers::keccak256::keccak256_hint;
 use ziskos_hints::handlers::kzg::verify_kzg_proof_hint;
 use ziskos_hints::handlers::modexp::modexp_hint;
 use ziskos_hints::handlers::ripemd160::ripemd160_hint;
+use ziskos_hints::handlers::rsa::rsa_verify_hint;
 use ziskos_hints::handlers::secp256k1::{secp256k1_ecdsa_verify_hint, secp256k1_ecrecover_hint};
 use ziskos_hints::handlers::secp256r1::secp256r1_ecdsa_verify_hint;
 use ziskos_hints::handlers::sha256::sha256_hint;
 
+use crate::hint_plugin::{HintPlugin, HintPluginConfig};
+
 /// Type alias for custom hint handler functions.
 pub type CustomHintHandler = Box<dyn Fn(&[u64]) -> Result<Vec<u64>> + Send + Sync>;
 
@@ -40,6 +45,33 @@ impl HintHandlers {
         self
     }
 
+    /// Register the plugins in `configs` as custom hint handlers.
+    ///
+    /// Each library is loaded once, even if it serves several hint codes.
+    pub fn with_plugins(mut self, configs: &[HintPluginConfig]) -> Result<Self> {
+        let mut loaded: HashMap<PathBuf, HintPlugin> = HashMap::new();
+        for config in configs {
+            if config.hint_code & 0x8000_0000 != 0 {
+                anyhow::bail!(
+                    "Hint plugin {} registered for invalid hint code {:#x}",
+                    config.path.display(),
+                    config.hint_code
+                );
+            }
+            let plugin = match loaded.get(&config.path) {
+                Some(plugin) => plugin.clone(),
+                None => {
+                    let plugin = HintPlugin::load(&config.path)?;
+                    loaded.insert(config.path.clone(), plugin.clone());
+                    plugin
+                }
+            };
+            let hint_code = config.hint_code;
+            self = self.register(hint_code, move |data| plugin.call(hint_code, data));
+        }
+        Ok(self)
+    }
+
     pub fn has_custom_hint_code(&self, code: u32) -> bool {
         self.custom.contains_key(&code)
     }
@@ -96,6 +128,11 @@ impl HintHandlers {
             BuiltInHint::Bls12_381PairingCheck => bls12_381_pairing_check_hint(&data),
             BuiltInHint::Bls12_381FpToG1 => bls12_381_fp_to_g1_hint(&data),
             BuiltInHint::Bls12_381Fp2ToG2 => bls12_381_fp2_to_g2_hint(&data),
+            BuiltInHint::Bls12_381HashToG1 => bls12_381_hash_to_g1_hint(&data),
+            BuiltInHint::Bls12_381HashToG2 => bls12_381_hash_to_g2_hint(&data),
+            BuiltInHint::BlsVerify => bls_verify_hint(&data),
+            BuiltInHint::BlsAggregateVerify => bls_aggregate_verify_hint(&data),
+            BuiltInHint::BlsFastAggregateVerify => bls_fast_aggregate_verify_hint(&data),
 
             // Modular Exponentiation Hint Codes
             BuiltInHint::ModExp => modexp_hint(&data),
@@ -112,6 +149,9 @@ impl HintHandlers {
             // RIPEMD-160 Hint Codes
             BuiltInHint::Ripemd160 => ripemd160_hint(&data, data_len_bytes),
 
+            // RSA Hint Codes
+            BuiltInHint::RsaVerify => rsa_verify_hint(&data),
+
             // Input Hint Codes
             BuiltInHint::Input => unreachable!(
                 "Input hints should be handled separately and not dispatched to workers"
@@ -0,0 +1,134 @@
+//! Custom hint handlers loaded at runtime from shared libraries.
+//!
+//! A hint plugin is a `cdylib` exporting the following C ABI:
+//!
+//! ```c
+//! // Must return ZISK_HINT_PLUGIN_ABI_VERSION (1).
+//! uint32_t zisk_hint_plugin_abi_version(void);
+//!
+//! // Computes the results of the hint `hint_code` with payload `data[0..data_len]`.
+//! // Returns the number of result words, or a negative value on error. Results are written
+//! // to `out` only when they fit in `out_capacity` words; otherwise the host calls again
+//! // with a buffer of the returned size.
+//! int64_t zisk_hint_plugin_handle(uint32_t hint_code,
+//!                                 const uint64_t *data, uint64_t data_len,
+//!                                 uint64_t *out, uint64_t out_capacity);
+//! ```
+//!
+//! The handler may be called concurrently from several threads and must be deterministic.
+//! Plugins are registered by hint code with [`HintPluginConfig`], so one library can serve
+//! several codes.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use libloading::Library;
+use serde::{Deserialize, Serialize};
+
+/// ABI version implemented by the host.
+pub const ZISK_HINT_PLUGIN_ABI_VERSION: u32 = 1;
+
+const ABI_VERSION_SYMBOL: &[u8] = b"zisk_hint_plugin_abi_version\0";
+const HANDLE_SYMBOL: &[u8] = b"zisk_hint_plugin_handle\0";
+
+/// Initial capacity, in words, of the results buffer handed to the plugin.
+const INITIAL_RESULT_CAPACITY: usize = 64;
+
+type AbiVersionFn = unsafe extern "C" fn() -> u32;
+type HandleFn = unsafe extern "C" fn(u32, *const u64, u64, *mut u64, u64) -> i64;
+
+/// Registers the shared library at `path` as the handler of the custom hint `hint_code`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HintPluginConfig {
+    pub hint_code: u32,
+    pub path: PathBuf,
+}
+
+impl HintPluginConfig {
+    pub fn new(hint_code: u32, path: impl Into<PathBuf>) -> Self {
+        Self { hint_code, path: path.into() }
+    }
+}
+
+/// A loaded hint plugin library.
+#[derive(Clone)]
+pub struct HintPlugin {
+    path: PathBuf,
+    handle: HandleFn,
+    // Keeps the library mapped while `handle` is reachable.
+    _library: Arc<Library>,
+}
+
+impl HintPlugin {
+    /// Load the plugin at `path` and check its ABI version.
+    pub fn load(path: &Path) -> Result<Self> {
+        // SAFETY: loading a library runs its initializers; plugins are trusted host configuration.
+        let library = unsafe { Library::new(path) }
+            .with_context(|| format!("Failed to load hint plugin {}", path.display()))?;
+
+        // SAFETY: the symbol types match the plugin ABI documented above.
+        let (abi_version, handle) = unsafe {
+            let abi_version =
+                library.get::<AbiVersionFn>(ABI_VERSION_SYMBOL).with_context(|| {
+                    format!(
+                        "Hint plugin {} does not export zisk_hint_plugin_abi_version",
+                        path.display()
+                    )
+                })?;
+            let handle = *library.get::<HandleFn>(HANDLE_SYMBOL).with_context(|| {
+                format!("Hint plugin {} does not export zisk_hint_plugin_handle", path.display())
+            })?;
+            (abi_version(), handle)
+        };
+
+        if abi_version != ZISK_HINT_PLUGIN_ABI_VERSION {
+            anyhow::bail!(
+                "Hint plugin {} implements ABI version {abi_version}, expected {ZISK_HINT_PLUGIN_ABI_VERSION}",
+                path.display()
+            );
+        }
+
+        Ok(Self { path: path.to_path_buf(), handle, _library: Arc::new(library) })
+    }
+
+    /// Compute the results of hint `hint_code` with payload `data`.
+    pub fn call(&self, hint_code: u32, data: &[u64]) -> Result<Vec<u64>> {
+        let mut results = vec![0u64; INITIAL_RESULT_CAPACITY];
+        loop {
+            // SAFETY: `data` and `results` are valid for the lengths passed to the plugin.
+            let len = unsafe {
+                (self.handle)(
+                    hint_code,
+                    data.as_ptr(),
+                    data.len() as u64,
+                    results.as_mut_ptr(),
+                    results.len() as u64,
+                )
+            };
+            if len < 0 {
+                anyhow::bail!(
+                    "Hint plugin {} failed on hint {hint_code:#x} with code {len}",
+                    self.path.display()
+                );
+            }
+            let len = len as usize;
+            if len <= results.len() {
+                results.truncate(len);
+                return Ok(results);
+            }
+            results.resize(len, 0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_plugin_fails_to_load() {
+        let err = HintPlugin::load(Path::new("/nonexistent/libzisk_hint_plugin.so")).err().unwrap();
+        assert!(err.to_string().contains("Failed to load hint plugin"));
+    }
+}
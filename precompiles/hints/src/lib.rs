@@ -1,5 +1,7 @@
 mod hint_handlers;
+mod hint_plugin;
 mod hints_processor;
 
 pub use hint_handlers::HintHandlers;
+pub use hint_plugin::{HintPlugin, HintPluginConfig, ZISK_HINT_PLUGIN_ABI_VERSION};
 pub use hints_processor::{HintsProcessor, MpiBroadcastFn};
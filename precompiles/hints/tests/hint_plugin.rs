@@ -0,0 +1,150 @@
+//! Builds a hint plugin library and checks that the hints it serves are dispatched
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use precompiles_hints::{HintHandlers, HintPluginConfig, HintsProcessor};
+use zisk_common::io::StreamSink;
+use zisk_common::{HintCode, PrecompileHint};
+
+const SQUARE_HINT: u32 = 0xA000;
+const REVERSE_HINT: u32 = 0xA001;
+const FAILING_HINT: u32 = 0xA002;
+
+/// Plugin serving three hint codes: the squares of the payload words, the payload reversed
+/// (more words than the initial results buffer for long payloads) and an error
+const PLUGIN_SOURCE: &str = r#"
+#[no_mangle]
+pub extern "C" fn zisk_hint_plugin_abi_version() -> u32 {
+    1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn zisk_hint_plugin_handle(
+    hint_code: u32,
+    data: *const u64,
+    data_len: u64,
+    out: *mut u64,
+    out_capacity: u64,
+) -> i64 {
+    let data = std::slice::from_raw_parts(data, data_len as usize);
+    let results: Vec<u64> = match hint_code {
+        0xA000 => data.iter().map(|w| w.wrapping_mul(*w)).collect(),
+        0xA001 => data.iter().rev().copied().collect(),
+        _ => return -1,
+    };
+    if results.len() as u64 <= out_capacity {
+        std::ptr::copy_nonoverlapping(results.as_ptr(), out, results.len());
+    }
+    results.len() as i64
+}
+"#;
+
+/// Compiles [`PLUGIN_SOURCE`] as a `cdylib` in `dir` and returns the library path
+fn build_plugin(dir: &Path) -> PathBuf {
+    std::fs::create_dir_all(dir).unwrap();
+    let source = dir.join("zisk_test_hint_plugin.rs");
+    std::fs::write(&source, PLUGIN_SOURCE).unwrap();
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let status = Command::new(rustc)
+        .args(["--edition", "2021", "--crate-type", "cdylib", "-C", "opt-level=1"])
+        .arg("--crate-name")
+        .arg("zisk_test_hint_plugin")
+        .arg("--out-dir")
+        .arg(dir)
+        .arg(&source)
+        .status()
+        .expect("Failed to run rustc");
+    assert!(status.success(), "Failed to build the test hint plugin");
+
+    let library = format!(
+        "{}zisk_test_hint_plugin{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_SUFFIX
+    );
+    dir.join(library)
+}
+
+fn plugin_handlers(name: &str) -> HintHandlers {
+    let path = build_plugin(&Path::new(env!("CARGO_TARGET_TMPDIR")).join(name));
+    HintHandlers::default()
+        .with_plugins(&[
+            HintPluginConfig::new(SQUARE_HINT, &path),
+            HintPluginConfig::new(REVERSE_HINT, &path),
+            HintPluginConfig::new(FAILING_HINT, &path),
+        ])
+        .unwrap()
+}
+
+fn custom_hint(code: u32, data: Vec<u64>) -> PrecompileHint {
+    PrecompileHint {
+        hint_code: HintCode::Custom(code),
+        is_passthrough: false,
+        data_len_bytes: data.len() * 8,
+        data,
+    }
+}
+
+#[test]
+fn test_plugin_hints_are_dispatched() {
+    let handlers = plugin_handlers("dispatch");
+    for code in [SQUARE_HINT, REVERSE_HINT, FAILING_HINT] {
+        assert!(handlers.has_custom_hint_code(code));
+    }
+    assert!(!handlers.has_custom_hint_code(0xA003));
+
+    assert_eq!(handlers.dispatch(custom_hint(SQUARE_HINT, vec![3, 5, 7])).unwrap(), [9, 25, 49]);
+
+    // Results larger than the initial buffer are fetched with a second call
+    let long: Vec<u64> = (0..200).collect();
+    let reversed: Vec<u64> = (0..200).rev().collect();
+    assert_eq!(handlers.dispatch(custom_hint(REVERSE_HINT, long)).unwrap(), reversed);
+
+    let err = handlers.dispatch(custom_hint(FAILING_HINT, vec![1])).unwrap_err();
+    assert!(err.to_string().contains("failed on hint 0xa002 with code -1"), "{err}");
+
+    assert!(handlers.dispatch(custom_hint(0xA003, vec![1])).is_err());
+}
+
+struct RecordingSink {
+    received: Arc<Mutex<Vec<Vec<u64>>>>,
+}
+
+impl StreamSink for RecordingSink {
+    fn submit(&self, processed: &[u64]) -> Result<()> {
+        self.received.lock().unwrap().push(processed.to_vec());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_processor_dispatches_plugin_hints() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let sink = RecordingSink { received: Arc::clone(&received) };
+    let processor = HintsProcessor::builder(Arc::new(sink), None::<Arc<RecordingSink>>)
+        .num_threads(4)
+        .with_hint_handlers(plugin_handlers("processor"))
+        .build()
+        .unwrap();
+
+    let header = |code: u32, words: u64| ((code as u64) << 32) | (words * 8);
+    let stream = [
+        header(SQUARE_HINT, 2),
+        4,
+        6,
+        header(REVERSE_HINT, 3),
+        1,
+        2,
+        3,
+        header(SQUARE_HINT, 1),
+        10,
+    ];
+    processor.process_hints(&stream, false).unwrap();
+    processor.wait_for_completion().unwrap();
+
+    let results = received.lock().unwrap();
+    assert_eq!(*results, [vec![16, 36], vec![3, 2, 1], vec![100]]);
+}
@@ -152,7 +152,7 @@ impl ProverClientBuilder<EmuB> {
         }
 
         let emu = EmuProver::new(
-            self.prover_options.plonk,
+            self.prover_options.snark.is_some(),
             self.prover_options.preload_plonk,
             proving_key,
             proving_key_snark,
@@ -223,7 +223,7 @@ impl ProverClientBuilder<AsmB> {
         }
 
         let asm = AsmProver::new(
-            self.prover_options.plonk,
+            self.prover_options.snark.is_some(),
             self.prover_options.preload_plonk,
             proving_key,
             proving_key_snark,
@@ -233,6 +233,7 @@ impl ProverClientBuilder<AsmB> {
             self.prover_options.asm_options.no_auto_setup,
             options,
             self.prover_options.asm_options.is_distributed,
+            self.prover_options.asm_options.hint_plugins.clone(),
             self.logging_config,
         )?;
 
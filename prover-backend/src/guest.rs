@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use rom_setup::rom_merkle_setup_verkey;
 use std::borrow::Cow;
 use std::fs;
@@ -6,7 +6,9 @@ use std::path::Path;
 use zisk_common::io::ZiskStdin;
 use zisk_common::ProgramVK;
 use zisk_core::Riscv2zisk;
-use ziskemu::ZiskEmulator;
+use ziskemu::{ZiskEmulator, ZiskEmulatorErr};
+
+use crate::symbolize_guest_panic;
 pub use ziskemu::{EmuOptions, ProfilingMode};
 
 /// Program identifier containing name and hash
@@ -126,8 +128,17 @@ impl GuestProgram {
         &self.program_id.hash_id
     }
 
+    /// Get the program verification key stored by the ROM setup of the program.
+    ///
+    /// It is an error if the setup has not been done. To compute the VK without the setup, call
+    /// `program_vk_from_elf` explicitly.
     pub fn vk(&self) -> Result<ProgramVK> {
-        rom_merkle_setup_verkey(self.elf(), &None)
+        rom_merkle_setup_verkey(self.elf(), &None).with_context(|| {
+            format!(
+                "No ROM setup for program '{}', run `cargo-zisk program-setup` first",
+                self.name()
+            )
+        })
     }
 
     /// Run the ZisK emulator with the given stdin.
@@ -176,6 +187,10 @@ impl GuestProgram {
                 }
                 Ok(())
             }
+            Err(ZiskEmulatorErr::GuestPanic(mut panic)) => {
+                symbolize_guest_panic(&mut panic, &self.elf);
+                Err(anyhow::Error::new(*panic))
+            }
             Err(e) => {
                 eprintln!("Error during emulation: {e:?}");
                 Err(anyhow::anyhow!("Emulation failed"))
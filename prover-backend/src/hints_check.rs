@@ -0,0 +1,287 @@
+//! Consistency check between a precompile hints file and the guest execution.
+//!
+//! A hints file is produced by running the guest natively, so it silently goes stale when the
+//! guest, its inputs or the hints generation drift apart from what the RISC-V guest really does.
+//! The prover then fails deep inside the ASM executor with little to go on. This check runs the
+//! emulator, records the result of every precompiled operation and fcall the guest executes, and
+//! walks the hints file in parallel, computing each hint's results with the native handlers. The
+//! first hint whose results differ from what the emulator recorded is reported.
+
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+pub use precompiles_hints::HintHandlers;
+use zisk_common::{BuiltInHint, HintCode, PrecompileHint, PrecompileHintParseResult};
+use zisk_core::Riscv2zisk;
+use ziskemu::{Emu, EmuOptions, PrecompileRecord};
+
+use crate::GuestProgram;
+
+/// First point where the hints file and the guest execution diverge
+#[derive(Debug)]
+pub struct HintMismatch {
+    /// Position of the hint in the hints file, counting every hint including control and input
+    /// hints. Equal to the number of hints in the file when the guest requested more results
+    /// than the file provides.
+    pub index: usize,
+    /// Code of the mismatched hint, `None` if the file ran out of hints
+    pub hint_code: Option<HintCode>,
+    /// Payload of the mismatched hint
+    pub payload: Vec<u64>,
+    /// Offset, in words, of the first differing word within the hint results
+    pub offset: usize,
+    /// Word recorded by the emulator, `None` if the guest made no further requests
+    pub expected: Option<u64>,
+    /// Word computed from the hint, `None` if the hints file ran out of hints
+    pub found: Option<u64>,
+    /// Guest instruction that consumes the expected word
+    pub precompile: Option<PrecompileRecord>,
+}
+
+/// Outcome of [`GuestProgram::check_hints`]
+#[derive(Debug)]
+pub struct HintsCheckReport {
+    /// Number of hints read from the hints file
+    pub hints: usize,
+    /// Number of precompiled operations and fcalls executed by the guest
+    pub precompiles: usize,
+    /// First divergence, `None` if the hints file matches the execution
+    pub mismatch: Option<HintMismatch>,
+}
+
+impl HintsCheckReport {
+    pub fn is_consistent(&self) -> bool {
+        self.mismatch.is_none()
+    }
+}
+
+impl fmt::Display for HintMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.hint_code {
+            Some(code) => {
+                writeln!(f, "hint #{} ({code}) diverges at word {}", self.index, self.offset)?
+            }
+            None => writeln!(
+                f,
+                "hints file ends after {} hints but the guest expects more",
+                self.index
+            )?,
+        }
+        if self.hint_code.is_some() {
+            writeln!(f, "  payload:  {}", words_hex(&self.payload))?;
+        }
+        writeln!(f, "  expected: {}", opt_word_hex(self.expected))?;
+        write!(f, "  found:    {}", opt_word_hex(self.found))?;
+        if let Some(record) = &self.precompile {
+            write!(
+                f,
+                "\n  consumed by {} at step={} pc=0x{:x}",
+                record.op_name(),
+                record.step,
+                record.pc
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn words_hex(words: &[u64]) -> String {
+    let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn opt_word_hex(word: Option<u64>) -> String {
+    word.map(|w| format!("{w:#018x}")).unwrap_or_else(|| "<none>".to_owned())
+}
+
+impl GuestProgram {
+    /// Run the guest in the emulator, feeding it the input hints of the hints file at
+    /// `hints_path`, and check that the file provides exactly the precompile results the
+    /// execution consumes.
+    pub fn check_hints(
+        &self,
+        hints_path: &Path,
+        handlers: &HintHandlers,
+    ) -> Result<HintsCheckReport> {
+        let bytes = std::fs::read(hints_path)
+            .with_context(|| format!("Failed to read hints file {}", hints_path.display()))?;
+        if bytes.len() % 8 != 0 {
+            anyhow::bail!(
+                "Hints file {} size {} is not a multiple of 8 bytes",
+                hints_path.display(),
+                bytes.len()
+            );
+        }
+        let words: Vec<u64> = bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        let hints = parse_hints(&words)?;
+
+        // Input hints are appended verbatim to the guest input, as the ASM executor does
+        let inputs: Vec<u8> = hints
+            .iter()
+            .filter(|hint| hint.hint_code == HintCode::BuiltIn(BuiltInHint::Input))
+            .flat_map(|hint| hint.data.iter().flat_map(|w| w.to_le_bytes()))
+            .collect();
+
+        let zisk_rom = Riscv2zisk::new(self.elf())
+            .run()
+            .map_err(|e| anyhow::anyhow!("Failed to convert ELF to ZISK ROM: {e:?}"))?;
+        let mut emu = Emu::new(&zisk_rom);
+        let records = emu.run_precompile_log(inputs, &EmuOptions::default());
+        if !emu.terminated() {
+            anyhow::bail!("Emulation did not complete after {} steps", emu.number_of_steps());
+        }
+
+        compare_hints(hints, &records, handlers)
+    }
+}
+
+/// Split a hints stream into its hints
+fn parse_hints(words: &[u64]) -> Result<Vec<PrecompileHint>> {
+    let mut hints = Vec::new();
+    let mut idx = 0;
+    while idx < words.len() {
+        let index = hints.len();
+        let (parsed, consumed) = PrecompileHint::from_u64_slice(words, idx, true, None)
+            .with_context(|| format!("Failed to parse hint #{index} at word {idx}"))?;
+        match parsed {
+            PrecompileHintParseResult::Complete(hint) => hints.push(hint),
+            PrecompileHintParseResult::Partial(partial) => anyhow::bail!(
+                "Hint #{index} ({}) is truncated, {} words missing",
+                partial.hint_code,
+                partial.remaining_u64s
+            ),
+        }
+        idx += consumed;
+    }
+    Ok(hints)
+}
+
+/// Walk `hints` against the emulator `records` and report the first divergence
+fn compare_hints(
+    hints: Vec<PrecompileHint>,
+    records: &[PrecompileRecord],
+    handlers: &HintHandlers,
+) -> Result<HintsCheckReport> {
+    // Flatten the recorded results, remembering which record each word belongs to
+    let expected: Vec<(u64, usize)> = records
+        .iter()
+        .enumerate()
+        .flat_map(|(i, record)| record.words.iter().map(move |w| (*w, i)))
+        .collect();
+
+    let num_hints = hints.len();
+    let mut cursor = 0;
+    for (index, hint) in hints.into_iter().enumerate() {
+        let hint_code = hint.hint_code;
+        if matches!(hint_code, HintCode::Ctrl(_) | HintCode::BuiltIn(BuiltInHint::Input)) {
+            continue;
+        }
+
+        let payload = hint.data.clone();
+        let results = if hint.is_passthrough {
+            hint.data
+        } else {
+            handlers
+                .dispatch(hint)
+                .with_context(|| format!("Failed to compute hint #{index} ({hint_code})"))?
+        };
+
+        for (offset, &found) in results.iter().enumerate() {
+            let expected_word = expected.get(cursor + offset);
+            if expected_word.map(|(w, _)| *w) != Some(found) {
+                return Ok(HintsCheckReport {
+                    hints: num_hints,
+                    precompiles: records.len(),
+                    mismatch: Some(HintMismatch {
+                        index,
+                        hint_code: Some(hint_code),
+                        payload,
+                        offset,
+                        expected: expected_word.map(|(w, _)| *w),
+                        found: Some(found),
+                        precompile: expected_word.map(|(_, i)| records[*i].clone()),
+                    }),
+                });
+            }
+        }
+        cursor += results.len();
+    }
+
+    let mismatch = expected.get(cursor).map(|(w, i)| HintMismatch {
+        index: num_hints,
+        hint_code: None,
+        payload: Vec::new(),
+        offset: 0,
+        expected: Some(*w),
+        found: None,
+        precompile: Some(records[*i].clone()),
+    });
+
+    Ok(HintsCheckReport { hints: num_hints, precompiles: records.len(), mismatch })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSTHROUGH: u64 = 1 << 63;
+
+    fn passthrough_hint(code: u64, data: &[u64]) -> Vec<u64> {
+        let mut words = vec![PASSTHROUGH | (code << 32) | (data.len() as u64 * 8)];
+        words.extend_from_slice(data);
+        words
+    }
+
+    fn record(words: &[u64]) -> PrecompileRecord {
+        PrecompileRecord { step: 7, pc: 0x8000_0000, op: 0xf7, words: words.to_vec() }
+    }
+
+    #[test]
+    fn matching_stream_is_consistent() {
+        let mut words = passthrough_hint(0x100, &[1, 2, 3, 4]);
+        words.extend(passthrough_hint(0x100, &[5, 6]));
+        let records = [record(&[1, 2, 3]), record(&[4, 5, 6])];
+
+        let report =
+            compare_hints(parse_hints(&words).unwrap(), &records, &HintHandlers::default())
+                .unwrap();
+        assert!(report.is_consistent());
+        assert_eq!(report.hints, 2);
+    }
+
+    #[test]
+    fn reports_first_divergent_hint() {
+        let mut words = passthrough_hint(0x100, &[1, 2]);
+        words.extend(passthrough_hint(0x700, &[3, 9]));
+        let records = [record(&[1, 2, 3, 4])];
+
+        let mismatch =
+            compare_hints(parse_hints(&words).unwrap(), &records, &HintHandlers::default())
+                .unwrap()
+                .mismatch
+                .unwrap();
+        assert_eq!(mismatch.index, 1);
+        assert_eq!(mismatch.hint_code, Some(HintCode::BuiltIn(BuiltInHint::Keccak256)));
+        assert_eq!(mismatch.offset, 1);
+        assert_eq!((mismatch.expected, mismatch.found), (Some(4), Some(9)));
+    }
+
+    #[test]
+    fn reports_missing_hints() {
+        let words = passthrough_hint(0x100, &[1]);
+        let records = [record(&[1, 2])];
+
+        let mismatch =
+            compare_hints(parse_hints(&words).unwrap(), &records, &HintHandlers::default())
+                .unwrap()
+                .mismatch
+                .unwrap();
+        assert_eq!(mismatch.index, 1);
+        assert_eq!(mismatch.hint_code, None);
+        assert_eq!((mismatch.expected, mismatch.found), (Some(2), None));
+    }
+}
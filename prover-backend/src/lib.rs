@@ -1,14 +1,18 @@
 mod builder;
 mod guest;
+mod hints_check;
 mod output;
 mod prover;
 mod utils;
 
 pub use executor::get_packed_info;
+pub use precompiles_hints::HintPluginConfig;
 pub use proofman_common::VerboseMode;
+pub use ziskemu::{BacktraceFrame, GuestPanic, PanicLocation};
 
 pub use builder::*;
 pub use guest::*;
+pub use hints_check::*;
 pub use output::*;
 pub use prover::*;
 pub use utils::*;
@@ -7,12 +7,14 @@ use zisk_common::{
 };
 
 pub use zisk_common::Proof;
+use ziskemu::GuestPanic;
 
 /// Shared execution statistics captured after any run (execute, prove, verify_constraints).
 pub(crate) struct ExecutionSummary {
     time: u64,
     steps: u64,
     cost: u64,
+    cost_per_type: StatsCostPerType,
 }
 
 impl ExecutionSummary {
@@ -21,11 +23,17 @@ impl ExecutionSummary {
             time: time.as_millis() as u64,
             steps: executor_summary.steps,
             cost: executor_summary.cost_per_type.total_cost(),
+            cost_per_type: executor_summary.cost_per_type.clone(),
         }
     }
 
     pub fn from_remote(time: Duration, steps: u64, cost_per_type: &StatsCostPerType) -> Self {
-        Self { time: time.as_millis() as u64, steps, cost: cost_per_type.total_cost() }
+        Self {
+            time: time.as_millis() as u64,
+            steps,
+            cost: cost_per_type.total_cost(),
+            cost_per_type: cost_per_type.clone(),
+        }
     }
 }
 
@@ -60,6 +68,8 @@ macro_rules! impl_public_outputs {
 pub struct ExecuteOutput {
     summary: ExecutionSummary,
     publics: PublicValues,
+    exit_code: Option<i32>,
+    panic: Option<GuestPanic>,
 }
 
 impl ExecuteOutput {
@@ -71,6 +81,23 @@ impl ExecuteOutput {
         Self {
             summary: ExecutionSummary::new(execution_time, &executor_summary),
             publics: PublicValues::new(publics),
+            exit_code: executor_summary.exit_code,
+            panic: None,
+        }
+    }
+
+    /// Construct the result of an execution that ended with a guest panic. The steps are the
+    /// ones executed up to the panic; the cost is not measured and there are no publics.
+    pub fn from_panic(execution_time: Duration, panic: GuestPanic) -> Self {
+        Self {
+            summary: ExecutionSummary::from_remote(
+                execution_time,
+                panic.step,
+                &StatsCostPerType::default(),
+            ),
+            publics: PublicValues::new(&[]),
+            exit_code: None,
+            panic: Some(panic),
         }
     }
 
@@ -82,20 +109,43 @@ impl ExecuteOutput {
         self.summary.cost
     }
 
+    pub fn get_execution_cost_per_type(&self) -> &StatsCostPerType {
+        &self.summary.cost_per_type
+    }
+
     pub fn get_execution_time(&self) -> u64 {
         self.summary.time
     }
 
+    /// Exit code of the guest program: the value returned by its entry point or passed to
+    /// `ziskos::exit`. `None` if it is unknown, e.g. for workers that do not report it.
+    pub fn get_exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Panic that ended the guest program, `None` if it did not panic or if it is unknown, e.g.
+    /// for workers that do not report it.
+    pub fn get_panic(&self) -> Option<&GuestPanic> {
+        self.panic.as_ref()
+    }
+
+    pub(crate) fn panic_mut(&mut self) -> Option<&mut GuestPanic> {
+        self.panic.as_mut()
+    }
+
     /// Construct a result from a remote coordinator response.
     pub fn from_remote(
         steps: u64,
         execution_time: Duration,
         cost_per_type: StatsCostPerType,
         publics: &[u8],
+        exit_code: Option<i32>,
     ) -> Self {
         Self {
             summary: ExecutionSummary::from_remote(execution_time, steps, &cost_per_type),
             publics: PublicValues::new(publics),
+            exit_code,
+            panic: None,
         }
     }
 }
@@ -138,6 +188,10 @@ impl ProveOutput {
         self.summary.cost
     }
 
+    pub fn get_execution_cost_per_type(&self) -> &StatsCostPerType {
+        &self.summary.cost_per_type
+    }
+
     pub fn get_proof(&self) -> &Proof {
         &self.proof
     }
@@ -213,3 +267,44 @@ impl VerifyConstraintsOutput {
 }
 
 impl_public_outputs!(VerifyConstraintsOutput, publics);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ziskemu::{BacktraceFrame, PanicLocation};
+
+    fn guest_panic() -> GuestPanic {
+        GuestPanic {
+            message: "index out of bounds".to_string(),
+            location: Some(PanicLocation { file: "src/main.rs".to_string(), line: 7, column: 5 }),
+            backtrace: vec![BacktraceFrame { pc: 0x8000_1000, function: None, location: None }],
+            step: 1234,
+        }
+    }
+
+    #[test]
+    fn test_execute_output_from_panic() {
+        let output = ExecuteOutput::from_panic(Duration::from_millis(20), guest_panic());
+
+        assert_eq!(output.get_panic(), Some(&guest_panic()));
+        assert_eq!(output.get_execution_steps(), 1234);
+        assert_eq!(output.get_execution_cost(), 0);
+        assert_eq!(output.get_execution_time(), 20);
+        assert_eq!(output.get_exit_code(), None);
+        assert!(output.get_publics().public_u64().iter().all(|word| *word == 0));
+    }
+
+    #[test]
+    fn test_execute_output_without_panic() {
+        let output = ExecuteOutput::from_remote(
+            10,
+            Duration::from_millis(5),
+            StatsCostPerType::default(),
+            &[],
+            Some(0),
+        );
+
+        assert_eq!(output.get_panic(), None);
+        assert_eq!(output.get_exit_code(), Some(0));
+    }
+}
@@ -2,12 +2,12 @@ use crate::{
     check_paths_exist, ensure_program_vk, get_asm_paths, get_rom_bin_path,
     guest::ProgramId,
     prover::{ProverBackend, ProverEngine, ZiskBackend},
-    BackendProverOpts, ExecuteOutput, GuestProgram, ProveOutput, VerifyConstraintsOutput,
-    ZiskAggPhaseResult, ZiskPhaseResult,
+    symbolize_guest_panic, BackendProverOpts, ExecuteOutput, GuestProgram, ProveOutput,
+    VerifyConstraintsOutput, ZiskAggPhaseResult, ZiskPhaseResult,
 };
 use asm_runner::{AsmRunnerOptions, AsmServices, HintsShmem};
 use executor::{initialize_executor, AsmResources, AsmSharedResources};
-use precompiles_hints::HintsProcessor;
+use precompiles_hints::{HintHandlers, HintPluginConfig, HintsProcessor};
 use proofman::{
     AggProofs, AggProofsRegister, ProofMan, ProvePhase, ProvePhaseInputs, SnarkWrapper, WitnessInfo,
 };
@@ -25,7 +25,8 @@ use std::sync::{
 use zisk_cluster_common::LoggingConfig;
 use zisk_common::{
     io::{StreamSource, ZiskStdin},
-    ExecutorStatsHandle, ProgramVK, ProofKind, PublicValues, SetupKey, ZiskExecutorTime, ZiskPaths,
+    ExecutorStatsHandle, ProgramVK, ProofKind, PublicValues, SetupKey, ZiskExecutorSummary,
+    ZiskExecutorTime, ZiskPaths,
 };
 use zisk_core::{Riscv2zisk, ZiskRom};
 
@@ -89,6 +90,7 @@ impl AsmProver {
         no_auto_setup: bool,
         options: ProofmanOptions,
         is_distributed: bool,
+        hint_plugins: Vec<HintPluginConfig>,
         logging_config: Option<LoggingConfig>,
     ) -> Result<Self> {
         AsmServices::cleanup_stale_shmem();
@@ -103,6 +105,7 @@ impl AsmProver {
             no_auto_setup,
             options,
             is_distributed,
+            hint_plugins,
             logging_config,
         )?;
         Ok(Self {
@@ -184,6 +187,12 @@ impl AsmProver {
             asm_runner_options,
         )?;
 
+        let hint_handlers = if with_hints {
+            HintHandlers::default().with_plugins(&self.core_prover.asm_info.hint_plugins)?
+        } else {
+            HintHandlers::default()
+        };
+
         let shared = Arc::new(AsmSharedResources::new(
             world_rank,
             local_rank,
@@ -192,6 +201,7 @@ impl AsmProver {
             mpi_broadcast_fn,
             init_rom,
             with_hints,
+            hint_handlers,
             asm_services.shm_prefix(),
         )?);
         timer_stop_and_log_info!(STARTING_ASM_MICROSERVICES);
@@ -339,10 +349,18 @@ impl ProverEngine for AsmProver {
         self.core_prover.backend.get_execution_info()
     }
 
+    fn get_execution_summary(&self) -> Result<ZiskExecutorSummary> {
+        self.core_prover.backend.execution_result().map(|(summary, _)| summary)
+    }
+
     fn execute(&self, program: &GuestProgram, stdin: ZiskStdin) -> Result<ExecuteOutput> {
         let with_hints = self.current_with_hints.load(Ordering::SeqCst);
         self.register_program(&program.program_id, with_hints)?;
-        self.core_prover.backend.execute(stdin)
+        let mut output = self.core_prover.backend.execute(stdin)?;
+        if let Some(panic) = output.panic_mut() {
+            symbolize_guest_panic(panic, &program.elf);
+        }
+        Ok(output)
     }
 
     fn stats(
@@ -414,7 +432,9 @@ impl ProverEngine for AsmProver {
     ) -> Result<ProveOutput> {
         match proof_kind {
             ProofKind::VadcopFinalMinimal => self.core_prover.backend.minimal(proof, publics, vk),
-            ProofKind::Plonk => self.core_prover.backend.plonk(proof, publics, vk),
+            ProofKind::Plonk | ProofKind::Groth16 => {
+                self.core_prover.backend.snark(proof, publics, vk, proof_kind)
+            }
             _ => Err(anyhow::anyhow!("Unsupported proof mode for wrap: {:?}", proof_kind)),
         }
     }
@@ -521,6 +541,7 @@ pub struct AsmInfo {
     pub asm_out_file: bool,
     pub verbose: VerboseMode,
     pub no_auto_setup: bool,
+    pub hint_plugins: Vec<HintPluginConfig>,
     pub n_setups: AtomicU64,
 }
 
@@ -543,6 +564,7 @@ impl AsmCoreProver {
         no_auto_setup: bool,
         options: ProofmanOptions,
         is_distributed: bool,
+        hint_plugins: Vec<HintPluginConfig>,
         logging_config: Option<LoggingConfig>,
     ) -> Result<Self> {
         check_paths_exist(&proving_key)?;
@@ -596,6 +618,7 @@ impl AsmCoreProver {
                 asm_out_file,
                 verbose: options.verbose_mode,
                 no_auto_setup,
+                hint_plugins,
                 n_setups: AtomicU64::new(0),
             },
         })
@@ -24,7 +24,7 @@ use zisk_common::io::StreamSource;
 use zisk_common::stats_mark;
 use zisk_common::ZiskExecutorTime;
 use zisk_common::{io::ZiskStdin, ExecutorStatsHandle, ZiskExecutorSummary};
-use zisk_common::{PlonkVkBlob, PlonkVkey, ProgramVK, Proof, ProofBody, ProofKind, PublicValues};
+use zisk_common::{ProgramVK, Proof, ProofBody, ProofKind, PublicValues, SnarkVkey};
 
 pub(crate) struct ProverBackend {
     proofman: ProofMan<Goldilocks>,
@@ -178,9 +178,13 @@ impl ProverBackend {
 
         let start = std::time::Instant::now();
 
-        self.proofman
-            .execute_from_lib(None)
-            .map_err(|e| anyhow::anyhow!("Error generating execution: {}", e))?;
+        if let Err(e) = self.proofman.execute_from_lib(None) {
+            // A guest panic is a result of the execution, not an error of the executor
+            return match self.executor.take_guest_panic() {
+                Some(panic) => Ok(ExecuteOutput::from_panic(start.elapsed(), panic)),
+                None => Err(anyhow::anyhow!("Error generating execution: {}", e)),
+            };
+        }
 
         let elapsed = start.elapsed();
 
@@ -301,7 +305,7 @@ impl ProverBackend {
         proof_kind: ProofKind,
         prover_options: BackendProverOpts,
     ) -> Result<ProveOutput> {
-        if proof_kind == ProofKind::Plonk && self.snark_wrapper.is_none() {
+        if proof_kind.is_snark() && self.snark_wrapper.is_none() {
             return Err(anyhow::anyhow!(
                 "Snark wrapper is not initialized. Cannot generate snark proof."
             ));
@@ -350,48 +354,18 @@ impl ProverBackend {
         let vadcop_vk_u64 = self.get_vadcop_vk(minimal)?;
 
         match (proof_kind, proof) {
-            (ProofKind::Plonk, Some(vadcop_proof)) => {
-                let snark_proof = self
-                    .snark_wrapper
-                    .as_ref()
-                    .unwrap()
-                    .generate_final_snark_proof(&vadcop_proof)?;
-
-                let publics = PublicValues::new_from_u64(&vadcop_proof.public_values);
-                let program_vk = ProgramVK::new_from_publics(&vadcop_proof.public_values);
-                if snark_proof.protocol_id == SnarkProtocol::Plonk.protocol_id() {
-                    let proving_key_snark =
-                        self.proving_key_snark_path.as_ref().ok_or_else(|| {
-                            anyhow::anyhow!("Proving key snark path is required for Plonk proofs")
-                        })?;
-                    let verkey_path = PathBuf::from(format!(
-                        "{}/{}/{}.verkey.json",
-                        proving_key_snark.display(),
-                        "final",
-                        "final"
-                    ));
-                    let plonk_vkey = PlonkVkey::load(&verkey_path)?;
-                    Ok(ProveOutput::new(
-                        execution_result,
-                        start.elapsed(),
-                        Proof {
-                            body: ProofBody::Plonk {
-                                proof_bytes: snark_proof.proof_bytes,
-                                plonk_vk: Box::new(PlonkVkBlob {
-                                    vadcop_vk: vadcop_vk_u64,
-                                    plonk_vkey,
-                                }),
-                            },
-                            publics,
-                            program_vk,
-                        },
-                    ))
-                } else {
-                    Err(anyhow::anyhow!(
-                        "Unsupported snark protocol id: {}",
-                        snark_proof.protocol_id
-                    ))
-                }
+            (ProofKind::Plonk | ProofKind::Groth16, Some(vadcop_proof)) => {
+                let body = self.snark_proof_body(proof_kind, &vadcop_proof, vadcop_vk_u64)?;
+
+                Ok(ProveOutput::new(
+                    execution_result,
+                    start.elapsed(),
+                    Proof {
+                        body,
+                        publics: PublicValues::new_from_u64(&vadcop_proof.public_values),
+                        program_vk: ProgramVK::new_from_publics(&vadcop_proof.public_values),
+                    },
+                ))
             }
             (_, Some(p)) => Ok(ProveOutput::new(
                 execution_result,
@@ -438,11 +412,12 @@ impl ProverBackend {
         Ok(ProveOutput::new(ZiskExecutorSummary::default(), time, proof))
     }
 
-    pub(crate) fn plonk(
+    pub(crate) fn snark(
         &self,
         proof: &[u64],
         publics: &PublicValues,
         program_vk: &ProgramVK,
+        proof_kind: ProofKind,
     ) -> Result<ProveOutput> {
         if self.snark_wrapper.is_none() {
             return Err(anyhow::anyhow!(
@@ -456,13 +431,34 @@ impl ProverBackend {
         pubs_u64.extend(publics.public_u64());
         let vadcop_final_proof = VadcopFinalProof::new(proof.to_vec(), pubs_u64, false);
 
-        let snark_proof =
-            self.snark_wrapper.as_ref().unwrap().generate_final_snark_proof(&vadcop_final_proof)?;
+        let body =
+            self.snark_proof_body(proof_kind, &vadcop_final_proof, self.get_vadcop_vk(false)?)?;
 
         let time = start.elapsed();
 
+        let proof = Proof {
+            body,
+            publics: PublicValues::new_from_u64(&vadcop_final_proof.public_values),
+            program_vk: ProgramVK::new_from_publics(&vadcop_final_proof.public_values),
+        };
+
+        Ok(ProveOutput::new(ZiskExecutorSummary::default(), time, proof))
+    }
+
+    /// Wraps a Vadcop final proof into a SNARK proof, with the verification key of the SNARK
+    /// proving key.
+    ///
+    /// The protocol of the SNARK wrapper is fixed when its proving key is set up, so the key
+    /// must have been set up for the requested kind, and the wrapper must report the protocol
+    /// of the key.
+    fn snark_proof_body(
+        &self,
+        proof_kind: ProofKind,
+        vadcop_proof: &VadcopFinalProof,
+        vadcop_vk: Vec<u64>,
+    ) -> Result<ProofBody> {
         let proving_key_snark = self.proving_key_snark_path.as_ref().ok_or_else(|| {
-            anyhow::anyhow!("Proving key snark path is required for Plonk proofs")
+            anyhow::anyhow!("Proving key snark path is required for {:?} proofs", proof_kind)
         })?;
         let verkey_path = PathBuf::from(format!(
             "{}/{}/{}.verkey.json",
@@ -470,21 +466,34 @@ impl ProverBackend {
             "final",
             "final"
         ));
-        let plonk_vkey = PlonkVkey::load(&verkey_path)?;
+        let snark_vkey = SnarkVkey::load(&verkey_path)?;
+        if snark_vkey.proof_kind() != proof_kind {
+            return Err(anyhow::anyhow!(
+                "SNARK proving key {} was set up for {:?}, cannot generate {:?} proofs",
+                proving_key_snark.display(),
+                snark_vkey.proof_kind(),
+                proof_kind
+            ));
+        }
 
-        let proof = Proof {
-            body: ProofBody::Plonk {
-                proof_bytes: snark_proof.proof_bytes.clone(),
-                plonk_vk: Box::new(PlonkVkBlob {
-                    vadcop_vk: self.get_vadcop_vk(false)?,
-                    plonk_vkey,
-                }),
-            },
-            publics: PublicValues::new_from_u64(&vadcop_final_proof.public_values),
-            program_vk: ProgramVK::new_from_publics(&vadcop_final_proof.public_values),
+        let protocol = match proof_kind {
+            ProofKind::Plonk => SnarkProtocol::Plonk,
+            ProofKind::Groth16 => SnarkProtocol::Groth16,
+            ProofKind::VadcopFinal | ProofKind::VadcopFinalMinimal => {
+                return Err(anyhow::anyhow!("{:?} proofs are not SNARK proofs", proof_kind));
+            }
         };
+        let snark_proof =
+            self.snark_wrapper.as_ref().unwrap().generate_final_snark_proof(vadcop_proof)?;
+        if snark_proof.protocol_id != protocol.protocol_id() {
+            return Err(anyhow::anyhow!(
+                "Unsupported snark protocol id {} for {:?} proofs",
+                snark_proof.protocol_id,
+                proof_kind
+            ));
+        }
 
-        Ok(ProveOutput::new(ZiskExecutorSummary::default(), time, proof))
+        Ok(snark_vkey.proof_body(snark_proof.proof_bytes, vadcop_vk))
     }
 
     pub(crate) fn prove_phase(
@@ -5,7 +5,7 @@ use crate::{
     prover::{ProverBackend, ProverEngine, ZiskBackend},
     ExecuteOutput, ProveOutput, VerifyConstraintsOutput, ZiskAggPhaseResult, ZiskPhaseResult,
 };
-use crate::{ensure_program_vk, get_rom_bin_path, BackendProverOpts};
+use crate::{ensure_program_vk, get_rom_bin_path, symbolize_guest_panic, BackendProverOpts};
 use asm_runner::HintsShmem;
 use executor::initialize_executor;
 use precompiles_hints::HintsProcessor;
@@ -18,7 +18,8 @@ use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 use zisk_cluster_common::LoggingConfig;
 use zisk_common::{
-    io::ZiskStdin, ExecutorStatsHandle, ProgramVK, ProofKind, PublicValues, ZiskExecutorTime,
+    io::ZiskStdin, ExecutorStatsHandle, ProgramVK, ProofKind, PublicValues, ZiskExecutorSummary,
+    ZiskExecutorTime,
 };
 use zisk_core::{Riscv2zisk, ZiskRom};
 
@@ -138,9 +139,17 @@ impl ProverEngine for EmuProver {
         self.core_prover.backend.get_execution_info()
     }
 
+    fn get_execution_summary(&self) -> Result<ZiskExecutorSummary> {
+        self.core_prover.backend.execution_result().map(|(summary, _)| summary)
+    }
+
     fn execute(&self, program: &GuestProgram, stdin: ZiskStdin) -> Result<ExecuteOutput> {
         self.register_program(&program.program_id, false)?;
-        self.core_prover.backend.execute(stdin)
+        let mut output = self.core_prover.backend.execute(stdin)?;
+        if let Some(panic) = output.panic_mut() {
+            symbolize_guest_panic(panic, &program.elf);
+        }
+        Ok(output)
     }
 
     fn stats(
@@ -209,7 +218,9 @@ impl ProverEngine for EmuProver {
     ) -> Result<ProveOutput> {
         match proof_kind {
             ProofKind::VadcopFinalMinimal => self.core_prover.backend.minimal(proof, publics, vk),
-            ProofKind::Plonk => self.core_prover.backend.plonk(proof, publics, vk),
+            ProofKind::Plonk | ProofKind::Groth16 => {
+                self.core_prover.backend.snark(proof, publics, vk, proof_kind)
+            }
             _ => Err(anyhow::anyhow!("Unsupported proof mode for wrap: {:?}", proof_kind)),
         }
     }
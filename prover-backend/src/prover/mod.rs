@@ -13,7 +13,7 @@ use proofman_common::{ProofOptions, ProofmanOptions, RowInfo};
 
 use anyhow::{anyhow, Result};
 use asm_runner::HintsShmem;
-use precompiles_hints::HintsProcessor;
+use precompiles_hints::{HintPluginConfig, HintsProcessor};
 use std::{
     collections::HashMap,
     path::PathBuf,
@@ -21,7 +21,8 @@ use std::{
 };
 use zisk_common::{
     io::{StreamSource, ZiskStdin},
-    ExecutorStatsHandle, ProgramVK, Proof, ProofBody, ProofKind, PublicValues, ZiskExecutorTime,
+    ExecutorStatsHandle, ProgramVK, Proof, ProofBody, ProofKind, PublicValues, ZiskExecutorSummary,
+    ZiskExecutorTime,
 };
 use zisk_core::ZiskRom;
 
@@ -35,6 +36,7 @@ pub struct AsmOptions {
     pub unlock_mapped_memory: bool,
     pub asm_out_file: bool,
     pub is_distributed: bool,
+    pub hint_plugins: Vec<HintPluginConfig>,
 }
 
 impl AsmOptions {
@@ -62,6 +64,12 @@ impl AsmOptions {
         self.is_distributed = true;
         self
     }
+
+    /// Serve custom hint `hint_code` with the hint plugin library at `path`.
+    pub fn hint_plugin(mut self, hint_code: u32, path: PathBuf) -> Self {
+        self.hint_plugins.push(HintPluginConfig::new(hint_code, path));
+        self
+    }
 }
 
 /// Comprehensive prover configuration containing all settings
@@ -77,8 +85,8 @@ pub struct BackendProverOpts {
     pub(crate) proving_key: Option<PathBuf>,
     pub(crate) proving_key_snark: Option<PathBuf>,
 
-    pub(crate) plonk: bool, // Whether to generate PLONK/SNARK proofs are allowed
-    pub(crate) preload_plonk: bool, // Whether to preload PLONK/SNARK proving keys
+    pub(crate) snark: Option<ProofKind>, // SNARK kind proofs may be wrapped in, if any
+    pub(crate) preload_plonk: bool,      // Whether to preload PLONK/SNARK proving keys
 
     // ProofmanOptions fields (flattened)
     pub(crate) gpu: bool,
@@ -102,7 +110,7 @@ impl Default for BackendProverOpts {
             proving_key: None,
             proving_key_snark: None,
             preload_plonk: false,
-            plonk: false,
+            snark: None,
             gpu: false,
             packed: false,
             max_witness_stored: None,
@@ -206,14 +214,26 @@ impl BackendProverOpts {
         self
     }
 
-    pub fn plonk(mut self, preload: bool) -> Self {
-        self.plonk = true;
+    /// Allows wrapping proofs in SNARKs of `kind`, a [`ProofKind::is_snark`] kind, preloading the
+    /// SNARK proving key if `preload` is set
+    pub fn snark(mut self, kind: ProofKind, preload: bool) -> Self {
+        assert!(kind.is_snark(), "{kind:?} is not a SNARK proof kind");
+        self.snark = Some(kind);
         if preload {
             self.preload_plonk = true;
         }
         self
     }
 
+    #[deprecated(note = "use `snark(ProofKind::Plonk, preload)`")]
+    pub fn plonk(self, preload: bool) -> Self {
+        self.snark(ProofKind::Plonk, preload)
+    }
+
+    pub fn snark_kind(&self) -> Option<ProofKind> {
+        self.snark
+    }
+
     pub fn gpu(mut self) -> Self {
         self.gpu = true;
         self
@@ -278,6 +298,8 @@ pub trait ProverEngine {
 
     fn get_execution_info(&self) -> Result<(WitnessInfo, ZiskExecutorTime)>;
 
+    fn get_execution_summary(&self) -> Result<ZiskExecutorSummary>;
+
     fn get_instance_trace(
         &self,
         instance_id: usize,
@@ -475,6 +497,11 @@ impl<C: ZiskBackend> ZiskProver<C> {
         self.prover.get_execution_info()
     }
 
+    /// Get the steps, cost per type and executor time of the last execution or proof run.
+    pub fn get_execution_summary(&self) -> Result<ZiskExecutorSummary> {
+        self.prover.get_execution_summary()
+    }
+
     /// Execute the prover with the given standard input and output path.
     /// It only runs the execution without generating a proof.
     /// The program must have been setup previously using `.setup()`.
@@ -730,7 +757,10 @@ impl<'a, C: ZiskBackend> ProveBuilder<'a, C> {
     /// Enable minimal/compressed/SNARK proof generation.
     pub fn wrap_proof(mut self, proof_kind: ProofKind) -> Self {
         assert!(
-            matches!(proof_kind, ProofKind::VadcopFinalMinimal | ProofKind::Plonk),
+            matches!(
+                proof_kind,
+                ProofKind::VadcopFinalMinimal | ProofKind::Plonk | ProofKind::Groth16
+            ),
             "Invalid proof mode for ProveBuilder: {:?}",
             proof_kind
         );
@@ -795,8 +825,8 @@ impl<'a, C: ZiskBackend> WrapBuilder<'a, C> {
         let program_vk = self.override_program_vk.unwrap_or(&self.proof.program_vk);
         let proof = match &self.proof.body {
             ProofBody::Vadcop { proof, .. } => proof.as_slice(),
-            ProofBody::Plonk { .. } => {
-                return Err(anyhow::anyhow!("Cannot wrap a Plonk proof"));
+            ProofBody::Plonk { .. } | ProofBody::Groth16 { .. } => {
+                return Err(anyhow::anyhow!("Cannot wrap a {:?} proof", self.proof.kind()));
             }
         };
         self.prover.wrap_proof(proof, publics, program_vk, self.proof_kind)
@@ -4,12 +4,13 @@ use std::path::PathBuf;
 use anyhow::Result;
 use zisk_common::ProgramVK;
 
-use crate::{GuestProgram, ProgramId};
+use crate::{Elf, GuestProgram, ProgramId};
 use proofman_common::{
     initialize_logger, json_to_debug_instances_map, DebugInfo, ProofCtx, ProofmanResult,
     VerboseMode,
 };
 use rom_setup::{get_elf_data_hash, get_rom_path, rom_merkle_setup};
+use ziskemu::{ElfSymbolReader, GuestPanic};
 
 pub fn ensure_program_vk<F: PrimeField64>(
     pctx: &ProofCtx<F>,
@@ -56,3 +57,11 @@ pub fn create_debug_info(
 pub fn setup_logger(verbose: VerboseMode) {
     initialize_logger(verbose, None);
 }
+
+/// Symbolizes the backtrace of a guest panic with the program symbols
+pub(crate) fn symbolize_guest_panic(panic: &mut GuestPanic, elf: &Elf) {
+    let mut symbols = ElfSymbolReader::new();
+    if symbols.load_from_bytes(&elf.data).is_ok() {
+        panic.symbolize(&symbols, None);
+    }
+}
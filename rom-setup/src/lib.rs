@@ -1,7 +1,9 @@
 mod asm_setup;
 mod rom_merkle;
+mod rom_vk;
 mod utils;
 
 pub use asm_setup::*;
 pub use rom_merkle::*;
+pub use rom_vk::*;
 pub use utils::*;
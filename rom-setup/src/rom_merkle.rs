@@ -74,18 +74,25 @@ pub fn rom_merkle_setup_verkey(
     elf: &[u8],
     output_dir: &Option<PathBuf>,
 ) -> Result<ProgramVK, anyhow::Error> {
+    rom_merkle_setup_cached_verkey(elf, output_dir)?
+        .ok_or_else(|| anyhow::anyhow!("ROM merkle setup has not been performed yet"))
+}
+
+/// Verification key stored by the ROM merkle setup of `elf`, or `None` if the setup has not been
+/// performed. A verkey file that cannot be read is an error, not a missing setup.
+pub fn rom_merkle_setup_cached_verkey(
+    elf: &[u8],
+    output_dir: &Option<PathBuf>,
+) -> Result<Option<ProgramVK>, anyhow::Error> {
     let output_path = get_output_path(output_dir)?;
 
     let elf_hash = get_elf_data_hash(elf);
 
     let elf_verkey_bin_path = get_elf_bin_verkey_file_path_with_hash(&elf_hash, &output_path)?;
 
-    if elf_verkey_bin_path.exists() {
-        let vk = get_elf_vk(elf_verkey_bin_path.as_path())?
-            .ok_or_else(|| anyhow::anyhow!("Failed to read existing verkey file"))?;
+    let vk = get_elf_vk(elf_verkey_bin_path.as_path()).map_err(|e| {
+        anyhow::anyhow!("Failed to read existing verkey file {elf_verkey_bin_path:?}: {e}")
+    })?;
 
-        Ok(ProgramVK { vk })
-    } else {
-        Err(anyhow::anyhow!("ROM merkle setup has not been performed yet"))
-    }
+    Ok(vk.map(|vk| ProgramVK { vk }))
 }
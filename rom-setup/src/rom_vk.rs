@@ -0,0 +1,220 @@
+//! Program verification key computed from the ELF alone.
+//!
+//! The program VK is the root of the ROM custom commit: the ROM trace is extended to a coset of
+//! a domain [`ROM_BLOWUP_FACTOR`] times larger and merkelized with Poseidon2 in a tree of arity
+//! [`ROM_MERKLE_TREE_ARITY`], as `write_custom_commit_trace` does during `rom_merkle_setup`.
+//! None of it depends on the proving key: the only constants involved are the shape of
+//! `RomRomTrace`, the blowup factor and arity above, and the Goldilocks generator, which is both
+//! the coset shift and the base of the roots of unity. They are compiled in together with
+//! `PILOUT_HASH`, so a VK computed here is bound to the same PIL as the proving key.
+
+use anyhow::{ensure, Result};
+use fields::{poseidon2_hash, Goldilocks, Poseidon16, PrimeField64};
+use rayon::prelude::*;
+use sm_rom::RomSM;
+use zisk_common::ProgramVK;
+use zisk_pil::RomRomTrace;
+
+use crate::{ROM_BLOWUP_FACTOR, ROM_MERKLE_TREE_ARITY};
+
+/// Goldilocks field modulus
+const GOLDILOCKS_PRIME: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// Multiplicative generator of the Goldilocks field, also the shift of the extended domain
+const GOLDILOCKS_GENERATOR: u64 = 7;
+
+/// Number of elements of the Poseidon2 state of the commit trees
+const SPONGE_WIDTH: usize = 16;
+
+/// Number of elements of a digest
+const CAPACITY: usize = 4;
+
+/// Number of elements absorbed per permutation by the linear hash
+const RATE: usize = SPONGE_WIDTH - CAPACITY;
+
+type Digest = [Goldilocks; CAPACITY];
+
+/// Computes the verification key of a program from its ELF, without the proving key.
+///
+/// The result is the same as the VK stored by `rom_merkle_setup`, but it takes a few seconds
+/// and about 1 GB of memory, so prefer the cached key when the ROM setup has been done.
+/// The ignored test `program_vk_matches_rom_setup` compares both for a given ELF; there is no
+/// committed ELF and verkey pair yet to compare them on every run.
+pub fn program_vk_from_elf(elf: &[u8]) -> Result<ProgramVK> {
+    let buffer = vec![
+        Goldilocks::ZERO;
+        RomRomTrace::<Goldilocks>::NUM_ROWS * RomRomTrace::<Goldilocks>::ROW_SIZE
+    ];
+    let mut custom_rom_trace: RomRomTrace<Goldilocks> = RomRomTrace::new_from_vec(buffer)?;
+
+    RomSM::compute_custom_trace_rom(elf, &mut custom_rom_trace);
+
+    let vk = custom_commit_root(
+        &custom_rom_trace.buffer,
+        RomRomTrace::<Goldilocks>::ROW_SIZE,
+        ROM_BLOWUP_FACTOR,
+    )?;
+
+    Ok(ProgramVK { vk })
+}
+
+/// Computes the root of a custom commit of a row-major trace with `n_cols` columns.
+///
+/// The number of rows and `blowup_factor` must be powers of two.
+pub fn custom_commit_root(
+    trace: &[Goldilocks],
+    n_cols: usize,
+    blowup_factor: u64,
+) -> Result<Vec<u64>> {
+    ensure!(
+        n_cols > 0 && trace.len() % n_cols == 0,
+        "Trace length is not a multiple of {n_cols} columns"
+    );
+    let n_rows = trace.len() / n_cols;
+    ensure!(n_rows.is_power_of_two(), "Number of trace rows {n_rows} is not a power of two");
+    ensure!(blowup_factor.is_power_of_two(), "Blowup factor {blowup_factor} is not a power of two");
+    ensure!(
+        ROM_MERKLE_TREE_ARITY as usize * CAPACITY == SPONGE_WIDTH,
+        "Unsupported Merkle tree arity"
+    );
+
+    let n_bits = n_rows.trailing_zeros();
+    let n_bits_ext = n_bits + blowup_factor.trailing_zeros();
+
+    let columns: Vec<Vec<Goldilocks>> = (0..n_cols)
+        .into_par_iter()
+        .map(|col| {
+            let column: Vec<Goldilocks> = trace.iter().skip(col).step_by(n_cols).copied().collect();
+            extend_column(column, n_bits, n_bits_ext)
+        })
+        .collect();
+
+    let leaves: Vec<Digest> = (0..1usize << n_bits_ext)
+        .into_par_iter()
+        .map(|row| {
+            let row: Vec<Goldilocks> = columns.iter().map(|column| column[row]).collect();
+            linear_hash(&row)
+        })
+        .collect();
+
+    Ok(merkle_root(leaves).iter().map(|x| x.as_canonical_u64()).collect())
+}
+
+/// Evaluates the polynomial with the given evaluations over the domain of size `2^n_bits` on
+/// the coset of the domain of size `2^n_bits_ext` shifted by the field generator.
+fn extend_column(mut values: Vec<Goldilocks>, n_bits: u32, n_bits_ext: u32) -> Vec<Goldilocks> {
+    // Interpolate
+    ntt(&mut values, root_of_unity(n_bits).inverse());
+    let n_inv = Goldilocks::from_u64(1 << n_bits).inverse();
+
+    // Scale the coefficients to evaluate on the shifted coset
+    let shift = Goldilocks::from_u64(GOLDILOCKS_GENERATOR);
+    let mut factor = n_inv;
+    for coefficient in values.iter_mut() {
+        *coefficient *= factor;
+        factor *= shift;
+    }
+
+    values.resize(1 << n_bits_ext, Goldilocks::ZERO);
+    ntt(&mut values, root_of_unity(n_bits_ext));
+    values
+}
+
+/// Primitive `2^n_bits`-th root of unity
+fn root_of_unity(n_bits: u32) -> Goldilocks {
+    pow(Goldilocks::from_u64(GOLDILOCKS_GENERATOR), (GOLDILOCKS_PRIME - 1) >> n_bits)
+}
+
+fn pow(base: Goldilocks, mut exp: u64) -> Goldilocks {
+    let mut result = Goldilocks::ONE;
+    let mut base = base;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        exp >>= 1;
+    }
+    result
+}
+
+/// In-place radix-2 NTT in natural order, evaluating at the powers of `root`
+fn ntt(values: &mut [Goldilocks], root: Goldilocks) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut w = Goldilocks::ONE;
+    for _ in 0..n / 2 {
+        twiddles.push(w);
+        w *= root;
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let step = n / len;
+        for chunk in values.chunks_exact_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for k in 0..half {
+                let t = hi[k] * twiddles[k * step];
+                let u = lo[k];
+                lo[k] = u + t;
+                hi[k] = u - t;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Poseidon2 linear hash of a row. Rows of at most [`CAPACITY`] elements are their own digest,
+/// zero-padded.
+fn linear_hash(row: &[Goldilocks]) -> Digest {
+    let mut digest = [Goldilocks::ZERO; CAPACITY];
+    if row.len() <= CAPACITY {
+        digest[..row.len()].copy_from_slice(row);
+        return digest;
+    }
+
+    for (i, chunk) in row.chunks(RATE).enumerate() {
+        let mut state = [Goldilocks::ZERO; SPONGE_WIDTH];
+        state[..chunk.len()].copy_from_slice(chunk);
+        if i > 0 {
+            state[RATE..].copy_from_slice(&digest);
+        }
+        let output = poseidon2_hash::<Goldilocks, Poseidon16, SPONGE_WIDTH>(&state);
+        digest.copy_from_slice(&output[..CAPACITY]);
+    }
+    digest
+}
+
+/// Root of the Merkle tree of [`ROM_MERKLE_TREE_ARITY`] children per node, completing the last
+/// node of each level with zero digests.
+fn merkle_root(mut level: Vec<Digest>) -> Digest {
+    let arity = ROM_MERKLE_TREE_ARITY as usize;
+    while level.len() > 1 {
+        level.resize(level.len().div_ceil(arity) * arity, [Goldilocks::ZERO; CAPACITY]);
+        level = level
+            .par_chunks_exact(arity)
+            .map(|children| {
+                let mut state = [Goldilocks::ZERO; SPONGE_WIDTH];
+                for (slot, child) in state.chunks_exact_mut(CAPACITY).zip(children) {
+                    slot.copy_from_slice(child);
+                }
+                let output = poseidon2_hash::<Goldilocks, Poseidon16, SPONGE_WIDTH>(&state);
+                output[..CAPACITY].try_into().unwrap()
+            })
+            .collect();
+    }
+    level[0]
+}
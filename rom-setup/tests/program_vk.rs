@@ -0,0 +1,131 @@
+use fields::{poseidon2_hash, Goldilocks, Poseidon16, PrimeField64};
+use std::path::{Path, PathBuf};
+
+use rom_setup::{
+    custom_commit_root, get_elf_bin_verkey_file_path_with_hash, get_elf_data_hash,
+    program_vk_from_elf, rom_merkle_setup_cached_verkey, rom_merkle_setup_verkey,
+};
+
+const P: u64 = 0xFFFF_FFFF_0000_0001;
+
+fn pow(base: u64, exp: u64) -> u64 {
+    (0..64).rev().fold(1u128, |acc, bit| {
+        let acc = acc * acc % P as u128;
+        if (exp >> bit) & 1 == 1 {
+            acc * base as u128 % P as u128
+        } else {
+            acc
+        }
+    }) as u64
+}
+
+fn permute(state: [u64; 16]) -> [u64; 4] {
+    let output = poseidon2_hash::<Goldilocks, Poseidon16, 16>(&state.map(Goldilocks::new));
+    std::array::from_fn(|i| output[i].as_canonical_u64())
+}
+
+/// Root of an arity 4 tree over at most 16 leaves
+fn root_of(leaves: &[[u64; 4]]) -> Vec<u64> {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level.resize(level.len().div_ceil(4) * 4, [0; 4]);
+        level = level
+            .chunks(4)
+            .map(|children| permute(std::array::from_fn(|i| children[i / 4][i % 4])))
+            .collect();
+    }
+    level[0].to_vec()
+}
+
+#[test]
+fn custom_commit_extends_to_shifted_coset() {
+    // The trace column is x over the 4th roots of unity, so its extension is x over the 8th
+    // roots of unity shifted by 7
+    let w4 = pow(7, (P - 1) >> 2);
+    let w8 = pow(7, (P - 1) >> 3);
+    let trace: Vec<Goldilocks> = (0..4).map(|i| Goldilocks::new(pow(w4, i))).collect();
+
+    let leaves: Vec<[u64; 4]> =
+        (0..8).map(|j| [(7 * pow(w8, j) as u128 % P as u128) as u64, 0, 0, 0]).collect();
+
+    assert_eq!(custom_commit_root(&trace, 1, 2).unwrap(), root_of(&leaves));
+}
+
+#[test]
+fn custom_commit_hashes_wide_rows() {
+    // Constant columns extend to the same values; rows wider than the rate take two
+    // permutations, the second one chaining the first digest
+    let row: Vec<u64> = (1..=13).collect();
+    let trace: Vec<Goldilocks> =
+        row.iter().cycle().take(2 * 13).map(|&x| Goldilocks::new(x)).collect();
+
+    let first = permute(std::array::from_fn(|i| if i < 12 { row[i] } else { 0 }));
+    let mut state = [0; 16];
+    state[0] = row[12];
+    state[12..].copy_from_slice(&first);
+    let leaf = permute(state);
+
+    assert_eq!(custom_commit_root(&trace, 13, 2).unwrap(), root_of(&[leaf; 4]));
+}
+
+#[test]
+fn custom_commit_rejects_bad_shapes() {
+    let trace = vec![Goldilocks::new(1); 6];
+    assert!(custom_commit_root(&trace, 4, 2).is_err());
+    assert!(custom_commit_root(&trace, 2, 2).is_err());
+    assert!(custom_commit_root(&trace[..4], 2, 3).is_err());
+}
+
+/// Checks that the VK matches the one of the prover for the ELF in `ZISK_VK_TEST_ELF`, whose ROM
+/// setup has been done with `cargo-zisk program-setup`. Run it with `cargo test -- --ignored`.
+#[test]
+#[ignore = "needs ZISK_VK_TEST_ELF pointing to an ELF with a ROM setup"]
+fn program_vk_matches_rom_setup() {
+    let path = std::env::var("ZISK_VK_TEST_ELF").expect("ZISK_VK_TEST_ELF is set");
+    let elf = std::fs::read(&path).expect("readable ELF");
+    let expected = rom_merkle_setup_verkey(&elf, &None).expect("ROM setup of the ELF");
+
+    assert_eq!(program_vk_from_elf(&elf).unwrap().vk, expected.vk);
+}
+
+/// Empty output directory of a ROM setup, one per test as the tests run concurrently
+fn setup_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("zisk-rom-setup-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn verkey_path(elf: &[u8], dir: &Path) -> PathBuf {
+    get_elf_bin_verkey_file_path_with_hash(&get_elf_data_hash(elf), dir).unwrap()
+}
+
+#[test]
+fn cached_verkey_is_none_without_setup() {
+    let dir = setup_dir("none");
+    let output_dir = Some(dir.clone());
+
+    assert!(rom_merkle_setup_cached_verkey(b"elf", &output_dir).unwrap().is_none());
+    assert!(rom_merkle_setup_verkey(b"elf", &output_dir).is_err());
+}
+
+#[test]
+fn cached_verkey_reads_setup() {
+    let dir = setup_dir("read");
+    let output_dir = Some(dir.clone());
+    let vk = [1u64, 2, 3, u64::MAX];
+    let bytes: Vec<u8> = vk.iter().flat_map(|w| w.to_le_bytes()).collect();
+    std::fs::write(verkey_path(b"elf", &dir), bytes).unwrap();
+
+    assert_eq!(rom_merkle_setup_cached_verkey(b"elf", &output_dir).unwrap().unwrap().vk, vk);
+    assert_eq!(rom_merkle_setup_verkey(b"elf", &output_dir).unwrap().vk, vk);
+}
+
+#[test]
+fn cached_verkey_rejects_truncated_setup() {
+    // A damaged setup is reported instead of falling back to the VK computed from the ELF
+    let dir = setup_dir("truncated");
+    std::fs::write(verkey_path(b"elf", &dir), [0u8; 12]).unwrap();
+
+    assert!(rom_merkle_setup_cached_verkey(b"elf", &Some(dir)).is_err());
+}
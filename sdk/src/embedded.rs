@@ -101,6 +101,13 @@ impl EmbeddedClientBuilder {
         self
     }
 
+    /// Enable Groth16 proof mode. The SNARK proving key must have been set up for Groth16.
+    #[must_use]
+    pub fn groth16(mut self) -> Self {
+        self.proof_kind = ProofKind::Groth16;
+        self
+    }
+
     /// Set ASM-specific options. Only valid with the Assembly executor.
     #[must_use]
     pub fn asm_options(mut self, opts: AsmOptions) -> Self {
@@ -158,7 +165,7 @@ impl EmbeddedClientBuilder {
         proof_kind: ProofKind,
     ) -> Result<EmbeddedProver> {
         let emu = EmuProver::new(
-            proof_kind == ProofKind::Plonk,        // plonk
+            proof_kind.is_snark(),                 // plonk
             backend_opts.preload_plonk(),          // preload_snark
             pk,                                    // proving_key
             pk_snark,                              // proving_key_snark
@@ -177,7 +184,7 @@ impl EmbeddedClientBuilder {
     ) -> Result<EmbeddedProver> {
         let asm_opts = backend_opts.asm_options();
         let asm = AsmProver::new(
-            proof_kind == ProofKind::Plonk,        // plonk
+            proof_kind.is_snark(),                 // plonk
             backend_opts.preload_plonk(),          // preload_snark
             pk,                                    // proving_key
             pk_snark,                              // proving_key_snark
@@ -187,6 +194,7 @@ impl EmbeddedClientBuilder {
             asm_opts.no_auto_setup,                // no_auto_setup
             backend_opts.build_proofman_options(), // options
             false,                                 // is_distributed
+            asm_opts.hint_plugins.clone(),         // hint_plugins
             None,                                  // logging_config
         )?;
         Ok(EmbeddedProver::Asm(ZiskProver::<Asm>::new(asm, backend_opts)))
@@ -2,7 +2,7 @@ use super::EmbeddedClient;
 use crate::embedded::{EmbeddedProver, ERR_ASSEMBLY_NOT_ENABLED};
 use crate::hints::HintsSource;
 use crate::input_source::InputSource;
-use crate::job_handle::{fire_event, fire_result_event, JobHandle, SubscriberList};
+use crate::job_handle::{fire_event, run_local_prove, JobHandle, SubscriberList};
 use crate::prove::ProveResult;
 use crate::{ExecutorKind, JobEvent};
 use anyhow::Result;
@@ -28,14 +28,12 @@ impl EmbeddedClient {
         let subs_cloned = Arc::clone(&subs);
         let prover = self.prover.clone();
 
-        let handle = tokio::task::spawn_blocking(move || {
-            fire_event(&subs_cloned, JobEvent::Started);
-
-            let result = Self::do_prove_inner(prover, &program, stdin, hints, executor, proof_kind);
+        fire_event(&subs, JobEvent::Queued);
 
-            fire_result_event(&subs_cloned, &result);
-
-            result
+        let handle = tokio::task::spawn_blocking(move || {
+            run_local_prove(&subs_cloned, || {
+                Self::do_prove_inner(prover, &program, stdin, hints, executor, proof_kind)
+            })
         });
 
         Ok(JobHandle::new_embedded(handle, subs, timeout))
@@ -57,6 +55,7 @@ impl EmbeddedClient {
                         $builder.wrap_proof(ProofKind::VadcopFinalMinimal)
                     }
                     ProofKind::Plonk => $builder.wrap_proof(ProofKind::Plonk),
+                    ProofKind::Groth16 => $builder.wrap_proof(ProofKind::Groth16),
                 }
             };
         }
@@ -21,6 +21,8 @@ impl EmbeddedClient {
         let subs_cloned = Arc::clone(&subs);
         let prover = self.prover.clone();
 
+        fire_event(&subs, JobEvent::Queued);
+
         let handle = tokio::task::spawn_blocking(move || {
             fire_event(&subs_cloned, JobEvent::Started);
 
@@ -24,6 +24,8 @@ impl EmbeddedClient {
         let prover = self.prover.clone();
         let proof = proof.clone();
 
+        fire_event(&subs, JobEvent::Queued);
+
         let handle = tokio::task::spawn_blocking(move || {
             fire_event(&subs_cloned, JobEvent::Started);
 
@@ -54,8 +56,8 @@ impl EmbeddedClient {
         let program_vk = override_program_vk.unwrap_or(&proof.program_vk);
         let proof_words = match &proof.body {
             ProofBody::Vadcop { proof, .. } => proof.as_slice(),
-            ProofBody::Plonk { .. } => {
-                return Err(anyhow::anyhow!("Cannot wrap a Plonk proof"));
+            ProofBody::Plonk { .. } | ProofBody::Groth16 { .. } => {
+                return Err(anyhow::anyhow!("Cannot wrap a {:?} proof", proof.kind()));
             }
         };
 
@@ -2,6 +2,7 @@ use std::ops::Deref;
 use std::time::Duration;
 
 use anyhow::Result;
+use zisk_common::io::RNG_SEED_SIZE;
 use zisk_prover_backend::{ExecuteOutput, GuestProgram};
 
 use crate::hints::HintsSource;
@@ -47,6 +48,7 @@ pub struct ExecuteRequest<'a, C> {
     hints: Option<HintsSource>,
     executor: Option<ExecutorKind>,
     timeout: Option<Duration>,
+    rng_seed: Option<[u8; RNG_SEED_SIZE]>,
 }
 
 #[allow(private_bounds)]
@@ -56,7 +58,15 @@ impl<'a, C: Client> ExecuteRequest<'a, C> {
         program: &'a GuestProgram,
         stdin: impl Into<InputSource>,
     ) -> Self {
-        Self { client, program, stdin: stdin.into(), hints: None, executor: None, timeout: None }
+        Self {
+            client,
+            program,
+            stdin: stdin.into(),
+            hints: None,
+            executor: None,
+            timeout: None,
+            rng_seed: None,
+        }
     }
 
     /// Attach a hints stream to this execute request.
@@ -84,10 +94,138 @@ impl<'a, C: Client> ExecuteRequest<'a, C> {
         self
     }
 
+    /// Seed the guest random number generator from the host.
+    ///
+    /// Requires a guest built with the `host-rng-seed` feature of `ziskos` and a buffered
+    /// [`ZiskStdin`](crate::ZiskStdin) input; see [`ZiskStdin::with_rng_seed`](crate::ZiskStdin::with_rng_seed).
+    #[must_use]
+    pub fn with_rng_seed(mut self, seed: [u8; RNG_SEED_SIZE]) -> Self {
+        self.rng_seed = Some(seed);
+        self
+    }
+
     /// Submit the execution, returning a [`JobHandle<ExecuteOutput>`].
     pub fn run(self) -> Result<JobHandle<ExecuteResult>> {
         let executor = self.executor.unwrap_or_else(|| self.client.default_executor());
+        let stdin = match (self.stdin, self.rng_seed) {
+            (stdin, None) => stdin,
+            (InputSource::Stdin(stdin), Some(seed)) => {
+                InputSource::Stdin(stdin.with_rng_seed(seed))
+            }
+            (InputSource::Stream(_), Some(_)) => {
+                anyhow::bail!("an RNG seed can only be set on a buffered stdin, not on a stream")
+            }
+        };
         let subs = new_subscriber_list();
-        self.client.run_execute(self.program, self.stdin, self.hints, executor, self.timeout, subs)
+        self.client.run_execute(self.program, stdin, self.hints, executor, self.timeout, subs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GuestPanic;
+
+    use std::sync::{Arc, Mutex};
+
+    use zisk_common::{ProgramVK, Proof, ProofKind, PublicValues};
+
+    use crate::job_handle::SubscriberList;
+    use crate::prove::ProveResult;
+    use crate::setup::SetupResult;
+    use crate::upload::UploadResult;
+    use crate::ZiskStdin;
+
+    /// Client that records the input of the execute requests instead of running them
+    #[derive(Clone, Default)]
+    struct RecordingClient {
+        stdin: Arc<Mutex<Option<Vec<u8>>>>,
+    }
+
+    impl Client for RecordingClient {
+        fn run_upload(&self, _: &GuestProgram) -> Result<UploadResult> {
+            unimplemented!()
+        }
+
+        fn run_setup(
+            &self,
+            _: &GuestProgram,
+            _: bool,
+            _: Option<Duration>,
+            _: SubscriberList,
+        ) -> Result<JobHandle<SetupResult>> {
+            unimplemented!()
+        }
+
+        fn run_prove(
+            &self,
+            _: &GuestProgram,
+            _: InputSource,
+            _: Option<HintsSource>,
+            _: ExecutorKind,
+            _: ProofKind,
+            _: Option<Duration>,
+            _: SubscriberList,
+        ) -> Result<JobHandle<ProveResult>> {
+            unimplemented!()
+        }
+
+        fn run_execute(
+            &self,
+            _: &GuestProgram,
+            stdin: InputSource,
+            _: Option<HintsSource>,
+            _: ExecutorKind,
+            _: Option<Duration>,
+            _: SubscriberList,
+        ) -> Result<JobHandle<ExecuteResult>> {
+            let InputSource::Stdin(stdin) = stdin else { unreachable!() };
+            *self.stdin.lock().unwrap() = Some(stdin.into_inner().read_data());
+            anyhow::bail!("recorded")
+        }
+
+        fn run_wrap(
+            &self,
+            _: &Proof,
+            _: ProofKind,
+            _: Option<PublicValues>,
+            _: Option<ProgramVK>,
+            _: Option<Duration>,
+            _: SubscriberList,
+        ) -> Result<JobHandle<ProveResult>> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn test_rng_seed_does_not_change_the_caller_stdin() {
+        let client = RecordingClient::default();
+        let program = GuestProgram::from_bytes("guest", Vec::new());
+        let stdin = ZiskStdin::new();
+        stdin.write_slice(&[1, 2, 3]);
+        let before = stdin.clone().into_inner().read_data();
+
+        let seed = [7u8; RNG_SEED_SIZE];
+        let request = ExecuteRequest::new(&client, &program, stdin.clone()).with_rng_seed(seed);
+        assert!(request.run().is_err());
+
+        let seeded = client.stdin.lock().unwrap().take().unwrap();
+        assert_eq!(seeded, stdin.deep_clone().with_rng_seed(seed).into_inner().read_data());
+        assert_eq!(stdin.into_inner().read_data(), before);
+    }
+
+    #[test]
+    fn test_execute_result_reports_the_guest_panic() {
+        let panic = GuestPanic {
+            message: "attempt to divide by zero".to_string(),
+            location: None,
+            backtrace: Vec::new(),
+            step: 42,
+        };
+        let result = ExecuteResult::from(ExecuteOutput::from_panic(Duration::ZERO, panic.clone()));
+
+        assert_eq!(result.get_panic(), Some(&panic));
+        assert_eq!(result.get_execution_steps(), 42);
+        assert!(result.job_id().is_none());
     }
 }
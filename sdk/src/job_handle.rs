@@ -2,7 +2,7 @@ use std::future::Future;
 use std::future::IntoFuture;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use anyhow::Result;
 use zisk_coordinator_api::dto::{
@@ -12,7 +12,7 @@ use zisk_coordinator_api::dto::{
 use zisk_coordinator_client::{Job, WatchHandle};
 
 use crate::input_stream::ZiskStream;
-use crate::prove::JobEvent;
+use crate::prove::{JobEvent, JobFailure, JobPhase};
 use crate::setup::SetupResult;
 
 const CANCELLED: &str = "Cancelled";
@@ -61,7 +61,7 @@ pub(crate) fn fire_event(subscribers: &SubscriberList, event: JobEvent) {
     let matching: Vec<Arc<dyn Fn(JobEvent) + Send + Sync>> = bus
         .subscribers
         .iter()
-        .filter(|(filter, _)| *filter == JobEvent::All || *filter == event)
+        .filter(|(filter, _)| filter.matches(&event))
         .map(|(_, cb)| Arc::clone(cb))
         .collect();
     drop(bus);
@@ -73,8 +73,47 @@ pub(crate) fn fire_event(subscribers: &SubscriberList, event: JobEvent) {
 pub(crate) fn fire_result_event<T>(subs: &SubscriberList, result: &Result<T>) {
     match result {
         Ok(_) => fire_event(subs, JobEvent::Completed),
-        Err(e) => fire_event(subs, JobEvent::Failed(e.to_string())),
+        Err(e) => {
+            fire_event(subs, JobEvent::Failed(JobFailure::Execution { reason: e.to_string() }))
+        }
+    }
+}
+
+/// Fires the start of `phase`, followed by the deprecated progress event for it.
+pub(crate) fn fire_phase(
+    subs: &SubscriberList,
+    phase: JobPhase,
+    started_at: SystemTime,
+    workers: Option<u32>,
+) {
+    fire_event(subs, JobEvent::Phase { phase, started_at, workers });
+    let pct = match phase {
+        JobPhase::Contributions => PROGRESS_CONTRIBUTIONS,
+        JobPhase::Prove => PROGRESS_PROVE,
+        JobPhase::Aggregate => PROGRESS_AGGREGATE,
+    };
+    #[allow(deprecated)]
+    fire_event(subs, JobEvent::Progress(pct));
+}
+
+/// Runs a local proving job, firing the same events as a remote one.
+///
+/// The local prover goes through every phase in a single call on one worker,
+/// so all the phases are fired when it starts.
+pub(crate) fn run_local_prove<T>(
+    subs: &SubscriberList,
+    prove: impl FnOnce() -> Result<T>,
+) -> Result<T> {
+    fire_event(subs, JobEvent::Started);
+    let started_at = SystemTime::now();
+    for phase in JobPhase::ALL {
+        fire_phase(subs, phase, started_at, Some(1));
     }
+
+    let result = prove();
+
+    fire_result_event(subs, &result);
+    result
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -186,7 +225,7 @@ impl<T> JobHandle<T> {
             bus.subscribers.push((event.clone(), Arc::clone(&cb)));
             drop(bus);
             for e in pending {
-                if event == JobEvent::All || event == e {
+                if event.matches(&e) {
                     cb(e);
                 }
             }
@@ -253,12 +292,8 @@ impl<T: FromWaitResult> JobHandle<T> {
         // Fire terminal event from the authoritative WaitJobResult response.
         match &terminal {
             TerminalStatus::Completed(_) => fire_event(&subscribers, JobEvent::Completed),
-            TerminalStatus::Failed(f) => {
-                fire_event(&subscribers, JobEvent::Failed(format_failure(f)));
-            }
-            TerminalStatus::Cancelled => {
-                fire_event(&subscribers, JobEvent::Failed(CANCELLED.to_string()));
-            }
+            TerminalStatus::Failed(f) => fire_event(&subscribers, failure_event(f)),
+            TerminalStatus::Cancelled => fire_event(&subscribers, JobEvent::Cancelled),
         }
 
         if let Some(hook) = pre_process {
@@ -308,18 +343,20 @@ impl<T: Send + 'static + FromWaitResult> IntoFuture for JobHandle<T> {
 /// Returns `true` for terminal events (to stop the watch stream).
 fn map_domain_event(subs: &SubscriberList, event: &DomainJobEvent) -> bool {
     match event {
-        DomainJobEvent::Queued(_) | DomainJobEvent::WaitingForInput(_) => false,
+        DomainJobEvent::Queued(_) => {
+            fire_event(subs, JobEvent::Queued);
+            false
+        }
+        DomainJobEvent::WaitingForInput(_) => {
+            fire_event(subs, JobEvent::WaitingForInput);
+            false
+        }
         DomainJobEvent::Started(_) => {
             fire_event(subs, JobEvent::Started);
             false
         }
         DomainJobEvent::Progress(p) => {
-            let pct = match p.phase {
-                DomainJobPhase::Contributions => PROGRESS_CONTRIBUTIONS,
-                DomainJobPhase::Prove => PROGRESS_PROVE,
-                DomainJobPhase::Aggregate => PROGRESS_AGGREGATE,
-            };
-            fire_event(subs, JobEvent::Progress(pct));
+            fire_phase(subs, (&p.phase).into(), p.timestamp.into(), p.workers);
             false
         }
         // Terminal events are fired authoritatively from the WaitJobResult response;
@@ -332,17 +369,36 @@ fn map_domain_event(subs: &SubscriberList, event: &DomainJobEvent) -> bool {
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
 
-fn format_failure(failure: &DomainJobFailure) -> String {
-    match failure {
+impl From<&DomainJobPhase> for JobPhase {
+    fn from(phase: &DomainJobPhase) -> Self {
+        match phase {
+            DomainJobPhase::Contributions => JobPhase::Contributions,
+            DomainJobPhase::Prove => JobPhase::Prove,
+            DomainJobPhase::Aggregate => JobPhase::Aggregate,
+        }
+    }
+}
+
+/// Terminal SDK event for a domain failure (a cancellation is not a failure).
+fn failure_event(failure: &DomainJobFailure) -> JobEvent {
+    let failure = match failure {
         DomainJobFailure::Timeout { phase, limit } => {
-            format!("Timeout (phase: {:?}, limit: {:?})", phase, limit)
+            JobFailure::Timeout { phase: phase.as_ref().map(JobPhase::from), limit: *limit }
         }
-        DomainJobFailure::Input { reason } => format!("Input error: {}", reason),
-        DomainJobFailure::Execution { reason } => format!("Execution error: {}", reason),
+        DomainJobFailure::Input { reason } => JobFailure::Input { reason: reason.clone() },
+        DomainJobFailure::Execution { reason } => JobFailure::Execution { reason: reason.clone() },
         DomainJobFailure::Internal { trace_id } => {
-            format!("Internal error (trace_id: {})", trace_id)
+            JobFailure::Internal { trace_id: trace_id.clone() }
         }
-        DomainJobFailure::Cancelled => CANCELLED.to_string(),
+        DomainJobFailure::Cancelled => return JobEvent::Cancelled,
+    };
+    JobEvent::Failed(failure)
+}
+
+fn format_failure(failure: &DomainJobFailure) -> String {
+    match failure_event(failure) {
+        JobEvent::Failed(failure) => failure.to_string(),
+        _ => CANCELLED.to_string(),
     }
 }
 
@@ -378,10 +434,8 @@ impl FromWaitResult for crate::prove::ProveResult {
     fn from_terminal(status: TerminalStatus, job_id: JobId) -> Result<Self> {
         match status {
             TerminalStatus::Completed(DomainJobKindResponse::Prove { proof, stats }) => {
-                let proof_with_pv: zisk_common::Proof =
-                    bincode::serde::decode_from_slice(&proof.data, bincode::config::standard())
-                        .map(|(v, _)| v)
-                        .map_err(|e| anyhow::anyhow!("failed to deserialize proof: {e}"))?;
+                let proof_with_pv = zisk_common::Proof::from_bytes(&proof.data)
+                    .map_err(|e| anyhow::anyhow!("failed to deserialize proof: {e}"))?;
                 let output = zisk_prover_backend::ProveOutput::from_remote(
                     proof_with_pv,
                     stats.steps,
@@ -391,10 +445,8 @@ impl FromWaitResult for crate::prove::ProveResult {
                 Ok(crate::prove::ProveResult::new(output, Some(job_id)))
             }
             TerminalStatus::Completed(DomainJobKindResponse::Wrap(proof)) => {
-                let proof_with_pv: zisk_common::Proof =
-                    bincode::serde::decode_from_slice(&proof.data, bincode::config::standard())
-                        .map(|(v, _)| v)
-                        .map_err(|e| anyhow::anyhow!("failed to deserialize wrapped proof: {e}"))?;
+                let proof_with_pv = zisk_common::Proof::from_bytes(&proof.data)
+                    .map_err(|e| anyhow::anyhow!("failed to deserialize wrapped proof: {e}"))?;
                 let output = zisk_prover_backend::ProveOutput::from_remote(
                     proof_with_pv,
                     0,
@@ -423,12 +475,17 @@ impl FromWaitResult for crate::verify_constraints::VerifyConstraintsResult {
 impl FromWaitResult for crate::execute::ExecuteResult {
     fn from_terminal(status: TerminalStatus, job_id: JobId) -> Result<Self> {
         match status {
-            TerminalStatus::Completed(DomainJobKindResponse::Execute { stats, public_outputs }) => {
+            TerminalStatus::Completed(DomainJobKindResponse::Execute {
+                stats,
+                public_outputs,
+                exit_code,
+            }) => {
                 let output = zisk_prover_backend::ExecuteOutput::from_remote(
                     stats.steps,
                     Duration::from_nanos(stats.duration_nanos),
                     domain_stats_to_cost(&stats),
                     &public_outputs,
+                    exit_code,
                 );
                 Ok(crate::execute::ExecuteResult::new(output, Some(job_id)))
             }
@@ -440,3 +497,62 @@ impl FromWaitResult for crate::execute::ExecuteResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zisk_coordinator_api::dto::{
+        DomainJobEventProgress, DomainJobEventQueued, DomainJobEventStarted,
+    };
+
+    /// Events buffered by `subs`, with the phase start times and worker counts cleared.
+    fn pending_events(subs: &SubscriberList) -> Vec<JobEvent> {
+        let bus = subs.lock().unwrap();
+        bus.pending
+            .iter()
+            .map(|event| match event {
+                JobEvent::Phase { phase, .. } => JobEvent::phase(*phase),
+                event => event.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_embedded_and_remote_prove_events_match() {
+        let embedded = new_subscriber_list();
+        fire_event(&embedded, JobEvent::Queued);
+        run_local_prove(&embedded, || Ok(())).unwrap();
+
+        // Events of a remote proving job, as the coordinator streams them
+        let job_id = uuid::Uuid::nil();
+        let timestamp = Default::default();
+        let mut domain_events = vec![
+            DomainJobEvent::Queued(DomainJobEventQueued { job_id, timestamp }),
+            DomainJobEvent::Started(DomainJobEventStarted { job_id, timestamp }),
+        ];
+        domain_events.extend(JobPhase::ALL.iter().map(|phase| {
+            let phase = match phase {
+                JobPhase::Contributions => DomainJobPhase::Contributions,
+                JobPhase::Prove => DomainJobPhase::Prove,
+                JobPhase::Aggregate => DomainJobPhase::Aggregate,
+            };
+            DomainJobEvent::Progress(DomainJobEventProgress {
+                job_id,
+                phase,
+                timestamp,
+                workers: Some(4),
+            })
+        }));
+
+        let remote = new_subscriber_list();
+        for event in &domain_events {
+            assert!(!map_domain_event(&remote, event));
+        }
+        // Fired by `await_remote` from the `WaitJobResult` response
+        fire_event(&remote, JobEvent::Completed);
+
+        let events = pending_events(&embedded);
+        assert_eq!(events, pending_events(&remote));
+        assert_eq!(events[2], JobEvent::phase(JobPhase::Contributions));
+    }
+}
@@ -24,12 +24,12 @@ pub use hints::{HintsSource, ZiskHints};
 pub use input_source::InputSource;
 pub use input_stream::ZiskStream;
 pub use job_handle::JobHandle;
-pub use prove::{JobEvent, ProveRequest, ProveResult};
+pub use prove::{JobEvent, JobFailure, JobPhase, ProveRequest, ProveResult};
 pub use remote::{RemoteClient, RemoteClientBuilder};
 pub use setup::SetupRequest;
 pub use stdin::ZiskStdin;
 pub use upload::UploadRequest;
-pub use verify::VerifyBuilder;
+pub use verify::{BatchVerifyBuilder, VerifyBuilder};
 pub use verify_constraints::{
     VerifyConstraintsExtension, VerifyConstraintsRequest, VerifyConstraintsResult,
 };
@@ -37,24 +37,36 @@ pub use wrap::WrapRequest;
 
 // Re-export guest types from backend (public API for loading programs)
 pub use zisk_prover_backend::{
-    load_program, Asm, AsmOptions, Elf, EmuOptions, GuestProgram, ProfilingMode, ProgramId,
+    load_program, Asm, AsmOptions, Elf, EmuOptions, GuestProgram, HintPluginConfig, ProfilingMode,
+    ProgramId,
 };
 
 pub use opts::EmbeddedOpts;
 
 // Re-export result and data types from backend (public outputs)
-pub use zisk_prover_backend::{setup_logger, ExecuteOutput, ProveOutput, VerifyConstraintsOutput};
+pub use zisk_prover_backend::{
+    setup_logger, BacktraceFrame, ExecuteOutput, GuestPanic, PanicLocation, ProveOutput,
+    VerifyConstraintsOutput,
+};
 
 // Re-export common types
 pub use proofman_common::VerboseMode;
 
 // Re-export types from zisk_common
+pub use zisk_common::io::{
+    input_commitment, rng_seed_commitment, HostEnv, InputCommitmentHash,
+    HOST_ENV_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_OUTPUT_ID, RNG_SEED_COMMITMENT_OUTPUT_ID,
+    RNG_SEED_SIZE,
+};
 pub use zisk_common::{
     PlonkVkBlob, PlonkVkey, ProgramVK, Proof, ProofBody, ProofKind, PublicValues,
 };
 
 pub use zisk_build::*;
 
+/// Computes the verification key of a program from its ELF, without the proving key
+pub use rom_setup::program_vk_from_elf;
+
 use anyhow::Result;
 
 /// Run the ZisK emulator with the given program and stdin.
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use zisk_common::ProofKind;
 use zisk_prover_backend::BackendProverOpts;
 
 /// Public prover configuration for the SDK.
@@ -97,7 +98,7 @@ impl EmbeddedOpts {
         }
 
         if self.preload_plonk {
-            opts = opts.plonk(true);
+            opts = opts.snark(ProofKind::Plonk, true);
         }
 
         if gpu {
@@ -1,6 +1,7 @@
+use std::fmt;
 use std::ops::Deref;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use anyhow::Result;
 use zisk_common::ProofKind;
@@ -39,22 +40,101 @@ impl From<ProveOutput> for ProveResult {
     }
 }
 
-/// Events emitted during proof generation.
+/// Phase of a running proving job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JobPhase {
+    /// Workers execute the program and compute their contributions.
+    Contributions,
+    /// Workers generate the partial proofs.
+    Prove,
+    /// Partial proofs are aggregated into the final proof.
+    Aggregate,
+}
+
+impl JobPhase {
+    /// Phases of a proving job, in the order they are reported.
+    pub const ALL: [JobPhase; 3] = [JobPhase::Contributions, JobPhase::Prove, JobPhase::Aggregate];
+}
+
+/// Reason a job failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobFailure {
+    /// The job exceeded its time limit, in the given phase when known.
+    Timeout { phase: Option<JobPhase>, limit: Duration },
+    /// The job input was rejected.
+    Input { reason: String },
+    /// Execution or proof generation failed.
+    Execution { reason: String },
+    /// The coordinator hit an internal error; `trace_id` identifies it in its logs.
+    Internal { trace_id: String },
+}
+
+impl fmt::Display for JobFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JobFailure::Timeout { phase, limit } => {
+                write!(f, "Timeout (phase: {:?}, limit: {:?})", phase, limit)
+            }
+            JobFailure::Input { reason } => write!(f, "Input error: {}", reason),
+            JobFailure::Execution { reason } => write!(f, "Execution error: {}", reason),
+            JobFailure::Internal { trace_id } => {
+                write!(f, "Internal error (trace_id: {})", trace_id)
+            }
+        }
+    }
+}
+
+/// Events emitted during a job.
 ///
 /// `JobEvent::All` is a subscription filter meaning "receive all events".
 /// It is never emitted as a concrete event in callbacks.
+///
+/// When used as a filter, an event matches by variant: `Phase` filters also
+/// compare the phase (see [`JobEvent::phase`]) and `Failed` filters match any
+/// failure.
 #[derive(Debug, Clone, PartialEq)]
 pub enum JobEvent {
     /// Subscribe to all events (filter only; never emitted to callbacks).
     All,
-    /// Job accepted and execution started.
+    /// Job accepted and waiting to be scheduled.
+    Queued,
+    /// Job scheduled and execution started.
     Started,
-    /// Proof generation progress (0–100).
+    /// Job is waiting for stdin or hints to be streamed in.
+    WaitingForInput,
+    /// Job entered a new phase.
+    ///
+    /// `workers` is the number of workers taking part in the phase, when known.
+    /// Every proving job reports the phases of [`JobPhase::ALL`] in order; the
+    /// embedded client runs them in a single local call, on one worker.
+    Phase { phase: JobPhase, started_at: SystemTime, workers: Option<u32> },
+    /// Proof generation progress (0–100), fired after each `Phase`.
+    #[deprecated(note = "use `JobEvent::Phase`")]
     Progress(u8),
-    /// Proof completed successfully.
+    /// Job completed successfully.
     Completed,
-    /// Proof generation failed.
-    Failed(String),
+    /// Job failed.
+    Failed(JobFailure),
+    /// Job was cancelled.
+    Cancelled,
+}
+
+impl JobEvent {
+    /// Filter matching the start of `phase`.
+    pub fn phase(phase: JobPhase) -> Self {
+        JobEvent::Phase { phase, started_at: SystemTime::UNIX_EPOCH, workers: None }
+    }
+
+    /// Returns `true` if `event` is selected by this event used as a filter.
+    pub fn matches(&self, event: &JobEvent) -> bool {
+        match (self, event) {
+            (JobEvent::All, _) => true,
+            (JobEvent::Phase { phase: filter, .. }, JobEvent::Phase { phase, .. }) => {
+                filter == phase
+            }
+            _ => std::mem::discriminant(self) == std::mem::discriminant(event),
+        }
+    }
 }
 
 /// Builder for a prove request.
@@ -152,3 +232,30 @@ impl<'a, C: Client> ProveRequest<'a, C> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase_event(phase: JobPhase) -> JobEvent {
+        JobEvent::Phase { phase, started_at: SystemTime::now(), workers: Some(4) }
+    }
+
+    #[test]
+    fn test_event_filter_matching() {
+        let failed = JobEvent::Failed(JobFailure::Input { reason: "bad".into() });
+
+        assert!(JobEvent::All.matches(&JobEvent::Started));
+        assert!(JobEvent::All.matches(&failed));
+        assert!(JobEvent::Started.matches(&JobEvent::Started));
+        assert!(!JobEvent::Started.matches(&JobEvent::Queued));
+
+        assert!(JobEvent::phase(JobPhase::Prove).matches(&phase_event(JobPhase::Prove)));
+        assert!(!JobEvent::phase(JobPhase::Prove).matches(&phase_event(JobPhase::Aggregate)));
+        assert!(!JobEvent::phase(JobPhase::Prove).matches(&JobEvent::Completed));
+
+        let any_failure = JobEvent::Failed(JobFailure::Execution { reason: String::new() });
+        assert!(any_failure.matches(&failed));
+        assert!(!any_failure.matches(&JobEvent::Cancelled));
+    }
+}
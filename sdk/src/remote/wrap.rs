@@ -15,7 +15,9 @@ impl RemoteClient {
         timeout: Option<Duration>,
         subs: SubscriberList,
     ) -> Result<JobHandle<ProveResult>> {
-        let data = bincode::serde::encode_to_vec(proof, bincode::config::standard())
+        // Legacy encoding, which older coordinators and workers can decode
+        let data = proof
+            .to_legacy_bytes()
             .map_err(|e| anyhow::anyhow!("failed to serialize proof: {e}"))?;
 
         // Derive a deterministic UUID from the serialized proof bytes so that retrying
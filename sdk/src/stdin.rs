@@ -1,4 +1,5 @@
-use zisk_common::io::ZiskStdin as ZiskStdinInner;
+use zisk_common::io::{HostEnv, InputCommitmentHash, ZiskStdin as ZiskStdinInner, RNG_SEED_SIZE};
+use zisk_common::{ProgramVK, Proof};
 
 use serde::{de::DeserializeOwned, Serialize};
 use std::path::Path;
@@ -46,6 +47,83 @@ impl ZiskStdin {
         self.0.write_slice(data);
     }
 
+    /// Appends a Vadcop final proof, to be verified by the guest with
+    /// `ziskos::recursion::verify_proof`.
+    ///
+    /// # Errors
+    /// Returns an error if the proof is not a Vadcop final proof.
+    pub fn write_proof(&self, proof: &Proof) -> anyhow::Result<()> {
+        self.0.write_proof(proof)
+    }
+
+    /// Appends a program VK, read by the guest as a `ziskos::recursion::ProgramVk`.
+    ///
+    /// # Errors
+    /// Returns an error if the VK does not have the expected length.
+    pub fn write_program_vk(&self, program_vk: &ProgramVK) -> anyhow::Result<()> {
+        self.0.write_program_vk(program_vk)
+    }
+
+    /// Appends the Vadcop final VK `proof` was generated with, read by the guest as a
+    /// `ziskos::recursion::VadcopVk`.
+    ///
+    /// # Errors
+    /// Returns an error if the proof is not a Vadcop final proof.
+    pub fn write_vadcop_vk(&self, proof: &Proof) -> anyhow::Result<()> {
+        self.0.write_vadcop_vk(proof)
+    }
+
+    /// Appends a serialized value as a public input, for guests built with the `input-commitment`
+    /// feature of `ziskos`. The guest reads it as any other input and commits to it in
+    /// [`PublicValues::input_commitment`](crate::PublicValues::input_commitment).
+    pub fn write_public<T: Serialize>(&self, data: &T) {
+        self.0.write_public(data);
+    }
+
+    /// Appends raw bytes as a public input, see [`write_public`](Self::write_public).
+    pub fn write_public_slice(&self, data: &[u8]) {
+        self.0.write_public_slice(data);
+    }
+
+    /// Returns the public inputs, in order.
+    pub fn public_inputs(&self) -> Vec<Vec<u8>> {
+        self.0.public_inputs()
+    }
+
+    /// Returns the input commitment of a guest that reads all the inputs.
+    pub fn input_commitment(&self, hash: InputCommitmentHash) -> [u8; 32] {
+        self.0.input_commitment(hash)
+    }
+
+    /// Sets the seed of the guest random number generator, for guests built with the
+    /// `host-rng-seed` feature of `ziskos`, replacing the previous one. The seed is inserted
+    /// before the other inputs and its
+    /// SHA-256 hash is committed by the guest to the public output slots starting at
+    /// [`RNG_SEED_COMMITMENT_OUTPUT_ID`](crate::RNG_SEED_COMMITMENT_OUTPUT_ID). The clones of
+    /// `self` are not changed.
+    #[must_use]
+    pub fn with_rng_seed(self, seed: [u8; RNG_SEED_SIZE]) -> Self {
+        Self(self.0.with_rng_seed(seed))
+    }
+
+    /// Sets the time, environment variables and read-only files of the guest, for guests built
+    /// with the `host-env` feature of `ziskos`, replacing the previous ones. The environment is
+    /// inserted before the other inputs, after the RNG seed set with
+    /// [`with_rng_seed`](Self::with_rng_seed) in either call order, and its
+    /// [`HostEnv::commitment`] is committed by the guest to the public output slots starting at
+    /// [`HOST_ENV_COMMITMENT_OUTPUT_ID`](crate::HOST_ENV_COMMITMENT_OUTPUT_ID). The clones of
+    /// `self` are not changed.
+    #[must_use]
+    pub fn with_host_env(self, env: &HostEnv) -> Self {
+        Self(self.0.with_host_env(env))
+    }
+
+    /// Returns a copy with its own buffer and read position. Unlike [`Clone::clone`], which
+    /// shares them, writes to the copy do not change `self`.
+    pub fn deep_clone(&self) -> Self {
+        Self(self.0.deep_clone())
+    }
+
     /// Saves the stdin buffer contents to a file.
     pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
         self.0.save(path.as_ref())
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use rayon::prelude::*;
 use zisk_common::{ProgramVK, Proof, PublicValues};
 
 /// Builder for proof verification with externally-supplied overrides.
@@ -9,6 +10,11 @@ pub struct VerifyBuilder<'a> {
 }
 
 impl<'a> VerifyBuilder<'a> {
+    /// Start the verification of several proofs at once, see [`BatchVerifyBuilder`].
+    pub fn batch(proofs: &'a [Proof]) -> BatchVerifyBuilder<'a> {
+        BatchVerifyBuilder::new(proofs)
+    }
+
     /// Override the public values embedded in the proof.
     #[must_use]
     pub fn with_publics(mut self, pv: &'a PublicValues) -> Self {
@@ -33,3 +39,79 @@ impl<'a> VerifyBuilder<'a> {
         }
     }
 }
+
+/// Builder for the verification of several proofs at once.
+///
+/// Obtain via [`VerifyBuilder::batch`]. Every proof goes through the same checks as
+/// [`Proof::verify`], in parallel. No key state is shared between proofs: the Vadcop verifier of
+/// proofman takes the raw verification key words and exposes no parsed key to reuse, and each
+/// SNARK proof carries its own verification key.
+pub struct BatchVerifyBuilder<'a> {
+    proofs: &'a [Proof],
+    program_vk: Option<&'a ProgramVK>,
+}
+
+impl<'a> BatchVerifyBuilder<'a> {
+    pub(crate) fn new(proofs: &'a [Proof]) -> Self {
+        Self { proofs, program_vk: None }
+    }
+
+    /// Verify every proof against this verification key instead of the one it embeds.
+    #[must_use]
+    pub fn with_program_vk(mut self, vk: &'a ProgramVK) -> Self {
+        self.program_vk = Some(vk);
+        self
+    }
+
+    /// Run the verification, returning one result per proof in the same order.
+    pub fn verify(self) -> Vec<Result<()>> {
+        self.proofs
+            .par_iter()
+            .enumerate()
+            .map(|(index, proof)| {
+                match self.program_vk {
+                    Some(vk) => proof.with_program_vk(vk).verify(),
+                    None => proof.verify(),
+                }
+                .map_err(|e| anyhow::anyhow!("Proof #{index} verification failed: {e}"))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zisk_common::{ProofBody, PROGRAM_VK_LEN};
+
+    fn malformed(minimal: bool) -> Proof {
+        Proof::new(
+            ProofBody::Vadcop { proof: vec![0; 3], zisk_vk: vec![0; PROGRAM_VK_LEN], minimal },
+            PublicValues::new_empty(),
+            ProgramVK::new_empty(),
+        )
+    }
+
+    #[test]
+    fn batch_runs_the_single_proof_checks() {
+        let proofs = [malformed(false), malformed(true)];
+        let results = VerifyBuilder::batch(&proofs).verify();
+
+        assert_eq!(results.len(), proofs.len());
+        for (index, (proof, result)) in proofs.iter().zip(results).enumerate() {
+            let single = proof.verify().unwrap_err();
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                format!("Proof #{index} verification failed: {single}")
+            );
+        }
+    }
+
+    #[test]
+    fn batch_of_nothing_is_empty() {
+        assert!(VerifyBuilder::batch(&[])
+            .with_program_vk(&ProgramVK::new_empty())
+            .verify()
+            .is_empty());
+    }
+}
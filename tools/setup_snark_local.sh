@@ -0,0 +1,72 @@
+#!/bin/bash
+
+set -e
+
+echo "Generate a local SNARK wrapper setup for testing"
+
+# Check that the pil2-proofman-js and pil2-proofman directories have been passed
+if [ "$#" -lt 2 ]; then
+    echo "Usage: $0 <pil2-proofman-js dir> <pil2-proofman dir> [-n/--protocol plonk|groth16 -p/--power <power> -d/--debug]"
+    echo "Must be run from the ZisK repository, after the build/provingKey setup has been generated"
+    exit 1
+fi
+
+PROOFMAN_JS_DIR="$1"
+PROOFMAN_DIR="$2"
+shift 2
+
+# Parse optional arguments
+PROTOCOL=plonk
+POWER=24
+DEBUG=0
+while [[ "$#" -gt 0 ]]; do
+    case $1 in
+        -n|--protocol) PROTOCOL=$2; shift; ;;
+        -p|--power) POWER=$2; shift; ;;
+        -d|--debug) DEBUG=1 ;;
+        *) echo "Unknown parameter passed: $1"; exit 1 ;;
+    esac
+    shift
+done
+
+if [ $DEBUG -eq 1 ]; then
+    echo "Debug mode enabled";
+    set -x;  # Enable debugging output
+else
+    set +x;  # Disable debugging output
+fi
+
+case $PROTOCOL in
+    plonk) VERIFIER_NAME=PlonkVerifier ;;
+    groth16) VERIFIER_NAME=Groth16Verifier ;;
+    *) echo "Unknown protocol: ${PROTOCOL}"; exit 1 ;;
+esac
+
+SNARKJS="node ${PROOFMAN_JS_DIR}/node_modules/snarkjs/cli.js"
+PTAU_DIR="build/ptau_local"
+
+# Powers of tau with a single contribution of fixed entropy. It is NOT a trusted setup and the
+# resulting keys must only be used for testing.
+echo "Generating local powers of tau of power ${POWER}..."
+mkdir -p $PTAU_DIR
+$SNARKJS powersoftau new bn128 $POWER $PTAU_DIR/pot_0000.ptau
+$SNARKJS powersoftau contribute $PTAU_DIR/pot_0000.ptau $PTAU_DIR/pot_0001.ptau \
+    --name="ZisK local test setup" -e="zisk local test setup"
+$SNARKJS powersoftau prepare phase2 $PTAU_DIR/pot_0001.ptau $PTAU_DIR/pot_final.ptau
+
+echo "Generating ${PROTOCOL} SNARK wrapper setup..."
+node --max-old-space-size=16384 --stack-size=8192 ${PROOFMAN_JS_DIR}/src/main_setup_snark.js \
+    -b build -t ${PROOFMAN_DIR}/pil2-components/lib/std/pil -f -w $PTAU_DIR/pot_final.ptau \
+    -p ./state-machines/publics.json -n $PROTOCOL
+
+ZKEY=`find build/provingKeySnark/final -maxdepth 1 -name "*.zkey" | head -n 1`
+if [ -z "$ZKEY" ]; then
+    echo "No zkey found under build/provingKeySnark/final"
+    exit 1
+fi
+
+echo "Exporting ${VERIFIER_NAME}.sol..."
+$SNARKJS zkey export solidityverifier $ZKEY build/provingKeySnark/final/${VERIFIER_NAME}.sol
+
+echo "Local ${PROTOCOL} setup stored under the build/provingKeySnark directory"
+echo "build/provingKeySnark/final/${VERIFIER_NAME}.sol is for tests only, never deploy it with the zisk-contracts"
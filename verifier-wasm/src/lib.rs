@@ -0,0 +1,160 @@
+//! WebAssembly bindings of `zisk-verifier`, to verify `VadcopFinal` and minimal proofs in
+//! browsers and edge workers.
+//!
+//! A proof is passed as the bytes returned by `Proof::get_proof_bytes`: little-endian `u64`
+//! words laid out as `[minimal][n_publics][program_vk][publics][proof][vadcop_vk]`.
+//! Verification keys are 32 bytes, their words big-endian as in the `bytes32` arguments of the
+//! Solidity verifier.
+//!
+//! Build the package with `wasm-pack build verifier-wasm --profile wasm-release` and test it
+//! headlessly with `wasm-pack test --node verifier-wasm`.
+//!
+//! ```js
+//! import { verify } from "zisk-verifier-wasm";
+//!
+//! const proof = verify(proofBytes, vadcopVk);
+//! if (!equal(proof.programVk, expectedProgramVk)) throw new Error("unexpected program");
+//! ```
+
+use wasm_bindgen::prelude::*;
+use zisk_verifier::{
+    try_verify_vadcop_final_proof, PROGRAM_VK_LEN, VADCOP_VK_LEN_WORDS, ZISK_PUBLICS,
+};
+
+/// Size in bytes of the public outputs of a proof
+const PUBLICS_SIZE: usize = ZISK_PUBLICS * 4;
+
+/// Offset in words of the program VK, after `minimal` and `n_publics`
+const PROGRAM_VK_OFFSET: usize = 2;
+
+/// Offset in words of the public outputs
+const PUBLICS_OFFSET: usize = PROGRAM_VK_OFFSET + PROGRAM_VK_LEN;
+
+/// Public part of a decoded proof
+#[wasm_bindgen]
+pub struct ZiskProof {
+    minimal: bool,
+    program_vk: [u64; PROGRAM_VK_LEN],
+    publics: Vec<u8>,
+    vadcop_vk: [u64; VADCOP_VK_LEN_WORDS],
+}
+
+#[wasm_bindgen]
+impl ZiskProof {
+    /// Whether the proof is a minimal (compressed) Vadcop final proof
+    #[wasm_bindgen(getter)]
+    pub fn minimal(&self) -> bool {
+        self.minimal
+    }
+
+    /// Verification key of the program that generated the proof, 32 bytes
+    #[wasm_bindgen(getter, js_name = programVk)]
+    pub fn program_vk(&self) -> Vec<u8> {
+        words_to_bytes(&self.program_vk)
+    }
+
+    /// Raw public outputs of the proof, 256 bytes
+    #[wasm_bindgen(getter)]
+    pub fn publics(&self) -> Vec<u8> {
+        self.publics.clone()
+    }
+
+    /// Vadcop final verification key appended to the proof, 32 bytes. It is not trusted by
+    /// [`verify`], which takes the key to verify against as an argument.
+    #[wasm_bindgen(getter, js_name = vadcopVk)]
+    pub fn vadcop_vk(&self) -> Vec<u8> {
+        words_to_bytes(&self.vadcop_vk)
+    }
+}
+
+/// Decodes the public part of a proof, without verifying it
+#[wasm_bindgen(js_name = decodeProof)]
+pub fn decode_proof(proof_bytes: &[u8]) -> Result<ZiskProof, JsError> {
+    decode_words(proof_bytes).and_then(|words| decode(&words))
+}
+
+/// Verifies a proof against the Vadcop final verification key `vk` and returns its decoded
+/// public part.
+///
+/// If `publics` is given, the public outputs of the proof must match it, zero-padded to 256
+/// bytes as the outputs committed by the guest are. The caller still has to check that
+/// `programVk` of the result is the VK of the expected program.
+#[wasm_bindgen]
+pub fn verify(
+    proof_bytes: &[u8],
+    vk: &[u8],
+    publics: Option<Vec<u8>>,
+) -> Result<ZiskProof, JsError> {
+    let words = decode_words(proof_bytes)?;
+    let proof = decode(&words)?;
+    let vk = bytes_to_words::<VADCOP_VK_LEN_WORDS>(vk)
+        .ok_or_else(|| JsError::new("verification key must be 32 bytes"))?;
+
+    if let Some(mut expected) = publics {
+        if expected.len() > PUBLICS_SIZE {
+            return Err(JsError::new("public outputs must be at most 256 bytes"));
+        }
+        expected.resize(PUBLICS_SIZE, 0);
+        if expected != proof.publics {
+            return Err(JsError::new("public outputs do not match the proof"));
+        }
+    }
+
+    let zisk_proof = &words[..words.len() - VADCOP_VK_LEN_WORDS];
+    try_verify_vadcop_final_proof(zisk_proof, &vk).map_err(|e| JsError::new(&e.to_string()))?;
+    Ok(proof)
+}
+
+fn decode_words(proof_bytes: &[u8]) -> Result<Vec<u64>, JsError> {
+    if proof_bytes.len() % 8 != 0 {
+        return Err(JsError::new("proof length is not a multiple of 8 bytes"));
+    }
+    Ok(proof_bytes
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+fn decode(words: &[u64]) -> Result<ZiskProof, JsError> {
+    let publics_end = PUBLICS_OFFSET + ZISK_PUBLICS;
+    if words.len() < publics_end + VADCOP_VK_LEN_WORDS {
+        return Err(JsError::new("proof is too short"));
+    }
+    if words[1] != (PROGRAM_VK_LEN + ZISK_PUBLICS) as u64 {
+        return Err(JsError::new(&format!(
+            "proof declares {} publics, expected {}",
+            words[1],
+            PROGRAM_VK_LEN + ZISK_PUBLICS
+        )));
+    }
+
+    // Each public output is a u32 widened to a u64 word
+    let mut publics = Vec::with_capacity(PUBLICS_SIZE);
+    for word in &words[PUBLICS_OFFSET..publics_end] {
+        let word = u32::try_from(*word)
+            .map_err(|_| JsError::new("public output does not fit in 32 bits"))?;
+        publics.extend_from_slice(&word.to_le_bytes());
+    }
+
+    Ok(ZiskProof {
+        minimal: words[0] == 1,
+        program_vk: words[PROGRAM_VK_OFFSET..PUBLICS_OFFSET].try_into().unwrap(),
+        publics,
+        vadcop_vk: words[words.len() - VADCOP_VK_LEN_WORDS..].try_into().unwrap(),
+    })
+}
+
+fn words_to_bytes(words: &[u64]) -> Vec<u8> {
+    words.iter().flat_map(|w| w.to_be_bytes()).collect()
+}
+
+fn bytes_to_words<const N: usize>(bytes: &[u8]) -> Option<[u64; N]> {
+    if bytes.len() != N * 8 {
+        return None;
+    }
+    let mut words = [0u64; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+        *word = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    Some(words)
+}
@@ -0,0 +1,101 @@
+//! Run with `wasm-pack test --node verifier-wasm`
+
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use zisk_verifier::{PROGRAM_VK_LEN, ZISK_PUBLICS};
+use zisk_verifier_wasm::{decode_proof, verify};
+
+const VADCOP_VK: [u8; 32] = {
+    let mut vk = [0u8; 32];
+    vk[7] = 5;
+    vk[15] = 6;
+    vk[23] = 7;
+    vk[31] = 8;
+    vk
+};
+
+#[wasm_bindgen(module = "fs")]
+extern "C" {
+    #[wasm_bindgen(js_name = readFileSync)]
+    fn read_file_sync(path: &str) -> Vec<u8>;
+}
+
+/// File of `tests/fixtures`, see its README
+fn fixture(name: &str) -> Vec<u8> {
+    read_file_sync(&format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR")))
+}
+
+/// Proof bytes with the expected layout and a dummy proof body
+fn proof_bytes(n_publics: u64) -> Vec<u8> {
+    let mut words = vec![1, n_publics, 1, 2, 3, 4];
+    words.extend((0..ZISK_PUBLICS as u64).map(|i| i + 100));
+    words.extend([0; 16]);
+    words.extend([5, 6, 7, 8]);
+    words.iter().flat_map(|w| w.to_le_bytes()).collect()
+}
+
+fn valid_proof_bytes() -> Vec<u8> {
+    proof_bytes((PROGRAM_VK_LEN + ZISK_PUBLICS) as u64)
+}
+
+#[wasm_bindgen_test]
+fn decode_proof_reads_public_part() {
+    let proof = decode_proof(&valid_proof_bytes()).unwrap();
+    assert!(proof.minimal());
+
+    let mut program_vk = [0u8; 32];
+    for (i, word) in [1u64, 2, 3, 4].iter().enumerate() {
+        program_vk[i * 8..(i + 1) * 8].copy_from_slice(&word.to_be_bytes());
+    }
+    assert_eq!(proof.program_vk(), program_vk);
+    assert_eq!(proof.vadcop_vk(), VADCOP_VK);
+
+    let publics = proof.publics();
+    assert_eq!(publics.len(), ZISK_PUBLICS * 4);
+    assert_eq!(publics[..8], [100, 0, 0, 0, 101, 0, 0, 0]);
+}
+
+#[wasm_bindgen_test]
+fn decode_proof_rejects_malformed_bytes() {
+    let bytes = valid_proof_bytes();
+    assert!(decode_proof(&bytes[1..]).is_err());
+    assert!(decode_proof(&bytes[..8 * (2 + PROGRAM_VK_LEN)]).is_err());
+    assert!(decode_proof(&proof_bytes(ZISK_PUBLICS as u64)).is_err());
+
+    let mut wide_public = bytes.clone();
+    wide_public[8 * (2 + PROGRAM_VK_LEN) + 4] = 1;
+    assert!(decode_proof(&wide_public).is_err());
+}
+
+#[wasm_bindgen_test]
+fn verify_rejects_invalid_vk() {
+    assert!(verify(&valid_proof_bytes(), &VADCOP_VK[1..], None).is_err());
+}
+
+#[wasm_bindgen_test]
+fn verify_rejects_other_publics() {
+    let bytes = valid_proof_bytes();
+    assert!(verify(&bytes, &VADCOP_VK, Some(vec![0; ZISK_PUBLICS * 4])).is_err());
+    assert!(verify(&bytes, &VADCOP_VK, Some(vec![100; ZISK_PUBLICS * 4 + 1])).is_err());
+}
+
+#[wasm_bindgen_test]
+#[ignore = "needs the proof fixture of tests/fixtures"]
+fn verify_accepts_real_proof() {
+    let proof = fixture("vadcop_final_minimal.bin");
+    let vk = fixture("vadcop_final_minimal.vk");
+
+    let decoded = verify(&proof, &vk, None).unwrap();
+    assert!(decoded.minimal());
+    assert_eq!(decoded.vadcop_vk(), vk);
+    assert!(verify(&proof, &vk, Some(decoded.publics())).is_ok());
+
+    // Flipping one bit of a public output or of the proof body makes it invalid
+    for word in [2 + PROGRAM_VK_LEN, 2 + PROGRAM_VK_LEN + ZISK_PUBLICS] {
+        let mut flipped = proof.clone();
+        flipped[word * 8] ^= 1;
+        assert!(verify(&flipped, &vk, None).is_err());
+    }
+}
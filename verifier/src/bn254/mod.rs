@@ -0,0 +1,194 @@
+//! BN254 types and helpers shared by the SNARK verifiers.
+//!
+//! Points and scalars use the Ethereum precompile encoding, the same as the calldata of the
+//! generated Solidity verifiers.
+
+// The generators and Keccak-256 are only needed by the Plonk verifier
+#![cfg_attr(not(feature = "plonk"), allow(dead_code))]
+
+pub(crate) mod ops;
+
+use alloc::vec::Vec;
+
+use ark_bn254::Fr;
+use ark_ff::{BigInt, BigInteger, PrimeField};
+
+use crate::VerifyError;
+
+/// G1 point, as big-endian `x || y`
+pub type G1Point = [u8; 64];
+
+/// G2 point, as big-endian `x.imaginary || x.real || y.imaginary || y.real`
+pub type G2Point = [u8; 128];
+
+/// Generator of G1
+pub(crate) const G1_GENERATOR: G1Point = {
+    let mut p = [0u8; 64];
+    p[31] = 1;
+    p[63] = 2;
+    p
+};
+
+/// Generator of G2
+pub(crate) const G2_GENERATOR: G2Point = g2_from_hex([
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+]);
+
+/// Parses a decimal number below 2^256 into 32 big-endian bytes.
+pub fn parse_decimal(s: &str) -> Option<[u8; 32]> {
+    if s.is_empty() {
+        return None;
+    }
+    let mut limbs = [0u64; 4];
+    for c in s.bytes() {
+        let digit = (c as char).to_digit(10)? as u128;
+        let mut carry = digit;
+        for limb in limbs.iter_mut() {
+            let v = (*limb as u128) * 10 + carry;
+            *limb = v as u64;
+            carry = v >> 64;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+    let mut out = [0u8; 32];
+    for (chunk, limb) in out.chunks_exact_mut(8).zip(limbs.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    Some(out)
+}
+
+/// Public input of a SNARK-wrapped ZisK proof: the SHA-256 hash of
+/// `program_vk || publics || vadcop_vk`, reduced modulo the scalar field order, where the
+/// verification keys are big-endian `u64` words.
+pub fn zisk_snark_public_input(program_vk: &[u64], publics: &[u8], vadcop_vk: &[u64]) -> [u8; 32] {
+    let mut data = Vec::with_capacity((program_vk.len() + vadcop_vk.len()) * 8 + publics.len());
+    data.extend(program_vk.iter().flat_map(|w| w.to_be_bytes()));
+    data.extend_from_slice(publics);
+    data.extend(vadcop_vk.iter().flat_map(|w| w.to_be_bytes()));
+    fr_bytes(&Fr::from_be_bytes_mod_order(&ops::sha256(&data)))
+}
+
+/// G1 point of a proof, `None` if it is not on the curve or is the identity
+pub(crate) fn g1_from_bytes(bytes: &[u8]) -> Option<G1Point> {
+    let p: G1Point = bytes.try_into().ok()?;
+    // Adding the identity validates the point without changing it
+    (ops::g1_add(&p, &[0; 64])? == p && p != [0; 64]).then_some(p)
+}
+
+/// Sum of `scalar * point`
+pub(crate) fn msm(terms: &[(&G1Point, Fr)]) -> Result<G1Point, VerifyError> {
+    let mut acc = [0u8; 64];
+    for (point, scalar) in terms {
+        let product = ops::g1_mul(point, &fr_bytes(scalar)).ok_or(VerifyError::MalformedProof)?;
+        acc = ops::g1_add(&acc, &product).ok_or(VerifyError::MalformedProof)?;
+    }
+    Ok(acc)
+}
+
+pub(crate) fn bigint_be(bytes: &[u8; 32]) -> BigInt<4> {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    BigInt::new(limbs)
+}
+
+/// Scalar from big-endian bytes, `None` if not below the field order
+pub(crate) fn fr_from_bytes(bytes: &[u8; 32]) -> Option<Fr> {
+    Fr::from_bigint(bigint_be(bytes))
+}
+
+pub(crate) fn fr_bytes(value: &Fr) -> [u8; 32] {
+    let bytes = value.into_bigint().to_bytes_be();
+    bytes.try_into().unwrap()
+}
+
+const fn g2_from_hex(words: [&str; 4]) -> G2Point {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+    let mut out = [0u8; 128];
+    let mut i = 0;
+    while i < 128 {
+        let word = words[i / 32].as_bytes();
+        let j = (i % 32) * 2;
+        out[i] = (nibble(word[j]) << 4) | nibble(word[j + 1]);
+        i += 1;
+    }
+    out
+}
+
+/// Points and scalars for the verifier tests, which build their keys and proofs from known
+/// setup trapdoors instead of running a prover
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use ark_bn254::G2Projective;
+    use ark_ec::{AffineRepr, CurveGroup, PrimeGroup};
+
+    pub const PROGRAM_VK: [u64; 4] = [1, 2, 3, 4];
+    pub const VADCOP_VK: [u64; 4] = [5, 6, 7, 8];
+    pub const PUBLICS: [u8; 256] = [7; 256];
+
+    pub fn scalar(seed: u8) -> Fr {
+        Fr::from_be_bytes_mod_order(&ops::sha256(&[seed]))
+    }
+
+    pub fn g1(s: Fr) -> G1Point {
+        ops::g1_mul(&G1_GENERATOR, &fr_bytes(&s)).unwrap()
+    }
+
+    pub fn g2(s: Fr) -> G2Point {
+        let p = (G2Projective::generator() * s).into_affine();
+        let (x, y) = p.xy().unwrap();
+        let mut out = [0u8; 128];
+        for (chunk, c) in out.chunks_exact_mut(32).zip([x.c1, x.c0, y.c1, y.c0]) {
+            chunk.copy_from_slice(&c.into_bigint().to_bytes_be());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::*;
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn g2_generator_matches_arkworks() {
+        assert_eq!(G2_GENERATOR, g2(Fr::one()));
+    }
+
+    #[test]
+    fn parse_decimal_values() {
+        let mut seven = [0u8; 32];
+        seven[31] = 7;
+        assert_eq!(parse_decimal("7"), Some(seven));
+        // 2^200
+        let mut pow = [0u8; 32];
+        pow[6] = 1;
+        assert_eq!(
+            parse_decimal("1606938044258990275541962092341162602522202993782792835301376"),
+            Some(pow)
+        );
+        assert_eq!(parse_decimal(""), None);
+        assert_eq!(parse_decimal("12a"), None);
+        // 2^256
+        assert_eq!(
+            parse_decimal(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            ),
+            None
+        );
+    }
+}
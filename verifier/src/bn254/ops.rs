@@ -0,0 +1,134 @@
+//! Curve and hash operations of the SNARK verifiers.
+//!
+//! Points and scalars use the Ethereum precompile encoding: big-endian coordinates, the G1
+//! identity as 64 zero bytes and G2 coordinates as `imaginary || real`. Inside a ZisK guest the
+//! operations run on the zkVM accelerators, elsewhere on arkworks.
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+mod imp {
+    use crate::bn254::{G1Point, G2Point};
+    use zkvm_interface::{
+        zkvm_bn254_g1_add, zkvm_bn254_g1_mul, zkvm_bn254_g1_point, zkvm_bn254_pairing,
+        zkvm_bn254_pairing_pair, zkvm_bn254_scalar, zkvm_keccak256, zkvm_keccak256_hash,
+        zkvm_sha256, zkvm_sha256_hash, zkvm_status_ZKVM_EOK as ZKVM_EOK,
+    };
+
+    pub fn g1_add(p1: &G1Point, p2: &G1Point) -> Option<G1Point> {
+        let p1 = zkvm_bn254_g1_point { data: *p1 };
+        let p2 = zkvm_bn254_g1_point { data: *p2 };
+        let mut result = zkvm_bn254_g1_point { data: [0; 64] };
+        let status = unsafe { zkvm_bn254_g1_add(&p1, &p2, &mut result) };
+        (status == ZKVM_EOK).then_some(result.data)
+    }
+
+    pub fn g1_mul(p: &G1Point, k: &[u8; 32]) -> Option<G1Point> {
+        let p = zkvm_bn254_g1_point { data: *p };
+        let k = zkvm_bn254_scalar { data: *k };
+        let mut result = zkvm_bn254_g1_point { data: [0; 64] };
+        let status = unsafe { zkvm_bn254_g1_mul(&p, &k, &mut result) };
+        (status == ZKVM_EOK).then_some(result.data)
+    }
+
+    pub fn pairing_check(pairs: &[(G1Point, G2Point)]) -> Option<bool> {
+        let pairs: alloc::vec::Vec<zkvm_bn254_pairing_pair> = pairs
+            .iter()
+            .map(|(g1, g2)| zkvm_bn254_pairing_pair {
+                g1: zkvm_bn254_g1_point { data: *g1 },
+                g2: zkvm_interface::zkvm_bn254_g2_point { data: *g2 },
+            })
+            .collect();
+        let mut verified = false;
+        let status = unsafe { zkvm_bn254_pairing(pairs.as_ptr(), pairs.len(), &mut verified) };
+        (status == ZKVM_EOK).then_some(verified)
+    }
+
+    pub fn keccak256(data: &[u8]) -> [u8; 32] {
+        let mut output = zkvm_keccak256_hash { data: [0; 32] };
+        unsafe { zkvm_keccak256(data.as_ptr(), data.len(), &mut output) };
+        output.data
+    }
+
+    pub fn sha256(data: &[u8]) -> [u8; 32] {
+        let mut output = zkvm_sha256_hash { data: [0; 32] };
+        unsafe { zkvm_sha256(data.as_ptr(), data.len(), &mut output) };
+        output.data
+    }
+}
+
+#[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+mod imp {
+    use crate::bn254::{G1Point, G2Point};
+    use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+    use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+    use ark_ff::{BigInteger, PrimeField, Zero};
+    use sha2::Digest;
+    use tiny_keccak::{Hasher, Keccak};
+
+    fn fq(bytes: &[u8]) -> Option<Fq> {
+        Fq::from_bigint(crate::bn254::bigint_be(bytes.try_into().unwrap()))
+    }
+
+    fn fq_bytes(value: &Fq, out: &mut [u8]) {
+        out.copy_from_slice(&value.into_bigint().to_bytes_be());
+    }
+
+    fn g1(p: &G1Point) -> Option<G1Affine> {
+        if p.iter().all(|b| *b == 0) {
+            return Some(G1Affine::zero());
+        }
+        let point = G1Affine::new_unchecked(fq(&p[..32])?, fq(&p[32..])?);
+        point.is_on_curve().then_some(point)
+    }
+
+    fn g1_bytes(p: G1Affine) -> G1Point {
+        let mut out = [0u8; 64];
+        if let Some((x, y)) = p.xy() {
+            fq_bytes(&x, &mut out[..32]);
+            fq_bytes(&y, &mut out[32..]);
+        }
+        out
+    }
+
+    fn g2(p: &G2Point) -> Option<G2Affine> {
+        if p.iter().all(|b| *b == 0) {
+            return Some(G2Affine::zero());
+        }
+        let x = Fq2::new(fq(&p[32..64])?, fq(&p[..32])?);
+        let y = Fq2::new(fq(&p[96..])?, fq(&p[64..96])?);
+        let point = G2Affine::new_unchecked(x, y);
+        (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+    }
+
+    pub fn g1_add(p1: &G1Point, p2: &G1Point) -> Option<G1Point> {
+        Some(g1_bytes((g1(p1)? + g1(p2)?).into_affine()))
+    }
+
+    pub fn g1_mul(p: &G1Point, k: &[u8; 32]) -> Option<G1Point> {
+        let k = Fr::from_be_bytes_mod_order(k);
+        Some(g1_bytes((g1(p)? * k).into_affine()))
+    }
+
+    pub fn pairing_check(pairs: &[(G1Point, G2Point)]) -> Option<bool> {
+        let mut g1s = alloc::vec::Vec::with_capacity(pairs.len());
+        let mut g2s = alloc::vec::Vec::with_capacity(pairs.len());
+        for (p, q) in pairs {
+            g1s.push(g1(p)?);
+            g2s.push(g2(q)?);
+        }
+        Some(Bn254::multi_pairing(g1s, g2s).is_zero())
+    }
+
+    pub fn keccak256(data: &[u8]) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        let mut keccak = Keccak::v256();
+        keccak.update(data);
+        keccak.finalize(&mut output);
+        output
+    }
+
+    pub fn sha256(data: &[u8]) -> [u8; 32] {
+        sha2::Sha256::digest(data).into()
+    }
+}
+
+pub(crate) use imp::*;
@@ -0,0 +1,321 @@
+//! BN254 Groth16 verifier for SNARK-wrapped ZisK proofs.
+//!
+//! Follows the snarkJS Groth16 verifier and takes the proof in the calldata layout of the
+//! generated Solidity verifier, so a proof accepted here is accepted on-chain. The verification
+//! key is held in memory, without touching the filesystem.
+
+use alloc::vec::Vec;
+
+use ark_bn254::Fr;
+use ark_ff::One;
+
+use crate::bn254::{
+    fr_from_bytes, g1_from_bytes, msm, ops, zisk_snark_public_input, G1Point, G2Point,
+};
+use crate::VerifyError;
+
+/// Size in bytes of a serialized Groth16 proof: the G1 points `A` and `C` and the G2 point `B`.
+pub const GROTH16_PROOF_SIZE: usize = 8 * 32;
+
+/// Groth16 verification key, see `Groth16Vkey` in `zisk-common` for its JSON form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Groth16VerifyingKey {
+    pub alpha_1: G1Point,
+    pub beta_2: G2Point,
+    pub gamma_2: G2Point,
+    pub delta_2: G2Point,
+    /// Commitments to the public inputs, the first one for the constant term
+    pub ic: Vec<G1Point>,
+}
+
+/// Verify a SNARK-wrapped ZisK proof against its program VK, public outputs and Vadcop final
+/// verification key.
+pub fn verify_zisk_groth16_proof(
+    vk: &Groth16VerifyingKey,
+    proof: &[u8],
+    program_vk: &[u64],
+    publics: &[u8],
+    vadcop_vk: &[u64],
+) -> Result<(), VerifyError> {
+    verify_groth16_proof(vk, proof, &[zisk_snark_public_input(program_vk, publics, vadcop_vk)])
+}
+
+/// Verify a Groth16 proof of [`GROTH16_PROOF_SIZE`] bytes, laid out as the `(pA, pB, pC)`
+/// arguments of the Solidity verifier, against big-endian public inputs.
+pub fn verify_groth16_proof(
+    vk: &Groth16VerifyingKey,
+    proof: &[u8],
+    public_inputs: &[[u8; 32]],
+) -> Result<(), VerifyError> {
+    // As in the Solidity verifier, other public inputs than those of the key fail verification
+    if public_inputs.len() + 1 != vk.ic.len() {
+        return Err(VerifyError::Rejected);
+    }
+    if proof.len() != GROTH16_PROOF_SIZE {
+        return Err(VerifyError::MalformedProof);
+    }
+    let a = g1_from_bytes(&proof[..64]).ok_or(VerifyError::MalformedProof)?;
+    let b: G2Point = proof[64..192].try_into().unwrap();
+    let c = g1_from_bytes(&proof[192..]).ok_or(VerifyError::MalformedProof)?;
+
+    // vk_x = IC_0 + sum(public_i * IC_{i+1})
+    let mut terms = Vec::with_capacity(vk.ic.len());
+    terms.push((&vk.ic[0], Fr::one()));
+    for (public, ic) in public_inputs.iter().zip(&vk.ic[1..]) {
+        terms.push((ic, fr_from_bytes(public).ok_or(VerifyError::MalformedProof)?));
+    }
+    let vk_x = msm(&terms)?;
+    let neg_a = msm(&[(&a, -Fr::one())])?;
+
+    // e(-A, B) e(alpha, beta) e(vk_x, gamma) e(C, delta) == 1
+    match ops::pairing_check(&[
+        (neg_a, b),
+        (vk.alpha_1, vk.beta_2),
+        (vk_x, vk.gamma_2),
+        (c, vk.delta_2),
+    ]) {
+        Some(true) => Ok(()),
+        Some(false) => Err(VerifyError::Rejected),
+        // B is the only point that was not validated, the key comes from the setup
+        None => Err(VerifyError::MalformedProof),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::testing::*;
+    use crate::bn254::G2_GENERATOR;
+    use crate::parse_decimal;
+    use alloc::vec;
+
+    /// Builds a key and a proof that verifies, using the trapdoor of the setup to compute `C`
+    /// instead of running a prover.
+    fn trapdoor_proof() -> (Groth16VerifyingKey, Vec<u8>) {
+        let (alpha, beta, gamma, delta) = (scalar(1), scalar(2), scalar(3), scalar(4));
+        let ic = [scalar(5), scalar(6)];
+        let vk = Groth16VerifyingKey {
+            alpha_1: g1(alpha),
+            beta_2: g2(beta),
+            gamma_2: g2(gamma),
+            delta_2: g2(delta),
+            ic: ic.iter().map(|s| g1(*s)).collect(),
+        };
+
+        let public_input = zisk_snark_public_input(&PROGRAM_VK, &PUBLICS, &VADCOP_VK);
+        let vk_x = ic[0] + fr_from_bytes(&public_input).unwrap() * ic[1];
+        let (a, b) = (scalar(7), scalar(8));
+        let c = (a * b - alpha * beta - vk_x * gamma) / delta;
+
+        let mut proof = Vec::with_capacity(GROTH16_PROOF_SIZE);
+        proof.extend_from_slice(&g1(a));
+        proof.extend_from_slice(&g2(b));
+        proof.extend_from_slice(&g1(c));
+        (vk, proof)
+    }
+
+    /// Key, proof and public input of `common/tests/fixtures/groth16`, a proof of a small
+    /// circuit that exposes the ZisK SNARK public input of [`FIXTURE_PROGRAM_VK`], [`fixture_publics`] and
+    /// [`FIXTURE_VADCOP_VK`]. Coordinates are decimal, G2 ones as `[real, imaginary]` as in the JSON
+    /// form of snarkJS.
+    const FIXTURE_ALPHA_1: [&str; 2] = [
+        "20671142367645398469791334943364421354926503644456916830788822367146540638619",
+        "16677535117195491643401010300855798847429400627303942083215248124789194584895",
+    ];
+    const FIXTURE_BETA_2: [[&str; 2]; 2] = [
+        [
+            "10415713861718830568061138501431605059122249007802641874375261371930765535225",
+            "317119039224006765409959504742848559448277823720394363532525700144485766232",
+        ],
+        [
+            "16611916351979057186939918635391959928730462030095730652541950058639805699828",
+            "11903813556394267337383308801449469653892816015127375757461231720030136797978",
+        ],
+    ];
+    const FIXTURE_GAMMA_2: [[&str; 2]; 2] = [
+        [
+            "15262372696125237711825344308534070357730624516692764725449218580758861023786",
+            "11646389906090031653431122159516768275137982460315050783695064189762179657459",
+        ],
+        [
+            "18451277337935116699058697012809236938912077648019257216592182066003139484281",
+            "372985997895520749961450365329913853081105935619008278847914793489562302111",
+        ],
+    ];
+    const FIXTURE_DELTA_2: [[&str; 2]; 2] = [
+        [
+            "10782162328701914588657158765999222694960053153664537132771650774051021146082",
+            "16810301502019380412122933309323497552856567872934371089247250757761003072061",
+        ],
+        [
+            "7351250619198087425943392289388425056232047396115949208324763261411650105441",
+            "17957847242201830787927712790782796681787616357218750994563741279127417481230",
+        ],
+    ];
+    const FIXTURE_IC: [[&str; 2]; 2] = [
+        [
+            "5264939298194162135718283791716282634743216909109586221659472397401403251169",
+            "3482771059654825994211732188654473247092715419276665170295240624407927464473",
+        ],
+        [
+            "16946312549124258438831468907671537419192965918973688289860314169043835802142",
+            "4460534075299384735681317322691370132348628710412099187392150377970451220943",
+        ],
+    ];
+    const FIXTURE_PI_A: [&str; 2] = [
+        "16690193607898152557067300245987223918520607957759753515759967141413970904590",
+        "1232209991166382017808658610359745937030613689946115221247608618447663831332",
+    ];
+    const FIXTURE_PI_B: [[&str; 2]; 2] = [
+        [
+            "11293577160997015868272270273105707469366165288519533934576795911020054759237",
+            "17042228771597833710602353459598686316459974719076221451501541264217576850644",
+        ],
+        [
+            "10998328694159223250841999716985691339190226970432962792814332568622469183741",
+            "7880854574327593003493723591034262659576991292708640349531882510507999818422",
+        ],
+    ];
+    const FIXTURE_PI_C: [&str; 2] = [
+        "5442239598465185983224837958105350849789846790578544000316520830340428383032",
+        "16825050899711631474483693053954517651818290809827013885584600171854829482812",
+    ];
+    const FIXTURE_PUBLIC_INPUT: &str =
+        "10162313907606884183762327559917759529248426665789884792787739721396007277670";
+    const FIXTURE_PROGRAM_VK: [u64; 4] =
+        [0x123456789abcdef0, 0xfedcba9876543210, 0x0123456789abcdef, 0x9e3779b97f4a7c15];
+    const FIXTURE_VADCOP_VK: [u64; 4] =
+        [0x5555555555555555, 0xaaaaaaaaaaaaaaaa, 0x3333333333333333, 0xcccccccccccccccc];
+
+    fn fixture_publics() -> Vec<u8> {
+        (0..64u32).flat_map(|i| (i * 7 + 1).to_le_bytes()).collect()
+    }
+
+    fn decimal(value: &str) -> [u8; 32] {
+        parse_decimal(value).unwrap()
+    }
+
+    fn fixture_g1(point: [&str; 2]) -> G1Point {
+        point.map(decimal).concat().try_into().unwrap()
+    }
+
+    fn fixture_g2(point: [[&str; 2]; 2]) -> G2Point {
+        [point[0][1], point[0][0], point[1][1], point[1][0]]
+            .map(decimal)
+            .concat()
+            .try_into()
+            .unwrap()
+    }
+
+    fn snarkjs_fixture() -> (Groth16VerifyingKey, Vec<u8>) {
+        let vk = Groth16VerifyingKey {
+            alpha_1: fixture_g1(FIXTURE_ALPHA_1),
+            beta_2: fixture_g2(FIXTURE_BETA_2),
+            gamma_2: fixture_g2(FIXTURE_GAMMA_2),
+            delta_2: fixture_g2(FIXTURE_DELTA_2),
+            ic: FIXTURE_IC.into_iter().map(fixture_g1).collect(),
+        };
+        let proof =
+            [&fixture_g1(FIXTURE_PI_A)[..], &fixture_g2(FIXTURE_PI_B), &fixture_g1(FIXTURE_PI_C)]
+                .concat();
+        (vk, proof)
+    }
+
+    fn verify(vk: &Groth16VerifyingKey, proof: &[u8]) -> Result<(), VerifyError> {
+        verify_zisk_groth16_proof(vk, proof, &PROGRAM_VK, &PUBLICS, &VADCOP_VK)
+    }
+
+    #[test]
+    fn verify_accepts_valid_proof() {
+        let (vk, proof) = trapdoor_proof();
+        assert_eq!(verify(&vk, &proof), Ok(()));
+    }
+
+    #[test]
+    fn verify_accepts_snarkjs_fixture() {
+        let (vk, proof) = snarkjs_fixture();
+        let publics = fixture_publics();
+        assert_eq!(
+            zisk_snark_public_input(&FIXTURE_PROGRAM_VK, &publics, &FIXTURE_VADCOP_VK),
+            decimal(FIXTURE_PUBLIC_INPUT)
+        );
+        assert_eq!(verify_groth16_proof(&vk, &proof, &[decimal(FIXTURE_PUBLIC_INPUT)]), Ok(()));
+        assert_eq!(
+            verify_zisk_groth16_proof(
+                &vk,
+                &proof,
+                &FIXTURE_PROGRAM_VK,
+                &publics,
+                &FIXTURE_VADCOP_VK
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verify_rejects_snarkjs_fixture_of_other_values() {
+        let (vk, proof) = snarkjs_fixture();
+        let publics = fixture_publics();
+        assert_eq!(
+            verify_zisk_groth16_proof(&vk, &proof, &PROGRAM_VK, &publics, &FIXTURE_VADCOP_VK),
+            Err(VerifyError::Rejected)
+        );
+        assert_eq!(
+            verify_zisk_groth16_proof(
+                &vk,
+                &proof,
+                &FIXTURE_PROGRAM_VK,
+                &PUBLICS,
+                &FIXTURE_VADCOP_VK
+            ),
+            Err(VerifyError::Rejected)
+        );
+        assert_eq!(
+            verify_zisk_groth16_proof(&vk, &proof, &FIXTURE_PROGRAM_VK, &publics, &VADCOP_VK),
+            Err(VerifyError::Rejected)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_other_publics() {
+        let (vk, proof) = trapdoor_proof();
+        let mut publics = PUBLICS;
+        publics[0] ^= 1;
+        assert_eq!(
+            verify_zisk_groth16_proof(&vk, &proof, &PROGRAM_VK, &publics, &VADCOP_VK),
+            Err(VerifyError::Rejected)
+        );
+        assert_eq!(verify_groth16_proof(&vk, &proof, &[]), Err(VerifyError::Rejected));
+    }
+
+    #[test]
+    fn verify_rejects_tampered_proof() {
+        let (vk, mut proof) = trapdoor_proof();
+        proof[64..192].copy_from_slice(&G2_GENERATOR);
+        assert_eq!(verify(&vk, &proof), Err(VerifyError::Rejected));
+    }
+
+    #[test]
+    fn verify_rejects_malformed_proof() {
+        let (vk, proof) = trapdoor_proof();
+        assert_eq!(verify(&vk, &proof[1..]), Err(VerifyError::MalformedProof));
+
+        // A off the curve
+        let mut off_curve = proof.clone();
+        off_curve[63] ^= 1;
+        assert_eq!(verify(&vk, &off_curve), Err(VerifyError::MalformedProof));
+
+        // B off the curve
+        let mut off_curve = proof.clone();
+        off_curve[191] ^= 1;
+        assert_eq!(verify(&vk, &off_curve), Err(VerifyError::MalformedProof));
+
+        // Public input not below the field order
+        assert_eq!(
+            verify_groth16_proof(&vk, &proof, &[[0xff; 32]]),
+            Err(VerifyError::MalformedProof)
+        );
+
+        assert_eq!(verify(&vk, &vec![0; GROTH16_PROOF_SIZE]), Err(VerifyError::MalformedProof));
+    }
+}
@@ -1,5 +1,21 @@
 #![no_std]
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "bn254")]
+mod bn254;
+#[cfg(feature = "groth16")]
+mod groth16;
+#[cfg(feature = "plonk")]
+mod plonk;
 mod verifier;
 
+#[cfg(feature = "bn254")]
+pub use bn254::{parse_decimal, zisk_snark_public_input, G1Point, G2Point};
+#[cfg(feature = "groth16")]
+pub use groth16::*;
+#[cfg(feature = "plonk")]
+pub use plonk::*;
 pub use verifier::*;
@@ -0,0 +1,382 @@
+//! BN254 Plonk verifier for SNARK-wrapped ZisK proofs.
+//!
+//! Follows the snarkJS Plonk verifier, with the same Keccak-256 transcript as the generated
+//! Solidity verifier, so a proof accepted here is accepted on-chain. The verification key is
+//! held in memory, without touching the filesystem.
+
+use alloc::vec::Vec;
+
+use ark_bn254::Fr;
+use ark_ff::{Field, One, PrimeField};
+
+use crate::bn254::{
+    fr_bytes, fr_from_bytes, g1_from_bytes, msm, ops, zisk_snark_public_input, G1Point, G2Point,
+    G1_GENERATOR, G2_GENERATOR,
+};
+use crate::VerifyError;
+
+/// Size in bytes of a serialized Plonk proof: 9 G1 points and 6 evaluations.
+pub const PLONK_PROOF_SIZE: usize = 24 * 32;
+
+/// Plonk verification key, see `PlonkVkey` in `zisk-common` for its JSON form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlonkVerifyingKey {
+    /// Number of public inputs
+    pub n_public: usize,
+    /// Log2 of the domain size
+    pub power: u32,
+    pub k1: [u8; 32],
+    pub k2: [u8; 32],
+    pub qm: G1Point,
+    pub ql: G1Point,
+    pub qr: G1Point,
+    pub qo: G1Point,
+    pub qc: G1Point,
+    pub s1: G1Point,
+    pub s2: G1Point,
+    pub s3: G1Point,
+    /// `[x]_2` of the setup
+    pub x_2: G2Point,
+    /// Generator of the evaluation domain
+    pub w: [u8; 32],
+}
+
+/// Verify a SNARK-wrapped ZisK proof against its program VK, public outputs and Vadcop final
+/// verification key.
+pub fn verify_zisk_plonk_proof(
+    vk: &PlonkVerifyingKey,
+    proof: &[u8],
+    program_vk: &[u64],
+    publics: &[u8],
+    vadcop_vk: &[u64],
+) -> Result<(), VerifyError> {
+    verify_plonk_proof(vk, proof, &[zisk_snark_public_input(program_vk, publics, vadcop_vk)])
+}
+
+/// Verify a Plonk proof of [`PLONK_PROOF_SIZE`] bytes, laid out as the `uint256[24]` argument of
+/// the Solidity verifier, against big-endian public inputs.
+pub fn verify_plonk_proof(
+    vk: &PlonkVerifyingKey,
+    proof: &[u8],
+    public_inputs: &[[u8; 32]],
+) -> Result<(), VerifyError> {
+    // As in the Solidity verifier, other public inputs than those of the key fail verification
+    if public_inputs.len() != vk.n_public {
+        return Err(VerifyError::Rejected);
+    }
+    let proof = Proof::parse(proof).ok_or(VerifyError::MalformedProof)?;
+    let pubs = public_inputs
+        .iter()
+        .map(fr_from_bytes)
+        .collect::<Option<Vec<_>>>()
+        .ok_or(VerifyError::MalformedProof)?;
+    let k1 = fr_from_bytes(&vk.k1).ok_or(VerifyError::InvalidVerificationKey)?;
+    let k2 = fr_from_bytes(&vk.k2).ok_or(VerifyError::InvalidVerificationKey)?;
+    let w = fr_from_bytes(&vk.w).ok_or(VerifyError::InvalidVerificationKey)?;
+    let n = Fr::from(1u64 << vk.power);
+
+    let Challenges { beta, gamma, alpha, xi, v, u } = Challenges::new(vk, &proof, public_inputs);
+    let e = &proof.evals;
+    let alpha2 = alpha.square();
+
+    let mut xin = xi;
+    for _ in 0..vk.power {
+        xin.square_in_place();
+    }
+    let zh = xin - Fr::one();
+
+    // Lagrange polynomials at xi for the public inputs, L_i(xi) = w^i zh / (n (xi - w^i))
+    let mut lagrange = Vec::with_capacity(vk.n_public.max(1));
+    let mut wi = Fr::one();
+    for _ in 0..vk.n_public.max(1) {
+        let denominator = (n * (xi - wi)).inverse().ok_or(VerifyError::Rejected)?;
+        lagrange.push(wi * zh * denominator);
+        wi *= w;
+    }
+    let l1 = lagrange[0];
+    let pi = -pubs.iter().zip(&lagrange).map(|(p, l)| *p * l).sum::<Fr>();
+
+    // r0
+    let e3 = (e.a + beta * e.s1 + gamma) * (e.b + beta * e.s2 + gamma) * (e.c + gamma) * e.zw;
+    let r0 = pi - l1 * alpha2 - e3 * alpha;
+
+    // Batched commitment [D]
+    let beta_xi = beta * xi;
+    let d2 = (e.a + beta_xi + gamma)
+        * (e.b + beta_xi * k1 + gamma)
+        * (e.c + beta_xi * k2 + gamma)
+        * alpha
+        + l1 * alpha2
+        + u;
+    let d3 = (e.a + beta * e.s1 + gamma) * (e.b + beta * e.s2 + gamma) * alpha * beta * e.zw;
+    let t = msm(&[(&proof.t1, Fr::one()), (&proof.t2, xin), (&proof.t3, xin.square())])?;
+    let d = msm(&[
+        (&vk.qc, Fr::one()),
+        (&vk.qm, e.a * e.b),
+        (&vk.ql, e.a),
+        (&vk.qr, e.b),
+        (&vk.qo, e.c),
+        (&proof.z, d2),
+        (&vk.s3, -d3),
+        (&t, -zh),
+    ])?;
+
+    // [F] and [E]
+    let f = msm(&[
+        (&d, Fr::one()),
+        (&proof.a, v[0]),
+        (&proof.b, v[1]),
+        (&proof.c, v[2]),
+        (&vk.s1, v[3]),
+        (&vk.s2, v[4]),
+    ])?;
+    let e_scalar =
+        -r0 + v[0] * e.a + v[1] * e.b + v[2] * e.c + v[3] * e.s1 + v[4] * e.s2 + u * e.zw;
+
+    // e(-(Wxi + u Wxiw), [x]_2) e(xi Wxi + u xi w Wxiw + F - E, [1]_2) == 1
+    let a1 = msm(&[(&proof.wxi, -Fr::one()), (&proof.wxiw, -u)])?;
+    let b1 = msm(&[
+        (&proof.wxi, xi),
+        (&proof.wxiw, u * xi * w),
+        (&f, Fr::one()),
+        (&G1_GENERATOR, -e_scalar),
+    ])?;
+
+    match ops::pairing_check(&[(a1, vk.x_2), (b1, G2_GENERATOR)]) {
+        Some(true) => Ok(()),
+        Some(false) => Err(VerifyError::Rejected),
+        None => Err(VerifyError::InvalidVerificationKey),
+    }
+}
+
+/// Fiat-Shamir challenges, from the Keccak-256 transcript of the Solidity verifier
+struct Challenges {
+    beta: Fr,
+    gamma: Fr,
+    alpha: Fr,
+    xi: Fr,
+    /// Powers `v^1..=v^5`
+    v: [Fr; 5],
+    u: Fr,
+}
+
+impl Challenges {
+    fn new(vk: &PlonkVerifyingKey, proof: &Proof, public_inputs: &[[u8; 32]]) -> Self {
+        let mut transcript = Vec::with_capacity((11 + public_inputs.len()) * 64);
+        for p in [&vk.qm, &vk.ql, &vk.qr, &vk.qo, &vk.qc, &vk.s1, &vk.s2, &vk.s3] {
+            transcript.extend_from_slice(p);
+        }
+        for public in public_inputs {
+            transcript.extend_from_slice(public);
+        }
+        for p in [&proof.a, &proof.b, &proof.c] {
+            transcript.extend_from_slice(p);
+        }
+        let beta = hash_to_fr(&transcript);
+        let gamma = hash_to_fr(&fr_bytes(&beta));
+        let alpha = hash_to_fr(&[fr_bytes(&beta).as_slice(), &fr_bytes(&gamma), &proof.z].concat());
+        let xi =
+            hash_to_fr(&[fr_bytes(&alpha).as_slice(), &proof.t1, &proof.t2, &proof.t3].concat());
+        let e = &proof.evals;
+        let v1 = hash_to_fr(
+            &[xi, e.a, e.b, e.c, e.s1, e.s2, e.zw].iter().flat_map(fr_bytes).collect::<Vec<_>>(),
+        );
+        let u = hash_to_fr(&[proof.wxi.as_slice(), &proof.wxiw].concat());
+
+        let mut v = [v1; 5];
+        for i in 1..5 {
+            v[i] = v[i - 1] * v1;
+        }
+        Self { beta, gamma, alpha, xi, v, u }
+    }
+}
+
+struct Evaluations {
+    a: Fr,
+    b: Fr,
+    c: Fr,
+    s1: Fr,
+    s2: Fr,
+    zw: Fr,
+}
+
+struct Proof {
+    a: G1Point,
+    b: G1Point,
+    c: G1Point,
+    z: G1Point,
+    t1: G1Point,
+    t2: G1Point,
+    t3: G1Point,
+    wxi: G1Point,
+    wxiw: G1Point,
+    evals: Evaluations,
+}
+
+impl Proof {
+    /// Parses a proof, checking that the commitments are on the curve and the evaluations in
+    /// the scalar field.
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != PLONK_PROOF_SIZE {
+            return None;
+        }
+        let point = |i: usize| g1_from_bytes(&bytes[i * 64..(i + 1) * 64]);
+        let eval = |i: usize| fr_from_bytes(bytes[576 + i * 32..608 + i * 32].try_into().unwrap());
+        Some(Self {
+            a: point(0)?,
+            b: point(1)?,
+            c: point(2)?,
+            z: point(3)?,
+            t1: point(4)?,
+            t2: point(5)?,
+            t3: point(6)?,
+            wxi: point(7)?,
+            wxiw: point(8)?,
+            evals: Evaluations {
+                a: eval(0)?,
+                b: eval(1)?,
+                c: eval(2)?,
+                s1: eval(3)?,
+                s2: eval(4)?,
+                zw: eval(5)?,
+            },
+        })
+    }
+}
+
+fn hash_to_fr(data: &[u8]) -> Fr {
+    Fr::from_be_bytes_mod_order(&ops::keccak256(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254::testing::*;
+    use alloc::vec;
+    use ark_ff::FftField;
+
+    /// Builds a key and a proof that verifies, using the trapdoor of the setup to open the
+    /// batched commitment at `xi` and `xi w` instead of running a prover.
+    fn trapdoor_proof() -> (PlonkVerifyingKey, Vec<u8>) {
+        let tau = scalar(0);
+        let q: Vec<Fr> = (1..=8).map(scalar).collect();
+        let (qm, ql, qr, qo, qc, s1, s2, s3) = (q[0], q[1], q[2], q[3], q[4], q[5], q[6], q[7]);
+        let (k1, k2) = (Fr::from(2u64), Fr::from(3u64));
+        let power = 3;
+        let w = Fr::get_root_of_unity(1 << power).unwrap();
+        let vk = PlonkVerifyingKey {
+            n_public: 1,
+            power,
+            k1: fr_bytes(&k1),
+            k2: fr_bytes(&k2),
+            qm: g1(qm),
+            ql: g1(ql),
+            qr: g1(qr),
+            qo: g1(qo),
+            qc: g1(qc),
+            s1: g1(s1),
+            s2: g1(s2),
+            s3: g1(s3),
+            x_2: g2(tau),
+            w: fr_bytes(&w),
+        };
+
+        let public_input = zisk_snark_public_input(&PROGRAM_VK, &PUBLICS, &VADCOP_VK);
+        let c: Vec<Fr> = (9..=15).map(scalar).collect();
+        let (a, b, cc, z, t1, t2, t3) = (c[0], c[1], c[2], c[3], c[4], c[5], c[6]);
+        let evals: Vec<Fr> = (16..=21).map(scalar).collect();
+
+        // Openings are placeholders until the challenges they do not depend on are known
+        let mut bytes = Vec::with_capacity(PLONK_PROOF_SIZE);
+        for s in [a, b, cc, z, t1, t2, t3, Fr::one(), Fr::one()] {
+            bytes.extend_from_slice(&g1(s));
+        }
+        for e in &evals {
+            bytes.extend_from_slice(&fr_bytes(e));
+        }
+        let proof = Proof::parse(&bytes).unwrap();
+        let Challenges { beta, gamma, alpha, xi, v, .. } =
+            Challenges::new(&vk, &proof, &[public_input]);
+        let e = &proof.evals;
+
+        let n = Fr::from(1u64 << power);
+        let zh = xi.pow([1u64 << power]) - Fr::one();
+        let l1 = zh / (n * (xi - Fr::one()));
+        let pi = -fr_from_bytes(&public_input).unwrap() * l1;
+        let alpha2 = alpha.square();
+        let e3 = (e.a + beta * e.s1 + gamma) * (e.b + beta * e.s2 + gamma) * (e.c + gamma) * e.zw;
+        let r0 = pi - l1 * alpha2 - e3 * alpha;
+        let beta_xi = beta * xi;
+        let d2 = (e.a + beta_xi + gamma)
+            * (e.b + beta_xi * k1 + gamma)
+            * (e.c + beta_xi * k2 + gamma)
+            * alpha
+            + l1 * alpha2;
+        let d3 = (e.a + beta * e.s1 + gamma) * (e.b + beta * e.s2 + gamma) * alpha * beta * e.zw;
+        let xin = zh + Fr::one();
+        let d = qc + e.a * e.b * qm + e.a * ql + e.b * qr + e.c * qo + d2 * z
+            - d3 * s3
+            - zh * (t1 + xin * t2 + xin.square() * t3);
+        let f = d + v[0] * a + v[1] * b + v[2] * cc + v[3] * s1 + v[4] * s2;
+        let e0 = -r0 + v[0] * e.a + v[1] * e.b + v[2] * e.c + v[3] * e.s1 + v[4] * e.s2;
+
+        // F - E = (f - e0) + u (z - zw), opened at xi and at xi w
+        let wxi = (f - e0) / (tau - xi);
+        let wxiw = (z - e.zw) / (tau - xi * w);
+        bytes[7 * 64..8 * 64].copy_from_slice(&g1(wxi));
+        bytes[8 * 64..9 * 64].copy_from_slice(&g1(wxiw));
+        (vk, bytes)
+    }
+
+    #[test]
+    fn verify_accepts_valid_proof() {
+        let (vk, proof) = trapdoor_proof();
+        assert_eq!(verify_zisk_plonk_proof(&vk, &proof, &PROGRAM_VK, &PUBLICS, &VADCOP_VK), Ok(()));
+    }
+
+    #[test]
+    fn verify_rejects_other_publics() {
+        let (vk, proof) = trapdoor_proof();
+        let mut publics = PUBLICS;
+        publics[0] ^= 1;
+        assert_eq!(
+            verify_zisk_plonk_proof(&vk, &proof, &PROGRAM_VK, &publics, &VADCOP_VK),
+            Err(VerifyError::Rejected)
+        );
+        assert_eq!(
+            verify_zisk_plonk_proof(&vk, &proof, &[1, 2, 3, 5], &PUBLICS, &VADCOP_VK),
+            Err(VerifyError::Rejected)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_tampered_proof() {
+        let (vk, mut proof) = trapdoor_proof();
+        // Last evaluation, eval_zw
+        proof[PLONK_PROOF_SIZE - 1] ^= 1;
+        assert_eq!(
+            verify_zisk_plonk_proof(&vk, &proof, &PROGRAM_VK, &PUBLICS, &VADCOP_VK),
+            Err(VerifyError::Rejected)
+        );
+    }
+
+    #[test]
+    fn verify_rejects_malformed_proof() {
+        let (vk, proof) = trapdoor_proof();
+        let verify =
+            |proof: &[u8]| verify_zisk_plonk_proof(&vk, proof, &PROGRAM_VK, &PUBLICS, &VADCOP_VK);
+        assert_eq!(verify(&proof[1..]), Err(VerifyError::MalformedProof));
+
+        // Point off the curve
+        let mut off_curve = proof.clone();
+        off_curve[63] ^= 1;
+        assert_eq!(verify(&off_curve), Err(VerifyError::MalformedProof));
+
+        // Evaluation not below the field order
+        let mut unreduced = proof;
+        unreduced[PLONK_PROOF_SIZE - 32..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(verify(&unreduced), Err(VerifyError::MalformedProof));
+
+        assert_eq!(verify(&vec![0; PLONK_PROOF_SIZE]), Err(VerifyError::MalformedProof));
+    }
+}
@@ -1,4 +1,12 @@
-use proofman_verifier::{verify_vadcop_final_compressed_u64, verify_vadcop_final_u64};
+use alloc::vec::Vec;
+use core::fmt;
+
+use proofman_verifier::{
+    expected_vadcop_final_compressed_proof_bytes, expected_vadcop_final_proof_bytes,
+    verify_vadcop_final_compressed_u64, verify_vadcop_final_u64,
+};
+#[cfg(feature = "std")]
+use rayon::prelude::*;
 
 /// Length, in u64 words, of the Vadcop final verification key appended to a serialized proof.
 pub const VADCOP_VK_LEN_WORDS: usize = 4;
@@ -12,27 +20,174 @@ pub const PROGRAM_VK_LEN: usize = 4;
 /// Expected `n_publics` header value: program VK + publics.
 const EXPECTED_N_PUBLICS: u64 = (PROGRAM_VK_LEN + ZISK_PUBLICS) as u64;
 
+type VadcopVk = [u64; VADCOP_VK_LEN_WORDS];
+
+/// Reason a proof failed verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyError {
+    /// The proof is shorter than its `[minimal][n_publics][publics]` header.
+    MalformedProof,
+    /// The proof after the header is not the size of a Vadcop final proof of its kind.
+    UnexpectedProofLength { expected: usize, found: usize },
+    /// The Vadcop final verification key does not have `VADCOP_VK_LEN_WORDS` words.
+    InvalidVerificationKey,
+    /// The proof header declares a number of publics other than program VK + publics.
+    UnexpectedPublicsCount(u64),
+    /// The proof is well formed but does not verify against the key.
+    Rejected,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::MalformedProof => write!(f, "malformed proof"),
+            VerifyError::UnexpectedProofLength { expected, found } => {
+                write!(f, "malformed proof: expected {expected} bytes, got {found}")
+            }
+            VerifyError::InvalidVerificationKey => {
+                write!(f, "verification key must be {VADCOP_VK_LEN_WORDS} words")
+            }
+            VerifyError::UnexpectedPublicsCount(n) => {
+                write!(f, "proof declares {n} publics, expected {EXPECTED_N_PUBLICS}")
+            }
+            VerifyError::Rejected => write!(f, "proof rejected"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerifyError {}
+
 pub fn verify_vadcop_final_proof(zisk_proof: &[u64], vadcop_final_vk: &[u64]) -> bool {
-    // Format: [minimal(1)][n_publics(1)][publics(EXPECTED_N_PUBLICS)][proof]
+    try_verify_vadcop_final_proof(zisk_proof, vadcop_final_vk).is_ok()
+}
 
-    if zisk_proof.len() < (2 + EXPECTED_N_PUBLICS as usize) {
-        return false;
+/// Verify a Vadcop final proof like [`verify_vadcop_final_proof`], reporting why it failed.
+pub fn try_verify_vadcop_final_proof(
+    zisk_proof: &[u64],
+    vadcop_final_vk: &[u64],
+) -> Result<(), VerifyError> {
+    parse_vk(vadcop_final_vk).and_then(|vk| check_vadcop_final_proof(zisk_proof, &vk))
+}
+
+/// Verify several Vadcop final proofs, each given as `(zisk_proof, vadcop_final_vk)` in the
+/// format accepted by [`verify_vadcop_final_proof`].
+///
+/// Every proof goes through the same checks as [`try_verify_vadcop_final_proof`]. With the `std`
+/// feature the proofs are verified in parallel; otherwise sequentially. The results are returned
+/// in the order of `proofs`.
+///
+/// Proofs of the same key share no parsed state: proofman verifies against the raw key words and
+/// exposes no parsed verification key, so there is nothing to reuse beyond the four words.
+pub fn verify_batch(proofs: &[(&[u64], &[u64])]) -> Vec<Result<(), VerifyError>> {
+    let verify_one = |(proof, vk): &(&[u64], &[u64])| try_verify_vadcop_final_proof(proof, vk);
+
+    #[cfg(feature = "std")]
+    {
+        proofs.par_iter().map(verify_one).collect()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        proofs.iter().map(verify_one).collect()
     }
+}
+
+fn parse_vk(vadcop_final_vk: &[u64]) -> Result<VadcopVk, VerifyError> {
+    vadcop_final_vk.try_into().map_err(|_| VerifyError::InvalidVerificationKey)
+}
+
+fn check_vadcop_final_proof(
+    zisk_proof: &[u64],
+    vadcop_final_vk: &VadcopVk,
+) -> Result<(), VerifyError> {
+    // Format: [minimal(1)][n_publics(1)][publics(EXPECTED_N_PUBLICS)][proof]
 
-    if vadcop_final_vk.len() != PROGRAM_VK_LEN {
-        return false;
+    if zisk_proof.len() < (2 + EXPECTED_N_PUBLICS as usize) {
+        return Err(VerifyError::MalformedProof);
     }
 
     let minimal = zisk_proof[0] == 1;
     let vadcop_proof = &zisk_proof[1..];
 
     if vadcop_proof[0] != EXPECTED_N_PUBLICS {
-        return false;
+        return Err(VerifyError::UnexpectedPublicsCount(vadcop_proof[0]));
+    }
+
+    let expected = if minimal {
+        expected_vadcop_final_compressed_proof_bytes()
+    } else {
+        expected_vadcop_final_proof_bytes()
+    };
+    let found = (zisk_proof.len() - 2 - EXPECTED_N_PUBLICS as usize) * 8;
+    if found != expected {
+        return Err(VerifyError::UnexpectedProofLength { expected, found });
     }
 
-    if minimal {
+    let is_valid = if minimal {
         verify_vadcop_final_compressed_u64(vadcop_proof, vadcop_final_vk)
     } else {
         verify_vadcop_final_u64(vadcop_proof, vadcop_final_vk)
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(VerifyError::Rejected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn verify_batch_reports_each_proof_in_order() {
+        let vk = [1u64; VADCOP_VK_LEN_WORDS];
+        let short_vk = [1u64; VADCOP_VK_LEN_WORDS - 1];
+        let mut wrong_publics = vec![0u64; 2 + EXPECTED_N_PUBLICS as usize];
+        wrong_publics[1] = EXPECTED_N_PUBLICS - 1;
+
+        let mut truncated = vec![0u64; 2 + EXPECTED_N_PUBLICS as usize + 1];
+        truncated[1] = EXPECTED_N_PUBLICS;
+
+        let results = verify_batch(&[
+            (&[0, EXPECTED_N_PUBLICS], &vk),
+            (&wrong_publics, &vk),
+            (&wrong_publics, &short_vk),
+            (&truncated, &vk),
+        ]);
+
+        assert_eq!(
+            results,
+            vec![
+                Err(VerifyError::MalformedProof),
+                Err(VerifyError::UnexpectedPublicsCount(EXPECTED_N_PUBLICS - 1)),
+                Err(VerifyError::InvalidVerificationKey),
+                Err(VerifyError::UnexpectedProofLength {
+                    expected: expected_vadcop_final_proof_bytes(),
+                    found: 8,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_batch_matches_single_verification() {
+        let vk = [1u64; VADCOP_VK_LEN_WORDS];
+        let mut truncated_minimal = vec![0u64; 2 + EXPECTED_N_PUBLICS as usize + 2];
+        truncated_minimal[0] = 1;
+        truncated_minimal[1] = EXPECTED_N_PUBLICS;
+        let proofs: [(&[u64], &[u64]); 3] =
+            [(&truncated_minimal, &vk), (&[1, 2, 3], &vk), (&truncated_minimal, &vk[..2])];
+
+        let single: Vec<_> =
+            proofs.iter().map(|(proof, vk)| try_verify_vadcop_final_proof(proof, vk)).collect();
+        assert_eq!(verify_batch(&proofs), single);
+    }
+
+    #[test]
+    fn verify_batch_of_nothing_is_empty() {
+        assert!(verify_batch(&[]).is_empty());
     }
 }
@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: AGPL-3.0
+pragma solidity ^0.8.20;
+
+// Placeholder for the Groth16 verifier of the SNARK wrapper. It must be exported from the zkey of
+// the Groth16 setup made with the ceremony powers of tau, never from a local test setup:
+//
+//   snarkjs zkey export solidityverifier <final.zkey> zisk-contracts/Groth16Verifier.sol
+//
+// Until it is, this file does not compile on purpose, so that `ZiskGroth16Verifier` can not be
+// deployed with a key whose toxic waste is known.
+contract Groth16Verifier {
+    uint256 constant GROTH16_VERIFIER_NOT_EXPORTED =
+        "Export Groth16Verifier.sol from the ceremony zkey with snarkjs zkey export solidityverifier";
+}
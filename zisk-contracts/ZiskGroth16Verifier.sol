@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: AGPL-3.0
+pragma solidity ^0.8.20;
+
+import {IZiskVerifier} from "./IZiskVerifier.sol";
+import {ZiskVerifierBase} from "./ZiskVerifierBase.sol";
+// Exported with `snarkjs zkey export solidityverifier <zkey> Groth16Verifier.sol` from the zkey of
+// the Groth16 SNARK wrapper setup. The committed one is a placeholder that does not compile.
+import {Groth16Verifier} from "./Groth16Verifier.sol";
+
+/// @title Zisk Groth16 Verifier
+/// @author SilentSig
+/// @notice This contracts implements a solidity verifier for Zisk proofs wrapped with Groth16.
+contract ZiskGroth16Verifier is Groth16Verifier, ZiskVerifierBase, IZiskVerifier {
+    /// @notice Verifies a proof with given public values and vkey.
+    /// @dev `proofBytes` holds `A || B || C`, with the coordinates of `B` in the `pB` order of
+    /// the Groth16 verifier (imaginary part first).
+    function verifySnarkProof(
+        bytes32 programVK,
+        bytes32 rootCVadcopFinal,
+        bytes calldata publicValues,
+        bytes calldata proofBytes
+    ) external view {
+        uint256 publicValuesDigest = hashPublicValues(programVK, rootCVadcopFinal, publicValues);
+
+        uint256[8] memory p = abi.decode(proofBytes, (uint256[8]));
+
+        bool success = this.verifyProof(
+            [p[0], p[1]],
+            [[p[2], p[3]], [p[4], p[5]]],
+            [p[6], p[7]],
+            [publicValuesDigest]
+        );
+
+        if (!success) {
+            revert InvalidProof();
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: AGPL-3.0
+pragma solidity ^0.8.20;
+
+/// @title Zisk Verifier Base
+/// @author SilentSig
+/// @notice Logic shared by the Zisk verifiers of every SNARK wrapper: the Vadcop final root and
+/// the hash of the public values into the public input of the SNARK.
+abstract contract ZiskVerifierBase {
+    error InvalidProof();
+
+    function VERSION() external pure returns (string memory) {
+        return "v0.18.0";
+    }
+
+    /// @notice Root constant as bytes32 (pre-packed to match the original uint64[4] layout)
+    function getRootCVadcopFinal() external pure returns (bytes32) {
+        return bytes32(
+            abi.encodePacked(
+                uint64(14927797345724729265),
+                uint64(4864849507313518298),
+                uint64(8191503835439821522),
+                uint64(363572921032801149)));
+    }
+
+    uint256 internal constant _RFIELD =
+        21888242871839275222246405745257275088548364400416034343698204186575808495617;
+
+    /// @notice Hashes the public values into a field element inside BN254.
+    function hashPublicValues(
+        bytes32 programVK,
+        bytes32 rootCVadcopFinal,
+        bytes calldata publicValues
+    ) public pure returns (uint256) {
+        return uint256(
+            sha256(abi.encodePacked(programVK, publicValues, rootCVadcopFinal))
+        ) % _RFIELD;
+    }
+}
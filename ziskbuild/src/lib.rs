@@ -1,8 +1,10 @@
 mod build;
 mod command;
+mod reproducible;
 mod utils;
 
 use build::build_program_internal;
+pub use reproducible::*;
 // pub use build::{execute_build_program, generate_elf_paths};
 
 use clap::Parser;
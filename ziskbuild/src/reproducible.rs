@@ -0,0 +1,529 @@
+//! Reproducible guest builds.
+//!
+//! The ELF of a guest, and therefore its program VK, depends on more than its sources: the
+//! toolchain, the paths of the sources and dependencies, the environment of the build and the
+//! resolved dependency versions all leak into it. A reproducible build pins or normalizes each
+//! of them so that anyone building the same sources gets the same ELF:
+//! - The toolchain is selected explicitly and its `rustc -vV` identity is recorded.
+//! - Dependencies are vendored from `Cargo.lock` with `cargo vendor --locked`, and the build
+//!   runs `--locked --offline` against the vendor directory.
+//! - Local paths are remapped with `--remap-path-prefix` to fixed `/zisk/...` prefixes.
+//! - The environment is cleared, except for the variables rustup and cargo need, and
+//!   `SOURCE_DATE_EPOCH`, `TZ` and the locale are fixed. The cargo home is an empty directory, so
+//!   user-wide cargo configuration does not apply either.
+//!
+//! The result is described by a [`BuildManifest`], written next to the ELFs, that third parties
+//! compare with their own build to check a published ELF hash and program VK.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use cargo_metadata::camino::Utf8PathBuf;
+use rom_setup::program_vk_from_elf;
+use serde::{Deserialize, Serialize};
+
+use crate::build::generate_elf_paths;
+use crate::{BuildArgs, HELPER_TARGET_SUBDIR, ZISK_TARGET};
+
+/// File name of the build manifest, next to the built ELFs
+pub const BUILD_MANIFEST_FILE: &str = "zisk-build-manifest.json";
+
+/// Version of the [`BuildManifest`] format
+pub const BUILD_MANIFEST_VERSION: u32 = 1;
+
+/// Environment variables kept from the caller, needed to locate the toolchain
+const KEPT_ENV_VARS: &[&str] = &["PATH", "HOME", "RUSTUP_HOME"];
+
+/// Inputs of a reproducible build, other than the sources.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildOptions {
+    pub release: bool,
+    pub features: Option<String>,
+    pub all_features: bool,
+    pub no_default_features: bool,
+    pub packages: Vec<String>,
+    pub binaries: Vec<String>,
+}
+
+/// Toolchain that built the ELFs, as reported by `rustc -vV`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolchainInfo {
+    pub name: String,
+    pub rustc: String,
+    pub commit_hash: String,
+    pub llvm_version: String,
+}
+
+impl ToolchainInfo {
+    /// Describes how this toolchain differs from `expected`, empty if it is the same build of
+    /// `rustc`. The rustup name of the toolchain is not compared.
+    pub fn differences(&self, expected: &ToolchainInfo) -> Vec<String> {
+        [
+            ("toolchain", &self.rustc, &expected.rustc),
+            ("toolchain commit", &self.commit_hash, &expected.commit_hash),
+        ]
+        .into_iter()
+        .filter_map(|(what, found, wanted)| difference(what, found, wanted))
+        .collect()
+    }
+}
+
+/// ELF produced by a reproducible build.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ElfManifest {
+    pub name: String,
+    /// Path relative to the workspace root
+    pub path: String,
+    /// blake3 hash of the ELF, as `ProgramId::hash_id`
+    pub hash: String,
+    /// Program VK as a `0x`-prefixed `bytes32`
+    pub program_vk: String,
+}
+
+/// Record of the inputs and outputs of a reproducible build.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildManifest {
+    pub version: u32,
+    pub zisk_version: String,
+    pub toolchain: ToolchainInfo,
+    pub target: String,
+    pub options: BuildOptions,
+    /// Git commit of the sources, if they are in a git repository
+    pub source_commit: Option<String>,
+    /// Whether the git working tree had uncommitted changes
+    pub source_dirty: bool,
+    pub source_date_epoch: u64,
+    /// blake3 hash of `Cargo.lock`
+    pub cargo_lock_hash: String,
+    pub elfs: Vec<ElfManifest>,
+}
+
+impl BuildManifest {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path.as_ref(), json + "\n")
+            .with_context(|| format!("Failed to write build manifest: {}", path.as_ref().display()))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path.as_ref()).with_context(|| {
+            format!("Failed to read build manifest: {}", path.as_ref().display())
+        })?;
+        serde_json::from_str(&json)
+            .with_context(|| format!("Failed to parse build manifest: {}", path.as_ref().display()))
+    }
+
+    /// Describes how this build differs from `expected`, empty if it reproduces it. Only the
+    /// toolchain, the build inputs and the ELFs are compared. Builds of sources with uncommitted
+    /// changes never reproduce a manifest, as the source commit does not identify them.
+    pub fn differences(&self, expected: &BuildManifest) -> Vec<String> {
+        let mut differences = self.toolchain.differences(&expected.toolchain);
+        differences.extend(source_differences(self.source_dirty, expected.source_dirty));
+        differences.extend(
+            [
+                ("target", self.target.clone(), expected.target.clone()),
+                ("build options", format!("{:?}", self.options), format!("{:?}", expected.options)),
+                (
+                    "source commit",
+                    format!("{:?}", self.source_commit),
+                    format!("{:?}", expected.source_commit),
+                ),
+                ("Cargo.lock hash", self.cargo_lock_hash.clone(), expected.cargo_lock_hash.clone()),
+            ]
+            .into_iter()
+            .filter_map(|(what, found, wanted)| difference(what, &found, &wanted)),
+        );
+
+        for wanted in &expected.elfs {
+            match self.elfs.iter().find(|elf| elf.name == wanted.name) {
+                Some(found) => {
+                    let name = &wanted.name;
+                    differences.extend(difference(
+                        &format!("{name} hash"),
+                        &found.hash,
+                        &wanted.hash,
+                    ));
+                    differences.extend(difference(
+                        &format!("{name} program VK"),
+                        &found.program_vk,
+                        &wanted.program_vk,
+                    ));
+                }
+                None => differences.push(format!("ELF {} was not built", wanted.name)),
+            }
+        }
+        for found in &self.elfs {
+            if !expected.elfs.iter().any(|elf| elf.name == found.name) {
+                differences.push(format!("ELF {} is not in the expected manifest", found.name));
+            }
+        }
+
+        differences
+    }
+}
+
+fn difference(what: &str, found: &str, wanted: &str) -> Option<String> {
+    (found != wanted).then(|| format!("{what}: expected {wanted}, found {found}"))
+}
+
+/// Differences due to uncommitted changes in the sources of either build
+fn source_differences(found_dirty: bool, expected_dirty: bool) -> Vec<String> {
+    let mut differences = Vec::new();
+    if found_dirty {
+        differences.push("sources: the working tree has uncommitted changes".to_string());
+    }
+    if expected_dirty {
+        differences
+            .push("sources: the expected manifest was built with uncommitted changes".to_string());
+    }
+    differences
+}
+
+/// Environment of a reproducible build of a program.
+pub struct ReproducibleBuild {
+    program_dir: PathBuf,
+    toolchain: ToolchainInfo,
+    sysroot: PathBuf,
+    metadata: cargo_metadata::Metadata,
+    target_dir: Utf8PathBuf,
+    vendor_dir: Utf8PathBuf,
+    vendor_config: Utf8PathBuf,
+    cargo_home: Utf8PathBuf,
+    source_commit: Option<String>,
+    source_dirty: bool,
+    source_date_epoch: u64,
+    cargo_lock_hash: String,
+}
+
+impl ReproducibleBuild {
+    /// Resolves the toolchain and vendors the locked dependencies of the workspace in
+    /// `program_dir`.
+    ///
+    /// With an `expected` manifest to reproduce, the toolchain and the state of the sources are
+    /// checked first, so that a build that can not reproduce it fails before vendoring and
+    /// building.
+    pub fn prepare(
+        program_dir: &Path,
+        toolchain: &str,
+        expected: Option<&BuildManifest>,
+    ) -> Result<Self> {
+        let metadata = cargo_metadata::MetadataCommand::new()
+            .manifest_path(program_dir.join("Cargo.toml"))
+            .exec()
+            .context("Failed to read the program metadata")?;
+
+        let cargo_lock = metadata.workspace_root.join("Cargo.lock");
+        let cargo_lock_bytes = std::fs::read(&cargo_lock).with_context(|| {
+            format!("A reproducible build needs a Cargo.lock, but {cargo_lock} could not be read")
+        })?;
+
+        let (toolchain, sysroot) = toolchain_info(toolchain)?;
+        let (source_commit, source_dirty) = git_source(metadata.workspace_root.as_std_path());
+
+        if let Some(expected) = expected {
+            let mut differences = toolchain.differences(&expected.toolchain);
+            differences.extend(source_differences(source_dirty, expected.source_dirty));
+            if !differences.is_empty() {
+                anyhow::bail!(
+                    "Build can not reproduce the expected manifest:\n  {}",
+                    differences.join("\n  ")
+                );
+            }
+        }
+
+        let target_dir = metadata.target_directory.join(HELPER_TARGET_SUBDIR);
+        let reproducible_dir = target_dir.join("reproducible");
+        let vendor_dir = reproducible_dir.join("vendor");
+        let vendor_config = reproducible_dir.join("vendor-config.toml");
+        let cargo_home = reproducible_dir.join("cargo-home");
+        std::fs::create_dir_all(&cargo_home)
+            .with_context(|| format!("Failed to create {cargo_home}"))?;
+
+        tracing::info!("Vendoring locked dependencies into {vendor_dir}");
+        let output = Command::new("cargo")
+            .args([&format!("+{}", toolchain.name), "vendor", "--locked", "--versioned-dirs"])
+            .arg(&vendor_dir)
+            .current_dir(&metadata.workspace_root)
+            .output()
+            .context("Failed to execute cargo vendor")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "cargo vendor failed with status {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+        std::fs::write(&vendor_config, &output.stdout)
+            .with_context(|| format!("Failed to write {vendor_config}"))?;
+
+        let source_date_epoch = match &source_commit {
+            Some(_) => git_commit_time(metadata.workspace_root.as_std_path())?,
+            None => 0,
+        };
+
+        Ok(Self {
+            program_dir: program_dir.to_path_buf(),
+            toolchain,
+            sysroot,
+            target_dir,
+            vendor_dir,
+            vendor_config,
+            cargo_home,
+            source_commit,
+            source_dirty,
+            source_date_epoch,
+            cargo_lock_hash: blake3::hash(&cargo_lock_bytes).to_hex().to_string(),
+            metadata,
+        })
+    }
+
+    /// Target directory of the build, as `build_program` uses
+    pub fn target_dir(&self) -> &Utf8PathBuf {
+        &self.target_dir
+    }
+
+    /// Normalizes the environment and arguments of a `cargo build` command.
+    pub fn configure(&self, command: &mut Command) {
+        // Later prefixes take precedence, so nested directories come after their parents
+        let remaps = [
+            (self.metadata.workspace_root.as_std_path(), "/zisk/src"),
+            (self.target_dir.as_std_path(), "/zisk/target"),
+            (self.vendor_dir.as_std_path(), "/zisk/vendor"),
+            (self.sysroot.as_path(), "/zisk/toolchain"),
+        ];
+        let rustflags: Vec<String> = remaps
+            .iter()
+            .map(|(from, to)| format!("--remap-path-prefix={}={}", from.display(), to))
+            .collect();
+
+        let kept: Vec<(&str, String)> = KEPT_ENV_VARS
+            .iter()
+            .filter_map(|name| std::env::var(name).ok().map(|value| (*name, value)))
+            .collect();
+        command.env_clear().envs(kept);
+
+        command
+            .env("RUSTUP_TOOLCHAIN", &self.toolchain.name)
+            .env("CARGO_HOME", &self.cargo_home)
+            .env("CARGO_TARGET_DIR", &self.target_dir)
+            .env("CARGO_ENCODED_RUSTFLAGS", rustflags.join("\x1f"))
+            .env("CARGO_INCREMENTAL", "0")
+            .env("SOURCE_DATE_EPOCH", self.source_date_epoch.to_string())
+            .env("TZ", "UTC")
+            .env("LC_ALL", "C")
+            .current_dir(&self.program_dir);
+
+        command.args(["--locked", "--offline", "--config"]).arg(&self.vendor_config);
+    }
+
+    /// Builds the manifest of the ELFs built with `options`, computing their program VKs.
+    pub fn manifest(&self, options: &BuildOptions) -> Result<BuildManifest> {
+        let args = BuildArgs {
+            release: options.release,
+            packages: options.packages.clone(),
+            binaries: options.binaries.clone(),
+            ..Default::default()
+        };
+
+        let mut elfs = Vec::new();
+        for (name, path) in generate_elf_paths(&self.metadata, Some(&args))? {
+            let elf = std::fs::read(&path).with_context(|| format!("Failed to read ELF {path}"))?;
+            tracing::info!("Computing program VK of {name}");
+            let program_vk = program_vk_from_elf(&elf)?;
+            let words: String = program_vk.vk.iter().map(|w| format!("{w:016x}")).collect();
+            elfs.push(ElfManifest {
+                name,
+                path: path
+                    .strip_prefix(&self.metadata.workspace_root)
+                    .unwrap_or(path.as_path())
+                    .to_string(),
+                hash: blake3::hash(&elf).to_hex().to_string(),
+                program_vk: format!("0x{words}"),
+            });
+        }
+
+        Ok(BuildManifest {
+            version: BUILD_MANIFEST_VERSION,
+            zisk_version: env!("CARGO_PKG_VERSION").to_string(),
+            toolchain: self.toolchain.clone(),
+            target: ZISK_TARGET.to_string(),
+            options: options.clone(),
+            source_commit: self.source_commit.clone(),
+            source_dirty: self.source_dirty,
+            source_date_epoch: self.source_date_epoch,
+            cargo_lock_hash: self.cargo_lock_hash.clone(),
+            elfs,
+        })
+    }
+
+    /// Path of the manifest of a build with the given profile
+    pub fn manifest_path(&self, release: bool) -> PathBuf {
+        let profile = if release { "release" } else { "debug" };
+        self.target_dir.join(ZISK_TARGET).join(profile).join(BUILD_MANIFEST_FILE).into()
+    }
+}
+
+/// Identity and sysroot of a rustup toolchain
+fn toolchain_info(name: &str) -> Result<(ToolchainInfo, PathBuf)> {
+    let rustc = |args: &[&str]| -> Result<String> {
+        let output =
+            Command::new("rustc").env("RUSTUP_TOOLCHAIN", name).args(args).output().map_err(
+                |_| {
+                    anyhow::anyhow!(
+                        "Toolchain '{name}' is not installed or rustup is not available.\n\
+                     Run `cargo zisk toolchain install` to install it."
+                    )
+                },
+            )?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Toolchain '{name}' is not installed.\n\
+                 Run `cargo zisk toolchain install` to install it."
+            );
+        }
+        String::from_utf8(output.stdout).context("Can't parse rustc output")
+    };
+
+    let version = rustc(&["-vV"])?;
+    let field = |key: &str| {
+        version
+            .lines()
+            .find_map(|line| line.strip_prefix(key))
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    };
+    let info = ToolchainInfo {
+        name: name.to_string(),
+        rustc: version.lines().next().unwrap_or_default().to_string(),
+        commit_hash: field("commit-hash:"),
+        llvm_version: field("LLVM version:"),
+    };
+
+    let sysroot = PathBuf::from(rustc(&["--print", "sysroot"])?.trim());
+    Ok((info, sysroot))
+}
+
+/// Git commit of the sources and whether the working tree is dirty, if they are in a git
+/// repository
+fn git_source(dir: &Path) -> (Option<String>, bool) {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "HEAD"]) {
+        Some(commit) => {
+            let dirty = git(&["status", "--porcelain"]).map_or(true, |status| !status.is_empty());
+            (Some(commit), dirty)
+        }
+        None => (None, false),
+    }
+}
+
+/// Commit time of `HEAD`, the conventional `SOURCE_DATE_EPOCH` of a checkout
+fn git_commit_time(dir: &Path) -> Result<u64> {
+    let output = Command::new("git")
+        .args(["log", "-1", "--format=%ct"])
+        .current_dir(dir)
+        .output()
+        .context("Failed to execute git log")?;
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .context("Failed to parse the commit time of HEAD")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> BuildManifest {
+        BuildManifest {
+            version: BUILD_MANIFEST_VERSION,
+            zisk_version: "0.0.0".to_string(),
+            toolchain: ToolchainInfo {
+                name: "zisk".to_string(),
+                rustc: "rustc 1.88.0-dev".to_string(),
+                commit_hash: "abc".to_string(),
+                llvm_version: "20.1.1".to_string(),
+            },
+            target: ZISK_TARGET.to_string(),
+            options: BuildOptions { release: true, ..Default::default() },
+            source_commit: Some("def".to_string()),
+            source_dirty: false,
+            source_date_epoch: 1700000000,
+            cargo_lock_hash: "01".to_string(),
+            elfs: vec![ElfManifest {
+                name: "guest".to_string(),
+                path: "target/elf/riscv64ima-zisk-zkvm-elf/release/guest".to_string(),
+                hash: "02".to_string(),
+                program_vk: format!("0x{}", "03".repeat(32)),
+            }],
+        }
+    }
+
+    #[test]
+    fn manifest_reproduces_itself() {
+        let expected = manifest();
+        let mut found = manifest();
+        // The version of the tools does not affect the ELFs
+        found.zisk_version = "0.0.1".to_string();
+        assert!(found.differences(&expected).is_empty());
+
+        let json = serde_json::to_string(&expected).unwrap();
+        assert_eq!(serde_json::from_str::<BuildManifest>(&json).unwrap(), expected);
+    }
+
+    #[test]
+    fn manifest_reports_differences() {
+        let expected = manifest();
+        let mut found = manifest();
+        found.toolchain.commit_hash = "abd".to_string();
+        found.elfs[0].hash = "04".to_string();
+        found.elfs.push(ElfManifest { name: "other".to_string(), ..found.elfs[0].clone() });
+
+        let differences = found.differences(&expected);
+        assert_eq!(differences.len(), 3, "{differences:?}");
+        assert!(differences[0].starts_with("toolchain commit"));
+        assert_eq!(differences[1], "guest hash: expected 02, found 04");
+        assert!(differences[2].contains("other"));
+    }
+
+    #[test]
+    fn manifest_reports_uncommitted_changes() {
+        let expected = manifest();
+        let mut found = manifest();
+        found.source_dirty = true;
+        assert_eq!(
+            found.differences(&expected),
+            ["sources: the working tree has uncommitted changes"]
+        );
+
+        // A manifest of a dirty tree is not reproduced by any build, not even by itself
+        assert_eq!(found.differences(&found).len(), 2);
+        assert_eq!(
+            expected.differences(&found),
+            ["sources: the expected manifest was built with uncommitted changes"]
+        );
+    }
+
+    #[test]
+    fn toolchain_reports_differences() {
+        let expected = manifest().toolchain;
+        let mut found = expected.clone();
+        found.name = "other".to_string();
+        assert!(found.differences(&expected).is_empty());
+
+        found.rustc = "rustc 1.89.0-dev".to_string();
+        assert_eq!(
+            found.differences(&expected),
+            ["toolchain: expected rustc 1.88.0-dev, found rustc 1.89.0-dev"]
+        );
+    }
+}
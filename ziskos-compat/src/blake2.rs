@@ -0,0 +1,123 @@
+//! BLAKE2b-512 backed by the `blake2br` precompile, compatible with `blake2::Blake2b512`
+
+#[cfg(not(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk"))))]
+pub use blake2::Blake2b512;
+
+#[cfg(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk")))]
+pub use zisk::*;
+
+#[cfg(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk")))]
+mod zisk {
+    use core::fmt;
+
+    use digest::{
+        block_buffer::Lazy,
+        core_api::{
+            AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper,
+            FixedOutputCore, OutputSizeUser, Reset, UpdateCore,
+        },
+        typenum::{U128, U64},
+        HashMarker, Output,
+    };
+    use ziskos::zisklib::blake2b_compress;
+
+    /// BLAKE2b-512 hasher
+    pub type Blake2b512 = CoreWrapper<Blake2b512Core>;
+
+    /// BLAKE2b initialization vectors
+    const IV: [u64; 8] = [
+        0x6A09E667F3BCC908,
+        0xBB67AE8584CAA73B,
+        0x3C6EF372FE94F82B,
+        0xA54FF53A5F1D36F1,
+        0x510E527FADE682D1,
+        0x9B05688C2B3E6C1F,
+        0x1F83D9ABFB41BD6B,
+        0x5BE0CD19137E2179,
+    ];
+
+    /// Number of rounds of BLAKE2b
+    const ROUNDS: u32 = 12;
+
+    /// Core BLAKE2b-512 hasher state
+    #[derive(Clone)]
+    pub struct Blake2b512Core {
+        h: [u64; 8],
+        /// Number of bytes hashed so far
+        t: u128,
+    }
+
+    impl Blake2b512Core {
+        fn compress(&mut self, block: &Block<Self>, last: bool) {
+            let mut m = [0u64; 16];
+            for (word, chunk) in m.iter_mut().zip(block.chunks_exact(8)) {
+                *word = u64::from_le_bytes(chunk.try_into().unwrap());
+            }
+            let t = [self.t as u64, (self.t >> 64) as u64];
+            blake2b_compress(ROUNDS, &mut self.h, &m, &t, last);
+        }
+    }
+
+    impl HashMarker for Blake2b512Core {}
+
+    impl BlockSizeUser for Blake2b512Core {
+        type BlockSize = U128;
+    }
+
+    // The last block is compressed with the finalization flag, so it must stay in the buffer
+    impl BufferKindUser for Blake2b512Core {
+        type BufferKind = Lazy;
+    }
+
+    impl OutputSizeUser for Blake2b512Core {
+        type OutputSize = U64;
+    }
+
+    impl UpdateCore for Blake2b512Core {
+        fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+            for block in blocks {
+                self.t += 128;
+                self.compress(block, false);
+            }
+        }
+    }
+
+    impl FixedOutputCore for Blake2b512Core {
+        fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+            self.t += buffer.get_pos() as u128;
+            let block = *buffer.pad_with_zeros();
+            self.compress(&block, true);
+
+            for (chunk, word) in out.chunks_exact_mut(8).zip(self.h) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+            }
+        }
+    }
+
+    impl Default for Blake2b512Core {
+        fn default() -> Self {
+            // Parameter block: 64-byte digest, no key, fanout and depth 1
+            let mut h = IV;
+            h[0] ^= 0x0101_0000 ^ 64;
+            Self { h, t: 0 }
+        }
+    }
+
+    impl Reset for Blake2b512Core {
+        fn reset(&mut self) {
+            *self = Self::default();
+        }
+    }
+
+    impl AlgorithmName for Blake2b512Core {
+        fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("Blake2b512")
+        }
+    }
+
+    impl fmt::Debug for Blake2b512Core {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("Blake2b512Core { ... }")
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! ECDSA verifying key shared by the [`crate::secp256k1`] and [`crate::secp256r1`] modules.
+
+/// Defines `Signature` and a `VerifyingKey` for a curve
+///
+/// * `$curve_crate` - RustCrypto crate of the curve (`k256`, `p256`)
+/// * `$curve` - curve type in that crate
+/// * `$zisk_verify` - `zisklib` function that verifies a signature over the curve
+/// * `$accept_s` - extra check on `s`, applied before the precompile-backed verification
+macro_rules! verifying_key {
+    ($curve_crate:ident, $curve:ident, $zisk_verify:ident, $accept_s:expr) => {
+        use $crate::digest::Digest;
+        use $crate::ecdsa::signature::{hazmat::PrehashVerifier, DigestVerifier, Error, Verifier};
+        #[allow(unused_imports)]
+        use $curve_crate::elliptic_curve::{scalar::IsHigh, sec1::ToEncodedPoint};
+
+        pub use $curve_crate::ecdsa::Signature;
+
+        /// ECDSA verifying key
+        ///
+        /// Wraps the software key of the curve crate, which is used to parse and validate the
+        /// public key and, off-target, to verify signatures.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct VerifyingKey {
+            inner: $curve_crate::ecdsa::VerifyingKey,
+        }
+
+        impl VerifyingKey {
+            /// Parses a SEC1-encoded public key, compressed or uncompressed
+            pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+                $curve_crate::ecdsa::VerifyingKey::from_sec1_bytes(bytes).map(Self::from)
+            }
+
+            /// Returns the wrapped software key
+            pub fn as_inner(&self) -> &$curve_crate::ecdsa::VerifyingKey {
+                &self.inner
+            }
+        }
+
+        impl From<$curve_crate::ecdsa::VerifyingKey> for VerifyingKey {
+            fn from(inner: $curve_crate::ecdsa::VerifyingKey) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl From<VerifyingKey> for $curve_crate::ecdsa::VerifyingKey {
+            fn from(key: VerifyingKey) -> Self {
+                key.inner
+            }
+        }
+
+        impl PrehashVerifier<Signature> for VerifyingKey {
+            #[cfg(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk")))]
+            fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> Result<(), Error> {
+                let limbs =
+                    |bytes: &[u8]| ziskos::zisklib::be_bytes_to_u64_4(bytes.try_into().unwrap());
+
+                if !($accept_s)(signature) {
+                    return Err(Error::new());
+                }
+
+                // z = bits2field(prehash), as in the software implementation
+                let z = $crate::ecdsa::hazmat::bits2field::<$curve_crate::$curve>(prehash)?;
+
+                // Verifying keys are never the identity, so both coordinates are present
+                let point = self.inner.as_affine().to_encoded_point(false);
+                let x = limbs(point.x().unwrap());
+                let y = limbs(point.y().unwrap());
+                let pk = [x[0], x[1], x[2], x[3], y[0], y[1], y[2], y[3]];
+
+                let (r, s) = signature.split_bytes();
+                let valid = ziskos::zisklib::$zisk_verify(&pk, &limbs(&z), &limbs(&r), &limbs(&s));
+                if valid {
+                    Ok(())
+                } else {
+                    Err(Error::new())
+                }
+            }
+
+            #[cfg(not(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk"))))]
+            fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> Result<(), Error> {
+                self.inner.verify_prehash(prehash, signature)
+            }
+        }
+
+        impl<D: Digest> DigestVerifier<D, Signature> for VerifyingKey {
+            fn verify_digest(&self, digest: D, signature: &Signature) -> Result<(), Error> {
+                self.verify_prehash(&digest.finalize(), signature)
+            }
+        }
+
+        /// Verifies a signature over the SHA-256 hash of the message
+        impl Verifier<Signature> for VerifyingKey {
+            fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+                self.verify_digest($crate::sha2::Sha256::new_with_prefix(msg), signature)
+            }
+        }
+    };
+}
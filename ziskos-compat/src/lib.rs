@@ -0,0 +1,197 @@
+//! RustCrypto trait implementations backed by `zisklib`.
+//!
+//! Generic code written against the [`digest`] and [`ecdsa`] traits can use these types in place
+//! of the `sha2`, `sha3`, `ripemd`, `blake2`, `k256` and `p256` ones, and get the ZisK precompiles
+//! inside the zkVM without `[patch]`-ing those crates. Off-target the same types are, or delegate
+//! to, the software implementations, unless the `zisklib` feature is enabled: then the
+//! precompile-backed types are built on the software fallbacks of the ZisK syscalls, which lets
+//! them be tested against the RustCrypto implementations on the host.
+//!
+//! ## Hashing ([`digest::Digest`])
+//! - [`sha2::Sha256`]
+//! - [`sha3::Keccak256`]
+//! - [`ripemd::Ripemd160`]
+//! - [`blake2::Blake2b512`]
+//!
+//! ## Signatures ([`ecdsa::signature::Verifier`], [`ecdsa::signature::DigestVerifier`] and
+//! [`ecdsa::hazmat::PrehashVerifier`])
+//! - [`secp256k1::VerifyingKey`]
+//! - [`secp256r1::VerifyingKey`]
+
+#![no_std]
+
+#[macro_use]
+mod ecdsa_impl;
+
+pub mod blake2;
+pub mod ripemd;
+pub mod sha2;
+pub mod sha3;
+
+pub use digest;
+pub use ecdsa;
+
+/// ECDSA over secp256k1, compatible with `k256::ecdsa`
+///
+/// As in `k256`, signatures with a high `s` are rejected.
+pub mod secp256k1 {
+    verifying_key!(k256, Secp256k1, ecdsa_verify_secp256k1, |s: &Signature| !bool::from(
+        s.s().is_high()
+    ));
+}
+
+/// ECDSA over secp256r1 (NIST P-256), compatible with `p256::ecdsa`
+pub mod secp256r1 {
+    verifying_key!(p256, NistP256, ecdsa_verify_secp256r1, |_: &Signature| true);
+}
+
+#[cfg(test)]
+mod tests {
+    use digest::Digest;
+    use ecdsa::signature::{Signer, Verifier};
+
+    fn hex(bytes: &[u8]) -> [u8; 128] {
+        let mut out = [b'0'; 128];
+        for (i, b) in bytes.iter().enumerate() {
+            out[2 * i] = b"0123456789abcdef"[(b >> 4) as usize];
+            out[2 * i + 1] = b"0123456789abcdef"[(b & 0xf) as usize];
+        }
+        out
+    }
+
+    fn check<D: Digest>(input: &[u8], expected: &str) {
+        let digest = D::digest(input);
+        assert_eq!(&hex(&digest)[..expected.len()], expected.as_bytes());
+
+        // Feeding the input in pieces gives the same digest
+        let mut hasher = D::new();
+        for chunk in input.chunks(7) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize(), digest);
+    }
+
+    #[test]
+    fn test_digests() {
+        check::<crate::sha2::Sha256>(
+            b"abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        );
+        check::<crate::sha3::Keccak256>(
+            b"",
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        );
+        check::<crate::ripemd::Ripemd160>(b"abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+        check::<crate::blake2::Blake2b512>(
+            b"abc",
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+        );
+
+        // Inputs spanning several blocks
+        let long = [0x61u8; 300];
+        check::<crate::sha2::Sha256>(&long, "");
+        check::<crate::sha3::Keccak256>(&long, "");
+        check::<crate::blake2::Blake2b512>(&long, "");
+    }
+
+    /// The precompile-backed hashers give the RustCrypto digests for every length and split
+    #[cfg(feature = "zisklib")]
+    #[test]
+    fn test_digests_match_rustcrypto() {
+        fn compare<Zisk: Digest, RustCrypto: Digest>() {
+            let input: [u8; 400] = core::array::from_fn(|i| (7 * i + 3) as u8);
+            for len in 0..=input.len() {
+                let expected = RustCrypto::digest(&input[..len]);
+                assert_eq!(Zisk::digest(&input[..len])[..], expected[..], "length {len}");
+
+                let (head, tail) = input[..len].split_at(len / 3);
+                let mut hasher = Zisk::new();
+                hasher.update(head);
+                hasher.update(tail);
+                assert_eq!(hasher.finalize()[..], expected[..], "length {len}");
+            }
+        }
+
+        compare::<crate::sha2::Sha256, ::sha2::Sha256>();
+        compare::<crate::sha3::Keccak256, ::sha3::Keccak256>();
+        compare::<crate::blake2::Blake2b512, ::blake2::Blake2b512>();
+    }
+
+    /// The precompile-backed `verify_prehash` accepts exactly what the RustCrypto one accepts
+    #[cfg(feature = "zisklib")]
+    #[test]
+    fn test_verify_prehash_matches_rustcrypto() {
+        use ecdsa::signature::hazmat::{PrehashSigner, PrehashVerifier};
+
+        macro_rules! compare {
+            ($curve_crate:ident, $module:ident) => {
+                for seed in 1u8..=4 {
+                    let signing_key =
+                        $curve_crate::ecdsa::SigningKey::from_bytes(&[seed; 32].into()).unwrap();
+                    let key = crate::$module::VerifyingKey::from(*signing_key.verifying_key());
+                    let other_key = crate::$module::VerifyingKey::from(
+                        *$curve_crate::ecdsa::SigningKey::from_bytes(&[seed + 1; 32].into())
+                            .unwrap()
+                            .verifying_key(),
+                    );
+
+                    let prehash = ::sha2::Sha256::digest([seed]);
+                    let signature: $curve_crate::ecdsa::Signature =
+                        signing_key.sign_prehash(&prehash).unwrap();
+                    let (r, s) = signature.split_scalars();
+                    let negated_s = $curve_crate::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+                    let mut tampered = prehash;
+                    tampered[0] ^= 1;
+                    let long_prehash = ::blake2::Blake2b512::digest([seed]);
+
+                    assert!(key.verify_prehash(&prehash, &signature).is_ok());
+                    for (key, prehash, signature) in [
+                        (&key, &prehash[..], &signature),
+                        (&key, &prehash[..], &negated_s),
+                        (&key, &tampered[..], &signature),
+                        (&other_key, &prehash[..], &signature),
+                        (&key, &long_prehash[..], &signature),
+                    ] {
+                        assert_eq!(
+                            key.verify_prehash(prehash, signature).is_ok(),
+                            key.as_inner().verify_prehash(prehash, signature).is_ok()
+                        );
+                    }
+                }
+            };
+        }
+
+        compare!(k256, secp256k1);
+        compare!(p256, secp256r1);
+    }
+
+    #[test]
+    fn test_secp256k1() {
+        let signing_key = k256::ecdsa::SigningKey::from_bytes(&[1u8; 32].into()).unwrap();
+        let key = crate::secp256k1::VerifyingKey::from(*signing_key.verifying_key());
+        let signature: k256::ecdsa::Signature = signing_key.sign(b"message");
+
+        assert!(key.verify(b"message", &signature).is_ok());
+        assert!(key.verify(b"other message", &signature).is_err());
+
+        // The same signature with a high `s` is rejected
+        let (r, s) = signature.split_scalars();
+        let high_s = k256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+        assert!(key.verify(b"message", &high_s).is_err());
+    }
+
+    #[test]
+    fn test_secp256r1() {
+        let signing_key = p256::ecdsa::SigningKey::from_bytes(&[1u8; 32].into()).unwrap();
+        let key = crate::secp256r1::VerifyingKey::from(*signing_key.verifying_key());
+        let signature: p256::ecdsa::Signature = signing_key.sign(b"message");
+
+        assert!(key.verify(b"message", &signature).is_ok());
+        assert!(key.verify(b"other message", &signature).is_err());
+
+        let sec1 = key.as_inner().to_encoded_point(true);
+        let parsed = crate::secp256r1::VerifyingKey::from_sec1_bytes(sec1.as_bytes()).unwrap();
+        assert_eq!(parsed, key);
+    }
+}
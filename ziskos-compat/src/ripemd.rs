@@ -0,0 +1,7 @@
+//! RIPEMD-160, compatible with `ripemd::Ripemd160`
+//!
+//! ZisK has no RIPEMD-160 precompile and `zisklib` hashes it in software, so this is the `ripemd`
+//! implementation on all targets. It is provided so that code can take all of its hashers from
+//! this crate.
+
+pub use ripemd::Ripemd160;
@@ -0,0 +1,89 @@
+//! SHA-256 backed by the `sha256f` precompile, compatible with `sha2::Sha256`
+
+#[cfg(not(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk"))))]
+pub use sha2::Sha256;
+
+#[cfg(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk")))]
+pub use zisk::*;
+
+#[cfg(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk")))]
+mod zisk {
+    use core::fmt;
+
+    use digest::{
+        block_buffer::Eager,
+        core_api::{
+            AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper,
+            FixedOutputCore, OutputSizeUser, Reset, UpdateCore,
+        },
+        typenum::{U32, U64},
+        HashMarker, Output,
+    };
+    use ziskos::zisklib::{sha256_compress, sha256_finalize, SHA256_INIT};
+
+    /// SHA-256 hasher
+    pub type Sha256 = CoreWrapper<Sha256Core>;
+
+    /// Core SHA-256 hasher state
+    #[derive(Clone)]
+    pub struct Sha256Core {
+        /// The eight 32-bit state words, stored as the precompile expects them
+        state: [u64; 4],
+        block_len: u64,
+    }
+
+    impl HashMarker for Sha256Core {}
+
+    impl BlockSizeUser for Sha256Core {
+        type BlockSize = U64;
+    }
+
+    impl BufferKindUser for Sha256Core {
+        type BufferKind = Eager;
+    }
+
+    impl OutputSizeUser for Sha256Core {
+        type OutputSize = U32;
+    }
+
+    impl UpdateCore for Sha256Core {
+        fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+            self.block_len += blocks.len() as u64;
+            for block in blocks {
+                sha256_compress(&mut self.state, block);
+            }
+        }
+    }
+
+    impl FixedOutputCore for Sha256Core {
+        fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+            let input_len = 64 * self.block_len + buffer.get_pos() as u64;
+            let mut state = self.state;
+            out.copy_from_slice(&sha256_finalize(&mut state, buffer.get_data(), input_len));
+        }
+    }
+
+    impl Default for Sha256Core {
+        fn default() -> Self {
+            Self { state: SHA256_INIT, block_len: 0 }
+        }
+    }
+
+    impl Reset for Sha256Core {
+        fn reset(&mut self) {
+            *self = Self::default();
+        }
+    }
+
+    impl AlgorithmName for Sha256Core {
+        fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("Sha256")
+        }
+    }
+
+    impl fmt::Debug for Sha256Core {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("Sha256Core { ... }")
+        }
+    }
+}
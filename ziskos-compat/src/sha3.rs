@@ -0,0 +1,97 @@
+//! Keccak-256 backed by the `keccakf` precompile, compatible with `sha3::Keccak256`
+
+#[cfg(not(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk"))))]
+pub use sha3::Keccak256;
+
+#[cfg(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk")))]
+pub use zisk::*;
+
+#[cfg(any(feature = "zisklib", all(target_os = "zkvm", target_vendor = "zisk")))]
+mod zisk {
+    use core::fmt;
+
+    use digest::{
+        block_buffer::Eager,
+        core_api::{
+            AlgorithmName, Block, BlockSizeUser, Buffer, BufferKindUser, CoreWrapper,
+            FixedOutputCore, OutputSizeUser, Reset, UpdateCore,
+        },
+        typenum::{U136, U32},
+        HashMarker, Output,
+    };
+    use ziskos::syscalls::syscall_keccak_f;
+
+    /// Keccak-256 hasher
+    pub type Keccak256 = CoreWrapper<Keccak256Core>;
+
+    /// Core Keccak-256 hasher state
+    #[derive(Clone, Default)]
+    pub struct Keccak256Core {
+        state: [u64; 25],
+    }
+
+    impl Keccak256Core {
+        fn absorb(&mut self, block: &Block<Self>) {
+            for (word, chunk) in self.state.iter_mut().zip(block.chunks_exact(8)) {
+                *word ^= u64::from_le_bytes(chunk.try_into().unwrap());
+            }
+            unsafe { syscall_keccak_f(&mut self.state) };
+        }
+    }
+
+    impl HashMarker for Keccak256Core {}
+
+    impl BlockSizeUser for Keccak256Core {
+        type BlockSize = U136;
+    }
+
+    impl BufferKindUser for Keccak256Core {
+        type BufferKind = Eager;
+    }
+
+    impl OutputSizeUser for Keccak256Core {
+        type OutputSize = U32;
+    }
+
+    impl UpdateCore for Keccak256Core {
+        fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+            for block in blocks {
+                self.absorb(block);
+            }
+        }
+    }
+
+    impl FixedOutputCore for Keccak256Core {
+        fn finalize_fixed_core(&mut self, buffer: &mut Buffer<Self>, out: &mut Output<Self>) {
+            // Keccak padding: 0x01 after the message and 0x80 at the end of the rate
+            let pos = buffer.get_pos();
+            let block = buffer.pad_with_zeros();
+            block[pos] = 0x01;
+            block[135] |= 0x80;
+            let block = *block;
+            self.absorb(&block);
+
+            for (chunk, word) in out.chunks_exact_mut(8).zip(self.state) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+            }
+        }
+    }
+
+    impl Reset for Keccak256Core {
+        fn reset(&mut self) {
+            *self = Self::default();
+        }
+    }
+
+    impl AlgorithmName for Keccak256Core {
+        fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("Keccak256")
+        }
+    }
+
+    impl fmt::Debug for Keccak256Core {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("Keccak256Core { ... }")
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{handlers::validate_hint_length, hint_fields, zisklib};
+use crate::{
+    handlers::{read_field_bytes, validate_hint_length},
+    hint_fields, zisklib,
+};
 
 use anyhow::Result;
 
@@ -171,3 +174,201 @@ pub fn bls12_381_fp2_to_g2_hint(data: &[u64]) -> Result<Vec<u64>> {
 
     Ok(hints)
 }
+
+/// Checks that the variable-length fields read so far cover the whole hint data.
+fn validate_fields_length(data: &[u64], pos: usize, hint_name: &str) -> Result<()> {
+    validate_hint_length(data, pos.div_ceil(8), hint_name)
+}
+
+/// Reads the 8-byte little-endian BLS variant field.
+fn read_variant(data: &[u64], pos: &mut usize, hint_name: &str) -> Result<u32> {
+    let (variant, _) = read_field_bytes(data, pos)?;
+    let variant: [u8; 8] = variant
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("{}: variant must be 8 bytes", hint_name))?;
+    Ok(u64::from_le_bytes(variant) as u32)
+}
+
+/// Processes an `HINT_BLS12_381_HASH_TO_G1` hint.
+#[inline]
+pub fn bls12_381_hash_to_g1_hint(data: &[u64]) -> Result<Vec<u64>> {
+    let mut pos = 0;
+    let (msg, msg_len) = read_field_bytes(data, &mut pos)?;
+    let (dst, dst_len) = read_field_bytes(data, &mut pos)?;
+    validate_fields_length(data, pos, "HINT_BLS12_381_HASH_TO_G1")?;
+
+    let mut hints = Vec::new();
+    let result: &mut [u8; 96] = &mut [0u8; 96];
+    unsafe {
+        zisklib::bls12_381_hash_to_g1_c(
+            result.as_mut_ptr(),
+            msg.as_ptr(),
+            msg_len,
+            dst.as_ptr(),
+            dst_len,
+            &mut hints,
+        );
+    }
+
+    Ok(hints)
+}
+
+/// Processes an `HINT_BLS12_381_HASH_TO_G2` hint.
+#[inline]
+pub fn bls12_381_hash_to_g2_hint(data: &[u64]) -> Result<Vec<u64>> {
+    let mut pos = 0;
+    let (msg, msg_len) = read_field_bytes(data, &mut pos)?;
+    let (dst, dst_len) = read_field_bytes(data, &mut pos)?;
+    validate_fields_length(data, pos, "HINT_BLS12_381_HASH_TO_G2")?;
+
+    let mut hints = Vec::new();
+    let result: &mut [u8; 192] = &mut [0u8; 192];
+    unsafe {
+        zisklib::bls12_381_hash_to_g2_c(
+            result.as_mut_ptr(),
+            msg.as_ptr(),
+            msg_len,
+            dst.as_ptr(),
+            dst_len,
+            &mut hints,
+        );
+    }
+
+    Ok(hints)
+}
+
+/// Returns the sizes of a public key and a signature for a BLS variant.
+fn bls_variant_sizes(variant: u32, hint_name: &str) -> Result<(usize, usize)> {
+    zisklib::bls_variant_sizes(variant)
+        .ok_or_else(|| anyhow::anyhow!("{}: invalid variant {}", hint_name, variant))
+}
+
+/// Processes an `HINT_BLS_VERIFY` hint.
+#[inline]
+pub fn bls_verify_hint(data: &[u64]) -> Result<Vec<u64>> {
+    const NAME: &str = "HINT_BLS_VERIFY";
+
+    let mut pos = 0;
+    let variant = read_variant(data, &mut pos, NAME)?;
+    let (pubkey, _) = read_field_bytes(data, &mut pos)?;
+    let (msg, msg_len) = read_field_bytes(data, &mut pos)?;
+    let (signature, _) = read_field_bytes(data, &mut pos)?;
+    let (dst, dst_len) = read_field_bytes(data, &mut pos)?;
+    validate_fields_length(data, pos, NAME)?;
+
+    let (pk_size, sig_size) = bls_variant_sizes(variant, NAME)?;
+    validate_hint_length(pubkey, pk_size, NAME)?;
+    validate_hint_length(signature, sig_size, NAME)?;
+
+    let mut hints = Vec::new();
+    unsafe {
+        zisklib::bls_verify_c(
+            variant,
+            pubkey.as_ptr(),
+            msg.as_ptr(),
+            msg_len,
+            signature.as_ptr(),
+            dst.as_ptr(),
+            dst_len,
+            &mut hints,
+        );
+    }
+
+    Ok(hints)
+}
+
+/// Processes an `HINT_BLS_AGGREGATE_VERIFY` hint.
+#[inline]
+pub fn bls_aggregate_verify_hint(data: &[u64]) -> Result<Vec<u64>> {
+    const NAME: &str = "HINT_BLS_AGGREGATE_VERIFY";
+
+    let mut pos = 0;
+    let variant = read_variant(data, &mut pos, NAME)?;
+    let (pubkeys, pubkeys_len) = read_field_bytes(data, &mut pos)?;
+    let (msgs, msgs_len) = read_field_bytes(data, &mut pos)?;
+    let (msg_lens, _) = read_field_bytes(data, &mut pos)?;
+    let (signature, _) = read_field_bytes(data, &mut pos)?;
+    let (dst, dst_len) = read_field_bytes(data, &mut pos)?;
+    validate_fields_length(data, pos, NAME)?;
+
+    let (pk_size, sig_size) = bls_variant_sizes(variant, NAME)?;
+    validate_hint_length(signature, sig_size, NAME)?;
+    if pubkeys_len % pk_size != 0 {
+        anyhow::bail!(
+            "{}: public keys length {} is not a multiple of {}",
+            NAME,
+            pubkeys_len,
+            pk_size
+        );
+    }
+    let num_pubkeys = pubkeys_len / pk_size;
+
+    // Message lengths are sent as little-endian u64 values
+    validate_hint_length(msg_lens, num_pubkeys * 8, NAME)?;
+    let msg_lens: Vec<usize> = msg_lens
+        .chunks_exact(8)
+        .map(|len| u64::from_le_bytes(len.try_into().unwrap()) as usize)
+        .collect();
+    if msg_lens.iter().sum::<usize>() != msgs_len {
+        anyhow::bail!("{}: message lengths do not add up to {} bytes", NAME, msgs_len);
+    }
+
+    let mut hints = Vec::new();
+    unsafe {
+        zisklib::bls_aggregate_verify_c(
+            variant,
+            pubkeys.as_ptr(),
+            num_pubkeys,
+            msgs.as_ptr(),
+            msg_lens.as_ptr(),
+            signature.as_ptr(),
+            dst.as_ptr(),
+            dst_len,
+            &mut hints,
+        );
+    }
+
+    Ok(hints)
+}
+
+/// Processes an `HINT_BLS_FAST_AGGREGATE_VERIFY` hint.
+#[inline]
+pub fn bls_fast_aggregate_verify_hint(data: &[u64]) -> Result<Vec<u64>> {
+    const NAME: &str = "HINT_BLS_FAST_AGGREGATE_VERIFY";
+
+    let mut pos = 0;
+    let variant = read_variant(data, &mut pos, NAME)?;
+    let (pubkeys, pubkeys_len) = read_field_bytes(data, &mut pos)?;
+    let (msg, msg_len) = read_field_bytes(data, &mut pos)?;
+    let (signature, _) = read_field_bytes(data, &mut pos)?;
+    let (dst, dst_len) = read_field_bytes(data, &mut pos)?;
+    validate_fields_length(data, pos, NAME)?;
+
+    let (pk_size, sig_size) = bls_variant_sizes(variant, NAME)?;
+    validate_hint_length(signature, sig_size, NAME)?;
+    if pubkeys_len % pk_size != 0 {
+        anyhow::bail!(
+            "{}: public keys length {} is not a multiple of {}",
+            NAME,
+            pubkeys_len,
+            pk_size
+        );
+    }
+
+    let mut hints = Vec::new();
+    unsafe {
+        zisklib::bls_fast_aggregate_verify_c(
+            variant,
+            pubkeys.as_ptr(),
+            pubkeys_len / pk_size,
+            msg.as_ptr(),
+            msg_len,
+            signature.as_ptr(),
+            dst.as_ptr(),
+            dst_len,
+            &mut hints,
+        );
+    }
+
+    Ok(hints)
+}
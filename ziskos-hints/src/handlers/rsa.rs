@@ -0,0 +1,50 @@
+use crate::handlers::{read_field_bytes, validate_hint_length};
+use crate::zisklib;
+
+use anyhow::Result;
+
+/// Reads an 8-byte little-endian integer field.
+fn read_u64_field(data: &[u64], pos: &mut usize, field: &str) -> Result<u64> {
+    let (bytes, _) = read_field_bytes(data, pos)?;
+    let bytes: [u8; 8] = bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("HINT_RSA_VERIFY: {} must be 8 bytes", field))?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Processes an `HINT_RSA_VERIFY` hint.
+#[inline]
+pub fn rsa_verify_hint(data: &[u64]) -> Result<Vec<u64>> {
+    const NAME: &str = "HINT_RSA_VERIFY";
+
+    let mut pos = 0;
+    let scheme = read_u64_field(data, &mut pos, "scheme")? as u32;
+    let (modulus, modulus_len) = read_field_bytes(data, &mut pos)?;
+    let (exponent, exponent_len) = read_field_bytes(data, &mut pos)?;
+    let (msg_hash, _) = read_field_bytes(data, &mut pos)?;
+    let (signature, signature_len) = read_field_bytes(data, &mut pos)?;
+    let salt_len = read_u64_field(data, &mut pos, "salt length")? as usize;
+    validate_hint_length(data, pos.div_ceil(8), NAME)?;
+    validate_hint_length(msg_hash, 32, NAME)?;
+
+    let mut hints = Vec::new();
+    let result = unsafe {
+        zisklib::rsa::rsa_verify_c(
+            scheme,
+            modulus.as_ptr(),
+            modulus_len,
+            exponent.as_ptr(),
+            exponent_len,
+            msg_hash.as_ptr(),
+            signature.as_ptr(),
+            signature_len,
+            salt_len,
+            &mut hints,
+        )
+    };
+    if result.is_none() {
+        anyhow::bail!("{}: invalid scheme {}", NAME, scheme);
+    }
+
+    Ok(hints)
+}
@@ -10,10 +10,10 @@ mod core;
 // Re-export everything from the symlinked implementation
 pub use core::*;
 
-// The symlinked source references INPUT_POS/INPUT_INITIAL_OFFSET as crate-root
+// The symlinked source references INPUT_POS/PROGRAM_INPUT_OFFSET as crate-root
 // items (since in the `ziskos` crate, core/lib.rs *is* the crate root). Mirror
 // that here so the same source compiles in both crates.
-pub(crate) use core::{INPUT_INITIAL_OFFSET, INPUT_POS};
+pub(crate) use core::{INPUT_POS, PROGRAM_INPUT_OFFSET};
 
 // Add hints-specific modules that only exist in ziskos-hints
 pub mod handlers;
@@ -0,0 +1,120 @@
+//! Guest program exit codes
+//!
+//! The exit code of a guest program is the value returned by its entry point, converted with
+//! [`Termination`], or the code passed to [`exit`] or `std::process::exit`. It is left in `a0`
+//! when the program halts, where the emulator reads it back. With the `commit-exit-code` feature
+//! it is also committed to the public output slot [`EXIT_CODE_OUTPUT_ID`], so that proofs attest
+//! it.
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use core::arch::asm;
+use core::fmt;
+
+pub use zisk_definitions::EXIT_CODE_OUTPUT_ID;
+
+/// Exit code of the values returned by the guest entry point, as `std::process::Termination`
+/// does for `main`
+pub trait Termination {
+    fn report(self) -> i32;
+}
+
+impl Termination for () {
+    fn report(self) -> i32 {
+        0
+    }
+}
+
+impl Termination for i32 {
+    fn report(self) -> i32 {
+        self
+    }
+}
+
+impl Termination for u8 {
+    fn report(self) -> i32 {
+        self as i32
+    }
+}
+
+impl<T: Termination, E: fmt::Debug> Termination for Result<T, E> {
+    fn report(self) -> i32 {
+        match self {
+            Ok(value) => value.report(),
+            Err(error) => {
+                print_error(&error);
+                1
+            }
+        }
+    }
+}
+
+/// Prints the error returned by the entry point to the standard error
+fn print_error(error: &dyn fmt::Debug) {
+    #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+    {
+        use core::fmt::Write;
+
+        struct Stderr;
+
+        impl fmt::Write for Stderr {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                crate::ziskos::sys_write(2, s.as_ptr(), s.len());
+                Ok(())
+            }
+        }
+
+        let _ = writeln!(Stderr, "Error: {error:?}");
+    }
+    #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+    eprintln!("Error: {error:?}");
+}
+
+/// Called with the exit code right before the program halts
+pub(crate) fn on_exit(_code: i32) {
+    #[cfg(feature = "commit-exit-code")]
+    crate::set_output(EXIT_CODE_OUTPUT_ID, _code as u32);
+}
+
+/// Terminates the guest program with the given exit code
+///
+/// On the zkVM target `std::process::exit` ends up here too, through the `sys_exit` syscall.
+pub fn exit(code: i32) -> ! {
+    crate::zkvm_deinit();
+    on_exit(code);
+
+    #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+    unsafe {
+        asm!(
+          "csrr t0, marchid",
+          "li   t1, 0xFFFEEEE",
+          "beq t0, t1, 1f",
+
+          // QEmu exit
+          "li t0, 0x100000",
+          "li t1, 0x5555",
+          "sw t1, 0(t0)",
+          "2: j 2b",
+
+          // Zisk exit, with the exit code in a0
+          "1: li   a7, 93",
+          "ecall",
+          "3: j 3b",
+
+          in("a0") code,
+          options(noreturn)
+        );
+    }
+
+    #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+    std::process::exit(code)
+}
+
+#[cfg(all(test, feature = "commit-exit-code"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code_slot_is_reserved() {
+        assert!(crate::is_reserved_output(EXIT_CODE_OUTPUT_ID));
+    }
+}
@@ -1,8 +1,9 @@
-use crate::hints::macros::{define_hint, define_hint_pairs};
+use crate::hints::macros::{define_hint, define_hint_pairs, define_hint_ptr};
 use zisk_common::{
     HINT_BLS12_381_FP2_TO_G2, HINT_BLS12_381_FP_TO_G1, HINT_BLS12_381_G1_ADD,
-    HINT_BLS12_381_G1_MSM, HINT_BLS12_381_G2_ADD, HINT_BLS12_381_G2_MSM,
-    HINT_BLS12_381_PAIRING_CHECK,
+    HINT_BLS12_381_G1_MSM, HINT_BLS12_381_G2_ADD, HINT_BLS12_381_G2_MSM, HINT_BLS12_381_HASH_TO_G1,
+    HINT_BLS12_381_HASH_TO_G2, HINT_BLS12_381_PAIRING_CHECK, HINT_BLS_AGGREGATE_VERIFY,
+    HINT_BLS_FAST_AGGREGATE_VERIFY, HINT_BLS_VERIFY,
 };
 
 define_hint! {
@@ -60,3 +61,43 @@ define_hint! {
         is_result: false,
     }
 }
+
+define_hint_ptr! {
+    bls12_381_hash_to_g1 => {
+        hint_id: HINT_BLS12_381_HASH_TO_G1,
+        params: (msg, dst),
+        is_result: false,
+    }
+}
+
+define_hint_ptr! {
+    bls12_381_hash_to_g2 => {
+        hint_id: HINT_BLS12_381_HASH_TO_G2,
+        params: (msg, dst),
+        is_result: false,
+    }
+}
+
+define_hint_ptr! {
+    bls_verify => {
+        hint_id: HINT_BLS_VERIFY,
+        params: (variant, pubkey, msg, signature, dst),
+        is_result: false,
+    }
+}
+
+define_hint_ptr! {
+    bls_aggregate_verify => {
+        hint_id: HINT_BLS_AGGREGATE_VERIFY,
+        params: (variant, pubkeys, msgs, msg_lens, signature, dst),
+        is_result: false,
+    }
+}
+
+define_hint_ptr! {
+    bls_fast_aggregate_verify => {
+        hint_id: HINT_BLS_FAST_AGGREGATE_VERIFY,
+        params: (variant, pubkeys, msg, signature, dst),
+        is_result: false,
+    }
+}
@@ -2,7 +2,11 @@ use crate::hints::macros::define_hint_ptr;
 use zisk_common::HINT_INPUT;
 
 #[no_mangle]
-pub unsafe extern "C" fn hint_input_data(input_data_ptr: *const u8, input_data_len: usize) {
+pub unsafe extern "C" fn hint_input_data(
+    input_data_ptr: *const u8,
+    input_data_len: usize,
+    public: bool,
+) {
     if !crate::hints::HINT_BUFFER.is_enabled() {
         return;
     }
@@ -15,9 +19,11 @@ pub unsafe extern "C" fn hint_input_data(input_data_ptr: *const u8, input_data_l
     let pad = (8 - (input_data_len & 7)) & 7;
     let mut w = crate::hints::HINT_BUFFER.begin_input_data();
 
-    // Write the length of the input data as the first 8 bytes of the hint data,
-    // followed by the input data itself, and then pad with zeros if necessary
-    let input_data_len_bytes: [u8; 8] = (input_data_len as u64).to_le_bytes();
+    // Write the length of the input data as the first 8 bytes of the hint data, with the public
+    // flag of the input item, followed by the input data itself, and then pad with zeros if
+    // necessary
+    let public_flag = if public { crate::PUBLIC_INPUT_FLAG } else { 0 };
+    let input_data_len_bytes: [u8; 8] = (input_data_len as u64 | public_flag).to_le_bytes();
     w.write_data_slice(&input_data_len_bytes);
     w.write_data_ptr(input_data_ptr, input_data_len);
     if pad > 0 {
@@ -9,6 +9,7 @@ mod kzg;
 mod macros;
 mod modexp;
 mod ripemd160;
+mod rsa;
 mod secp256k1;
 mod secp256r1;
 mod sha256f;
@@ -47,6 +48,7 @@ pub use keccak256::*;
 pub use kzg::*;
 pub use modexp::*;
 pub use ripemd160::*;
+pub use rsa::*;
 pub use secp256k1::*;
 pub use secp256r1::*;
 pub use sha256f::*;
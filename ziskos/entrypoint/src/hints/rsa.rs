@@ -0,0 +1,10 @@
+use crate::hints::macros::define_hint_ptr;
+use zisk_common::HINT_RSA_VERIFY;
+
+define_hint_ptr! {
+    rsa_verify => {
+        hint_id: HINT_RSA_VERIFY,
+        params: (scheme, modulus, exponent, msg_hash, signature, salt_len),
+        is_result: false,
+    }
+}
@@ -0,0 +1,132 @@
+//! Commitment to the public inputs
+//!
+//! With the `input-commitment` feature, the input items that the host marks as public (see
+//! `ZiskStdin::write_public`) are folded into a running digest as the program reads them, starting
+//! from 32 zero bytes:
+//! - with SHA-256, `c = sha256(c || sha256(item))`
+//! - with Poseidon2, when the `input-commitment-poseidon2` feature is also enabled,
+//!   `c = compress(c, hash_bytes(item))`, the digest being serialized as four little-endian `u64`
+//!
+//! When the program ends, the digest is committed to the 8 public output slots starting at
+//! [`INPUT_COMMITMENT_OUTPUT_ID`], so that verifiers can recompute it from the public items with
+//! `input_commitment()`, which folds them with [`fold_sha256`] or [`fold_poseidon2`] as the
+//! guest does. Public items that the program never reads are not part of it.
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::vec::Vec;
+
+pub use zisk_definitions::{INPUT_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_SIZE};
+
+/// Number of public output slots of the commitment
+#[cfg(feature = "input-commitment")]
+pub(crate) const INPUT_COMMITMENT_SLOTS: usize = INPUT_COMMITMENT_SIZE / 4;
+
+/// Running digest of the public inputs read so far
+#[cfg(feature = "input-commitment")]
+static mut COMMITMENT: [u8; INPUT_COMMITMENT_SIZE] = [0; INPUT_COMMITMENT_SIZE];
+
+/// Folds a public input item into a SHA-256 input commitment: `sha256(c || sha256(item))`
+pub fn fold_sha256(
+    commitment: &[u8; INPUT_COMMITMENT_SIZE],
+    item: &[u8],
+) -> [u8; INPUT_COMMITMENT_SIZE] {
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(commitment);
+    preimage[32..].copy_from_slice(&crate::zisklib::sha256(
+        item,
+        #[cfg(feature = "hints")]
+        &mut Vec::new(),
+    ));
+    crate::zisklib::sha256(
+        &preimage,
+        #[cfg(feature = "hints")]
+        &mut Vec::new(),
+    )
+}
+
+/// Folds a public input item into a Poseidon2 input commitment: `compress(c, hash_bytes(item))`,
+/// the digests being serialized as four little-endian `u64`
+pub fn fold_poseidon2(
+    commitment: &[u8; INPUT_COMMITMENT_SIZE],
+    item: &[u8],
+) -> [u8; INPUT_COMMITMENT_SIZE] {
+    use crate::zisklib::poseidon2::{compress, hash_bytes, Digest};
+
+    let mut left: Digest = [0; 4];
+    for (element, chunk) in left.iter_mut().zip(commitment.chunks_exact(8)) {
+        *element = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    let right = hash_bytes(
+        item,
+        #[cfg(feature = "hints")]
+        &mut Vec::new(),
+    );
+    let digest = compress(
+        &left,
+        &right,
+        #[cfg(feature = "hints")]
+        &mut Vec::new(),
+    );
+
+    let mut bytes = [0u8; INPUT_COMMITMENT_SIZE];
+    for (chunk, element) in bytes.chunks_exact_mut(8).zip(digest) {
+        chunk.copy_from_slice(&element.to_le_bytes());
+    }
+    bytes
+}
+
+/// Folds a public input item into the commitment
+#[cfg(feature = "input-commitment")]
+pub(crate) fn absorb(item: &[u8]) {
+    let commitment = unsafe { COMMITMENT };
+
+    #[cfg(not(feature = "input-commitment-poseidon2"))]
+    let commitment = fold_sha256(&commitment, item);
+
+    #[cfg(feature = "input-commitment-poseidon2")]
+    let commitment = fold_poseidon2(&commitment, item);
+
+    unsafe { COMMITMENT = commitment };
+}
+
+/// Writes the commitment to its public output slots
+#[cfg(feature = "input-commitment")]
+pub(crate) fn commit() {
+    let commitment = unsafe { COMMITMENT };
+    for (i, word) in commitment.chunks_exact(4).enumerate() {
+        crate::set_output(
+            INPUT_COMMITMENT_OUTPUT_ID + i,
+            u32::from_le_bytes(word.try_into().unwrap()),
+        );
+    }
+}
+
+#[cfg(all(feature = "input-commitment", not(all(target_os = "zkvm", target_vendor = "zisk"))))]
+pub(crate) fn reset() {
+    unsafe { COMMITMENT = [0; INPUT_COMMITMENT_SIZE] };
+}
+
+#[cfg(all(test, feature = "input-commitment"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commitment_slots_are_reserved() {
+        assert!((INPUT_COMMITMENT_OUTPUT_ID..INPUT_COMMITMENT_OUTPUT_ID + INPUT_COMMITMENT_SLOTS)
+            .all(crate::is_reserved_output));
+    }
+
+    /// Without the features that read a first input item, the user outputs fill the slots up to
+    /// the commitment and the next word is refused
+    #[cfg(not(any(feature = "host-rng-seed", feature = "host-env")))]
+    #[test]
+    fn test_commit_stops_at_the_commitment_slots() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        crate::set_native_input(Vec::new());
+        crate::zkvm_init();
+
+        crate::io::commit_slice(&[0u8; INPUT_COMMITMENT_OUTPUT_ID * 4]);
+        let result = std::panic::catch_unwind(|| crate::io::commit_slice(&[0u8; 4]));
+        assert!(result.is_err());
+    }
+}
@@ -54,7 +54,11 @@ pub fn commit<T: Serialize>(value: &T) {
 ///
 /// Successive calls append to the same byte stream; partial 32-bit output slots
 /// are shared across calls.
+///
+/// Panics if the bytes reach a slot reserved by an enabled feature, such as the host environment
+/// commitment of `host-env`.
 pub fn commit_slice(buf: &[u8]) {
+    crate::zisklib::zkvm_io::check_output_len(buf.len());
     // SAFETY: buf.as_ptr() is valid for buf.len() bytes by construction of &[u8].
     unsafe { crate::zisklib::zkvm_io::write_output(buf.as_ptr(), buf.len()) };
 }
@@ -21,11 +21,25 @@ extern crate alloc as alloc_crate;
 #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
 pub(crate) use alloc_crate as alloc_extern;
 
+mod exit;
+pub mod input_commitment;
 mod profile;
+#[cfg(feature = "host-rng-seed")]
+mod rng;
+#[cfg(feature = "host-env")]
+pub mod shims;
+pub use exit::*;
 #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
 pub use fcall::*;
+pub use input_commitment::{INPUT_COMMITMENT_OUTPUT_ID, INPUT_COMMITMENT_SIZE};
 pub mod io;
 pub use profile::*;
+pub mod recursion;
+#[cfg(feature = "host-rng-seed")]
+pub use rng::{RNG_SEED_COMMITMENT_OUTPUT_ID, RNG_SEED_SIZE};
+#[cfg(feature = "host-env")]
+pub use shims::HOST_ENV_COMMITMENT_OUTPUT_ID;
+pub use ziskos_macros::profile;
 pub mod syscalls;
 pub mod zisklib;
 pub mod ziskos_definitions;
@@ -39,7 +53,7 @@ pub mod hints;
 
 #[cfg(all(not(all(target_os = "zkvm", target_vendor = "zisk")), zisk_hints))]
 extern "C" {
-    fn hint_input_data(input_data_ptr: *const u8, input_data_len: usize);
+    fn hint_input_data(input_data_ptr: *const u8, input_data_len: usize, public: bool);
 }
 
 #[cfg(all(not(all(target_os = "zkvm", target_vendor = "zisk")), zisk_hints_debug))]
@@ -70,8 +84,12 @@ pub fn hint_log<S: AsRef<str>>(msg: S) {
 pub extern "C" fn zkvm_init() {
     #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
     {
+        unsafe { PROGRAM_INPUT_OFFSET = INPUT_INITIAL_OFFSET };
         read_input_reset();
         crate::zisklib::zkvm_io::reset();
+        *HOST_OUTPUTS.lock().unwrap() = [0; 64];
+        #[cfg(feature = "input-commitment")]
+        input_commitment::reset();
     }
 
     #[cfg(all(
@@ -88,11 +106,22 @@ pub extern "C" fn zkvm_init() {
             });
         crate::hints::init_hints_file(path, None).expect("hints init failed");
     }
+
+    #[cfg(feature = "host-rng-seed")]
+    rng::init_host_rng_seed();
+
+    #[cfg(feature = "host-env")]
+    crate::shims::init_host_env();
+
+    unsafe { PROGRAM_INPUT_OFFSET = INPUT_POS };
 }
 
 #[cfg_attr(not(feature = "hints"), no_mangle)]
 #[cfg_attr(feature = "hints", export_name = "hints_zkvm_deinit")]
 pub extern "C" fn zkvm_deinit() {
+    #[cfg(feature = "input-commitment")]
+    input_commitment::commit();
+
     #[cfg(all(
         not(all(target_os = "zkvm", target_vendor = "zisk")),
         zisk_hints,
@@ -106,14 +135,17 @@ pub extern "C" fn zkvm_deinit() {
 #[macro_export]
 macro_rules! entrypoint {
     ($path:path) => {
-        const ZISK_ENTRY: fn() = $path;
+        fn zisk_entry() -> impl $crate::Termination {
+            $path()
+        }
 
         mod zkvm_generated_main {
             #[no_mangle]
-            fn main() {
+            extern "C" fn main() -> i32 {
                 $crate::zkvm_init();
-                super::ZISK_ENTRY();
+                let code = $crate::Termination::report(super::zisk_entry());
                 $crate::zkvm_deinit();
+                code
             }
         }
     };
@@ -137,12 +169,25 @@ pub(crate) const INPUT_INITIAL_OFFSET: usize = 8;
 #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
 pub(crate) const INPUT_INITIAL_OFFSET: usize = 0;
 
+pub(crate) use zisk_definitions::PUBLIC_INPUT_FLAG;
+
+/// Splits the length prefix of an input item into the item length and its public flag
+#[inline]
+pub(crate) fn input_item_len(prefix: u64) -> (usize, bool) {
+    ((prefix & !PUBLIC_INPUT_FLAG) as usize, prefix & PUBLIC_INPUT_FLAG != 0)
+}
+
 /// Pointer to the current position in the input buffer/file.
 pub(crate) static mut INPUT_POS: usize = INPUT_INITIAL_OFFSET;
 
-/// Reset the input position to the beginning.
+/// Position of the first input item of the program, after the items consumed by [`zkvm_init`]
+/// for the enabled features, such as the RNG seed of `host-rng-seed` and the environment frame
+/// of `host-env`
+pub(crate) static mut PROGRAM_INPUT_OFFSET: usize = INPUT_INITIAL_OFFSET;
+
+/// Reset the input position to the first input item of the program.
 pub fn read_input_reset() {
-    unsafe { INPUT_POS = INPUT_INITIAL_OFFSET };
+    unsafe { INPUT_POS = PROGRAM_INPUT_OFFSET };
 }
 
 #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
@@ -166,10 +211,10 @@ pub(crate) fn read_slice_zerocopy<'a>() -> &'a [u8] {
 
     // Ensure the 8-byte length prefix is ready and read it
     crate::zisklib::fcall_input_ready(&((addr + 7) as u64));
-    let len = unsafe {
+    let (len, _public) = input_item_len(unsafe {
         let bytes = core::slice::from_raw_parts(addr as *const u8, 8);
-        u64::from_le_bytes(bytes.try_into().unwrap()) as usize
-    };
+        u64::from_le_bytes(bytes.try_into().unwrap())
+    });
 
     // Ensure the data is ready (8-byte aligned)
     let data_addr = addr + 8;
@@ -181,6 +226,11 @@ pub(crate) fn read_slice_zerocopy<'a>() -> &'a [u8] {
 
     let data_slice = unsafe { core::slice::from_raw_parts(data_addr as *const u8, len) };
 
+    #[cfg(feature = "input-commitment")]
+    if _public {
+        input_commitment::absorb(data_slice);
+    }
+
     #[cfg(zisk_hints_debug)]
     {
         let start_bytes = &data_slice[..data_slice.len().min(64)];
@@ -200,20 +250,21 @@ pub(crate) fn read_slice_zerocopy<'a>() -> &'a [u8] {
 pub(crate) fn read_input() -> Vec<u8> {
     let input_pos = unsafe { INPUT_POS };
 
-    let data = if let Some(buf) = NATIVE_INPUT.lock().unwrap().as_ref() {
+    #[allow(unused_variables)]
+    let (data, public) = if let Some(buf) = NATIVE_INPUT.lock().unwrap().as_ref() {
         let len_bytes: [u8; 8] = buf
             .get(input_pos..input_pos + 8)
             .expect("Failed to read length prefix from native input")
             .try_into()
             .unwrap();
-        let len = u64::from_le_bytes(len_bytes) as usize;
+        let (len, public) = input_item_len(u64::from_le_bytes(len_bytes));
         let data = buf
             .get(input_pos + 8..input_pos + 8 + len)
             .expect("Failed to read data from native input")
             .to_vec();
         let aligned_len = (len + 7) & !0x7;
         unsafe { INPUT_POS = input_pos + 8 + aligned_len };
-        data
+        (data, public)
     } else {
         use std::{
             fs::File,
@@ -232,7 +283,7 @@ pub(crate) fn read_input() -> Vec<u8> {
         // Read the 8-byte length prefix
         let mut len_bytes = [0u8; 8];
         file.read_exact(&mut len_bytes).expect("Failed to read length prefix from input file");
-        let len = u64::from_le_bytes(len_bytes) as usize;
+        let (len, public) = input_item_len(u64::from_le_bytes(len_bytes));
 
         // Read the actual data
         let mut data = vec![0u8; len];
@@ -242,12 +293,17 @@ pub(crate) fn read_input() -> Vec<u8> {
         let aligned_len = (len + 7) & !0x7;
         unsafe { INPUT_POS = input_pos + 8 + aligned_len };
 
-        data
+        (data, public)
     };
 
+    #[cfg(feature = "input-commitment")]
+    if public {
+        input_commitment::absorb(&data);
+    }
+
     #[cfg(zisk_hints)]
     unsafe {
-        hint_input_data(data.as_ptr(), data.len());
+        hint_input_data(data.as_ptr(), data.len(), public);
     }
 
     #[cfg(zisk_hints_debug)]
@@ -289,11 +345,76 @@ pub(crate) fn set_output(id: usize, value: u32) {
     unsafe { core::ptr::write_volatile(addr_v, value) };
 }
 
+/// Public outputs written so far, kept on native builds so that tests can read them back
+#[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+static HOST_OUTPUTS: std::sync::Mutex<[u32; 64]> = std::sync::Mutex::new([0; 64]);
+
 #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
 pub(crate) fn set_output(id: usize, value: u32) {
+    assert!(id < 64, "Maximum number of public outputs: 64");
+    HOST_OUTPUTS.lock().unwrap()[id] = value;
     println!("public {id}: {value:#010x}");
 }
 
+/// Returns the public outputs written so far on native builds, e.g. to compare them with the
+/// values computed on the host
+#[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+pub fn native_outputs() -> [u32; 64] {
+    *HOST_OUTPUTS.lock().unwrap()
+}
+
+/// Serializes the tests that go through the global input and output state
+#[cfg(test)]
+#[allow(dead_code)]
+pub(crate) static HOST_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Encodes input items as the host does: a little-endian length prefix followed by the data
+/// padded to 8 bytes
+#[cfg(test)]
+#[allow(dead_code)]
+pub(crate) fn encode_input_items<T: AsRef<[u8]>>(items: &[T]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for item in items {
+        let item = item.as_ref();
+        data.extend_from_slice(&(item.len() as u64).to_le_bytes());
+        data.extend_from_slice(item);
+        data.resize(data.len().next_multiple_of(8), 0);
+    }
+    data
+}
+
+/// Returns if a public output slot is reserved by an enabled feature, and so can not be written
+/// with [`io::commit`]
+#[allow(unused_variables)]
+pub(crate) fn is_reserved_output(id: usize) -> bool {
+    #[cfg(feature = "host-env")]
+    if (HOST_ENV_COMMITMENT_OUTPUT_ID
+        ..HOST_ENV_COMMITMENT_OUTPUT_ID + shims::HOST_ENV_COMMITMENT_SLOTS)
+        .contains(&id)
+    {
+        return true;
+    }
+    #[cfg(feature = "host-rng-seed")]
+    if (RNG_SEED_COMMITMENT_OUTPUT_ID
+        ..RNG_SEED_COMMITMENT_OUTPUT_ID + rng::RNG_SEED_COMMITMENT_SLOTS)
+        .contains(&id)
+    {
+        return true;
+    }
+    #[cfg(feature = "input-commitment")]
+    if (INPUT_COMMITMENT_OUTPUT_ID
+        ..INPUT_COMMITMENT_OUTPUT_ID + input_commitment::INPUT_COMMITMENT_SLOTS)
+        .contains(&id)
+    {
+        return true;
+    }
+    #[cfg(feature = "commit-exit-code")]
+    if id == EXIT_CODE_OUTPUT_ID {
+        return true;
+    }
+    false
+}
+
 #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
 pub mod ziskos {
     use crate::ziskos_definitions::ziskos_config::*;
@@ -313,7 +434,7 @@ pub mod ziskos {
           // set the stack pointer
           "la sp, _init_stack_top",
 
-          // "tail-call" to {entry}
+          // "tail-call" to {entry}, which leaves the exit code in a0
           "call {_zisk_main}",
           "csrr t0, marchid",
           //"li   t1, {_ARCH_ID_ZISK}",
@@ -328,7 +449,7 @@ pub mod ziskos {
           "sw t1, 0(t0)",
           "j 2f",
 
-          // Zisk exit
+          // Zisk exit, with the exit code in a0
           "1: li   a7, 93",
           "ecall",
 
@@ -350,10 +471,10 @@ pub mod ziskos {
     }
 
     #[no_mangle]
-    unsafe extern "C" fn _zisk_main() {
+    unsafe extern "C" fn _zisk_main() -> i32 {
         {
             extern "C" {
-                fn main();
+                fn main() -> i32;
             }
             #[cfg(any(
                 feature = "zisk-embedded-alloc",
@@ -370,12 +491,17 @@ pub mod ziskos {
             ))]
             crate::alloc::init_sys_alloc();
 
-            main()
+            let code = main();
+            crate::exit::on_exit(code);
+            code
         }
     }
 
+    /// File descriptor of the standard error stream
+    const STDERR_FD: u32 = 2;
+
     #[no_mangle]
-    pub extern "C" fn sys_write(_fd: u32, write_ptr: *const u8, nbytes: usize) {
+    pub extern "C" fn sys_write(fd: u32, write_ptr: *const u8, nbytes: usize) {
         let arch_id_zisk: usize;
         let mut addr: *mut u8 = 0x1000_0000 as *mut u8;
 
@@ -386,7 +512,7 @@ pub mod ziskos {
             )
         };
         if arch_id_zisk == ARCH_ID_ZISK as usize {
-            addr = UART_ADDR as *mut u8;
+            addr = if fd == STDERR_FD { UART_ERR_ADDR } else { UART_ADDR } as *mut u8;
         }
 
         for i in 0..nbytes {
@@ -403,10 +529,17 @@ pub mod ziskos {
     static RNG: Mutex<Option<SmallRng>> = Mutex::new(None);
     static SYS_RAND_WARNING: AtomicBool = AtomicBool::new(false);
 
+    /// Seeds the generator behind `sys_rand` with the seed provided by the host
+    #[cfg(feature = "host-rng-seed")]
+    pub(crate) fn seed_rng(seed: [u8; 32]) {
+        SYS_RAND_WARNING.store(true, Ordering::Relaxed);
+        *RNG.lock() = Some(SmallRng::from_seed(seed));
+    }
+
     #[no_mangle]
     unsafe extern "C" fn sys_rand(recv_buf: *mut u8, words: usize) {
         if !SYS_RAND_WARNING.swap(true, Ordering::Relaxed) {
-            let msg = b"WARNING: Using insecure random number generator.\n";
+            let msg = b"WARNING: Using insecure random number generator, enable the `host-rng-seed` feature to seed it from the host.\n";
             sys_write(1, msg.as_ptr(), msg.len());
         }
         let mut rng_guard = RNG.lock();
@@ -417,14 +550,48 @@ pub mod ziskos {
         }
     }
 
+    /// Backs `std::env::var`: copies up to `words` words of the value of the variable to
+    /// `recv_buf` and returns its length in bytes, or `usize::MAX` if it is not set. Variables
+    /// are set by the host with the `host-env` feature, otherwise none is set.
+    #[no_mangle]
+    unsafe extern "C" fn sys_getenv(
+        recv_buf: *mut u32,
+        words: usize,
+        varname: *const u8,
+        varname_len: usize,
+    ) -> usize {
+        #[cfg(feature = "host-env")]
+        {
+            let key = core::slice::from_raw_parts(varname, varname_len);
+            let buf = core::slice::from_raw_parts_mut(recv_buf as *mut u8, words * 4);
+            crate::shims::env::copy_var(key, buf).unwrap_or(usize::MAX)
+        }
+        #[cfg(not(feature = "host-env"))]
+        {
+            let _ = (recv_buf, words, varname, varname_len);
+            usize::MAX
+        }
+    }
+
+    #[no_mangle]
+    unsafe extern "C" fn sys_alloc_words(nwords: usize) -> *mut u32 {
+        let layout = core::alloc::Layout::from_size_align(nwords * 4, 4).unwrap();
+        crate::alloc_extern::alloc::alloc(layout) as *mut u32
+    }
+
+    /// Exit syscall of the zkVM standard library, behind `std::process::exit`. Ends the program
+    /// like [`crate::exit`], so the exit code is reported and committed as when returned by the
+    /// entry point. The standard library passes the code as the first argument, in `a0`, which
+    /// `emulator/tests/exit_code.rs` checks on a guest built with the toolchain.
     #[no_mangle]
-    extern "C" fn sys_getenv() {
-        //unimplemented!("sys_getenv")
+    extern "C" fn sys_exit(code: i32) -> ! {
+        crate::exit::exit(code)
     }
 
+    /// Halt syscall of the zkVM standard library, see [`sys_exit`]
     #[no_mangle]
-    extern "C" fn sys_alloc_words() {
-        //unimplemented!("sys_alloc_words")
+    extern "C" fn sys_halt(code: i32) -> ! {
+        crate::exit::exit(code)
     }
 
     #[no_mangle]
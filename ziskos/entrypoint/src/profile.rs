@@ -130,3 +130,128 @@ macro_rules! profile_report_steps_start {
 macro_rules! profile_report_steps_end {
     ($name:ident) => {};
 }
+
+/// Metric and report mode of a profile region opened by a [`ProfileScope`]
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+#[derive(Clone, Copy)]
+enum ProfileKind {
+    Cost,
+    Steps,
+    ReportCost,
+    ReportSteps,
+}
+
+/// Profile region that ends when the guard is dropped
+///
+/// Emits the `PROFILE_START_*` mark when created and the matching `PROFILE_END_*` mark when
+/// dropped, so the region is closed on every return path of the enclosing scope. Outside the
+/// zkVM it is an empty type and compiles to nothing. Functions can be profiled as a whole with
+/// the [`profile`](macro@crate::profile) attribute.
+///
+/// ```ignore
+/// let _scope = ziskos::ProfileScope::start("parse_block");
+/// ```
+#[must_use = "the profile region ends as soon as the scope is dropped"]
+pub struct ProfileScope {
+    #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+    name: &'static str,
+    #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+    kind: ProfileKind,
+}
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+impl ProfileScope {
+    /// Opens a region that measures cost, printed when the region ends
+    #[inline(always)]
+    pub fn start(name: &'static str) -> Self {
+        let scope = Self { name, kind: ProfileKind::Cost };
+        crate::ziskos_syscall!(
+            SYSCALL_PROFILE_ID,
+            PROFILE_START_COST_ID,
+            &scope.name as *const _ as usize
+        );
+        scope
+    }
+
+    /// Opens a region that measures steps, printed when the region ends
+    #[inline(always)]
+    pub fn start_steps(name: &'static str) -> Self {
+        let scope = Self { name, kind: ProfileKind::Steps };
+        crate::ziskos_syscall!(
+            SYSCALL_PROFILE_ID,
+            PROFILE_START_STEPS_ID,
+            &scope.name as *const _ as usize
+        );
+        scope
+    }
+
+    /// Opens a region that measures cost, aggregated in the profile tags report
+    #[inline(always)]
+    pub fn start_report(name: &'static str) -> Self {
+        let scope = Self { name, kind: ProfileKind::ReportCost };
+        crate::ziskos_syscall!(
+            SYSCALL_PROFILE_ID,
+            PROFILE_REPORT_START_COST_ID,
+            &scope.name as *const _ as usize
+        );
+        scope
+    }
+
+    /// Opens a region that measures steps, aggregated in the profile tags report
+    #[inline(always)]
+    pub fn start_report_steps(name: &'static str) -> Self {
+        let scope = Self { name, kind: ProfileKind::ReportSteps };
+        crate::ziskos_syscall!(
+            SYSCALL_PROFILE_ID,
+            PROFILE_REPORT_START_STEPS_ID,
+            &scope.name as *const _ as usize
+        );
+        scope
+    }
+}
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+impl Drop for ProfileScope {
+    #[inline(always)]
+    fn drop(&mut self) {
+        // The mark id is an immediate of the syscall, so each kind needs its own constant
+        let name = &self.name as *const _ as usize;
+        match self.kind {
+            ProfileKind::Cost => {
+                crate::ziskos_syscall!(SYSCALL_PROFILE_ID, PROFILE_END_COST_ID, name)
+            }
+            ProfileKind::Steps => {
+                crate::ziskos_syscall!(SYSCALL_PROFILE_ID, PROFILE_END_STEPS_ID, name)
+            }
+            ProfileKind::ReportCost => {
+                crate::ziskos_syscall!(SYSCALL_PROFILE_ID, PROFILE_REPORT_END_COST_ID, name)
+            }
+            ProfileKind::ReportSteps => {
+                crate::ziskos_syscall!(SYSCALL_PROFILE_ID, PROFILE_REPORT_END_STEPS_ID, name)
+            }
+        }
+    }
+}
+
+#[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+impl ProfileScope {
+    #[inline(always)]
+    pub fn start(_name: &'static str) -> Self {
+        Self {}
+    }
+
+    #[inline(always)]
+    pub fn start_steps(_name: &'static str) -> Self {
+        Self {}
+    }
+
+    #[inline(always)]
+    pub fn start_report(_name: &'static str) -> Self {
+        Self {}
+    }
+
+    #[inline(always)]
+    pub fn start_report_steps(_name: &'static str) -> Self {
+        Self {}
+    }
+}
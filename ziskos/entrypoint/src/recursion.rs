@@ -0,0 +1,296 @@
+//! Verification of ZisK proofs inside a guest program
+//!
+//! The host writes each inner proof as one input item with `ZiskStdin::write_proof`, in the
+//! layout `[minimal][n_publics][program_vk][publics][proof][vadcop_vk]`. [`verify_proof`] checks
+//! that the proof was generated for the expected program, verifies it against the expected
+//! Vadcop final VK and returns its public outputs deserialized into the type the inner program
+//! committed with [`crate::io::commit`].
+//!
+//! The Vadcop VK at the end of the proof is never trusted: a proof generated with another setup
+//! carries the VK of that setup, so it is only accepted if it matches the expected one.
+//!
+//! The expected VKs usually come from the host as well. Committing them with
+//! [`VerifiedProof::commit_program_vk`] and [`VerifiedProof::commit_vadcop_vk`] binds the outer
+//! proof to the inner program and setup, so that the verifier of the outer proof only has to
+//! check the committed VKs.
+//!
+//! ```ignore
+//! let program_vk: ProgramVk = ziskos::io::read();
+//! let vadcop_vk: VadcopVk = ziskos::io::read();
+//! let proof = ziskos::io::read_input_slice();
+//! let publics: InnerPublics = ziskos::recursion::verify_proof(&proof, &program_vk, &vadcop_vk);
+//! ```
+//!
+//! Plonk-wrapped proofs are verified with [`verify_plonk`], on the BN254 precompiles. They do not
+//! carry their public outputs, so the host writes them next to the proof.
+
+use core::fmt;
+
+use serde::de::DeserializeOwned;
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::vec::Vec;
+
+/// Length of a program VK in u64 words
+pub const PROGRAM_VK_LEN: usize = zisk_verifier::PROGRAM_VK_LEN;
+
+/// Length of a Vadcop final VK in u64 words
+pub const VADCOP_VK_LEN: usize = zisk_verifier::VADCOP_VK_LEN_WORDS;
+
+/// Size in bytes of the public outputs of a proof
+pub const PUBLICS_SIZE: usize = zisk_verifier::ZISK_PUBLICS * 4;
+
+pub use zisk_verifier::{PlonkVerifyingKey, PLONK_PROOF_SIZE};
+
+/// Verification key of a guest program, as found in its proofs
+pub type ProgramVk = [u64; PROGRAM_VK_LEN];
+
+/// Verification key of the Vadcop final proofs of a setup, as found at the end of its proofs
+pub type VadcopVk = [u64; VADCOP_VK_LEN];
+
+/// Offset of the program VK in a serialized proof, after `minimal` and `n_publics`
+const PROGRAM_VK_OFFSET: usize = 2;
+
+/// Offset of the public outputs in a serialized proof
+const PUBLICS_OFFSET: usize = PROGRAM_VK_OFFSET + PROGRAM_VK_LEN;
+
+/// Reason an inner proof was not accepted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecursionError {
+    /// The proof bytes are not a serialized Vadcop final proof
+    MalformedProof,
+    /// The proof was generated for another program
+    ProgramVkMismatch,
+    /// The proof was generated with another setup
+    VadcopVkMismatch,
+    /// The proof does not verify
+    Rejected,
+    /// The public outputs do not deserialize into the requested type
+    InvalidPublics,
+}
+
+impl fmt::Display for RecursionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecursionError::MalformedProof => write!(f, "malformed proof"),
+            RecursionError::ProgramVkMismatch => write!(f, "proof is for another program"),
+            RecursionError::VadcopVkMismatch => write!(f, "proof is for another setup"),
+            RecursionError::Rejected => write!(f, "proof rejected"),
+            RecursionError::InvalidPublics => write!(f, "invalid public outputs"),
+        }
+    }
+}
+
+/// Inner proof that passed verification
+pub struct VerifiedProof {
+    program_vk: ProgramVk,
+    vadcop_vk: VadcopVk,
+    publics: [u8; PUBLICS_SIZE],
+}
+
+impl VerifiedProof {
+    /// Verification key of the program that generated the proof
+    pub fn program_vk(&self) -> &ProgramVk {
+        &self.program_vk
+    }
+
+    /// Vadcop final VK the proof was verified against
+    pub fn vadcop_vk(&self) -> &VadcopVk {
+        &self.vadcop_vk
+    }
+
+    /// Raw public outputs of the proof
+    pub fn publics_bytes(&self) -> &[u8; PUBLICS_SIZE] {
+        &self.publics
+    }
+
+    /// Deserializes the public outputs, committed by the inner program with [`crate::io::commit`]
+    pub fn publics<T: DeserializeOwned>(&self) -> Result<T, RecursionError> {
+        bincode::serde::decode_from_slice(&self.publics, bincode::config::standard())
+            .map(|(value, _)| value)
+            .map_err(|_| RecursionError::InvalidPublics)
+    }
+
+    /// SHA-256 hash of the raw public outputs, as returned by `PublicValues::hash` on the host
+    pub fn publics_hash(&self) -> [u8; 32] {
+        crate::zisklib::sha256(
+            &self.publics,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        )
+    }
+
+    /// Commits the program VK to the public outputs of this program
+    pub fn commit_program_vk(&self) {
+        crate::io::commit(&self.program_vk);
+    }
+
+    /// Commits the Vadcop final VK to the public outputs of this program
+    pub fn commit_vadcop_vk(&self) {
+        crate::io::commit(&self.vadcop_vk);
+    }
+
+    /// Commits [`publics_hash`](Self::publics_hash) to the public outputs of this program
+    pub fn commit_publics_hash(&self) {
+        crate::io::commit(&self.publics_hash());
+    }
+}
+
+/// Verifies a proof of the program with VK `expected_program_vk`, generated with the setup of
+/// Vadcop final VK `expected_vadcop_vk`, and returns its public outputs deserialized into `T`
+///
+/// Panics if the proof is not accepted, see [`try_verify_proof`].
+pub fn verify_proof<T: DeserializeOwned>(
+    proof_bytes: &[u8],
+    expected_program_vk: &ProgramVk,
+    expected_vadcop_vk: &VadcopVk,
+) -> T {
+    match try_verify_proof(proof_bytes, expected_program_vk, expected_vadcop_vk) {
+        Ok(publics) => publics,
+        Err(e) => panic!("Inner proof verification failed: {}", e),
+    }
+}
+
+/// Verifies a proof of the program with VK `expected_program_vk`, generated with the setup of
+/// Vadcop final VK `expected_vadcop_vk`, and returns its public outputs deserialized into `T`
+pub fn try_verify_proof<T: DeserializeOwned>(
+    proof_bytes: &[u8],
+    expected_program_vk: &ProgramVk,
+    expected_vadcop_vk: &VadcopVk,
+) -> Result<T, RecursionError> {
+    verify(proof_bytes, expected_program_vk, expected_vadcop_vk)?.publics()
+}
+
+/// Verifies a proof of the program with VK `expected_program_vk`, generated with the setup of
+/// Vadcop final VK `expected_vadcop_vk`
+pub fn verify(
+    proof_bytes: &[u8],
+    expected_program_vk: &ProgramVk,
+    expected_vadcop_vk: &VadcopVk,
+) -> Result<VerifiedProof, RecursionError> {
+    if proof_bytes.len() % 8 != 0 {
+        return Err(RecursionError::MalformedProof);
+    }
+
+    // Input items are 8-byte aligned; copy only if the caller passed an unaligned slice
+    let copy: Vec<u64>;
+    let words = match unsafe { proof_bytes.align_to::<u64>() } {
+        ([], words, []) => words,
+        _ => {
+            copy = proof_bytes
+                .chunks_exact(8)
+                .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+                .collect();
+            &copy
+        }
+    };
+
+    let publics_end = PUBLICS_OFFSET + zisk_verifier::ZISK_PUBLICS;
+    if words.len() < publics_end + VADCOP_VK_LEN
+        || words[1] != (PROGRAM_VK_LEN + zisk_verifier::ZISK_PUBLICS) as u64
+    {
+        return Err(RecursionError::MalformedProof);
+    }
+
+    let program_vk: ProgramVk = words[PROGRAM_VK_OFFSET..PUBLICS_OFFSET].try_into().unwrap();
+    if program_vk != *expected_program_vk {
+        return Err(RecursionError::ProgramVkMismatch);
+    }
+
+    let (proof, vadcop_vk) = words.split_at(words.len() - VADCOP_VK_LEN);
+    if vadcop_vk != expected_vadcop_vk {
+        return Err(RecursionError::VadcopVkMismatch);
+    }
+
+    // Each public output is a u32 widened to a u64 word. A word with high bits set has no u32
+    // representation, so the publics returned would not be the ones the proof is verified for.
+    let mut publics = [0u8; PUBLICS_SIZE];
+    for (chunk, word) in publics.chunks_exact_mut(4).zip(&words[PUBLICS_OFFSET..publics_end]) {
+        let word = u32::try_from(*word).map_err(|_| RecursionError::MalformedProof)?;
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+
+    if !zisk_verifier::verify_vadcop_final_proof(proof, expected_vadcop_vk) {
+        return Err(RecursionError::Rejected);
+    }
+
+    Ok(VerifiedProof { program_vk, vadcop_vk: *expected_vadcop_vk, publics })
+}
+
+/// Verifies a Plonk-wrapped proof of the program with VK `expected_program_vk`, generated with the
+/// setup of Vadcop final VK `expected_vadcop_vk`, against its public outputs `publics`
+///
+/// `vk` is the Plonk key of the setup, as converted by `PlonkVkey::to_verifying_key` on the host.
+/// The VKs and the public outputs are bound by the single public input of the proof, so a proof of
+/// another program or setup is [`RecursionError::Rejected`].
+pub fn verify_plonk(
+    vk: &PlonkVerifyingKey,
+    proof_bytes: &[u8],
+    expected_program_vk: &ProgramVk,
+    publics: &[u8; PUBLICS_SIZE],
+    expected_vadcop_vk: &VadcopVk,
+) -> Result<VerifiedProof, RecursionError> {
+    zisk_verifier::verify_zisk_plonk_proof(
+        vk,
+        proof_bytes,
+        expected_program_vk,
+        publics,
+        expected_vadcop_vk,
+    )
+    .map_err(|e| match e {
+        zisk_verifier::VerifyError::MalformedProof => RecursionError::MalformedProof,
+        _ => RecursionError::Rejected,
+    })?;
+
+    Ok(VerifiedProof {
+        program_vk: *expected_program_vk,
+        vadcop_vk: *expected_vadcop_vk,
+        publics: *publics,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM_VK: ProgramVk = [1, 2, 3, 4];
+    const VADCOP_VK: VadcopVk = [5, 6, 7, 8];
+
+    /// Serialized proof of `PROGRAM_VK` ending with `vadcop_vk`
+    fn proof_bytes(vadcop_vk: &VadcopVk) -> Vec<u8> {
+        let mut words = vec![0, (PROGRAM_VK_LEN + zisk_verifier::ZISK_PUBLICS) as u64];
+        words.extend(PROGRAM_VK);
+        words.extend([0; zisk_verifier::ZISK_PUBLICS]);
+        words.extend([0; 16]);
+        words.extend(vadcop_vk);
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_rejects_a_proof_of_another_program() {
+        let proof = proof_bytes(&VADCOP_VK);
+        assert_eq!(
+            verify(&proof, &[4, 3, 2, 1], &VADCOP_VK).err(),
+            Some(RecursionError::ProgramVkMismatch)
+        );
+    }
+
+    #[test]
+    fn test_rejects_publics_that_are_not_u32() {
+        let mut proof = proof_bytes(&VADCOP_VK);
+        proof[(PUBLICS_OFFSET + 3) * 8 + 4] = 1;
+        assert_eq!(
+            verify(&proof, &PROGRAM_VK, &VADCOP_VK).err(),
+            Some(RecursionError::MalformedProof)
+        );
+    }
+
+    #[test]
+    fn test_rejects_a_swapped_vadcop_vk() {
+        let proof = proof_bytes(&[8, 7, 6, 5]);
+        assert_eq!(
+            verify(&proof, &PROGRAM_VK, &VADCOP_VK).err(),
+            Some(RecursionError::VadcopVkMismatch)
+        );
+    }
+}
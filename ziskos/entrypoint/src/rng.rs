@@ -0,0 +1,113 @@
+//! Host-seeded random number generator
+//!
+//! With the `host-rng-seed` feature, the first input item is a seed chosen by the host (see
+//! `ZiskStdin::with_rng_seed`). It is read before the entry point runs and seeds the generator
+//! behind `sys_rand`, and so `getrandom`, instead of the fixed seed used otherwise. Its SHA-256
+//! hash is committed to the 8 public output slots starting at [`RNG_SEED_COMMITMENT_OUTPUT_ID`],
+//! so that verifiers can tell which seed a proof used without the seed being public.
+
+/// Size in bytes of the host seed
+pub const RNG_SEED_SIZE: usize = 32;
+
+pub use zisk_definitions::RNG_SEED_COMMITMENT_OUTPUT_ID;
+
+/// Number of public output slots of the seed commitment
+pub(crate) const RNG_SEED_COMMITMENT_SLOTS: usize = 8;
+
+/// Reads the host seed from the input, commits its hash and seeds the generator
+pub(crate) fn init_host_rng_seed() {
+    let input = crate::io::read_input_slice();
+    let seed: [u8; RNG_SEED_SIZE] = input
+        .as_ref()
+        .try_into()
+        .expect("The first input must be the 32-byte RNG seed set with with_rng_seed()");
+
+    let commitment = crate::zisklib::sha256(
+        &seed,
+        #[cfg(feature = "hints")]
+        &mut Vec::new(),
+    );
+    for (i, word) in commitment.chunks_exact(4).enumerate() {
+        crate::set_output(
+            RNG_SEED_COMMITMENT_OUTPUT_ID + i,
+            u32::from_le_bytes(word.try_into().unwrap()),
+        );
+    }
+
+    #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+    crate::ziskos::seed_rng(seed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    /// Input with the seed and, with `host-env`, the environment frame read by `zkvm_init`,
+    /// followed by `items`
+    fn init_input(seed: &[u8], items: &[&[u8]]) -> Vec<u8> {
+        let mut all = vec![seed.to_vec()];
+        #[cfg(feature = "host-env")]
+        all.push(crate::shims::default_frame());
+        all.extend(items.iter().map(|item| item.to_vec()));
+        crate::encode_input_items(&all)
+    }
+
+    #[test]
+    fn test_seed_commitment_keeps_user_output_slots() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let seed = [7u8; RNG_SEED_SIZE];
+        crate::set_native_input(init_input(&seed, &[&[1, 2, 3, 4, 5, 6, 7, 8]]));
+        crate::zkvm_init();
+
+        let user: [u8; 8] = crate::io::read_input_slice().as_ref().try_into().unwrap();
+        crate::io::commit_slice(&user);
+
+        let outputs = crate::native_outputs();
+        assert_eq!(outputs[0], u32::from_le_bytes([1, 2, 3, 4]));
+        assert_eq!(outputs[1], u32::from_le_bytes([5, 6, 7, 8]));
+
+        let expected: [u8; 32] = Sha256::digest(seed).into();
+        let committed: Vec<u8> = outputs[RNG_SEED_COMMITMENT_OUTPUT_ID
+            ..RNG_SEED_COMMITMENT_OUTPUT_ID + RNG_SEED_COMMITMENT_SLOTS]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect();
+        assert_eq!(committed, expected);
+    }
+
+    /// The standard IO interface and the streaming reads start from the first item of the
+    /// program, after the seed read by `zkvm_init`
+    #[test]
+    fn test_program_input_follows_frames() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        crate::set_native_input(init_input(&[7u8; RNG_SEED_SIZE], &[b"first", b"second"]));
+        crate::zkvm_init();
+
+        let (mut data, mut len) = (core::ptr::null(), 0);
+        unsafe { crate::zisklib::zkvm_io::read_input(&mut data, &mut len) };
+        assert_eq!(unsafe { core::slice::from_raw_parts(data, len) }, b"first");
+
+        assert_eq!(crate::io::read_input_slice().as_ref(), b"first");
+        assert_eq!(crate::io::read_input_slice().as_ref(), b"second");
+        crate::read_input_reset();
+        assert_eq!(crate::io::read_input_slice().as_ref(), b"first");
+    }
+
+    #[test]
+    fn test_seed_commitment_slots_are_reserved() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        crate::set_native_input(init_input(&[7u8; RNG_SEED_SIZE], &[]));
+        crate::zkvm_init();
+
+        assert!((RNG_SEED_COMMITMENT_OUTPUT_ID
+            ..RNG_SEED_COMMITMENT_OUTPUT_ID + RNG_SEED_COMMITMENT_SLOTS)
+            .all(crate::is_reserved_output));
+
+        // The user outputs fill the slots up to the first reserved one, the next word is refused
+        let first_reserved = (0..).find(|&id| crate::is_reserved_output(id)).unwrap();
+        crate::io::commit_slice(&vec![0u8; first_reserved * 4]);
+        let result = std::panic::catch_unwind(|| crate::io::commit_slice(&[0u8; 4]));
+        assert!(result.is_err());
+    }
+}
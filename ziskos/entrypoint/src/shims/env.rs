@@ -0,0 +1,28 @@
+//! Environment variables set by the host with `HostEnv::with_var`
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::{string::String, vec::Vec};
+
+/// Returns the value of the variable `key`, or `None` if the host did not set it
+pub fn var(key: &str) -> Option<String> {
+    super::with_host_env(|env| {
+        env.vars.iter().find(|(name, _)| name == key).map(|(_, value)| value.clone())
+    })
+}
+
+/// Returns all the variables, in the order the host set them
+pub fn vars() -> Vec<(String, String)> {
+    super::with_host_env(|env| env.vars.clone())
+}
+
+/// Copies the value of the variable `key` to `buf`, returning its full length, or `None` if the
+/// host did not set it
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+pub(crate) fn copy_var(key: &[u8], buf: &mut [u8]) -> Option<usize> {
+    super::with_host_env(|env| {
+        let (_, value) = env.vars.iter().find(|(name, _)| name.as_bytes() == key)?;
+        let len = value.len().min(buf.len());
+        buf[..len].copy_from_slice(&value.as_bytes()[..len]);
+        Some(value.len())
+    })
+}
@@ -0,0 +1,29 @@
+//! Read-only in-memory filesystem with the files set by the host with `HostEnv::with_file`
+//!
+//! Paths are compared as given by the host, without normalization.
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::{string::String, vec::Vec};
+
+/// Returns the contents of the file at `path`, or `None` if the host did not provide it
+pub fn read(path: &str) -> Option<Vec<u8>> {
+    super::with_host_env(|env| {
+        env.files.iter().find(|(name, _)| name == path).map(|(_, contents)| contents.clone())
+    })
+}
+
+/// Returns the contents of the file at `path`, or `None` if the host did not provide it or it is
+/// not valid UTF-8
+pub fn read_to_string(path: &str) -> Option<String> {
+    read(path).and_then(|contents| String::from_utf8(contents).ok())
+}
+
+/// Returns whether the host provided a file at `path`
+pub fn exists(path: &str) -> bool {
+    super::with_host_env(|env| env.files.iter().any(|(name, _)| name == path))
+}
+
+/// Returns the paths of all the files, in the order the host set them
+pub fn paths() -> Vec<String> {
+    super::with_host_env(|env| env.files.iter().map(|(name, _)| name.clone()).collect())
+}
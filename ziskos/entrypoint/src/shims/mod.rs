@@ -0,0 +1,213 @@
+//! Deterministic time, environment and filesystem for guests
+//!
+//! With the `host-env` feature, the first input item (after the RNG seed, with `host-rng-seed`)
+//! is an environment frame built by the host with `HostEnv` and `ZiskStdin::with_host_env`: a
+//! wall-clock time, environment variables and read-only files. It is read before the entry point
+//! runs and its SHA-256 hash is committed to the 8 public output slots starting at
+//! [`HOST_ENV_COMMITMENT_OUTPUT_ID`], so that verifiers can tell which environment a proof ran
+//! in.
+//!
+//! - [`time`] provides `Instant` and `SystemTime` that always read the host time. Time does not
+//!   advance during an execution, so elapsed durations are zero.
+//! - [`env`] reads the host variables. They are also behind `std::env::var` for guests built
+//!   with `std`, through the `sys_getenv` syscall.
+//! - [`fs`] reads the host files from memory. The guest can not write files.
+//!
+//! `std::time` and `std::fs` are not backed by these modules: the zkVM standard library has no
+//! syscalls for clocks or files, so they keep failing at runtime. Crates that read the clock or
+//! configuration files are ported by pointing them at [`time`] and [`fs`], usually through their
+//! own feature flags, instead of forking them.
+
+pub mod env;
+pub mod fs;
+pub mod time;
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::{string::String, vec::Vec};
+use serde::Deserialize;
+
+pub use zisk_definitions::HOST_ENV_COMMITMENT_OUTPUT_ID;
+
+/// Number of public output slots of the environment commitment
+pub(crate) const HOST_ENV_COMMITMENT_SLOTS: usize = 8;
+
+/// Environment frame, encoded by the host with bincode
+#[derive(Deserialize)]
+#[cfg_attr(test, derive(serde::Serialize, Default))]
+struct HostEnv {
+    unix_time_secs: u64,
+    unix_time_nanos: u32,
+    vars: Vec<(String, String)>,
+    files: Vec<(String, Vec<u8>)>,
+}
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+static HOST_ENV: spin::Mutex<Option<HostEnv>> = spin::Mutex::new(None);
+
+#[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+static HOST_ENV: std::sync::Mutex<Option<HostEnv>> = std::sync::Mutex::new(None);
+
+/// Reads the environment frame from the input and commits its hash
+pub(crate) fn init_host_env() {
+    let input = crate::io::read_input_slice();
+    let (host_env, _): (HostEnv, usize) =
+        bincode::serde::decode_from_slice(input.as_ref(), bincode::config::standard())
+            .expect("The next input must be the environment frame set with with_host_env()");
+
+    let commitment = crate::zisklib::sha256(
+        input.as_ref(),
+        #[cfg(feature = "hints")]
+        &mut Vec::new(),
+    );
+    for (i, word) in commitment.chunks_exact(4).enumerate() {
+        crate::set_output(
+            HOST_ENV_COMMITMENT_OUTPUT_ID + i,
+            u32::from_le_bytes(word.try_into().unwrap()),
+        );
+    }
+
+    #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+    let mut guard = HOST_ENV.lock();
+    #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+    let mut guard = HOST_ENV.lock().unwrap();
+    *guard = Some(host_env);
+}
+
+/// Runs `f` on the environment frame read at start-up
+fn with_host_env<R>(f: impl FnOnce(&HostEnv) -> R) -> R {
+    #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+    let guard = HOST_ENV.lock();
+    #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+    let guard = HOST_ENV.lock().unwrap();
+    f(guard.as_ref().expect("The host environment is read by zkvm_init()"))
+}
+
+/// Frame of an environment without time, variables or files, for the tests that only need
+/// `zkvm_init` to read one
+#[cfg(test)]
+pub(crate) fn default_frame() -> Vec<u8> {
+    tests::encode_frame(&HostEnv::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration;
+    use sha2::{Digest, Sha256};
+
+    pub(super) fn encode_frame(env: &HostEnv) -> Vec<u8> {
+        bincode::serde::encode_to_vec(env, bincode::config::standard()).unwrap()
+    }
+
+    /// Input with the seed, with `host-rng-seed`, and the environment frame read by
+    /// `zkvm_init`, followed by `items`
+    fn init_input(frame: &[u8], items: &[&[u8]]) -> Vec<u8> {
+        let mut all = Vec::new();
+        #[cfg(feature = "host-rng-seed")]
+        all.push(vec![7u8; crate::RNG_SEED_SIZE]);
+        all.push(frame.to_vec());
+        all.extend(items.iter().map(|item| item.to_vec()));
+        crate::encode_input_items(&all)
+    }
+
+    fn host_env() -> HostEnv {
+        HostEnv {
+            unix_time_secs: 1_700_000_000,
+            unix_time_nanos: 5,
+            vars: vec![("CHAIN_ID".into(), "1".into()), ("MODE".into(), "fast".into())],
+            files: vec![("config.toml".into(), b"x = 1".to_vec()), ("bin".into(), vec![0xff])],
+        }
+    }
+
+    #[test]
+    fn test_init_reads_frame_and_commits_its_hash() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let frame = encode_frame(&host_env());
+        crate::set_native_input(init_input(&frame, &[&[1, 2, 3, 4]]));
+        crate::zkvm_init();
+
+        // The program reads its own inputs after the frame, and commits from slot 0
+        let user: [u8; 4] = crate::io::read_input_slice().as_ref().try_into().unwrap();
+        crate::io::commit_slice(&user);
+
+        let outputs = crate::native_outputs();
+        assert_eq!(outputs[0], u32::from_le_bytes([1, 2, 3, 4]));
+
+        let expected: [u8; 32] = Sha256::digest(&frame).into();
+        let committed: Vec<u8> = outputs[HOST_ENV_COMMITMENT_OUTPUT_ID
+            ..HOST_ENV_COMMITMENT_OUTPUT_ID + HOST_ENV_COMMITMENT_SLOTS]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect();
+        assert_eq!(committed, expected);
+    }
+
+    #[test]
+    fn test_env_and_fs_read_frame() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        crate::set_native_input(init_input(&encode_frame(&host_env()), &[]));
+        crate::zkvm_init();
+
+        assert_eq!(env::var("CHAIN_ID").as_deref(), Some("1"));
+        assert_eq!(env::var("chain_id"), None);
+        assert_eq!(env::vars(), host_env().vars);
+
+        assert_eq!(fs::read("config.toml").as_deref(), Some(&b"x = 1"[..]));
+        assert_eq!(fs::read_to_string("config.toml").as_deref(), Some("x = 1"));
+        assert_eq!(fs::read_to_string("bin"), None);
+        assert_eq!(fs::read("./config.toml"), None);
+        assert!(fs::exists("bin"));
+        assert!(!fs::exists("missing"));
+        assert_eq!(fs::paths(), ["config.toml", "bin"]);
+    }
+
+    #[test]
+    fn test_clocks_read_frame_time() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        crate::set_native_input(init_input(&encode_frame(&host_env()), &[]));
+        crate::zkvm_init();
+
+        let now = time::SystemTime::now();
+        assert_eq!(now.duration_since(time::UNIX_EPOCH).unwrap(), Duration::new(1_700_000_000, 5));
+        assert_eq!(now.elapsed().unwrap(), Duration::ZERO);
+        assert_eq!(time::SystemTime::now(), now);
+
+        let start = time::Instant::now();
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert_eq!(time::Instant::now() - start, Duration::ZERO);
+    }
+
+    /// The standard IO interface and the streaming reads start from the first item of the
+    /// program, after the frames read by `zkvm_init`, with or without the RNG seed
+    #[test]
+    fn test_program_input_follows_frames() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        crate::set_native_input(init_input(&encode_frame(&host_env()), &[b"first", b"second"]));
+        crate::zkvm_init();
+
+        let (mut data, mut len) = (core::ptr::null(), 0);
+        unsafe { crate::zisklib::zkvm_io::read_input(&mut data, &mut len) };
+        assert_eq!(unsafe { core::slice::from_raw_parts(data, len) }, b"first");
+
+        assert_eq!(crate::io::read_input_slice().as_ref(), b"first");
+        assert_eq!(crate::io::read_input_slice().as_ref(), b"second");
+        crate::read_input_reset();
+        assert_eq!(crate::io::read_input_slice().as_ref(), b"first");
+    }
+
+    #[test]
+    fn test_commitment_slots_are_reserved() {
+        let _guard = crate::HOST_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        crate::set_native_input(init_input(&default_frame(), &[]));
+        crate::zkvm_init();
+
+        assert!((HOST_ENV_COMMITMENT_OUTPUT_ID
+            ..HOST_ENV_COMMITMENT_OUTPUT_ID + HOST_ENV_COMMITMENT_SLOTS)
+            .all(crate::is_reserved_output));
+
+        // The user outputs fill the slots up to the commitment, the next word is refused
+        crate::io::commit_slice(&[0u8; HOST_ENV_COMMITMENT_OUTPUT_ID * 4]);
+        let result = std::panic::catch_unwind(|| crate::io::commit_slice(&[0u8; 4]));
+        assert!(result.is_err());
+    }
+}
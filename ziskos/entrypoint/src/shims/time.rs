@@ -0,0 +1,214 @@
+//! `std::time` replacements that read the host time
+//!
+//! Both clocks return the time set with `HostEnv::with_time`, which does not change during an
+//! execution: `Instant::now().elapsed()` is always zero and two `SystemTime::now()` are equal.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+pub use core::time::Duration;
+
+/// Time of the host, as a duration since the Unix epoch
+fn host_time() -> Duration {
+    super::with_host_env(|env| Duration::new(env.unix_time_secs, env.unix_time_nanos))
+}
+
+/// Monotonic clock, see `std::time::Instant`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(Duration);
+
+impl Instant {
+    /// Returns the host time
+    pub fn now() -> Instant {
+        Instant(host_time())
+    }
+
+    /// Returns the time elapsed from `earlier` to `self`, or zero if `earlier` is later
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        self.saturating_duration_since(earlier)
+    }
+
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+
+    pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Returns the time elapsed since `self`, zero if it was created with [`Instant::now`]
+    pub fn elapsed(&self) -> Duration {
+        Instant::now().duration_since(*self)
+    }
+
+    pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_add(duration).map(Instant)
+    }
+
+    pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_sub(duration).map(Instant)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, duration: Duration) -> Instant {
+        self.checked_add(duration).expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, duration: Duration) -> Instant {
+        self.checked_sub(duration).expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, other: Instant) -> Duration {
+        self.duration_since(other)
+    }
+}
+
+/// Wall clock, see `std::time::SystemTime`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SystemTime(Duration);
+
+/// Start of the Unix epoch, 1970-01-01 00:00:00 UTC
+pub const UNIX_EPOCH: SystemTime = SystemTime::UNIX_EPOCH;
+
+impl SystemTime {
+    pub const UNIX_EPOCH: SystemTime = SystemTime(Duration::ZERO);
+
+    /// Returns the host time
+    pub fn now() -> SystemTime {
+        SystemTime(host_time())
+    }
+
+    /// Returns the time elapsed from `earlier` to `self`, or an error holding the time from
+    /// `self` to `earlier` if `earlier` is later
+    pub fn duration_since(&self, earlier: SystemTime) -> Result<Duration, SystemTimeError> {
+        self.0.checked_sub(earlier.0).ok_or_else(|| SystemTimeError(earlier.0 - self.0))
+    }
+
+    /// Returns the time elapsed since `self`, zero if it was created with [`SystemTime::now`]
+    pub fn elapsed(&self) -> Result<Duration, SystemTimeError> {
+        SystemTime::now().duration_since(*self)
+    }
+
+    pub fn checked_add(&self, duration: Duration) -> Option<SystemTime> {
+        self.0.checked_add(duration).map(SystemTime)
+    }
+
+    pub fn checked_sub(&self, duration: Duration) -> Option<SystemTime> {
+        self.0.checked_sub(duration).map(SystemTime)
+    }
+}
+
+impl Add<Duration> for SystemTime {
+    type Output = SystemTime;
+
+    fn add(self, duration: Duration) -> SystemTime {
+        self.checked_add(duration).expect("overflow when adding duration to system time")
+    }
+}
+
+impl AddAssign<Duration> for SystemTime {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for SystemTime {
+    type Output = SystemTime;
+
+    fn sub(self, duration: Duration) -> SystemTime {
+        self.checked_sub(duration).expect("overflow when subtracting duration from system time")
+    }
+}
+
+impl SubAssign<Duration> for SystemTime {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+/// Error of [`SystemTime::duration_since`] when the second time is later than the first
+#[derive(Clone, Debug)]
+pub struct SystemTimeError(Duration);
+
+impl SystemTimeError {
+    /// Returns how much later the second time was
+    pub fn duration(&self) -> Duration {
+        self.0
+    }
+}
+
+impl fmt::Display for SystemTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "second time provided was later than self")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instant_arithmetic() {
+        let start = Instant(Duration::new(10, 0));
+        let later = start + Duration::from_millis(1500);
+
+        assert_eq!(later - start, Duration::from_millis(1500));
+        assert_eq!(later.duration_since(start), Duration::from_millis(1500));
+        assert_eq!(later - Duration::from_millis(1500), start);
+
+        // Durations from a later instant saturate to zero, as `std::time::Instant` does
+        assert_eq!(start - later, Duration::ZERO);
+        assert_eq!(start.checked_duration_since(later), None);
+        assert_eq!(start.saturating_duration_since(later), Duration::ZERO);
+
+        let mut instant = start;
+        instant += Duration::from_secs(2);
+        instant -= Duration::from_secs(1);
+        assert_eq!(instant, Instant(Duration::new(11, 0)));
+
+        assert_eq!(start.checked_sub(Duration::from_secs(11)), None);
+        assert_eq!(Instant(Duration::MAX).checked_add(Duration::from_nanos(1)), None);
+    }
+
+    #[test]
+    fn test_system_time_arithmetic() {
+        let time = UNIX_EPOCH + Duration::new(100, 5);
+
+        assert_eq!(time.duration_since(UNIX_EPOCH).unwrap(), Duration::new(100, 5));
+        assert_eq!(time - Duration::new(100, 5), SystemTime::UNIX_EPOCH);
+        assert!(time > UNIX_EPOCH);
+
+        let error = UNIX_EPOCH.duration_since(time).unwrap_err();
+        assert_eq!(error.duration(), Duration::new(100, 5));
+        assert_eq!(error.to_string(), "second time provided was later than self");
+
+        let mut other = time;
+        other += Duration::from_secs(1);
+        other -= Duration::new(101, 5);
+        assert_eq!(other, UNIX_EPOCH);
+
+        assert_eq!(UNIX_EPOCH.checked_sub(Duration::from_nanos(1)), None);
+    }
+}
@@ -354,7 +354,7 @@ pub unsafe extern "C" fn modexp_u64_c(
 
 /// Convert big-endian bytes to little-endian u64 array
 #[allow(dead_code)]
-fn bytes_be_to_u64_le(bytes: &[u8]) -> Vec<u64> {
+pub(crate) fn bytes_be_to_u64_le(bytes: &[u8]) -> Vec<u64> {
     if bytes.is_empty() {
         return vec![0];
     }
@@ -381,7 +381,7 @@ fn bytes_be_to_u64_le(bytes: &[u8]) -> Vec<u64> {
 
 /// Convert big-endian bytes to little-endian U256 array
 #[allow(dead_code)]
-fn bytes_be_to_u256_le(bytes: &[u8]) -> Vec<U256> {
+pub(crate) fn bytes_be_to_u256_le(bytes: &[u8]) -> Vec<U256> {
     let u64_le = bytes_be_to_u64_le(bytes);
 
     // Pad to multiple of 4 u64s
@@ -394,7 +394,7 @@ fn bytes_be_to_u256_le(bytes: &[u8]) -> Vec<U256> {
 
 /// Convert little-endian U256 array to big-endian bytes
 #[allow(dead_code)]
-fn u256_le_to_bytes_be(limbs: &[U256], output: &mut [u8]) {
+pub(crate) fn u256_le_to_bytes_be(limbs: &[U256], output: &mut [u8]) {
     let flat = U256::slice_to_flat(limbs);
     let out_len = output.len();
     output.fill(0);
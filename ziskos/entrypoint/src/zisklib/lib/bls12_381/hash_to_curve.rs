@@ -0,0 +1,427 @@
+//! Hashing to the BLS12-381 G1 and G2 groups, following RFC 9380 with the
+//! `BLS12381G1_XMD:SHA-256_SSWU_RO_` and `BLS12381G2_XMD:SHA-256_SSWU_RO_` suites.
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::vec::Vec;
+use crate::{
+    syscalls::{syscall_arith384_mod, SyscallArith384ModParams},
+    zisklib::{eq, sha256},
+};
+
+use super::{
+    constants::{COFACTOR_G1, G1_IDENTITY, G2_IDENTITY, P},
+    curve::{add_bls12_381, g1_u64_le_to_bytes_be_bls12_381, scalar_mul_bls12_381},
+    map_to_curve::{
+        isogeny_map_g1_bls12_381, isogeny_map_g2_bls12_381, map_to_curve_simple_swu_g1_bls12_381,
+        map_to_curve_simple_swu_g2_bls12_381,
+    },
+    twist::{add_twist_bls12_381, clear_cofactor_twist_bls12_381, g2_u64_le_to_bytes_be_bls12_381},
+};
+
+/// Length in bytes of the SHA-256 output
+const B_IN_BYTES: usize = 32;
+
+/// Length in bytes of the SHA-256 input block
+const S_IN_BYTES: usize = 64;
+
+/// Length in bytes of each hashed field element, L = ceil((ceil(log2(p)) + k) / 8) with k = 128
+const L: usize = 64;
+
+/// Prefix used to hash destination separation tags longer than 255 bytes
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// `expand_message_xmd` with SHA-256 (RFC 9380, section 5.3.1)
+///
+/// Returns an error if `len_in_bytes` is too large for a single expansion.
+pub fn expand_message_xmd(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Result<Vec<u8>, &'static str> {
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err("expand_message_xmd: requested length is too large");
+    }
+
+    // Tags longer than 255 bytes are replaced by their hash (RFC 9380, section 5.3.3)
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        let mut input = Vec::with_capacity(OVERSIZE_DST_PREFIX.len() + dst.len());
+        input.extend_from_slice(OVERSIZE_DST_PREFIX);
+        input.extend_from_slice(dst);
+        oversize_dst = sha256(
+            &input,
+            #[cfg(feature = "hints")]
+            hints,
+        );
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+
+    // DST_prime = DST || I2OSP(len(DST), 1)
+    let mut dst_prime = Vec::with_capacity(dst.len() + 1);
+    dst_prime.extend_from_slice(dst);
+    dst_prime.push(dst.len() as u8);
+
+    // msg_prime = Z_pad || msg || I2OSP(len_in_bytes, 2) || I2OSP(0, 1) || DST_prime
+    let mut msg_prime = Vec::with_capacity(S_IN_BYTES + msg.len() + 3 + dst_prime.len());
+    msg_prime.resize(S_IN_BYTES, 0);
+    msg_prime.extend_from_slice(msg);
+    msg_prime.extend_from_slice(&(len_in_bytes as u16).to_be_bytes());
+    msg_prime.push(0);
+    msg_prime.extend_from_slice(&dst_prime);
+
+    // b_0 = H(msg_prime)
+    let b_0 = sha256(
+        &msg_prime,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+
+    // b_i = H(strxor(b_0, b_(i-1)) || I2OSP(i, 1) || DST_prime), with b_1 = H(b_0 || 1 || DST_prime)
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    let mut block = Vec::with_capacity(B_IN_BYTES + 1 + dst_prime.len());
+    let mut b_i = [0u8; B_IN_BYTES];
+    for i in 1..=ell {
+        block.clear();
+        block.extend(b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b));
+        block.push(i as u8);
+        block.extend_from_slice(&dst_prime);
+        b_i = sha256(
+            &block,
+            #[cfg(feature = "hints")]
+            hints,
+        );
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// Reduces a 64-byte big-endian integer modulo p
+fn reduce_fp_bls12_381(bytes: &[u8], #[cfg(feature = "hints")] hints: &mut Vec<u64>) -> [u64; 6] {
+    debug_assert_eq!(bytes.len(), L);
+
+    // Split the integer as hi·2²⁵⁶ + lo, with hi, lo < 2²⁵⁶ < p
+    let mut hi = [0u64; 6];
+    let mut lo = [0u64; 6];
+    for i in 0..4 {
+        hi[3 - i] = u64::from_be_bytes(bytes[i * 8..i * 8 + 8].try_into().unwrap());
+        lo[3 - i] = u64::from_be_bytes(bytes[32 + i * 8..32 + i * 8 + 8].try_into().unwrap());
+    }
+
+    // hi·2²⁵⁶ + lo mod p
+    let mut params = SyscallArith384ModParams {
+        a: &hi,
+        b: &[0, 0, 0, 0, 1, 0],
+        c: &lo,
+        module: &P,
+        d: &mut [0, 0, 0, 0, 0, 0],
+    };
+    syscall_arith384_mod(
+        &mut params,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    *params.d
+}
+
+/// Hashes a message to two elements of Fp (RFC 9380, section 5.2)
+fn hash_to_field_fp_bls12_381(
+    msg: &[u8],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> [[u64; 6]; 2] {
+    let uniform_bytes = expand_message_xmd(
+        msg,
+        dst,
+        2 * L,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+    .unwrap();
+
+    let mut u = [[0u64; 6]; 2];
+    for (i, u_i) in u.iter_mut().enumerate() {
+        *u_i = reduce_fp_bls12_381(
+            &uniform_bytes[i * L..(i + 1) * L],
+            #[cfg(feature = "hints")]
+            hints,
+        );
+    }
+    u
+}
+
+/// Hashes a message to two elements of Fp2 (RFC 9380, section 5.2)
+fn hash_to_field_fp2_bls12_381(
+    msg: &[u8],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> [[u64; 12]; 2] {
+    let uniform_bytes = expand_message_xmd(
+        msg,
+        dst,
+        4 * L,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+    .unwrap();
+
+    let mut u = [[0u64; 12]; 2];
+    for (i, u_i) in u.iter_mut().enumerate() {
+        for j in 0..2 {
+            let offset = (2 * i + j) * L;
+            let e_j = reduce_fp_bls12_381(
+                &uniform_bytes[offset..offset + L],
+                #[cfg(feature = "hints")]
+                hints,
+            );
+            u_i[j * 6..(j + 1) * 6].copy_from_slice(&e_j);
+        }
+    }
+    u
+}
+
+/// Hashes a message to a point of G1 with the given domain separation tag
+pub fn hash_to_g1_bls12_381(
+    msg: &[u8],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> [u64; 12] {
+    let u = hash_to_field_fp_bls12_381(
+        msg,
+        dst,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+
+    // Q_i = iso_map(map_to_curve_simple_swu(u_i))
+    let q: [[u64; 12]; 2] = u.map(|u_i| {
+        let q_prime = map_to_curve_simple_swu_g1_bls12_381(
+            &u_i,
+            #[cfg(feature = "hints")]
+            hints,
+        );
+        isogeny_map_g1_bls12_381(
+            &q_prime,
+            #[cfg(feature = "hints")]
+            hints,
+        )
+    });
+
+    // P = clear_cofactor(Q_0 + Q_1)
+    let r = add_bls12_381(
+        &q[0],
+        &q[1],
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    if eq(&r, &G1_IDENTITY) {
+        return G1_IDENTITY;
+    }
+    scalar_mul_bls12_381(
+        &r,
+        &COFACTOR_G1,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Hashes a message to a point of G2 with the given domain separation tag
+pub fn hash_to_g2_bls12_381(
+    msg: &[u8],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> [u64; 24] {
+    let u = hash_to_field_fp2_bls12_381(
+        msg,
+        dst,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+
+    // Q_i = iso_map(map_to_curve_simple_swu(u_i))
+    let q: [[u64; 24]; 2] = u.map(|u_i| {
+        let q_prime = map_to_curve_simple_swu_g2_bls12_381(
+            &u_i,
+            #[cfg(feature = "hints")]
+            hints,
+        );
+        isogeny_map_g2_bls12_381(
+            &q_prime,
+            #[cfg(feature = "hints")]
+            hints,
+        )
+    });
+
+    // P = clear_cofactor(Q_0 + Q_1)
+    let r = add_twist_bls12_381(
+        &q[0],
+        &q[1],
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    if eq(&r, &G2_IDENTITY) {
+        return G2_IDENTITY;
+    }
+    clear_cofactor_twist_bls12_381(
+        &r,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// BLS12-381 hash to G1
+///
+/// Output format: 96 bytes G1 point (x || y big-endian)
+///
+/// ### Safety
+/// - `msg` must point to `msg_len` bytes and `dst` to `dst_len` bytes
+/// - `ret` must point to a valid `[u8; 96]` for the output
+#[allow(dead_code)]
+#[inline]
+pub(crate) unsafe fn bls12_381_hash_to_g1_c(
+    ret: *mut u8,
+    msg: *const u8,
+    msg_len: usize,
+    dst: *const u8,
+    dst_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) {
+    let msg = core::slice::from_raw_parts(msg, msg_len);
+    let dst = core::slice::from_raw_parts(dst, dst_len);
+    let ret_bytes: &mut [u8; 96] = &mut *(ret as *mut [u8; 96]);
+
+    let result = hash_to_g1_bls12_381(
+        msg,
+        dst,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    g1_u64_le_to_bytes_be_bls12_381(&result, ret_bytes);
+}
+
+/// BLS12-381 hash to G2
+///
+/// Output format: 192 bytes G2 point (x_r || x_i || y_r || y_i, each 48 bytes big-endian)
+///
+/// ### Safety
+/// - `msg` must point to `msg_len` bytes and `dst` to `dst_len` bytes
+/// - `ret` must point to a valid `[u8; 192]` for the output
+#[allow(dead_code)]
+#[inline]
+pub(crate) unsafe fn bls12_381_hash_to_g2_c(
+    ret: *mut u8,
+    msg: *const u8,
+    msg_len: usize,
+    dst: *const u8,
+    dst_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) {
+    let msg = core::slice::from_raw_parts(msg, msg_len);
+    let dst = core::slice::from_raw_parts(dst, dst_len);
+    let ret_bytes: &mut [u8; 192] = &mut *(ret as *mut [u8; 192]);
+
+    let result = hash_to_g2_bls12_381(
+        msg,
+        dst,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    g2_u64_le_to_bytes_be_bls12_381(&result, ret_bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex<const N: usize>(s: &str) -> [u8; N] {
+        let mut out = [0u8; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        super::expand_message_xmd(
+            msg,
+            dst,
+            len_in_bytes,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        )
+        .unwrap()
+    }
+
+    // Test vectors from RFC 9380, appendices K.1, J.9.1 and J.10.1
+
+    #[test]
+    fn test_expand_message_xmd() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors = [
+            (&b""[..], "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            (b"abc", "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+            (
+                b"abcdef0123456789",
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+        ];
+        for (msg, expected) in vectors {
+            assert_eq!(expand_message_xmd(msg, dst, 0x20), from_hex::<32>(expected));
+        }
+    }
+
+    #[test]
+    fn test_expand_message_xmd_too_long() {
+        assert!(super::expand_message_xmd(
+            b"",
+            b"DST",
+            256 * B_IN_BYTES,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_hash_to_g1() {
+        let p = hash_to_g1_bls12_381(
+            b"",
+            b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_",
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        );
+        let mut bytes = [0u8; 96];
+        g1_u64_le_to_bytes_be_bls12_381(&p, &mut bytes);
+
+        let expected = from_hex::<96>(
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1\
+             08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        );
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_hash_to_g2() {
+        let p = hash_to_g2_bls12_381(
+            b"",
+            b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_",
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        );
+        let mut bytes = [0u8; 192];
+        g2_u64_le_to_bytes_be_bls12_381(&p, &mut bytes);
+
+        let expected = from_hex::<192>(
+            "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a\
+             05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d\
+             0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92\
+             12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+        );
+        assert_eq!(bytes, expected);
+    }
+}
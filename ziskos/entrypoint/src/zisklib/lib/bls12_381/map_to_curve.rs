@@ -99,7 +99,7 @@ pub fn map_to_curve_g2_bls12_381(
 
 /// Maps a field element u ∈ Fp to a point on the isogenous curve E'
 /// using the simplified Shallue-van de Woestijne-Ulas (SWU) method for AB != 0
-fn map_to_curve_simple_swu_g1_bls12_381(
+pub(crate) fn map_to_curve_simple_swu_g1_bls12_381(
     u: &[u64; 6],
     #[cfg(feature = "hints")] hints: &mut Vec<u64>,
 ) -> [u64; 12] {
@@ -247,7 +247,7 @@ fn map_to_curve_simple_swu_g1_bls12_381(
 
 /// Maps a field element u ∈ Fp2 to a point on the isogenous curve E'
 /// using the simplified Shallue-van de Woestijne-Ulas (SWU) method for AB != 0
-fn map_to_curve_simple_swu_g2_bls12_381(
+pub(crate) fn map_to_curve_simple_swu_g2_bls12_381(
     u: &[u64; 12],
     #[cfg(feature = "hints")] hints: &mut Vec<u64>,
 ) -> [u64; 24] {
@@ -469,7 +469,7 @@ fn compute_y2_iso_g2_bls12_381(
 }
 
 /// Apply the 11-isogeny map from E' to E for G1
-fn isogeny_map_g1_bls12_381(
+pub(crate) fn isogeny_map_g1_bls12_381(
     p: &[u64; 12],
     #[cfg(feature = "hints")] hints: &mut Vec<u64>,
 ) -> [u64; 12] {
@@ -539,7 +539,7 @@ fn isogeny_map_g1_bls12_381(
 }
 
 /// Apply the 3-isogeny map from E' to E for G2
-fn isogeny_map_g2_bls12_381(
+pub(crate) fn isogeny_map_g2_bls12_381(
     p: &[u64; 24],
     #[cfg(feature = "hints")] hints: &mut Vec<u64>,
 ) -> [u64; 24] {
@@ -19,7 +19,11 @@
 //!
 //! ## KZG and hash-to-curve
 //! - [`kzg`] — KZG polynomial commitment proof verification.
-//! - [`map_to_curve`] — Hash-to-curve for G1 and G2.
+//! - [`map_to_curve`] — Map-to-curve for G1 and G2.
+//! - [`hash_to_curve`] — Hash-to-curve for G1 and G2 (RFC 9380).
+//!
+//! ## Signatures
+//! - [`signature`] — BLS signature verification (min-pk and min-sig variants).
 
 mod constants;
 mod curve;
@@ -30,10 +34,12 @@ mod fp12;
 mod fp2;
 mod fp6;
 mod fr;
+mod hash_to_curve;
 mod kzg;
 mod map_to_curve;
 mod miller_loop;
 mod pairing;
+mod signature;
 mod twist;
 
 pub use curve::*;
@@ -44,7 +50,9 @@ pub use fp12::*;
 pub use fp2::*;
 pub use fp6::*;
 pub use fr::*;
+pub use hash_to_curve::*;
 pub use kzg::*;
 pub use map_to_curve::*;
 pub use pairing::*;
+pub use signature::*;
 pub use twist::*;
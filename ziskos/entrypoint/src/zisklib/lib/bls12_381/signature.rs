@@ -0,0 +1,694 @@
+//! BLS signature verification over BLS12-381
+//!
+//! Implements `Verify`, `AggregateVerify` and `FastAggregateVerify` from the IETF BLS signature
+//! draft in both of its variants:
+//! - minimal-pubkey-size (`min_pk`): public keys in G1 (48 bytes), signatures in G2 (96 bytes),
+//!   as used by Ethereum consensus.
+//! - minimal-signature-size (`min_sig`): public keys in G2 (96 bytes), signatures in G1 (48 bytes).
+//!
+//! Points are given in compressed form. Public keys must be valid, non-identity subgroup points;
+//! signatures must be valid subgroup points. Messages are hashed with the given DST using
+//! [`hash_to_g1_bls12_381`] or [`hash_to_g2_bls12_381`].
+//!
+//! `AggregateVerify` does not require the messages to be distinct, so it must only be used with
+//! the proof-of-possession scheme, where rogue key attacks are ruled out by the key registration.
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::vec::Vec;
+
+use crate::zisklib::lib::utils::{eq, is_one};
+
+use super::{
+    constants::{G1_GENERATOR, G1_IDENTITY, G2_GENERATOR, G2_IDENTITY},
+    curve::{
+        add_complete_bls12_381, decompress_bls12_381, is_on_subgroup_bls12_381, neg_bls12_381,
+    },
+    hash_to_curve::{hash_to_g1_bls12_381, hash_to_g2_bls12_381},
+    pairing::pairing_batch_bls12_381,
+    twist::{
+        add_complete_twist_bls12_381, decompress_twist_bls12_381, is_on_subgroup_twist_bls12_381,
+        neg_twist_bls12_381,
+    },
+};
+
+/// Minimal-pubkey-size variant: public keys in G1, signatures in G2
+pub const BLS_MIN_PK: u32 = 0;
+
+/// Minimal-signature-size variant: public keys in G2, signatures in G1
+pub const BLS_MIN_SIG: u32 = 1;
+
+/// Returns the sizes in bytes of a compressed public key and signature for the given variant
+pub fn bls_variant_sizes(variant: u32) -> Option<(usize, usize)> {
+    match variant {
+        BLS_MIN_PK => Some((48, 96)),
+        BLS_MIN_SIG => Some((96, 48)),
+        _ => None,
+    }
+}
+
+/// Decompresses and validates a G1 point, rejecting the identity if `allow_identity` is false
+fn parse_g1_bls12_381(
+    bytes: &[u8; 48],
+    allow_identity: bool,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Option<[u64; 12]> {
+    let p = decompress_bls12_381(
+        bytes,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+    .ok()?;
+    if eq(&p, &G1_IDENTITY) {
+        return allow_identity.then_some(p);
+    }
+    if !is_on_subgroup_bls12_381(
+        &p,
+        #[cfg(feature = "hints")]
+        hints,
+    ) {
+        return None;
+    }
+    Some(p)
+}
+
+/// Decompresses and validates a G2 point, rejecting the identity if `allow_identity` is false
+fn parse_g2_bls12_381(
+    bytes: &[u8; 96],
+    allow_identity: bool,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Option<[u64; 24]> {
+    let (p, is_inf) = decompress_twist_bls12_381(
+        bytes,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+    .ok()?;
+    if is_inf {
+        return allow_identity.then_some(G2_IDENTITY);
+    }
+    if !is_on_subgroup_twist_bls12_381(
+        &p,
+        #[cfg(feature = "hints")]
+        hints,
+    ) {
+        return None;
+    }
+    Some(p)
+}
+
+/// Checks that e(P₁, Q₁) · ... · e(Pₙ, Qₙ) = 1, skipping the pairs with an identity point
+fn pairing_product_is_one_bls12_381(
+    g1_points: &[[u64; 12]],
+    g2_points: &[[u64; 24]],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    let mut valid_g1: Vec<[u64; 12]> = Vec::with_capacity(g1_points.len());
+    let mut valid_g2: Vec<[u64; 24]> = Vec::with_capacity(g2_points.len());
+    for (g1, g2) in g1_points.iter().zip(g2_points.iter()) {
+        // e(P, 𝒪) = e(𝒪, Q) = 1
+        if eq(g1, &G1_IDENTITY) || eq(g2, &G2_IDENTITY) {
+            continue;
+        }
+        valid_g1.push(*g1);
+        valid_g2.push(*g2);
+    }
+
+    is_one(&pairing_batch_bls12_381(
+        &valid_g1,
+        &valid_g2,
+        #[cfg(feature = "hints")]
+        hints,
+    ))
+}
+
+/// Verifies a signature in G2 of `msg` under a public key in G1 (min-pk variant)
+pub fn bls_verify_min_pk(
+    pubkey: &[u8; 48],
+    msg: &[u8],
+    signature: &[u8; 96],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    bls_aggregate_verify_min_pk(
+        core::slice::from_ref(pubkey),
+        &[msg],
+        signature,
+        dst,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Verifies an aggregate signature in G2 of `msgs[i]` under `pubkeys[i]` (min-pk variant)
+pub fn bls_aggregate_verify_min_pk(
+    pubkeys: &[[u8; 48]],
+    msgs: &[&[u8]],
+    signature: &[u8; 96],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    if pubkeys.is_empty() || pubkeys.len() != msgs.len() {
+        return false;
+    }
+
+    let Some(sig) = parse_g2_bls12_381(
+        signature,
+        true,
+        #[cfg(feature = "hints")]
+        hints,
+    ) else {
+        return false;
+    };
+
+    // e(PK₁, H(m₁)) · ... · e(PKₙ, H(mₙ)) · e(-G₁, σ) = 1
+    let mut g1_points: Vec<[u64; 12]> = Vec::with_capacity(pubkeys.len() + 1);
+    let mut g2_points: Vec<[u64; 24]> = Vec::with_capacity(pubkeys.len() + 1);
+    for (pubkey, msg) in pubkeys.iter().zip(msgs.iter()) {
+        let Some(pk) = parse_g1_bls12_381(
+            pubkey,
+            false,
+            #[cfg(feature = "hints")]
+            hints,
+        ) else {
+            return false;
+        };
+        g1_points.push(pk);
+        g2_points.push(hash_to_g2_bls12_381(
+            msg,
+            dst,
+            #[cfg(feature = "hints")]
+            hints,
+        ));
+    }
+    g1_points.push(neg_bls12_381(
+        &G1_GENERATOR,
+        #[cfg(feature = "hints")]
+        hints,
+    ));
+    g2_points.push(sig);
+
+    pairing_product_is_one_bls12_381(
+        &g1_points,
+        &g2_points,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Verifies an aggregate signature in G2 of the same `msg` under all `pubkeys` (min-pk variant)
+pub fn bls_fast_aggregate_verify_min_pk(
+    pubkeys: &[[u8; 48]],
+    msg: &[u8],
+    signature: &[u8; 96],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    if pubkeys.is_empty() {
+        return false;
+    }
+
+    let Some(sig) = parse_g2_bls12_381(
+        signature,
+        true,
+        #[cfg(feature = "hints")]
+        hints,
+    ) else {
+        return false;
+    };
+
+    // Aggregate the public keys
+    let mut agg_pk = G1_IDENTITY;
+    for pubkey in pubkeys {
+        let Some(pk) = parse_g1_bls12_381(
+            pubkey,
+            false,
+            #[cfg(feature = "hints")]
+            hints,
+        ) else {
+            return false;
+        };
+        agg_pk = match add_complete_bls12_381(
+            &agg_pk,
+            &pk,
+            #[cfg(feature = "hints")]
+            hints,
+        ) {
+            Ok(result) => result,
+            Err(_) => return false,
+        };
+    }
+    if eq(&agg_pk, &G1_IDENTITY) {
+        return false;
+    }
+
+    // e(PK, H(m)) · e(-G₁, σ) = 1
+    let h = hash_to_g2_bls12_381(
+        msg,
+        dst,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    let neg_g1 = neg_bls12_381(
+        &G1_GENERATOR,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    pairing_product_is_one_bls12_381(
+        &[agg_pk, neg_g1],
+        &[h, sig],
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Verifies a signature in G1 of `msg` under a public key in G2 (min-sig variant)
+pub fn bls_verify_min_sig(
+    pubkey: &[u8; 96],
+    msg: &[u8],
+    signature: &[u8; 48],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    bls_aggregate_verify_min_sig(
+        core::slice::from_ref(pubkey),
+        &[msg],
+        signature,
+        dst,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Verifies an aggregate signature in G1 of `msgs[i]` under `pubkeys[i]` (min-sig variant)
+pub fn bls_aggregate_verify_min_sig(
+    pubkeys: &[[u8; 96]],
+    msgs: &[&[u8]],
+    signature: &[u8; 48],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    if pubkeys.is_empty() || pubkeys.len() != msgs.len() {
+        return false;
+    }
+
+    let Some(sig) = parse_g1_bls12_381(
+        signature,
+        true,
+        #[cfg(feature = "hints")]
+        hints,
+    ) else {
+        return false;
+    };
+
+    // e(H(m₁), PK₁) · ... · e(H(mₙ), PKₙ) · e(σ, -G₂) = 1
+    let mut g1_points: Vec<[u64; 12]> = Vec::with_capacity(pubkeys.len() + 1);
+    let mut g2_points: Vec<[u64; 24]> = Vec::with_capacity(pubkeys.len() + 1);
+    for (pubkey, msg) in pubkeys.iter().zip(msgs.iter()) {
+        let Some(pk) = parse_g2_bls12_381(
+            pubkey,
+            false,
+            #[cfg(feature = "hints")]
+            hints,
+        ) else {
+            return false;
+        };
+        g1_points.push(hash_to_g1_bls12_381(
+            msg,
+            dst,
+            #[cfg(feature = "hints")]
+            hints,
+        ));
+        g2_points.push(pk);
+    }
+    g1_points.push(sig);
+    g2_points.push(neg_twist_bls12_381(
+        &G2_GENERATOR,
+        #[cfg(feature = "hints")]
+        hints,
+    ));
+
+    pairing_product_is_one_bls12_381(
+        &g1_points,
+        &g2_points,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Verifies an aggregate signature in G1 of the same `msg` under all `pubkeys` (min-sig variant)
+pub fn bls_fast_aggregate_verify_min_sig(
+    pubkeys: &[[u8; 96]],
+    msg: &[u8],
+    signature: &[u8; 48],
+    dst: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    if pubkeys.is_empty() {
+        return false;
+    }
+
+    let Some(sig) = parse_g1_bls12_381(
+        signature,
+        true,
+        #[cfg(feature = "hints")]
+        hints,
+    ) else {
+        return false;
+    };
+
+    // Aggregate the public keys
+    let mut agg_pk = G2_IDENTITY;
+    for pubkey in pubkeys {
+        let Some(pk) = parse_g2_bls12_381(
+            pubkey,
+            false,
+            #[cfg(feature = "hints")]
+            hints,
+        ) else {
+            return false;
+        };
+        agg_pk = match add_complete_twist_bls12_381(
+            &agg_pk,
+            &pk,
+            #[cfg(feature = "hints")]
+            hints,
+        ) {
+            Ok(result) => result,
+            Err(_) => return false,
+        };
+    }
+    if eq(&agg_pk, &G2_IDENTITY) {
+        return false;
+    }
+
+    // e(H(m), PK) · e(σ, -G₂) = 1
+    let h = hash_to_g1_bls12_381(
+        msg,
+        dst,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    let neg_g2 = neg_twist_bls12_381(
+        &G2_GENERATOR,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    pairing_product_is_one_bls12_381(
+        &[h, sig],
+        &[agg_pk, neg_g2],
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// BLS signature verification
+///
+/// # Arguments
+/// * `variant` - [`BLS_MIN_PK`] or [`BLS_MIN_SIG`]
+/// * `pubkey` - compressed public key (48 bytes for min-pk, 96 bytes for min-sig)
+/// * `signature` - compressed signature (96 bytes for min-pk, 48 bytes for min-sig)
+///
+/// # Safety
+/// All pointers must be valid for the sizes implied by `variant` and the given lengths.
+///
+/// # Returns
+/// * `None` if `variant` is unknown
+/// * `Some(valid)` otherwise
+#[allow(dead_code, clippy::too_many_arguments)]
+#[inline]
+pub(crate) unsafe fn bls_verify_c(
+    variant: u32,
+    pubkey: *const u8,
+    msg: *const u8,
+    msg_len: usize,
+    signature: *const u8,
+    dst: *const u8,
+    dst_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Option<bool> {
+    bls_variant_sizes(variant)?;
+    let msg = core::slice::from_raw_parts(msg, msg_len);
+    let dst = core::slice::from_raw_parts(dst, dst_len);
+
+    Some(match variant {
+        BLS_MIN_PK => bls_verify_min_pk(
+            &*(pubkey as *const [u8; 48]),
+            msg,
+            &*(signature as *const [u8; 96]),
+            dst,
+            #[cfg(feature = "hints")]
+            hints,
+        ),
+        _ => bls_verify_min_sig(
+            &*(pubkey as *const [u8; 96]),
+            msg,
+            &*(signature as *const [u8; 48]),
+            dst,
+            #[cfg(feature = "hints")]
+            hints,
+        ),
+    })
+}
+
+/// BLS aggregate signature verification over distinct messages
+///
+/// # Arguments
+/// * `variant` - [`BLS_MIN_PK`] or [`BLS_MIN_SIG`]
+/// * `pubkeys` - `num_pubkeys` concatenated compressed public keys
+/// * `msgs` - the `num_pubkeys` messages, concatenated
+/// * `msg_lens` - `num_pubkeys` message lengths
+/// * `signature` - compressed aggregate signature
+///
+/// # Safety
+/// All pointers must be valid for the sizes implied by `variant` and the given lengths.
+///
+/// # Returns
+/// * `None` if `variant` is unknown
+/// * `Some(valid)` otherwise
+#[allow(dead_code, clippy::too_many_arguments)]
+#[inline]
+pub(crate) unsafe fn bls_aggregate_verify_c(
+    variant: u32,
+    pubkeys: *const u8,
+    num_pubkeys: usize,
+    msgs: *const u8,
+    msg_lens: *const usize,
+    signature: *const u8,
+    dst: *const u8,
+    dst_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Option<bool> {
+    bls_variant_sizes(variant)?;
+    let msg_lens = core::slice::from_raw_parts(msg_lens, num_pubkeys);
+    let dst = core::slice::from_raw_parts(dst, dst_len);
+
+    let mut msg_slices: Vec<&[u8]> = Vec::with_capacity(num_pubkeys);
+    let mut offset = 0;
+    for &len in msg_lens {
+        msg_slices.push(core::slice::from_raw_parts(msgs.add(offset), len));
+        offset += len;
+    }
+
+    Some(match variant {
+        BLS_MIN_PK => bls_aggregate_verify_min_pk(
+            core::slice::from_raw_parts(pubkeys as *const [u8; 48], num_pubkeys),
+            &msg_slices,
+            &*(signature as *const [u8; 96]),
+            dst,
+            #[cfg(feature = "hints")]
+            hints,
+        ),
+        _ => bls_aggregate_verify_min_sig(
+            core::slice::from_raw_parts(pubkeys as *const [u8; 96], num_pubkeys),
+            &msg_slices,
+            &*(signature as *const [u8; 48]),
+            dst,
+            #[cfg(feature = "hints")]
+            hints,
+        ),
+    })
+}
+
+/// BLS aggregate signature verification over a common message
+///
+/// # Arguments
+/// * `variant` - [`BLS_MIN_PK`] or [`BLS_MIN_SIG`]
+/// * `pubkeys` - `num_pubkeys` concatenated compressed public keys
+/// * `signature` - compressed aggregate signature
+///
+/// # Safety
+/// All pointers must be valid for the sizes implied by `variant` and the given lengths.
+///
+/// # Returns
+/// * `None` if `variant` is unknown
+/// * `Some(valid)` otherwise
+#[allow(dead_code, clippy::too_many_arguments)]
+#[inline]
+pub(crate) unsafe fn bls_fast_aggregate_verify_c(
+    variant: u32,
+    pubkeys: *const u8,
+    num_pubkeys: usize,
+    msg: *const u8,
+    msg_len: usize,
+    signature: *const u8,
+    dst: *const u8,
+    dst_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Option<bool> {
+    bls_variant_sizes(variant)?;
+    let msg = core::slice::from_raw_parts(msg, msg_len);
+    let dst = core::slice::from_raw_parts(dst, dst_len);
+
+    Some(match variant {
+        BLS_MIN_PK => bls_fast_aggregate_verify_min_pk(
+            core::slice::from_raw_parts(pubkeys as *const [u8; 48], num_pubkeys),
+            msg,
+            &*(signature as *const [u8; 96]),
+            dst,
+            #[cfg(feature = "hints")]
+            hints,
+        ),
+        _ => bls_fast_aggregate_verify_min_sig(
+            core::slice::from_raw_parts(pubkeys as *const [u8; 96], num_pubkeys),
+            msg,
+            &*(signature as *const [u8; 48]),
+            dst,
+            #[cfg(feature = "hints")]
+            hints,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DST_MIN_PK: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+    const DST_MIN_SIG: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn from_hex<const N: usize>(s: &str) -> [u8; N] {
+        let mut out = [0u8; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    // Signatures of b"zisk" under the key derived from IKM = [0x42; 32] (KeyGen, RFC draft-irtf-cfrg-bls-signature)
+
+    fn min_pk_vector() -> ([u8; 48], [u8; 96]) {
+        (
+            from_hex(
+                "95e8938e0974808cacb1926f1cf87561b1b98e76a7a74291285b4f7d84092ffae92609a21a56394d6aa19be7195c7a65",
+            ),
+            from_hex(
+                "80689afbe805227259e7da83b5c3ea3b166ba53c3e4af7f12cc5f822a305d9ebc35d05cda923599fe4dc3dd79b793e81\
+                 0e22e9050a9d505ed485f8b53ed6420b2c2d0c0bcb3f0341fa907ecc7cf45eac34c1ba8adceff83516ce891a193a22aa",
+            ),
+        )
+    }
+
+    fn min_sig_vector() -> ([u8; 96], [u8; 48]) {
+        (
+            from_hex(
+                "981e7e992ab88b62afe0c27c006af90d43bc42300eef15c21c50198cb8c389e11b4de7e282076868eb18ba5b520a2819\
+                 153062abf515f2a7e593d180dec9ec2ed74fcd0dbb884743e61c4afb3ae6eb3356030c299de34fac5b62672b12aa745c",
+            ),
+            from_hex(
+                "957cfbadd63f7cb80b6aad19748914a324a5101edfece685b332b4b95602c6c84b01a381255913d7dcf0f22c3e6776bc",
+            ),
+        )
+    }
+
+    #[test]
+    fn test_verify_min_pk() {
+        let (pk, sig) = min_pk_vector();
+        let verify = |pk, msg, sig, dst| {
+            bls_verify_min_pk(
+                pk,
+                msg,
+                sig,
+                dst,
+                #[cfg(feature = "hints")]
+                &mut Vec::new(),
+            )
+        };
+        assert!(verify(&pk, b"zisk", &sig, DST_MIN_PK));
+        assert!(!verify(&pk, b"ziSk", &sig, DST_MIN_PK));
+        assert!(!verify(&pk, b"zisk", &sig, DST_MIN_SIG));
+
+        // Identity public key and signature must be rejected
+        let mut identity_pk = [0u8; 48];
+        identity_pk[0] = 0xc0;
+        let mut identity_sig = [0u8; 96];
+        identity_sig[0] = 0xc0;
+        assert!(!verify(&identity_pk, b"zisk", &identity_sig, DST_MIN_PK));
+        assert!(!verify(&pk, b"zisk", &identity_sig, DST_MIN_PK));
+    }
+
+    #[test]
+    fn test_verify_min_sig() {
+        let (pk, sig) = min_sig_vector();
+        let verify = |pk, msg, sig, dst| {
+            bls_verify_min_sig(
+                pk,
+                msg,
+                sig,
+                dst,
+                #[cfg(feature = "hints")]
+                &mut Vec::new(),
+            )
+        };
+        assert!(verify(&pk, b"zisk", &sig, DST_MIN_SIG));
+        assert!(!verify(&pk, b"", &sig, DST_MIN_SIG));
+        assert!(!verify(&pk, b"zisk", &sig, DST_MIN_PK));
+    }
+
+    #[test]
+    fn test_aggregate_single_signer() {
+        // With a single signer, both aggregate forms reduce to a plain verification
+        let (pk, sig) = min_pk_vector();
+        assert!(bls_aggregate_verify_min_pk(
+            &[pk],
+            &[b"zisk"],
+            &sig,
+            DST_MIN_PK,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        ));
+        assert!(bls_fast_aggregate_verify_min_pk(
+            &[pk],
+            b"zisk",
+            &sig,
+            DST_MIN_PK,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        ));
+        assert!(!bls_fast_aggregate_verify_min_pk(
+            &[],
+            b"zisk",
+            &sig,
+            DST_MIN_PK,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        ));
+
+        let (pk, sig) = min_sig_vector();
+        assert!(bls_aggregate_verify_min_sig(
+            &[pk],
+            &[b"zisk"],
+            &sig,
+            DST_MIN_SIG,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        ));
+        assert!(!bls_aggregate_verify_min_sig(
+            &[pk, pk],
+            &[b"zisk", b"zisk"],
+            &sig,
+            DST_MIN_SIG,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        ));
+    }
+}
@@ -6,6 +6,10 @@
 //!
 //! ## Hashing
 //! - [`blake2b`], [`keccak256`], [`sha256`], [`ripemd160`]
+//! - [`poseidon2`] — Poseidon2 sponge and Merkle tree over the Goldilocks field.
+//!
+//! ## Signatures
+//! - [`rsa`] — RSA PKCS#1 v1.5 and PSS signature verification over SHA-256.
 //!
 //! ## Elliptic curves
 //! - [`secp256k1`], [`secp256r1`], [`bn254`], [`bls12_381`]
@@ -16,7 +20,9 @@ mod bls12_381;
 mod bn254;
 mod constants;
 mod keccak256;
+pub mod poseidon2;
 mod ripemd160;
+pub mod rsa;
 mod secp256k1;
 mod secp256r1;
 mod sha256;
@@ -0,0 +1,552 @@
+//! Poseidon2 sponge and Merkle tree over the Goldilocks field.
+//!
+//! Built on the 16-element permutation of [`syscall_poseidon2`], with a rate of 12 and a capacity
+//! of 4 elements, as the proofman linear hash and Merkle trees:
+//! - [`hash_elements`] absorbs the input in chunks of [`RATE`] elements, zero-padding the last one,
+//!   and chaining the first [`CAPACITY`] elements of each output into the next state. Inputs of at
+//!   most [`CAPACITY`] elements are returned zero-padded, without hashing.
+//! - [`compress`] hashes two digests, zero-padded to the state width, into one.
+//! - [`merkle_root`] and [`verify_merkle_path`] build binary trees with [`compress`], pairing the
+//!   last node of a level with a zero digest when the level has an odd number of nodes.
+//!
+//! Field elements are `u64` values in canonical form, i.e. lower than [`GOLDILOCKS_PRIME`]. Bytes
+//! are packed in little-endian chunks of [`BYTES_PER_ELEMENT`] bytes, followed by a `0x01` byte, so
+//! that byte strings of different length never pack into the same elements.
+//!
+//! On native targets the permutation falls back to `fields::poseidon2_hash`, so the same code
+//! computes the same digests on the host.
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::vec::Vec;
+use crate::syscalls::syscall_poseidon2;
+
+/// Goldilocks field modulus, `2^64 - 2^32 + 1`
+pub const GOLDILOCKS_PRIME: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// Number of elements of the permutation state
+pub const WIDTH: usize = 16;
+
+/// Number of elements absorbed per permutation
+pub const RATE: usize = 12;
+
+/// Number of elements of a digest
+pub const CAPACITY: usize = 4;
+
+/// Number of bytes packed into a field element
+pub const BYTES_PER_ELEMENT: usize = 7;
+
+/// Poseidon2 digest
+pub type Digest = [u64; CAPACITY];
+
+/// Applies the Poseidon2 permutation to the state
+#[inline]
+fn permute(state: &mut [u64; WIDTH], #[cfg(feature = "hints")] hints: &mut Vec<u64>) {
+    unsafe {
+        syscall_poseidon2(
+            state,
+            #[cfg(feature = "hints")]
+            hints,
+        );
+    }
+}
+
+#[inline]
+fn check_canonical(element: u64) {
+    assert!(
+        element < GOLDILOCKS_PRIME,
+        "Poseidon2 input {element:#x} is not a canonical field element"
+    );
+}
+
+/// Streaming Poseidon2 sponge over bytes and field elements.
+///
+/// The digest is the linear hash of all the absorbed elements, so absorbing the same elements in
+/// one call or in several gives the same digest. Consecutive [`update`](Hasher::update) calls
+/// form a single byte string, which is packed and terminated when elements are absorbed or when
+/// the hasher is finalized.
+#[derive(Clone, Debug)]
+pub struct Hasher {
+    state: [u64; WIDTH],
+    /// Elements absorbed since the last permutation
+    buffer: [u64; RATE],
+    buffered: usize,
+    /// Whether the state holds the output of a previous permutation
+    permuted: bool,
+    /// Bytes not packed into an element yet
+    bytes: [u8; BYTES_PER_ELEMENT],
+    bytes_len: usize,
+    /// Whether a byte string is being absorbed
+    in_bytes: bool,
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher {
+    pub fn new() -> Self {
+        Self {
+            state: [0; WIDTH],
+            buffer: [0; RATE],
+            buffered: 0,
+            permuted: false,
+            bytes: [0; BYTES_PER_ELEMENT],
+            bytes_len: 0,
+            in_bytes: false,
+        }
+    }
+
+    /// Absorbs field elements, which must be in canonical form
+    pub fn update_elements(
+        &mut self,
+        elements: &[u64],
+        #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+    ) {
+        self.finish_bytes(
+            #[cfg(feature = "hints")]
+            hints,
+        );
+        for &element in elements {
+            check_canonical(element);
+            self.absorb(
+                element,
+                #[cfg(feature = "hints")]
+                hints,
+            );
+        }
+    }
+
+    /// Absorbs bytes, packed into field elements
+    pub fn update(&mut self, data: &[u8], #[cfg(feature = "hints")] hints: &mut Vec<u64>) {
+        self.in_bytes = true;
+        for &byte in data {
+            self.bytes[self.bytes_len] = byte;
+            self.bytes_len += 1;
+            if self.bytes_len == BYTES_PER_ELEMENT {
+                self.flush_bytes(
+                    #[cfg(feature = "hints")]
+                    hints,
+                );
+            }
+        }
+    }
+
+    /// Returns the digest of the absorbed data
+    pub fn finalize(mut self, #[cfg(feature = "hints")] hints: &mut Vec<u64>) -> Digest {
+        self.finish_bytes(
+            #[cfg(feature = "hints")]
+            hints,
+        );
+
+        // Short inputs are returned as they are, as the linear hash does
+        if !self.permuted && self.buffered <= CAPACITY {
+            let mut digest = [0; CAPACITY];
+            digest[..self.buffered].copy_from_slice(&self.buffer[..self.buffered]);
+            return digest;
+        }
+
+        self.permute_buffer(
+            #[cfg(feature = "hints")]
+            hints,
+        );
+        self.state[..CAPACITY].try_into().unwrap()
+    }
+
+    fn absorb(&mut self, element: u64, #[cfg(feature = "hints")] hints: &mut Vec<u64>) {
+        // A full buffer is only permuted once more input arrives, since the last chunk is
+        // handled by finalize
+        if self.buffered == RATE {
+            self.permute_buffer(
+                #[cfg(feature = "hints")]
+                hints,
+            );
+        }
+        self.buffer[self.buffered] = element;
+        self.buffered += 1;
+    }
+
+    fn permute_buffer(&mut self, #[cfg(feature = "hints")] hints: &mut Vec<u64>) {
+        let mut state = [0; WIDTH];
+        state[..self.buffered].copy_from_slice(&self.buffer[..self.buffered]);
+        if self.permuted {
+            state[RATE..].copy_from_slice(&self.state[..CAPACITY]);
+        }
+        permute(
+            &mut state,
+            #[cfg(feature = "hints")]
+            hints,
+        );
+        self.state = state;
+        self.permuted = true;
+        self.buffered = 0;
+    }
+
+    fn flush_bytes(&mut self, #[cfg(feature = "hints")] hints: &mut Vec<u64>) {
+        let mut element = [0u8; 8];
+        element[..self.bytes_len].copy_from_slice(&self.bytes[..self.bytes_len]);
+        self.bytes_len = 0;
+        self.absorb(
+            u64::from_le_bytes(element),
+            #[cfg(feature = "hints")]
+            hints,
+        );
+    }
+
+    /// Terminates the byte string being absorbed, if any
+    fn finish_bytes(&mut self, #[cfg(feature = "hints")] hints: &mut Vec<u64>) {
+        if !self.in_bytes {
+            return;
+        }
+        self.in_bytes = false;
+        // bytes_len is always lower than BYTES_PER_ELEMENT here, so the terminator fits
+        self.bytes[self.bytes_len] = 0x01;
+        self.bytes_len += 1;
+        self.flush_bytes(
+            #[cfg(feature = "hints")]
+            hints,
+        );
+    }
+}
+
+/// Poseidon2 linear hash of field elements, which must be in canonical form
+pub fn hash_elements(elements: &[u64], #[cfg(feature = "hints")] hints: &mut Vec<u64>) -> Digest {
+    let mut hasher = Hasher::new();
+    hasher.update_elements(
+        elements,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    hasher.finalize(
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Poseidon2 hash of a byte string
+pub fn hash_bytes(data: &[u8], #[cfg(feature = "hints")] hints: &mut Vec<u64>) -> Digest {
+    let mut hasher = Hasher::new();
+    hasher.update(
+        data,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    hasher.finalize(
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Poseidon2 2-to-1 compression of two digests
+pub fn compress(
+    left: &Digest,
+    right: &Digest,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Digest {
+    let mut state = [0; WIDTH];
+    state[..CAPACITY].copy_from_slice(left);
+    state[CAPACITY..2 * CAPACITY].copy_from_slice(right);
+    permute(
+        &mut state,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    state[..CAPACITY].try_into().unwrap()
+}
+
+/// Computes the root of the binary Merkle tree with the given leaves.
+///
+/// Panics if there are no leaves.
+pub fn merkle_root(leaves: &[Digest], #[cfg(feature = "hints")] hints: &mut Vec<u64>) -> Digest {
+    assert!(!leaves.is_empty(), "A Merkle tree needs at least one leaf");
+
+    let mut level: Vec<Digest> = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                compress(
+                    &pair[0],
+                    pair.get(1).unwrap_or(&[0; CAPACITY]),
+                    #[cfg(feature = "hints")]
+                    hints,
+                )
+            })
+            .collect();
+    }
+    level[0]
+}
+
+/// Verifies that `leaf` is at position `index` of the Merkle tree with the given root.
+///
+/// `siblings` are the sibling nodes from the leaf level up to the level below the root.
+pub fn verify_merkle_path(
+    leaf: &Digest,
+    index: u64,
+    siblings: &[Digest],
+    root: &Digest,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    if siblings.len() < 64 && index >> siblings.len() != 0 {
+        return false;
+    }
+
+    let mut node = *leaf;
+    for (level, sibling) in siblings.iter().enumerate() {
+        node = if (index >> level) & 1 == 0 {
+            compress(
+                &node,
+                sibling,
+                #[cfg(feature = "hints")]
+                hints,
+            )
+        } else {
+            compress(
+                sibling,
+                &node,
+                #[cfg(feature = "hints")]
+                hints,
+            )
+        };
+    }
+    node == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_elements(elements: &[u64]) -> Digest {
+        super::hash_elements(
+            elements,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        )
+    }
+
+    fn compress(left: &Digest, right: &Digest) -> Digest {
+        super::compress(
+            left,
+            right,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        )
+    }
+
+    fn leaf(i: u64) -> Digest {
+        [i, i + 1, i + 2, i + 3]
+    }
+
+    #[test]
+    fn test_short_input_is_not_hashed() {
+        assert_eq!(hash_elements(&[]), [0; CAPACITY]);
+        assert_eq!(hash_elements(&[1, 2, 3]), [1, 2, 3, 0]);
+        assert_eq!(hash_elements(&[1, 2, 3, 4]), [1, 2, 3, 4]);
+    }
+
+    // Known answers of the Poseidon2 Goldilocks width 16 instance, the one of the HorizenLabs
+    // reference implementation and of `lib-c/c/src/poseidon2`, with the sponge layout of the
+    // proofman linear hash: chunks of 12 elements, the previous capacity in the last 4
+
+    #[test]
+    fn test_permutation_known_answer() {
+        let mut state: [u64; WIDTH] = core::array::from_fn(|i| i as u64);
+        permute(
+            &mut state,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        );
+        assert_eq!(
+            state,
+            [
+                0x85c54702470d9756,
+                0xaa53c7a7d52d9898,
+                0x285128096efb0dd7,
+                0xf3fde5edd3050ac8,
+                0xc7b65efd040df908,
+                0x4be3f6c467f57ae9,
+                0x274e9a67b41754fb,
+                0x0f7d39cd5de94dac,
+                0xd0224b9794d0b78c,
+                0x372f6139570042e1,
+                0xce6e8a93dc4ec26c,
+                0xace65e30a4daf7af,
+                0x016f2824cc1ba3db,
+                0x2e8f3af37c434dec,
+                0xc80831bb6e09da01,
+                0x3a7d670bf1a86ee8,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_linear_hash_known_answers() {
+        let range = |n: u64| (1..=n).collect::<Vec<u64>>();
+
+        assert_eq!(
+            hash_elements(&range(5)),
+            [0xb4e128aae6c443ce, 0xfced0f84b41e67fc, 0x53fc5f89716f47a2, 0x60b06a5df7d2b131]
+        );
+        // A single full chunk
+        assert_eq!(
+            hash_elements(&range(12)),
+            [0x356a206e7e816d9c, 0xfd5af01d35ca1fbe, 0x1c0628d481a96861, 0x6dc42ecafde836c5]
+        );
+        // Two full chunks and a partial one
+        assert_eq!(
+            hash_elements(&range(29)),
+            [0x3726e9f2eef9d950, 0x23036eb6d06b28d2, 0xd8e8417110a544e1, 0x07bbf19907aae0bb]
+        );
+        // Elements close to the modulus
+        let high: Vec<u64> = (0..13).map(|i| GOLDILOCKS_PRIME - 1 - i).collect();
+        assert_eq!(
+            hash_elements(&high),
+            [0x204770840296a3c9, 0x3caf28657f88845b, 0xf62915c1d4715ec1, 0x2b44208be2bbf4ed]
+        );
+    }
+
+    #[test]
+    fn test_hash_bytes_known_answers() {
+        let hash_bytes = |data: &[u8]| {
+            super::hash_bytes(
+                data,
+                #[cfg(feature = "hints")]
+                &mut Vec::new(),
+            )
+        };
+
+        // 30 bytes pack into 5 elements, the last one holding the 0x01 terminator
+        let data: Vec<u8> = (0..30).collect();
+        assert_eq!(
+            hash_elements(&[
+                0x0006050403020100,
+                0x000d0c0b0a090807,
+                0x0014131211100f0e,
+                0x001b1a1918171615,
+                0x0000000000011d1c,
+            ]),
+            hash_bytes(&data)
+        );
+        assert_eq!(
+            hash_bytes(&data),
+            [0x759361814d276a4d, 0xa1dffb4ac78ca47e, 0xa1aa9ce12521140b, 0x69f54446079ee522]
+        );
+
+        let data: Vec<u8> = (0..100).collect();
+        assert_eq!(
+            hash_bytes(&data),
+            [0x4fcc36913dc7fe9f, 0x8e5c02cb4ac45a32, 0x1d9d203c3c9f1049, 0x266b613b5eae4547]
+        );
+    }
+
+    #[test]
+    fn test_compress_known_answer() {
+        assert_eq!(
+            compress(&[1, 2, 3, 4], &[5, 6, 7, 8]),
+            [0x51fd752cf9555935, 0x4ee407d4951f745c, 0x241534cf89820180, 0x7e531cbb82b70a1f]
+        );
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let data: Vec<u8> = (0..200u8).collect();
+        let elements: Vec<u64> = (0..30).collect();
+
+        let mut one_shot = Hasher::new();
+        one_shot.update(
+            &data,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        );
+        one_shot.update_elements(
+            &elements,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        );
+
+        let mut streaming = Hasher::new();
+        for chunk in data.chunks(13) {
+            streaming.update(
+                chunk,
+                #[cfg(feature = "hints")]
+                &mut Vec::new(),
+            );
+        }
+        for chunk in elements.chunks(5) {
+            streaming.update_elements(
+                chunk,
+                #[cfg(feature = "hints")]
+                &mut Vec::new(),
+            );
+        }
+
+        assert_eq!(
+            one_shot.finalize(
+                #[cfg(feature = "hints")]
+                &mut Vec::new()
+            ),
+            streaming.finalize(
+                #[cfg(feature = "hints")]
+                &mut Vec::new()
+            )
+        );
+    }
+
+    #[test]
+    fn test_byte_packing_is_length_delimited() {
+        let hash_bytes = |data: &[u8]| {
+            super::hash_bytes(
+                data,
+                #[cfg(feature = "hints")]
+                &mut Vec::new(),
+            )
+        };
+
+        assert_eq!(hash_bytes(&[]), [1, 0, 0, 0]);
+        assert_eq!(hash_bytes(&[0xaa]), [0x01aa, 0, 0, 0]);
+        assert_ne!(hash_bytes(&[0]), hash_bytes(&[0, 0]));
+        assert_ne!(hash_bytes(&[0; 7]), hash_bytes(&[0; 8]));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a canonical field element")]
+    fn test_non_canonical_element_panics() {
+        hash_elements(&[GOLDILOCKS_PRIME]);
+    }
+
+    #[test]
+    fn test_merkle_paths() {
+        let leaves: Vec<Digest> = (0..5).map(|i| leaf(i * 4)).collect();
+        let root = merkle_root(
+            &leaves,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        );
+
+        // The odd node of each level is paired with a zero digest
+        let zero = [0; CAPACITY];
+        let n01 = compress(&leaves[0], &leaves[1]);
+        let n23 = compress(&leaves[2], &leaves[3]);
+        let n4 = compress(&leaves[4], &zero);
+        let n0123 = compress(&n01, &n23);
+        let n4_ = compress(&n4, &zero);
+        assert_eq!(root, compress(&n0123, &n4_));
+
+        let verify = |leaf: &Digest, index: u64, siblings: &[Digest]| {
+            verify_merkle_path(
+                leaf,
+                index,
+                siblings,
+                &root,
+                #[cfg(feature = "hints")]
+                &mut Vec::new(),
+            )
+        };
+        assert!(verify(&leaves[2], 2, &[leaves[3], n01, n4_]));
+        assert!(verify(&leaves[4], 4, &[zero, zero, n0123]));
+        assert!(!verify(&leaves[2], 3, &[leaves[3], n01, n4_]));
+        assert!(!verify(&leaves[2], 10, &[leaves[3], n01, n4_]));
+        assert!(!verify(&leaves[1], 2, &[leaves[3], n01, n4_]));
+    }
+}
@@ -0,0 +1,501 @@
+//! RSA signature verification (RFC 8017) with SHA-256, for 2048 to 4096-bit moduli.
+//!
+//! - [`verify_pkcs1v15_sha256`] — RSASSA-PKCS1-v1_5 (e.g. DKIM, JWT `RS256`).
+//! - [`verify_pss_sha256`] — RSASSA-PSS with MGF1-SHA-256 (e.g. JWT `PS256`).
+//!
+//! The `_digest` variants take the SHA-256 digest of the message instead of the message itself.
+//! The public operation `s^e mod n` runs on [`modexp`], whose reductions are hinted by the
+//! `bigint_div` free call and checked with the arithmetic precompiles.
+//!
+//! All integers (modulus, exponent and signature) are big-endian byte strings.
+
+#[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+use crate::alloc_extern::{vec, vec::Vec};
+
+use super::{bytes_be_to_u256_le, bytes_be_to_u64_le, modexp, sha256, u256_le_to_bytes_be};
+
+/// Scheme identifier for RSASSA-PKCS1-v1_5 with SHA-256
+pub const SCHEME_PKCS1V15_SHA256: u32 = 0;
+
+/// Scheme identifier for RSASSA-PSS with SHA-256 and MGF1-SHA-256
+pub const SCHEME_PSS_SHA256: u32 = 1;
+
+/// Smallest supported modulus size in bits
+pub const MIN_MODULUS_BITS: usize = 2048;
+
+/// Largest supported modulus size in bits
+pub const MAX_MODULUS_BITS: usize = 4096;
+
+/// Largest supported public exponent, the bound of `RsaPublicKey::new` in the `rsa` crate
+pub const MAX_PUBLIC_EXPONENT: u64 = (1 << 33) - 1;
+
+/// Length in bytes of a SHA-256 digest
+const H_LEN: usize = 32;
+
+/// DER encoding of the SHA-256 `DigestInfo` prefix (RFC 8017, section 9.2, note 1)
+const SHA256_DIGEST_INFO_PREFIX: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+/// Verifies an RSASSA-PKCS1-v1_5 signature over `msg` with SHA-256
+pub fn verify_pkcs1v15_sha256(
+    modulus: &[u8],
+    exponent: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    let digest = sha256(
+        msg,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    verify_pkcs1v15_sha256_digest(
+        modulus,
+        exponent,
+        &digest,
+        signature,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Verifies an RSASSA-PKCS1-v1_5 signature over a SHA-256 digest (RFC 8017, section 8.2.2)
+pub fn verify_pkcs1v15_sha256_digest(
+    modulus: &[u8],
+    exponent: &[u8],
+    digest: &[u8; 32],
+    signature: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    let Some((em, _)) = public_op(
+        modulus,
+        exponent,
+        signature,
+        #[cfg(feature = "hints")]
+        hints,
+    ) else {
+        return false;
+    };
+
+    // EM = 0x00 || 0x01 || PS || 0x00 || DigestInfo || H, with PS = 0xff..0xff
+    let k = em.len();
+    let t_len = SHA256_DIGEST_INFO_PREFIX.len() + H_LEN;
+    let ps_len = k - t_len - 3;
+    em[0] == 0x00
+        && em[1] == 0x01
+        && em[2..2 + ps_len].iter().all(|&b| b == 0xff)
+        && em[2 + ps_len] == 0x00
+        && em[k - t_len..k - H_LEN] == SHA256_DIGEST_INFO_PREFIX
+        && em[k - H_LEN..] == digest[..]
+}
+
+/// Verifies an RSASSA-PSS signature over `msg` with SHA-256, MGF1-SHA-256 and a salt of
+/// `salt_len` bytes
+pub fn verify_pss_sha256(
+    modulus: &[u8],
+    exponent: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+    salt_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    let digest = sha256(
+        msg,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    verify_pss_sha256_digest(
+        modulus,
+        exponent,
+        &digest,
+        signature,
+        salt_len,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
+
+/// Verifies an RSASSA-PSS signature over a SHA-256 digest (RFC 8017, sections 8.1.2 and 9.1.2)
+pub fn verify_pss_sha256_digest(
+    modulus: &[u8],
+    exponent: &[u8],
+    digest: &[u8; 32],
+    signature: &[u8],
+    salt_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> bool {
+    let Some((m, mod_bits)) = public_op(
+        modulus,
+        exponent,
+        signature,
+        #[cfg(feature = "hints")]
+        hints,
+    ) else {
+        return false;
+    };
+
+    // EM = I2OSP(m, emLen), with emBits = modBits - 1. When modBits ≡ 1 (mod 8), emLen = k - 1
+    // and the leading byte of m must be zero
+    let em_bits = mod_bits - 1;
+    let em_len = em_bits.div_ceil(8);
+    let em = if em_len < m.len() {
+        if m[0] != 0 {
+            return false;
+        }
+        &m[1..]
+    } else {
+        &m[..]
+    };
+
+    if salt_len > em_len - H_LEN - 2 || em[em_len - 1] != 0xbc {
+        return false;
+    }
+
+    // EM = maskedDB || H || 0xbc
+    let db_len = em_len - H_LEN - 1;
+    let (masked_db, h) = em[..em_len - 1].split_at(db_len);
+
+    // The leftmost 8·emLen - emBits bits of maskedDB must be zero
+    let zero_bits = 8 * em_len - em_bits;
+    if masked_db[0] & !(0xff >> zero_bits) != 0 {
+        return false;
+    }
+
+    // DB = maskedDB ⊕ MGF1(H, emLen - hLen - 1)
+    let mut db = mgf1_sha256(
+        h,
+        db_len,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    for (d, m) in db.iter_mut().zip(masked_db) {
+        *d ^= m;
+    }
+    db[0] &= 0xff >> zero_bits;
+
+    // DB = PS || 0x01 || salt, with PS = 0x00..0x00
+    let ps_len = em_len - H_LEN - salt_len - 2;
+    if db[..ps_len].iter().any(|&b| b != 0) || db[ps_len] != 0x01 {
+        return false;
+    }
+    let salt = &db[db_len - salt_len..];
+
+    // H' = Hash(0x00 x 8 || mHash || salt)
+    let mut m_prime = Vec::with_capacity(8 + H_LEN + salt_len);
+    m_prime.extend_from_slice(&[0u8; 8]);
+    m_prime.extend_from_slice(digest);
+    m_prime.extend_from_slice(salt);
+    let h_prime = sha256(
+        &m_prime,
+        #[cfg(feature = "hints")]
+        hints,
+    );
+
+    h_prime[..] == *h
+}
+
+/// Checks the key and signature sizes and computes `s^e mod n`
+///
+/// Returns the result as a `k`-byte big-endian string, where `k` is the length of the modulus in
+/// bytes, together with the modulus size in bits, or `None` if the key or signature is invalid.
+fn public_op(
+    modulus: &[u8],
+    exponent: &[u8],
+    signature: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Option<(Vec<u8>, usize)> {
+    // Leading zeros, e.g. from a DER integer, are not part of the modulus length
+    let modulus = strip_leading_zeros(modulus);
+    let exponent = strip_leading_zeros(exponent);
+
+    let k = modulus.len();
+    if k == 0 || modulus[k - 1] & 1 == 0 {
+        return None;
+    }
+    let mod_bits = 8 * k - modulus[0].leading_zeros() as usize;
+    if !(MIN_MODULUS_BITS..=MAX_MODULUS_BITS).contains(&mod_bits) {
+        return None;
+    }
+
+    // The public exponent must be odd, greater than one and at most MAX_PUBLIC_EXPONENT, which
+    // also makes it smaller than the modulus
+    if exponent.is_empty() || exponent.len() > 8 {
+        return None;
+    }
+    let e = exponent.iter().fold(0u64, |e, &byte| (e << 8) | byte as u64);
+    if e & 1 == 0 || e == 1 || e > MAX_PUBLIC_EXPONENT {
+        return None;
+    }
+
+    // The signature must be exactly k bytes long and represent an integer smaller than n
+    if signature.len() != k || signature >= modulus {
+        return None;
+    }
+
+    let m = modexp(
+        &bytes_be_to_u256_le(signature),
+        &bytes_be_to_u64_le(exponent),
+        &bytes_be_to_u256_le(modulus),
+        #[cfg(feature = "hints")]
+        hints,
+    );
+
+    let mut em = vec![0u8; k];
+    u256_le_to_bytes_be(&m, &mut em);
+    Some((em, mod_bits))
+}
+
+/// MGF1 mask generation with SHA-256 (RFC 8017, appendix B.2.1)
+fn mgf1_sha256(
+    seed: &[u8],
+    mask_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Vec<u8> {
+    let mut mask = Vec::with_capacity(mask_len.next_multiple_of(H_LEN));
+    let mut block = Vec::with_capacity(seed.len() + 4);
+    for counter in 0..mask_len.div_ceil(H_LEN) as u32 {
+        block.clear();
+        block.extend_from_slice(seed);
+        block.extend_from_slice(&counter.to_be_bytes());
+        mask.extend_from_slice(&sha256(
+            &block,
+            #[cfg(feature = "hints")]
+            hints,
+        ));
+    }
+    mask.truncate(mask_len);
+    mask
+}
+
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    &bytes[first_nonzero..]
+}
+
+/// RSA signature verification over a SHA-256 digest
+///
+/// # Arguments
+/// * `scheme` - [`SCHEME_PKCS1V15_SHA256`] or [`SCHEME_PSS_SHA256`]
+/// * `salt_len` - PSS salt length in bytes, ignored for PKCS#1 v1.5
+///
+/// # Safety
+/// - `modulus`, `exponent` and `signature` must point to `modulus_len`, `exponent_len` and
+///   `signature_len` bytes respectively
+/// - `digest` must point to 32 bytes
+///
+/// # Returns
+/// * `None` if `scheme` is unknown
+/// * `Some(valid)` otherwise
+#[allow(dead_code, clippy::too_many_arguments)]
+#[inline]
+pub(crate) unsafe fn rsa_verify_c(
+    scheme: u32,
+    modulus: *const u8,
+    modulus_len: usize,
+    exponent: *const u8,
+    exponent_len: usize,
+    digest: *const u8,
+    signature: *const u8,
+    signature_len: usize,
+    salt_len: usize,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> Option<bool> {
+    let modulus = core::slice::from_raw_parts(modulus, modulus_len);
+    let exponent = core::slice::from_raw_parts(exponent, exponent_len);
+    let digest = &*(digest as *const [u8; 32]);
+    let signature = core::slice::from_raw_parts(signature, signature_len);
+
+    match scheme {
+        SCHEME_PKCS1V15_SHA256 => Some(verify_pkcs1v15_sha256_digest(
+            modulus,
+            exponent,
+            digest,
+            signature,
+            #[cfg(feature = "hints")]
+            hints,
+        )),
+        SCHEME_PSS_SHA256 => Some(verify_pss_sha256_digest(
+            modulus,
+            exponent,
+            digest,
+            signature,
+            salt_len,
+            #[cfg(feature = "hints")]
+            hints,
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    // 2048-bit key with e = 65537, signatures of b"hello zisk" (PSS with a 32-byte salt)
+    const MODULUS: &str = "\
+        ddcc5c6336d6b7bb8e8a4e67190e05f2b96cbf1891ccea6018ed271280ff6adf3689ff7368bf93676c809c0e\
+        b3fe821b91af2ada755e0b1f16d424751d779433c82d6711e90aba8dea7e24530f67c2444728484fe9b6d96a\
+        bcefcaa11c46d3d6466ae2da435f20122a08ee72f621bd410fd9af28f101d2eadbadbeab7b6af1ec69d3bb51\
+        0ff399147840474bf768ffa10ca8807da1f17d6b5656854c082a65061447a16094b5ef0dcb8ea0c66bc2fb4c\
+        544f5a358dfb80e57ee87a139a4f67703f764968070a94b75347154b25cb6eb37f34b5b847a32f692d87ee06\
+        051954c7e38192ac74385f376ae2c8d130778faa3990f1115ab28cb1db83cd6c05810e39";
+
+    const PKCS1V15_SIGNATURE: &str = "\
+        38f1b5a3f907eb971512922be7b1c1d291fa246c23fb74203d47879fc7b44b19e1aaca24801060d3bf5c995f\
+        810ba5e6a475a3ecb0703ccd3c356101225c18208bc38eb0d7b993257c481bc272b416f8cfb6bb181fc5935e\
+        b0b635be7fbed676363d589bbcc7d3a81ece25b84bc27ad39161146554cf72ab1a6afc4a345575f1ef10d1c1\
+        c8d38f52df881376aa70af9c58122bd0a18a5c34c209c8dd18d68b27f36666e4dfa5217ea80e370999a2d7b6\
+        044730767ba5e0e92bdb662b6cc5bbf24de88fb4490a7c2c621ad04fe8d6dfe9b8e88b2a99e51f7b8abe09c4\
+        0f4246618eb24362b2bbb6d26196f2b6613d2fd584aeb2aaa57986e9a7ae0fa28b16c523";
+
+    const PSS_SIGNATURE: &str = "\
+        b16db78ba11d96f540222dfa11194720e46e170576ebbef081fb2d067afa1252f95e35b71ad7f08a8c53cd1e\
+        5bcb88119862890b744b9e0888ba2362babb70e317d9da25f12c6523629e21f9238f6cae06e409899b831714\
+        9c7f1393b550c56473f3e29f9ca3cd7f5c52863b1c38733f1b42f799185eb52bbed21a5ccae292aa17b60dd9\
+        9a99a679594612a7a83ff96f5d94c18c572123f416bb977056764b66920248b46f11464a0194a65d899fc216\
+        c372b1b300715fbec8b10af3c5fc9475907089f53a56c949a0c4fcea688e2b902383d95f4ce207136fe392af\
+        542673f9d8a9c8387a95d923fd7e0cad68c9d1657c3fa0e798bff723b581be161b58c934";
+
+    const EXPONENT: [u8; 3] = [0x01, 0x00, 0x01];
+
+    // Boundary keys, with PKCS#1 v1.5 signatures of b"hello zisk": a 2048-bit key whose public
+    // exponent is MAX_PUBLIC_EXPONENT, the signature of the same primes with exponent
+    // MAX_PUBLIC_EXPONENT + 2, and a 4096-bit key with e = 65537
+    pub(crate) const E_MAX_MODULUS: &str = "\
+        b7c03ffce3f727c0af41c438466c0c8f030cd3ec315cacfe4a2cc90e13534569864907a6f2ab217bc3257c51\
+        c8fa6fce6f915f2bd84615348d47ec90ec544dcdb7dabd6735ba5de191ed69323ecedca69f242fd8aa186a3a\
+        0eb44133952742892f6f5ce536930e0e75d7c652e4c3f3ee5d9a9a56e87d003334e4cd937dc9b816ef3d23e1\
+        cae09875a9f66de6f2c9c6068e584ec87d20f6ab658c1f8a25218f0b76d71bcfc9458591c25c1e0e422b6396\
+        8733a5104d884f4a3751e63c8f99c8635766ac3d7b74fc49df9508220d87a1c668276f08fb88d0fabcdbe94a\
+        f48276019c5dddcc0d7620a4c73b0794600a2308fa59bbe3295238254266c82b9a52191d";
+
+    pub(crate) const E_MAX_SIGNATURE: &str = "\
+        60fcae6596c905873282a59393c2fa32689579818966886d6f0ae4084f447e4e1a349a1794f47cc016c0f4a1\
+        fb9b081c15fb0694db5e6efbec5e529947d5015ea1fd9e503e8255dbd797493116e2a1bc75e71c17fb9d4da9\
+        51d30b9342591a66245a5f9fa7808469596926be208dfbc7f35c5de1d850e6b054b53f3c3feadb45ebfaaa72\
+        2f5e4d16a5dc4fce135c8fa006ac3933a248d0e890090b1a6efb68f38483bc744540a952774b7a10db5e4246\
+        163c66f070c8178e33becadc0553d198c9d974df1e52f331a22b8fb21e923044481249f6a910912797ab66cc\
+        18dd7e37c4d2921704add98dd03fd97c9252371653a9c0a6d07b27c01bbe1d9c9e5db7be";
+
+    pub(crate) const E_OVER_SIGNATURE: &str = "\
+        544d7df9a7b0485c1290a520b465fee11547312e32cb8b32e9a6b1eafbae10825d75ad5054f5b4d64c0ebd48\
+        8014e5e0d415dfe8af1bf7e428e38f9b9c7202c1df429be0ebb6a268ac8c60322d5589ede719dae74895420d\
+        826263f938bf6d37201c2520acdc23eb12d2ab95934c3b02acf99429020ccd987d4547a7dbcc494c66f70a71\
+        f35267791f9a79eca9be74bd35ba1bbd5424c55319b93b992febc739101571a3c3098b70aebd4e11b41c742a\
+        abd4aa9c368311ffa442e926d5886c968d6ee9e86bb36a8ef1280d9540df72684427685ae24b8975638db3d7\
+        edf5eaf17775ea24efe68175449811af442d4c21afc39dec236976a5b300a456f9719d46";
+
+    pub(crate) const MAX_BITS_MODULUS: &str = "\
+        c5a01b07b4d8ce5ed415bc087bc46db6af437bea3848e9c713b4104931d40c9561cc348a0f9e880e8df10d5e\
+        5dee28a41a123f7598635724c5d58931aa6f9fdbfd4930900569a86e867a6e0d0744659507d2b58b3ca9de7e\
+        90ba1792829f1b29a4e37bbbfec906a43b924d25f198e39280aff2be5566f12e232174adefa4b7f890a38c11\
+        62f57564a34fe0a7c72239a68fd8a8ecd715e7991e0fc0250b591b2ac81aa08c1bdd9d2428ae304bebd01107\
+        686e138fbb9fa91afdab7350eddf0bff3be509307f1326ce913edbccd5cf7f8a4299de6735a8cb7672ceaec2\
+        6e16ed9db01da7db4057dfe59d01d68973ac94c000d4c2c067690388a87d4c8538120eace403499b9203ac39\
+        e4ce7350d6dd2c6dd1eb788c11a3817480258cb6a361af92a8d67975d747e32b4aeb870702bc4cb59d175ef1\
+        9b093c0229dc689fdc9bc156aca81ba781e5b707c50b486501b2c692f8a96ff7bf5c20c218d7cef9ccb74cb7\
+        f633dba2b2e6612943110d24e005fc278bcef8381312f8cbe25ad24a91fdc83c6f0bf2e2946e06f91111735e\
+        b12a6ccc1ef5962f3d3122ee0eed9fd7bfcaab0b03c6766eba6943e7d0bb456e27d753b53b97ea335b6437b5\
+        f76bb7430bbefa4c4b937b1f0a57109288069edbdc21408ffd59b8269d8b88a4f59e83b5c077b4b648873099\
+        3ae51c509b9e4bc80e0787c39206fc672e999f3cce3cea95e1a6dd91";
+
+    pub(crate) const MAX_BITS_SIGNATURE: &str = "\
+        a6b281bc400bd69e3b4850151ef85d3c260028cd75eec301df17012e66b44b2b2354c64b79446a95320ac83e\
+        27e02b3e3e276b521e40af1e5bdd620bc65fce707b6793379199e64b542d2c819dd001c6c9ec6fe177fd384f\
+        fba37c362f4393ed29e02a072667377032df439373c763f859af53319ff1f01c82a02f053f6f82b8977fe323\
+        077e74296c159ee42c130b974d10a325dbdb37205fd4e17c4e458308249d352da1eac0449205b69d3bffa107\
+        817934d97fce862c1b72abe563d89f49c41086f22c4c45a4a3bad4de54c4f6bbd58c2d8de80bbfb1450804c0\
+        3eb3fab36d4e069cadb3ec01cbbdb70100c0acecb116ee6fa7d65e88659ec6886c911f3a88dffaf391b76a29\
+        42c4d29476d56fb98258c10da85bf27274d89f6e44aa0c3cceaf4999f09b8d3a8fecbe7391528c6ba0a5e8b0\
+        3bdbc0a7324158b6529384be1e3059c998ff9f03f8d58c77e64a35aa2f3e3b75cbdc5bf3bd78bbae46e55e28\
+        0fc183423d15caeb156e5e0a0d251a0e951f1ca71f0461adedef10972cfcb9cd1e8d90063d49d78fddbeb247\
+        ebf0247e89b3955334d1693e2561a0cf90a1c7d40f416b2e28461dd8268ebc934ebcf50f0c1df6a7130e71be\
+        84b996189f5b0a46f0e8e7f23d945e5acb7660553b833f6681a830979414d725dd3097b315a553beed07cb59\
+        47b03a14561e3be788a18b40c5bc12d8dc0c0113c4a995d21edfbe3e";
+
+    pub(crate) fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn verify_pkcs1v15(modulus: &[u8], exponent: &[u8], msg: &[u8], signature: &[u8]) -> bool {
+        verify_pkcs1v15_sha256(
+            modulus,
+            exponent,
+            msg,
+            signature,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        )
+    }
+
+    fn verify_pss(msg: &[u8], signature: &[u8], salt_len: usize) -> bool {
+        verify_pss_sha256(
+            &from_hex(MODULUS),
+            &EXPONENT,
+            msg,
+            signature,
+            salt_len,
+            #[cfg(feature = "hints")]
+            &mut Vec::new(),
+        )
+    }
+
+    #[test]
+    fn test_pkcs1v15() {
+        let modulus = from_hex(MODULUS);
+        let signature = from_hex(PKCS1V15_SIGNATURE);
+        assert!(verify_pkcs1v15(&modulus, &EXPONENT, b"hello zisk", &signature));
+        assert!(!verify_pkcs1v15(&modulus, &EXPONENT, b"hello zisK", &signature));
+        assert!(!verify_pkcs1v15(&modulus, &[0x03], b"hello zisk", &signature));
+
+        // A leading zero byte in the modulus, as in a DER integer, is ignored
+        let mut der_modulus = vec![0];
+        der_modulus.extend_from_slice(&modulus);
+        assert!(verify_pkcs1v15(&der_modulus, &EXPONENT, b"hello zisk", &signature));
+
+        let mut tampered = signature.clone();
+        tampered[100] ^= 1;
+        assert!(!verify_pkcs1v15(&modulus, &EXPONENT, b"hello zisk", &tampered));
+        assert!(!verify_pkcs1v15(&modulus, &EXPONENT, b"hello zisk", &signature[1..]));
+    }
+
+    #[test]
+    fn test_pss() {
+        let signature = from_hex(PSS_SIGNATURE);
+        assert!(verify_pss(b"hello zisk", &signature, 32));
+        assert!(!verify_pss(b"hello zisk", &signature, 31));
+        assert!(!verify_pss(b"hello zisk", &signature, usize::MAX));
+        assert!(!verify_pss(b"hello zisK", &signature, 32));
+        assert!(!verify_pss(b"hello zisk", &from_hex(PKCS1V15_SIGNATURE), 32));
+    }
+
+    #[test]
+    fn test_key_size() {
+        // A 1024-bit modulus is rejected before any signature check
+        let modulus = from_hex(MODULUS);
+        let signature = from_hex(PKCS1V15_SIGNATURE);
+        assert!(!verify_pkcs1v15(&modulus[128..], &EXPONENT, b"hello zisk", &signature[128..]));
+    }
+
+    #[test]
+    fn test_key_bounds() {
+        let modulus = from_hex(E_MAX_MODULUS);
+        let e_max = MAX_PUBLIC_EXPONENT.to_be_bytes();
+        let e_over = (MAX_PUBLIC_EXPONENT + 2).to_be_bytes();
+        assert!(verify_pkcs1v15(&modulus, &e_max, b"hello zisk", &from_hex(E_MAX_SIGNATURE)));
+        assert!(!verify_pkcs1v15(&modulus, &e_over, b"hello zisk", &from_hex(E_OVER_SIGNATURE)));
+
+        let modulus = from_hex(MAX_BITS_MODULUS);
+        let signature = from_hex(MAX_BITS_SIGNATURE);
+        assert!(verify_pkcs1v15(&modulus, &EXPONENT, b"hello zisk", &signature));
+
+        // A 4104-bit modulus is above MAX_MODULUS_BITS
+        let mut modulus = modulus;
+        modulus.insert(0, 1);
+        let mut signature = signature;
+        signature.insert(0, 0);
+        assert!(!verify_pkcs1v15(&modulus, &EXPONENT, b"hello zisk", &signature));
+    }
+}
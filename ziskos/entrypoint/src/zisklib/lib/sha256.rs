@@ -4,54 +4,81 @@ use crate::syscalls::{syscall_sha256_f, SyscallSha256Params};
 
 use super::is_aligned_8;
 
-/// SHA-256 initial hash values
-const SHA256_INIT: [u32; 8] = [
-    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
-];
+/// SHA-256 initial hash values, as the eight 32-bit words laid out in the `u64`s the precompile
+/// takes
+pub const SHA256_INIT: [u64; 4] =
+    [0xbb67ae85_6a09e667, 0xa54ff53a_3c6ef372, 0x9b05688c_510e527f, 0x5be0cd19_1f83d9ab];
 
 /// SHA-256 hash function. For reference: https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
 pub fn sha256(input: &[u8], #[cfg(feature = "hints")] hints: &mut Vec<u64>) -> [u8; 32] {
     let mut state = SHA256_INIT;
-    let input_len = input.len();
+    let blocks_len = input.len() - input.len() % 64;
+    sha256_compress(
+        &mut state,
+        &input[..blocks_len],
+        #[cfg(feature = "hints")]
+        hints,
+    );
+    sha256_finalize(
+        &mut state,
+        &input[blocks_len..],
+        input.len() as u64,
+        #[cfg(feature = "hints")]
+        hints,
+    )
+}
 
-    // Process complete 64-byte blocks
-    let mut offset = 0;
-    if is_aligned_8(input.as_ptr()) {
+/// Compresses the complete 64-byte blocks of `blocks` into `state`
+///
+/// Trailing bytes that do not fill a block are ignored.
+pub fn sha256_compress(
+    state: &mut [u64; 4],
+    blocks: &[u8],
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) {
+    if is_aligned_8(blocks.as_ptr()) {
         // Fast path: input is aligned, use directly
-        while offset + 64 <= input_len {
-            let block: &[u8; 64] = input[offset..offset + 64].try_into().unwrap();
+        for block in blocks.chunks_exact(64) {
+            let input: &[u64; 8] = unsafe { &*(block.as_ptr() as *const [u64; 8]) };
             compress_block(
-                &mut state,
-                block,
+                state,
+                input,
                 #[cfg(feature = "hints")]
                 hints,
             );
-            offset += 64;
         }
     } else {
         // Slow path: input is unaligned, copy each block
-        let mut aligned_block = [0u8; 64];
-        while offset + 64 <= input_len {
-            aligned_block.copy_from_slice(&input[offset..offset + 64]);
+        for block in blocks.chunks_exact(64) {
             compress_block(
-                &mut state,
-                &aligned_block,
+                state,
+                &block_words(block),
                 #[cfg(feature = "hints")]
                 hints,
             );
-            offset += 64;
         }
     }
+}
 
-    // Handle final block(s) with padding
-    let remaining = input_len - offset;
-    let bit_len = (input_len as u64) * 8;
+/// Pads the last bytes of a message, compresses them into `state` and returns the digest
+///
+/// * `tail` - bytes of the message after its last complete block, less than 64
+/// * `input_len` - length of the whole message in bytes
+pub fn sha256_finalize(
+    state: &mut [u64; 4],
+    tail: &[u8],
+    input_len: u64,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> [u8; 32] {
+    let remaining = tail.len();
+    assert!(remaining < 64, "The tail of the message must be shorter than a block");
+    let bit_len = input_len * 8;
 
     // We need: remaining bytes + 1 (0x80) + padding + 8 (length)
     let mut final_block = [0u8; 64];
 
     // Copy remaining bytes
-    final_block[..remaining].copy_from_slice(&input[offset..]);
+    final_block[..remaining].copy_from_slice(tail);
 
     // Append 0x80
     final_block[remaining] = 0x80;
@@ -60,51 +87,51 @@ pub fn sha256(input: &[u8], #[cfg(feature = "hints")] hints: &mut Vec<u64>) -> [
     if remaining + 9 > 64 {
         // First block
         compress_block(
-            &mut state,
-            &final_block,
+            state,
+            &block_words(&final_block),
             #[cfg(feature = "hints")]
             hints,
         );
-
-        // Second block
         final_block = [0u8; 64];
-        final_block[56..64].copy_from_slice(&bit_len.to_be_bytes());
-        compress_block(
-            &mut state,
-            &final_block,
-            #[cfg(feature = "hints")]
-            hints,
-        );
-    } else {
-        // Single final block
-        final_block[56..64].copy_from_slice(&bit_len.to_be_bytes());
-        compress_block(
-            &mut state,
-            &final_block,
-            #[cfg(feature = "hints")]
-            hints,
-        );
     }
 
-    // Convert state to big-endian bytes
+    // Last block, ending with the bit length
+    final_block[56..64].copy_from_slice(&bit_len.to_be_bytes());
+    compress_block(
+        state,
+        &block_words(&final_block),
+        #[cfg(feature = "hints")]
+        hints,
+    );
+
+    // Convert state to big-endian bytes, each u64 holding two 32-bit words
     let mut result = [0u8; 32];
-    for (i, &word) in state.iter().enumerate() {
-        result[i * 4..(i + 1) * 4].copy_from_slice(&word.to_be_bytes());
+    for (chunk, &word) in result.chunks_exact_mut(8).zip(state.iter()) {
+        chunk[..4].copy_from_slice(&(word as u32).to_be_bytes());
+        chunk[4..].copy_from_slice(&((word >> 32) as u32).to_be_bytes());
     }
 
     result
 }
 
+/// Copies a 64-byte block into the 8-byte aligned words the precompile takes
+#[inline]
+fn block_words(block: &[u8]) -> [u64; 8] {
+    let mut words = [0u64; 8];
+    for (word, chunk) in words.iter_mut().zip(block.chunks_exact(8)) {
+        *word = u64::from_ne_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
 /// Compress a single 64-byte block into the state
 #[inline]
 fn compress_block(
-    state: &mut [u32; 8],
-    block: &[u8; 64],
+    state: &mut [u64; 4],
+    input: &[u64; 8],
     #[cfg(feature = "hints")] hints: &mut Vec<u64>,
 ) {
-    let state_64: &mut [u64; 4] = unsafe { &mut *(state.as_mut_ptr() as *mut [u64; 4]) };
-    let input_u64: &[u64; 8] = unsafe { &*(block.as_ptr() as *const [u64; 8]) };
-    let mut sha256_params = SyscallSha256Params { state: state_64, input: input_u64 };
+    let mut sha256_params = SyscallSha256Params { state, input };
     syscall_sha256_f(
         &mut sha256_params,
         #[cfg(feature = "hints")]
@@ -18,11 +18,12 @@
 
 use blst::{
     blst_bendian_from_fp, blst_final_exp, blst_fp, blst_fp12, blst_fp12_is_one, blst_fp12_mul,
-    blst_fp2, blst_fp_from_bendian, blst_map_to_g1, blst_map_to_g2, blst_miller_loop, blst_p1,
-    blst_p1_add_or_double_affine, blst_p1_affine, blst_p1_affine_in_g1, blst_p1_affine_on_curve,
-    blst_p1_from_affine, blst_p1_mult, blst_p1_to_affine, blst_p2, blst_p2_add_or_double_affine,
-    blst_p2_affine, blst_p2_affine_in_g2, blst_p2_affine_on_curve, blst_p2_from_affine,
-    blst_p2_mult, blst_p2_to_affine, blst_scalar, blst_scalar_from_bendian, MultiPoint,
+    blst_fp2, blst_fp_from_bendian, blst_hash_to_g1, blst_hash_to_g2, blst_map_to_g1,
+    blst_map_to_g2, blst_miller_loop, blst_p1, blst_p1_add_or_double_affine, blst_p1_affine,
+    blst_p1_affine_in_g1, blst_p1_affine_on_curve, blst_p1_from_affine, blst_p1_mult,
+    blst_p1_to_affine, blst_p2, blst_p2_add_or_double_affine, blst_p2_affine, blst_p2_affine_in_g2,
+    blst_p2_affine_on_curve, blst_p2_from_affine, blst_p2_mult, blst_p2_to_affine, blst_scalar,
+    blst_scalar_from_bendian, MultiPoint,
 };
 use std::sync::OnceLock;
 use std::vec::Vec;
@@ -1201,3 +1202,96 @@ pub fn verify_kzg_proof(
 ) -> bool {
     kzg_verify_kzg_proof(commitment, z, y, proof)
 }
+
+/// Hash to G1 (RFC 9380, BLS12381G1_XMD:SHA-256_SSWU_RO_) → 96-byte result.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> [u8; 96] {
+    let mut p = blst_p1::default();
+    // SAFETY: all pointers are valid for the given lengths
+    unsafe {
+        blst_hash_to_g1(
+            &mut p,
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            core::ptr::null(),
+            0,
+        )
+    };
+    encode_g1_point(&p1_to_affine(&p))
+}
+
+/// Hash to G2 (RFC 9380, BLS12381G2_XMD:SHA-256_SSWU_RO_) → 192-byte result.
+pub fn hash_to_g2(msg: &[u8], dst: &[u8]) -> [u8; 192] {
+    let mut p = blst_p2::default();
+    // SAFETY: all pointers are valid for the given lengths
+    unsafe {
+        blst_hash_to_g2(
+            &mut p,
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            core::ptr::null(),
+            0,
+        )
+    };
+    encode_g2_point(&p2_to_affine(&p))
+}
+
+/// BLS signature verification schemes, instantiated for the min-pk and min-sig variants.
+///
+/// Public keys must be valid non-identity subgroup points; signatures must be valid subgroup
+/// points. `pubkeys` holds concatenated compressed public keys.
+macro_rules! bls_verify_impl {
+    ($variant:ident, $pk_size:expr) => {
+        pub mod $variant {
+            use blst::{$variant::*, BLST_ERROR};
+
+            fn parse(pubkeys: &[u8], signature: &[u8]) -> Option<(Vec<PublicKey>, Signature)> {
+                let sig = Signature::sig_validate(signature, false).ok()?;
+                let pks = pubkeys
+                    .chunks_exact($pk_size)
+                    .map(|pk| PublicKey::key_validate(pk).ok())
+                    .collect::<Option<Vec<_>>>()?;
+                Some((pks, sig))
+            }
+
+            /// Verifies a signature of `msg` under `pubkey`.
+            pub fn verify(pubkey: &[u8], msg: &[u8], signature: &[u8], dst: &[u8]) -> bool {
+                aggregate_verify(pubkey, &[msg], signature, dst)
+            }
+
+            /// Verifies an aggregate signature of `msgs[i]` under the i-th public key.
+            pub fn aggregate_verify(
+                pubkeys: &[u8],
+                msgs: &[&[u8]],
+                signature: &[u8],
+                dst: &[u8],
+            ) -> bool {
+                let Some((pks, sig)) = parse(pubkeys, signature) else {
+                    return false;
+                };
+                let pks: Vec<&PublicKey> = pks.iter().collect();
+                sig.aggregate_verify(false, msgs, dst, &pks, false) == BLST_ERROR::BLST_SUCCESS
+            }
+
+            /// Verifies an aggregate signature of the same `msg` under all public keys.
+            pub fn fast_aggregate_verify(
+                pubkeys: &[u8],
+                msg: &[u8],
+                signature: &[u8],
+                dst: &[u8],
+            ) -> bool {
+                let Some((pks, sig)) = parse(pubkeys, signature) else {
+                    return false;
+                };
+                let pks: Vec<&PublicKey> = pks.iter().collect();
+                sig.fast_aggregate_verify(false, msg, dst, &pks) == BLST_ERROR::BLST_SUCCESS
+            }
+        }
+    };
+}
+
+bls_verify_impl!(min_pk, 48);
+bls_verify_impl!(min_sig, 96);
@@ -10,6 +10,8 @@ pub mod modexp;
 #[cfg(all(not(feature = "hints"), not(all(target_os = "zkvm", target_vendor = "zisk"))))]
 pub mod ripemd160;
 #[cfg(all(not(feature = "hints"), not(all(target_os = "zkvm", target_vendor = "zisk"))))]
+pub mod rsa;
+#[cfg(all(not(feature = "hints"), not(all(target_os = "zkvm", target_vendor = "zisk"))))]
 pub mod secp256k1;
 #[cfg(all(not(feature = "hints"), not(all(target_os = "zkvm", target_vendor = "zisk"))))]
 pub mod sha256;
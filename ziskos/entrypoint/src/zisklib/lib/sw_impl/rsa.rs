@@ -0,0 +1,66 @@
+//! RSA signature verification software fallback using the rsa crate (non-hints, non-zkVM builds only).
+use rsa::{sha2::Sha256, traits::PublicKeyParts, BigUint, Pkcs1v15Sign, Pss, RsaPublicKey};
+
+use crate::zisklib::rsa::{
+    MAX_MODULUS_BITS, MIN_MODULUS_BITS, SCHEME_PKCS1V15_SHA256, SCHEME_PSS_SHA256,
+};
+
+pub fn verify(
+    scheme: u32,
+    modulus: &[u8],
+    exponent: &[u8],
+    msg_hash: &[u8; 32],
+    signature: &[u8],
+    salt_len: usize,
+) -> bool {
+    let Ok(key) =
+        RsaPublicKey::new(BigUint::from_bytes_be(modulus), BigUint::from_bytes_be(exponent))
+    else {
+        return false;
+    };
+    if !(MIN_MODULUS_BITS..=MAX_MODULUS_BITS).contains(&key.n().bits()) {
+        return false;
+    }
+
+    match scheme {
+        SCHEME_PKCS1V15_SHA256 => {
+            key.verify(Pkcs1v15Sign::new::<Sha256>(), msg_hash, signature).is_ok()
+        }
+        SCHEME_PSS_SHA256 => {
+            key.verify(Pss::new_with_salt::<Sha256>(salt_len), msg_hash, signature).is_ok()
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zisklib::rsa::tests::{
+        from_hex, E_MAX_MODULUS, E_MAX_SIGNATURE, E_OVER_SIGNATURE, MAX_BITS_MODULUS,
+        MAX_BITS_SIGNATURE,
+    };
+    use crate::zisklib::rsa::MAX_PUBLIC_EXPONENT;
+    use rsa::sha2::Digest;
+
+    #[test]
+    fn test_key_bounds() {
+        let hash: [u8; 32] = Sha256::digest(b"hello zisk").into();
+        let verify = |modulus: &str, exponent: &[u8], signature: &str| {
+            super::verify(
+                SCHEME_PKCS1V15_SHA256,
+                &from_hex(modulus),
+                exponent,
+                &hash,
+                &from_hex(signature),
+                0,
+            )
+        };
+
+        let e_max = MAX_PUBLIC_EXPONENT.to_be_bytes();
+        let e_over = (MAX_PUBLIC_EXPONENT + 2).to_be_bytes();
+        assert!(verify(E_MAX_MODULUS, &e_max, E_MAX_SIGNATURE));
+        assert!(!verify(E_MAX_MODULUS, &e_over, E_OVER_SIGNATURE));
+        assert!(verify(MAX_BITS_MODULUS, &[0x01, 0x00, 0x01], MAX_BITS_SIGNATURE));
+    }
+}
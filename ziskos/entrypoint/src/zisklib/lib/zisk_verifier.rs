@@ -1,3 +1,8 @@
+/// Verifies a serialized ZisK proof against the Vadcop final VK at its end.
+///
+/// The VK is taken from the proof itself, so this only shows that the proof is consistent with
+/// some setup. Check the VK against a trusted one, as `ziskos::recursion::verify` does, before
+/// relying on the result.
 pub fn verify_zisk_proof(zisk_proof: &[u64]) -> bool {
     if zisk_proof.len() < zisk_verifier::VADCOP_VK_LEN_WORDS {
         return false;
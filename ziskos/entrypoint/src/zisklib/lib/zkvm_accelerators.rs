@@ -9,18 +9,19 @@
 #[cfg(all(not(feature = "hints"), not(all(target_os = "zkvm", target_vendor = "zisk"))))]
 use super::sw_impl::{
     blake2, bls12 as bls12_sw, bn254 as bn254_sw, modexp as modexp_sw, ripemd160 as ripemd160_sw,
-    secp256k1 as secp256k1_sw, sha256 as sha256_sw,
+    rsa as rsa_sw, secp256k1 as secp256k1_sw, sha256 as sha256_sw,
 };
-use super::{bls12_381, bn254};
+use super::{bls12_381, bn254, rsa};
 use zkvm_interface::{
     zkvm_blake2f_message, zkvm_blake2f_offset, zkvm_blake2f_state, zkvm_bls12_381_fp,
     zkvm_bls12_381_fp2, zkvm_bls12_381_g1_msm_pair, zkvm_bls12_381_g1_point,
     zkvm_bls12_381_g2_msm_pair, zkvm_bls12_381_g2_point, zkvm_bls12_381_pairing_pair,
-    zkvm_bn254_g1_point, zkvm_bn254_pairing_pair, zkvm_bn254_scalar, zkvm_keccak256_hash,
-    zkvm_kzg_commitment, zkvm_kzg_field_element, zkvm_kzg_proof, zkvm_ripemd160_hash,
-    zkvm_secp256k1_hash, zkvm_secp256k1_pubkey, zkvm_secp256k1_signature, zkvm_secp256r1_hash,
-    zkvm_secp256r1_pubkey, zkvm_secp256r1_signature, zkvm_sha256_hash, zkvm_status,
-    zkvm_status_ZKVM_EFAIL as ZKVM_EFAIL, zkvm_status_ZKVM_EOK as ZKVM_EOK,
+    zkvm_bls_variant, zkvm_bn254_g1_point, zkvm_bn254_pairing_pair, zkvm_bn254_scalar,
+    zkvm_keccak256_hash, zkvm_kzg_commitment, zkvm_kzg_field_element, zkvm_kzg_proof,
+    zkvm_ripemd160_hash, zkvm_rsa_scheme, zkvm_secp256k1_hash, zkvm_secp256k1_pubkey,
+    zkvm_secp256k1_signature, zkvm_secp256r1_hash, zkvm_secp256r1_pubkey, zkvm_secp256r1_signature,
+    zkvm_sha256_hash, zkvm_status, zkvm_status_ZKVM_EFAIL as ZKVM_EFAIL,
+    zkvm_status_ZKVM_EOK as ZKVM_EOK,
 };
 
 #[cfg_attr(not(feature = "hints"), no_mangle)]
@@ -1224,6 +1225,552 @@ pub unsafe extern "C" fn zkvm_secp256k1_ecrecover(
     }
 }
 
+// ---------------------------------------------------------------------------
+// ZisK extensions: accelerators declared in the ZisK section of zkvm_accelerators.h
+// ---------------------------------------------------------------------------
+
+#[cfg_attr(not(feature = "hints"), no_mangle)]
+#[cfg_attr(feature = "hints", export_name = "hints_zkvm_bls12_hash_to_g1")]
+pub unsafe extern "C" fn zkvm_bls12_hash_to_g1(
+    msg: *const u8,
+    msg_len: usize,
+    dst: *const u8,
+    dst_len: usize,
+    result: *mut zkvm_bls12_381_g1_point,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> zkvm_status {
+    #[cfg(feature = "hints")]
+    {
+        super::bls12_381_hash_to_g1_c(
+            (*result).data.as_mut_ptr(),
+            msg,
+            msg_len,
+            dst,
+            dst_len,
+            hints,
+        );
+        ZKVM_EOK
+    }
+
+    #[cfg(not(feature = "hints"))]
+    {
+        #[cfg(any(all(target_os = "zkvm", target_vendor = "zisk"), zisk_hints))]
+        {
+            #[cfg(zisk_hints)]
+            unsafe {
+                crate::hints::hint_bls12_381_hash_to_g1(msg, msg_len, dst, dst_len);
+            }
+
+            #[cfg(zisk_hints_debug)]
+            crate::hint_log(format!(
+                "hint_bls12_381_hash_to_g1 (msg_len: {}, dst_len: {})",
+                msg_len, dst_len
+            ));
+
+            #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+            {
+                super::bls12_381_hash_to_g1_c(
+                    (*result).data.as_mut_ptr(),
+                    msg,
+                    msg_len,
+                    dst,
+                    dst_len,
+                );
+                return ZKVM_EOK;
+            }
+        }
+
+        #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+        {
+            (*result).data = bls12_sw::hash_to_g1(
+                std::slice::from_raw_parts(msg, msg_len),
+                std::slice::from_raw_parts(dst, dst_len),
+            );
+            ZKVM_EOK
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "hints"), no_mangle)]
+#[cfg_attr(feature = "hints", export_name = "hints_zkvm_bls12_hash_to_g2")]
+pub unsafe extern "C" fn zkvm_bls12_hash_to_g2(
+    msg: *const u8,
+    msg_len: usize,
+    dst: *const u8,
+    dst_len: usize,
+    result: *mut zkvm_bls12_381_g2_point,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> zkvm_status {
+    #[cfg(feature = "hints")]
+    {
+        super::bls12_381_hash_to_g2_c(
+            (*result).data.as_mut_ptr(),
+            msg,
+            msg_len,
+            dst,
+            dst_len,
+            hints,
+        );
+        ZKVM_EOK
+    }
+
+    #[cfg(not(feature = "hints"))]
+    {
+        #[cfg(any(all(target_os = "zkvm", target_vendor = "zisk"), zisk_hints))]
+        {
+            #[cfg(zisk_hints)]
+            unsafe {
+                crate::hints::hint_bls12_381_hash_to_g2(msg, msg_len, dst, dst_len);
+            }
+
+            #[cfg(zisk_hints_debug)]
+            crate::hint_log(format!(
+                "hint_bls12_381_hash_to_g2 (msg_len: {}, dst_len: {})",
+                msg_len, dst_len
+            ));
+
+            #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+            {
+                super::bls12_381_hash_to_g2_c(
+                    (*result).data.as_mut_ptr(),
+                    msg,
+                    msg_len,
+                    dst,
+                    dst_len,
+                );
+                return ZKVM_EOK;
+            }
+        }
+
+        #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+        {
+            (*result).data = bls12_sw::hash_to_g2(
+                std::slice::from_raw_parts(msg, msg_len),
+                std::slice::from_raw_parts(dst, dst_len),
+            );
+            ZKVM_EOK
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "hints"), no_mangle)]
+#[cfg_attr(feature = "hints", export_name = "hints_zkvm_bls_verify")]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn zkvm_bls_verify(
+    variant: zkvm_bls_variant,
+    pubkey: *const u8,
+    msg: *const u8,
+    msg_len: usize,
+    signature: *const u8,
+    dst: *const u8,
+    dst_len: usize,
+    verified: *mut bool,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> zkvm_status {
+    #[cfg(feature = "hints")]
+    {
+        match super::bls_verify_c(variant, pubkey, msg, msg_len, signature, dst, dst_len, hints) {
+            Some(result) => {
+                *verified = result;
+                ZKVM_EOK
+            }
+            None => ZKVM_EFAIL,
+        }
+    }
+
+    #[cfg(not(feature = "hints"))]
+    {
+        #[allow(unused_variables)]
+        let Some((pk_size, sig_size)) = bls12_381::bls_variant_sizes(variant) else {
+            return ZKVM_EFAIL;
+        };
+
+        #[cfg(any(all(target_os = "zkvm", target_vendor = "zisk"), zisk_hints))]
+        {
+            #[cfg(zisk_hints)]
+            unsafe {
+                let variant_bytes = (variant as u64).to_le_bytes();
+                crate::hints::hint_bls_verify(
+                    variant_bytes.as_ptr(),
+                    variant_bytes.len(),
+                    pubkey,
+                    pk_size,
+                    msg,
+                    msg_len,
+                    signature,
+                    sig_size,
+                    dst,
+                    dst_len,
+                );
+            }
+
+            #[cfg(zisk_hints_debug)]
+            crate::hint_log(format!(
+                "hint_bls_verify (variant: {}, msg_len: {}, dst_len: {})",
+                variant, msg_len, dst_len
+            ));
+
+            #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+            {
+                return match super::bls_verify_c(
+                    variant, pubkey, msg, msg_len, signature, dst, dst_len,
+                ) {
+                    Some(result) => {
+                        *verified = result;
+                        ZKVM_EOK
+                    }
+                    None => ZKVM_EFAIL,
+                };
+            }
+        }
+
+        #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+        {
+            let pubkey = std::slice::from_raw_parts(pubkey, pk_size);
+            let msg = std::slice::from_raw_parts(msg, msg_len);
+            let signature = std::slice::from_raw_parts(signature, sig_size);
+            let dst = std::slice::from_raw_parts(dst, dst_len);
+            *verified = if variant == bls12_381::BLS_MIN_PK {
+                bls12_sw::min_pk::verify(pubkey, msg, signature, dst)
+            } else {
+                bls12_sw::min_sig::verify(pubkey, msg, signature, dst)
+            };
+            ZKVM_EOK
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "hints"), no_mangle)]
+#[cfg_attr(feature = "hints", export_name = "hints_zkvm_bls_aggregate_verify")]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn zkvm_bls_aggregate_verify(
+    variant: zkvm_bls_variant,
+    pubkeys: *const u8,
+    num_pubkeys: usize,
+    msgs: *const u8,
+    msg_lens: *const usize,
+    signature: *const u8,
+    dst: *const u8,
+    dst_len: usize,
+    verified: *mut bool,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> zkvm_status {
+    #[cfg(feature = "hints")]
+    {
+        match super::bls_aggregate_verify_c(
+            variant,
+            pubkeys,
+            num_pubkeys,
+            msgs,
+            msg_lens,
+            signature,
+            dst,
+            dst_len,
+            hints,
+        ) {
+            Some(result) => {
+                *verified = result;
+                ZKVM_EOK
+            }
+            None => ZKVM_EFAIL,
+        }
+    }
+
+    #[cfg(not(feature = "hints"))]
+    {
+        #[allow(unused_variables)]
+        let Some((pk_size, sig_size)) = bls12_381::bls_variant_sizes(variant) else {
+            return ZKVM_EFAIL;
+        };
+
+        #[cfg(any(all(target_os = "zkvm", target_vendor = "zisk"), zisk_hints))]
+        {
+            #[cfg(zisk_hints)]
+            unsafe {
+                let variant_bytes = (variant as u64).to_le_bytes();
+                let msgs_len: usize =
+                    std::slice::from_raw_parts(msg_lens, num_pubkeys).iter().sum();
+                crate::hints::hint_bls_aggregate_verify(
+                    variant_bytes.as_ptr(),
+                    variant_bytes.len(),
+                    pubkeys,
+                    num_pubkeys * pk_size,
+                    msgs,
+                    msgs_len,
+                    msg_lens as *const u8,
+                    num_pubkeys * core::mem::size_of::<usize>(),
+                    signature,
+                    sig_size,
+                    dst,
+                    dst_len,
+                );
+            }
+
+            #[cfg(zisk_hints_debug)]
+            crate::hint_log(format!(
+                "hint_bls_aggregate_verify (variant: {}, num_pubkeys: {}, dst_len: {})",
+                variant, num_pubkeys, dst_len
+            ));
+
+            #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+            {
+                return match super::bls_aggregate_verify_c(
+                    variant,
+                    pubkeys,
+                    num_pubkeys,
+                    msgs,
+                    msg_lens,
+                    signature,
+                    dst,
+                    dst_len,
+                ) {
+                    Some(result) => {
+                        *verified = result;
+                        ZKVM_EOK
+                    }
+                    None => ZKVM_EFAIL,
+                };
+            }
+        }
+
+        #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+        {
+            let pubkeys = std::slice::from_raw_parts(pubkeys, num_pubkeys * pk_size);
+            let mut offset = 0;
+            let msgs: Vec<&[u8]> = std::slice::from_raw_parts(msg_lens, num_pubkeys)
+                .iter()
+                .map(|&len| {
+                    let msg = std::slice::from_raw_parts(msgs.add(offset), len);
+                    offset += len;
+                    msg
+                })
+                .collect();
+            let signature = std::slice::from_raw_parts(signature, sig_size);
+            let dst = std::slice::from_raw_parts(dst, dst_len);
+            *verified = if variant == bls12_381::BLS_MIN_PK {
+                bls12_sw::min_pk::aggregate_verify(pubkeys, &msgs, signature, dst)
+            } else {
+                bls12_sw::min_sig::aggregate_verify(pubkeys, &msgs, signature, dst)
+            };
+            ZKVM_EOK
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "hints"), no_mangle)]
+#[cfg_attr(feature = "hints", export_name = "hints_zkvm_bls_fast_aggregate_verify")]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn zkvm_bls_fast_aggregate_verify(
+    variant: zkvm_bls_variant,
+    pubkeys: *const u8,
+    num_pubkeys: usize,
+    msg: *const u8,
+    msg_len: usize,
+    signature: *const u8,
+    dst: *const u8,
+    dst_len: usize,
+    verified: *mut bool,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> zkvm_status {
+    #[cfg(feature = "hints")]
+    {
+        match super::bls_fast_aggregate_verify_c(
+            variant,
+            pubkeys,
+            num_pubkeys,
+            msg,
+            msg_len,
+            signature,
+            dst,
+            dst_len,
+            hints,
+        ) {
+            Some(result) => {
+                *verified = result;
+                ZKVM_EOK
+            }
+            None => ZKVM_EFAIL,
+        }
+    }
+
+    #[cfg(not(feature = "hints"))]
+    {
+        #[allow(unused_variables)]
+        let Some((pk_size, sig_size)) = bls12_381::bls_variant_sizes(variant) else {
+            return ZKVM_EFAIL;
+        };
+
+        #[cfg(any(all(target_os = "zkvm", target_vendor = "zisk"), zisk_hints))]
+        {
+            #[cfg(zisk_hints)]
+            unsafe {
+                let variant_bytes = (variant as u64).to_le_bytes();
+                crate::hints::hint_bls_fast_aggregate_verify(
+                    variant_bytes.as_ptr(),
+                    variant_bytes.len(),
+                    pubkeys,
+                    num_pubkeys * pk_size,
+                    msg,
+                    msg_len,
+                    signature,
+                    sig_size,
+                    dst,
+                    dst_len,
+                );
+            }
+
+            #[cfg(zisk_hints_debug)]
+            crate::hint_log(format!(
+                "hint_bls_fast_aggregate_verify (variant: {}, num_pubkeys: {}, msg_len: {})",
+                variant, num_pubkeys, msg_len
+            ));
+
+            #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+            {
+                return match super::bls_fast_aggregate_verify_c(
+                    variant,
+                    pubkeys,
+                    num_pubkeys,
+                    msg,
+                    msg_len,
+                    signature,
+                    dst,
+                    dst_len,
+                ) {
+                    Some(result) => {
+                        *verified = result;
+                        ZKVM_EOK
+                    }
+                    None => ZKVM_EFAIL,
+                };
+            }
+        }
+
+        #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+        {
+            let pubkeys = std::slice::from_raw_parts(pubkeys, num_pubkeys * pk_size);
+            let msg = std::slice::from_raw_parts(msg, msg_len);
+            let signature = std::slice::from_raw_parts(signature, sig_size);
+            let dst = std::slice::from_raw_parts(dst, dst_len);
+            *verified = if variant == bls12_381::BLS_MIN_PK {
+                bls12_sw::min_pk::fast_aggregate_verify(pubkeys, msg, signature, dst)
+            } else {
+                bls12_sw::min_sig::fast_aggregate_verify(pubkeys, msg, signature, dst)
+            };
+            ZKVM_EOK
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "hints"), no_mangle)]
+#[cfg_attr(feature = "hints", export_name = "hints_zkvm_rsa_verify")]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn zkvm_rsa_verify(
+    scheme: zkvm_rsa_scheme,
+    modulus: *const u8,
+    mod_len: usize,
+    exp: *const u8,
+    exp_len: usize,
+    msg_hash: *const zkvm_sha256_hash,
+    signature: *const u8,
+    sig_len: usize,
+    salt_len: usize,
+    verified: *mut bool,
+    #[cfg(feature = "hints")] hints: &mut Vec<u64>,
+) -> zkvm_status {
+    #[cfg(feature = "hints")]
+    {
+        match rsa::rsa_verify_c(
+            scheme,
+            modulus,
+            mod_len,
+            exp,
+            exp_len,
+            (*msg_hash).data.as_ptr(),
+            signature,
+            sig_len,
+            salt_len,
+            hints,
+        ) {
+            Some(result) => {
+                *verified = result;
+                ZKVM_EOK
+            }
+            None => ZKVM_EFAIL,
+        }
+    }
+
+    #[cfg(not(feature = "hints"))]
+    {
+        if scheme != rsa::SCHEME_PKCS1V15_SHA256 && scheme != rsa::SCHEME_PSS_SHA256 {
+            return ZKVM_EFAIL;
+        }
+
+        #[cfg(any(all(target_os = "zkvm", target_vendor = "zisk"), zisk_hints))]
+        {
+            #[cfg(zisk_hints)]
+            unsafe {
+                let scheme_bytes = (scheme as u64).to_le_bytes();
+                let salt_len_bytes = (salt_len as u64).to_le_bytes();
+                crate::hints::hint_rsa_verify(
+                    scheme_bytes.as_ptr(),
+                    scheme_bytes.len(),
+                    modulus,
+                    mod_len,
+                    exp,
+                    exp_len,
+                    (*msg_hash).data.as_ptr(),
+                    32,
+                    signature,
+                    sig_len,
+                    salt_len_bytes.as_ptr(),
+                    salt_len_bytes.len(),
+                );
+            }
+
+            #[cfg(zisk_hints_debug)]
+            crate::hint_log(format!(
+                "hint_rsa_verify (scheme: {}, mod_len: {}, sig_len: {}, salt_len: {})",
+                scheme, mod_len, sig_len, salt_len
+            ));
+
+            #[cfg(all(target_os = "zkvm", target_vendor = "zisk"))]
+            {
+                return match rsa::rsa_verify_c(
+                    scheme,
+                    modulus,
+                    mod_len,
+                    exp,
+                    exp_len,
+                    (*msg_hash).data.as_ptr(),
+                    signature,
+                    sig_len,
+                    salt_len,
+                ) {
+                    Some(result) => {
+                        *verified = result;
+                        ZKVM_EOK
+                    }
+                    None => ZKVM_EFAIL,
+                };
+            }
+        }
+
+        #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
+        {
+            *verified = rsa_sw::verify(
+                scheme,
+                std::slice::from_raw_parts(modulus, mod_len),
+                std::slice::from_raw_parts(exp, exp_len),
+                &(*msg_hash).data,
+                std::slice::from_raw_parts(signature, sig_len),
+                salt_len,
+            );
+            ZKVM_EOK
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Compile-time interface checks: verify our implementations match the C header.
 //
@@ -1261,5 +1808,11 @@ mod _interface_type_checks {
         let _ = [bindings::zkvm_secp256r1_verify, super::zkvm_secp256r1_verify];
         let _ = [bindings::zkvm_secp256k1_verify, super::zkvm_secp256k1_verify];
         let _ = [bindings::zkvm_secp256k1_ecrecover, super::zkvm_secp256k1_ecrecover];
+        let _ = [bindings::zkvm_bls12_hash_to_g1, super::zkvm_bls12_hash_to_g1];
+        let _ = [bindings::zkvm_bls12_hash_to_g2, super::zkvm_bls12_hash_to_g2];
+        let _ = [bindings::zkvm_bls_verify, super::zkvm_bls_verify];
+        let _ = [bindings::zkvm_bls_aggregate_verify, super::zkvm_bls_aggregate_verify];
+        let _ = [bindings::zkvm_bls_fast_aggregate_verify, super::zkvm_bls_fast_aggregate_verify];
+        let _ = [bindings::zkvm_rsa_verify, super::zkvm_rsa_verify];
     }
 }
@@ -1,7 +1,8 @@
 //! Standard zkVM IO functions implementing the C interface from zkvm_io.h.
 //!
 //! ZisK stdin is stored as length-prefixed records. The standard IO interface is
-//! exposed as the first logical input record and is idempotent. Guests should use
+//! exposed as the first input record of the program, after the records consumed by
+//! `zkvm_init` for the enabled features, and is idempotent. Guests should use
 //! either this standard IO interface or ZisK's streaming input APIs for a given
 //! input, not both: standard reads do not advance ZisK's streaming input cursor.
 
@@ -22,8 +23,8 @@ static mut OUTPUT_PENDING_LEN: usize = 0;
 /// `buf_ptr` and `buf_size` must be valid writable pointers.
 ///
 /// This function is idempotent and does not advance ZisK's streaming input
-/// cursor. Mixing it with streaming reads may expose the first input record more
-/// than once.
+/// cursor. Mixing it with streaming reads may expose the first input record of the
+/// program more than once.
 #[cfg_attr(not(feature = "hints"), no_mangle)]
 #[cfg_attr(feature = "hints", export_name = "hints_read_input")]
 pub unsafe extern "C" fn read_input(buf_ptr: *mut *const u8, buf_size: *mut usize) {
@@ -38,7 +39,7 @@ pub unsafe extern "C" fn read_input(buf_ptr: *mut *const u8, buf_size: *mut usiz
         let mut input = STANDARD_INPUT.lock().unwrap();
         if input.is_none() {
             let saved_pos = unsafe { crate::INPUT_POS };
-            unsafe { crate::INPUT_POS = crate::INPUT_INITIAL_OFFSET };
+            unsafe { crate::INPUT_POS = crate::PROGRAM_INPUT_OFFSET };
             let data: &'static [u8] = Box::leak(crate::read_input().into_boxed_slice());
             unsafe { crate::INPUT_POS = saved_pos };
             *input = Some(data);
@@ -58,13 +59,13 @@ fn zkvm_standard_input() -> (*const u8, usize) {
     unsafe {
         if !INPUT_READY {
             let addr = (crate::ziskos_definitions::ziskos_config::INPUT_ADDR as usize)
-                + crate::INPUT_INITIAL_OFFSET;
+                + crate::PROGRAM_INPUT_OFFSET;
 
             crate::zisklib::fcall_input_ready(&((addr + 7) as u64));
-            let len = {
+            let (len, _public) = crate::input_item_len({
                 let bytes = core::slice::from_raw_parts(addr as *const u8, 8);
-                u64::from_le_bytes(bytes.try_into().unwrap()) as usize
-            };
+                u64::from_le_bytes(bytes.try_into().unwrap())
+            });
 
             let data_addr = addr + 8;
             if len > 0 {
@@ -76,6 +77,14 @@ fn zkvm_standard_input() -> (*const u8, usize) {
             }
             INPUT_LEN = len;
             INPUT_READY = true;
+
+            #[cfg(feature = "input-commitment")]
+            if _public {
+                crate::input_commitment::absorb(core::slice::from_raw_parts(
+                    data_addr as *const u8,
+                    len,
+                ));
+            }
         }
 
         (INPUT_PTR, INPUT_LEN)
@@ -106,7 +115,7 @@ pub unsafe extern "C" fn write_output(output: *const u8, size: usize) {
 
     while remaining >= OUTPUT_WORD_SIZE {
         let value = u32::from_le_bytes(ptr::read_unaligned(ptr as *const [u8; OUTPUT_WORD_SIZE]));
-        crate::set_output(OUTPUT_WORD_SLOT, value);
+        set_user_output(OUTPUT_WORD_SLOT, value);
         OUTPUT_WORD_SLOT += 1;
         ptr = ptr.add(OUTPUT_WORD_SIZE);
         remaining -= OUTPUT_WORD_SIZE;
@@ -124,7 +133,7 @@ pub unsafe extern "C" fn write_output(output: *const u8, size: usize) {
 
 unsafe fn write_pending_word() {
     if OUTPUT_PENDING_LEN == OUTPUT_WORD_SIZE {
-        crate::set_output(OUTPUT_WORD_SLOT, u32::from_le_bytes(OUTPUT_PENDING));
+        set_user_output(OUTPUT_WORD_SLOT, u32::from_le_bytes(OUTPUT_PENDING));
         OUTPUT_WORD_SLOT += 1;
         OUTPUT_PENDING_LEN = 0;
     }
@@ -137,7 +146,24 @@ unsafe fn write_padded_pending_word() {
         bytes.as_mut_ptr(),
         OUTPUT_PENDING_LEN,
     );
-    crate::set_output(OUTPUT_WORD_SLOT, u32::from_le_bytes(bytes));
+    set_user_output(OUTPUT_WORD_SLOT, u32::from_le_bytes(bytes));
+}
+
+/// Panics if appending `size` bytes to the outputs would reach a slot reserved by the enabled
+/// features
+pub(crate) fn check_output_len(size: usize) {
+    let (slot, pending) = unsafe { (OUTPUT_WORD_SLOT, OUTPUT_PENDING_LEN) };
+    let end = slot + (pending + size).div_ceil(OUTPUT_WORD_SIZE);
+    if let Some(id) = (slot..end).find(|&id| crate::is_reserved_output(id)) {
+        panic!("Public output slot {id} is reserved");
+    }
+}
+
+/// Writes a slot of the user outputs, which must not overlap the slots reserved by the enabled
+/// features
+fn set_user_output(id: usize, value: u32) {
+    assert!(!crate::is_reserved_output(id), "Public output slot {id} is reserved");
+    crate::set_output(id, value);
 }
 
 #[cfg(not(all(target_os = "zkvm", target_vendor = "zisk")))]
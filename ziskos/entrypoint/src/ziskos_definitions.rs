@@ -6,6 +6,7 @@ pub mod ziskos_config {
     pub const INPUT_ADDR: u64 = 0x4000_0000;
     pub const OUTPUT_ADDR: u64 = 0xa001_0000;
     pub const UART_ADDR: u64 = 0xa000_0200;
+    pub const UART_ERR_ADDR: u64 = 0xa000_0208;
     pub const ARCH_ID_ZISK: u64 = 0xFFFEEEE; // TEMPORARY  // TODO register one
 
     pub const MAX_INPUT: usize = 0x2000;
@@ -0,0 +1,124 @@
+//! Checks the BLS signature verification and hash to G2 against the ethereum/bls12-381-tests
+//! vectors in `tests/bls12-381-tests`
+
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use ziskos::zisklib::{
+    bls_aggregate_verify_min_pk, bls_fast_aggregate_verify_min_pk, bls_verify_min_pk,
+    g2_u64_le_to_bytes_be_bls12_381, hash_to_g2_bls12_381,
+};
+
+/// Ciphersuite of the Ethereum signatures
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Ciphersuite of the hash to G2 vectors
+const HASH_TO_G2_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+/// Returns the name and content of the cases of a test suite
+fn cases(suite: &str) -> Vec<(String, Value)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/bls12-381-tests").join(suite);
+    let mut paths: Vec<PathBuf> =
+        std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "No test cases in {}", dir.display());
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            let case = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            (name, case)
+        })
+        .collect()
+}
+
+fn hex(value: &Value) -> Vec<u8> {
+    let s = value.as_str().unwrap().trim_start_matches("0x");
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+/// Decodes a fixed size point, or returns `None` for the malformed inputs of the invalid cases
+fn point<const N: usize>(value: &Value) -> Option<[u8; N]> {
+    hex(value).try_into().ok()
+}
+
+fn points<const N: usize>(value: &Value) -> Option<Vec<[u8; N]>> {
+    value.as_array().unwrap().iter().map(point).collect()
+}
+
+#[test]
+fn test_verify_vectors() {
+    for (name, case) in cases("verify") {
+        let input = &case["input"];
+        let expected = case["output"].as_bool().unwrap();
+
+        let valid = match (point(&input["pubkey"]), point(&input["signature"])) {
+            (Some(pubkey), Some(signature)) => {
+                bls_verify_min_pk(&pubkey, &hex(&input["message"]), &signature, DST)
+            }
+            _ => false,
+        };
+        assert_eq!(valid, expected, "{name}");
+    }
+}
+
+#[test]
+fn test_aggregate_verify_vectors() {
+    let mut valid_cases = 0;
+    for (name, case) in cases("aggregate_verify") {
+        let input = &case["input"];
+        let expected = case["output"].as_bool().unwrap();
+
+        let messages: Vec<Vec<u8>> =
+            input["messages"].as_array().unwrap().iter().map(hex).collect();
+        let messages: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+        let valid = match (points(&input["pubkeys"]), point(&input["signature"])) {
+            (Some(pubkeys), Some(signature)) => {
+                bls_aggregate_verify_min_pk(&pubkeys, &messages, &signature, DST)
+            }
+            _ => false,
+        };
+        assert_eq!(valid, expected, "{name}");
+        valid_cases += usize::from(valid);
+    }
+    assert!(valid_cases > 0, "No valid aggregate signature was checked");
+}
+
+#[test]
+fn test_fast_aggregate_verify_vectors() {
+    let mut valid_cases = 0;
+    for (name, case) in cases("fast_aggregate_verify") {
+        let input = &case["input"];
+        let expected = case["output"].as_bool().unwrap();
+
+        let valid = match (points(&input["pubkeys"]), point(&input["signature"])) {
+            (Some(pubkeys), Some(signature)) => {
+                bls_fast_aggregate_verify_min_pk(&pubkeys, &hex(&input["message"]), &signature, DST)
+            }
+            _ => false,
+        };
+        assert_eq!(valid, expected, "{name}");
+        valid_cases += usize::from(valid);
+    }
+    assert!(valid_cases > 0, "No valid aggregate signature was checked");
+}
+
+#[test]
+fn test_hash_to_g2_vectors() {
+    for (name, case) in cases("hash_to_G2") {
+        let msg = case["input"]["msg"].as_str().unwrap();
+        let point = hash_to_g2_bls12_381(msg.as_bytes(), HASH_TO_G2_DST);
+        let mut bytes = [0u8; 192];
+        g2_u64_le_to_bytes_be_bls12_381(&point, &mut bytes);
+
+        // Coordinates are given as "c0,c1", serialized as x.c0 || x.c1 || y.c0 || y.c1
+        let output = &case["output"];
+        let expected: Vec<u8> = [&output["x"], &output["y"]]
+            .iter()
+            .flat_map(|coordinate| coordinate.as_str().unwrap().split(','))
+            .flat_map(|element| hex(&Value::from(element)))
+            .collect();
+        assert_eq!(bytes.to_vec(), expected, "{name}");
+    }
+}
@@ -0,0 +1,98 @@
+//! Checks `recursion::verify_plonk` against the Plonk fixture of `zisk-common`, in
+//! `common/tests/fixtures/plonk`
+
+use std::path::Path;
+
+use serde_json::Value;
+use zisk_verifier::parse_decimal;
+use ziskos::recursion::{
+    verify_plonk, PlonkVerifyingKey, ProgramVk, RecursionError, VadcopVk, PUBLICS_SIZE,
+};
+
+/// Values bound by the public input of the fixture
+const PROGRAM_VK: ProgramVk =
+    [0x123456789abcdef0, 0xfedcba9876543210, 0x0123456789abcdef, 0x9e3779b97f4a7c15];
+const VADCOP_VK: VadcopVk =
+    [0x5555555555555555, 0xaaaaaaaaaaaaaaaa, 0x3333333333333333, 0xcccccccccccccccc];
+
+fn read_fixture(name: &str) -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../common/tests/fixtures/plonk");
+    serde_json::from_str(&std::fs::read_to_string(path.join(name)).unwrap()).unwrap()
+}
+
+fn scalar(value: &Value) -> [u8; 32] {
+    parse_decimal(value.as_str().unwrap()).unwrap()
+}
+
+fn g1(point: &Value) -> [u8; 64] {
+    [scalar(&point[0]), scalar(&point[1])].concat().try_into().unwrap()
+}
+
+/// G2 point from `[real, imaginary]` coordinates
+fn g2(point: &Value) -> [u8; 128] {
+    [&point[0][1], &point[0][0], &point[1][1], &point[1][0]]
+        .map(scalar)
+        .concat()
+        .try_into()
+        .unwrap()
+}
+
+fn verifying_key() -> PlonkVerifyingKey {
+    let vk = read_fixture("final.verkey.json");
+    PlonkVerifyingKey {
+        n_public: vk["nPublic"].as_u64().unwrap() as usize,
+        power: vk["power"].as_u64().unwrap() as u32,
+        k1: scalar(&vk["k1"]),
+        k2: scalar(&vk["k2"]),
+        qm: g1(&vk["Qm"]),
+        ql: g1(&vk["Ql"]),
+        qr: g1(&vk["Qr"]),
+        qo: g1(&vk["Qo"]),
+        qc: g1(&vk["Qc"]),
+        s1: g1(&vk["S1"]),
+        s2: g1(&vk["S2"]),
+        s3: g1(&vk["S3"]),
+        x_2: g2(&vk["X_2"]),
+        w: scalar(&vk["w"]),
+    }
+}
+
+fn proof_bytes() -> Vec<u8> {
+    let proof = read_fixture("proof.json");
+    let points = ["A", "B", "C", "Z", "T1", "T2", "T3", "Wxi", "Wxiw"].map(|p| g1(&proof[p]));
+    let evals =
+        ["eval_a", "eval_b", "eval_c", "eval_s1", "eval_s2", "eval_zw"].map(|e| scalar(&proof[e]));
+    [points.concat(), evals.concat()].concat()
+}
+
+fn publics() -> [u8; PUBLICS_SIZE] {
+    let bytes: Vec<u8> =
+        (0..PUBLICS_SIZE as u32 / 4).flat_map(|i| (i * 7 + 1).to_le_bytes()).collect();
+    bytes.try_into().unwrap()
+}
+
+#[test]
+fn test_verify_plonk_fixture() {
+    let vk = verifying_key();
+    let verified = verify_plonk(&vk, &proof_bytes(), &PROGRAM_VK, &publics(), &VADCOP_VK).unwrap();
+    assert_eq!(verified.program_vk(), &PROGRAM_VK);
+    assert_eq!(verified.vadcop_vk(), &VADCOP_VK);
+    assert_eq!(verified.publics_bytes(), &publics());
+}
+
+#[test]
+fn test_verify_plonk_rejects_other_values() {
+    let vk = verifying_key();
+    let proof = proof_bytes();
+    let verify = |program_vk: &ProgramVk, publics: &[u8; PUBLICS_SIZE], vadcop_vk: &VadcopVk| {
+        verify_plonk(&vk, &proof, program_vk, publics, vadcop_vk).err()
+    };
+
+    assert_eq!(verify(&[1, 2, 3, 4], &publics(), &VADCOP_VK), Some(RecursionError::Rejected));
+    assert_eq!(verify(&PROGRAM_VK, &[0; PUBLICS_SIZE], &VADCOP_VK), Some(RecursionError::Rejected));
+    assert_eq!(verify(&PROGRAM_VK, &publics(), &[5, 6, 7, 8]), Some(RecursionError::Rejected));
+    assert_eq!(
+        verify_plonk(&vk, &proof[1..], &PROGRAM_VK, &publics(), &VADCOP_VK).err(),
+        Some(RecursionError::MalformedProof)
+    );
+}
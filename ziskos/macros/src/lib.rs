@@ -0,0 +1,213 @@
+//! Procedural macros re-exported by `ziskos`
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse::Parser, parse_macro_input, punctuated::Punctuated, ItemFn, LitStr, Meta, Token};
+
+/// Profiles every call of the annotated function as a profile tag
+///
+/// The body runs inside a `ziskos::ProfileScope`, so the region is closed on every return path,
+/// including early returns and `?`. Outside the zkVM the scope is empty and the function is left
+/// unchanged.
+///
+/// Arguments, all optional:
+/// - `name = "tag"`: tag of the region, the function name by default
+/// - `steps`: measure steps instead of cost
+/// - `report`: aggregate the calls in the profile tags report instead of printing each one
+///
+/// ```ignore
+/// #[ziskos::profile]
+/// fn verify_block(block: &Block) -> bool { .. }
+///
+/// #[ziskos::profile(name = "keccak", steps, report)]
+/// fn hash(data: &[u8]) -> [u8; 32] { .. }
+/// ```
+#[proc_macro_attribute]
+pub fn profile(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(item as ItemFn);
+    expand_profile(attr.into(), function).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+fn expand_profile(attr: TokenStream2, mut function: ItemFn) -> syn::Result<TokenStream2> {
+    let args = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(attr)?;
+    let options = ProfileOptions::parse(&args)?;
+    if let Some(constness) = function.sig.constness {
+        return Err(syn::Error::new_spanned(constness, "#[profile] can not be used on a const fn"));
+    }
+
+    let name = options
+        .name
+        .unwrap_or_else(|| LitStr::new(&function.sig.ident.to_string(), Span::call_site()));
+    let start = match (options.steps, options.report) {
+        (false, false) => quote!(start),
+        (true, false) => quote!(start_steps),
+        (false, true) => quote!(start_report),
+        (true, true) => quote!(start_report_steps),
+    };
+    function.block.stmts.insert(
+        0,
+        syn::parse_quote!(let __ziskos_profile_scope = ::ziskos::ProfileScope::#start(#name);),
+    );
+    Ok(quote!(#function))
+}
+
+#[derive(Default)]
+struct ProfileOptions {
+    name: Option<LitStr>,
+    steps: bool,
+    report: bool,
+}
+
+impl ProfileOptions {
+    fn parse(args: &Punctuated<Meta, Token![,]>) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for arg in args {
+            match arg {
+                Meta::Path(path) if path.is_ident("steps") => options.steps = true,
+                Meta::Path(path) if path.is_ident("report") => options.report = true,
+                Meta::NameValue(name_value) if name_value.path.is_ident("name") => {
+                    let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(name), .. }) =
+                        &name_value.value
+                    else {
+                        return Err(syn::Error::new_spanned(
+                            &name_value.value,
+                            "expected a string literal",
+                        ));
+                    };
+                    if name.value().is_empty() {
+                        return Err(syn::Error::new_spanned(name, "profile tag can not be empty"));
+                    }
+                    options.name = Some(name.clone());
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        "expected `name = \"tag\"`, `steps` or `report`",
+                    ))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(attr: TokenStream2, function: TokenStream2) -> syn::Result<String> {
+        expand_profile(attr, syn::parse2(function).unwrap()).map(|tokens| tokens.to_string())
+    }
+
+    fn error(attr: TokenStream2, function: TokenStream2) -> String {
+        expand(attr, function).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_profile_opens_a_scope_named_after_the_function() {
+        let expanded = expand(
+            quote!(),
+            quote!(
+                fn verify(x: u64) -> bool {
+                    x > 1
+                }
+            ),
+        )
+        .unwrap();
+        let expected = quote! {
+            fn verify(x: u64) -> bool {
+                let __ziskos_profile_scope = ::ziskos::ProfileScope::start("verify");
+                x > 1
+            }
+        };
+        assert_eq!(expanded, expected.to_string());
+    }
+
+    #[test]
+    fn test_profile_options_select_the_scope() {
+        let cases = [
+            (quote!(steps), quote!(start_steps)),
+            (quote!(report), quote!(start_report)),
+            (quote!(report, steps), quote!(start_report_steps)),
+        ];
+        for (attr, start) in cases {
+            let expanded = expand(
+                attr,
+                quote!(
+                    fn f() {}
+                ),
+            )
+            .unwrap();
+            let expected = quote! {
+                fn f() {
+                    let __ziskos_profile_scope = ::ziskos::ProfileScope::#start("f");
+                }
+            };
+            assert_eq!(expanded, expected.to_string());
+        }
+
+        let expanded = expand(
+            quote!(name = "keccak", steps),
+            quote!(
+                fn f() {}
+            ),
+        )
+        .unwrap();
+        assert!(expanded.contains("ProfileScope :: start_steps (\"keccak\")"), "{expanded}");
+    }
+
+    #[test]
+    fn test_profile_keeps_the_signature_and_attributes() {
+        let function = quote! {
+            #[inline]
+            pub(crate) async unsafe fn f<T: Copy>(x: T) -> Result<T, ()> where T: Default { Ok(x) }
+        };
+        let expanded = expand(quote!(), function).unwrap();
+        assert!(expanded.starts_with(
+            "# [inline] pub (crate) async unsafe fn f < T : Copy > (x : T) -> Result < T , () > \
+             where T : Default { let __ziskos_profile_scope"
+        ), "{expanded}");
+        assert!(expanded.ends_with("; Ok (x) }"), "{expanded}");
+    }
+
+    #[test]
+    fn test_profile_rejects_invalid_uses() {
+        assert_eq!(
+            error(
+                quote!(),
+                quote!(
+                    const fn f() {}
+                )
+            ),
+            "#[profile] can not be used on a const fn"
+        );
+        assert_eq!(
+            error(
+                quote!(name = ""),
+                quote!(
+                    fn f() {}
+                )
+            ),
+            "profile tag can not be empty"
+        );
+        assert_eq!(
+            error(
+                quote!(name = 1),
+                quote!(
+                    fn f() {}
+                )
+            ),
+            "expected a string literal"
+        );
+        assert_eq!(
+            error(
+                quote!(cost),
+                quote!(
+                    fn f() {}
+                )
+            ),
+            "expected `name = \"tag\"`, `steps` or `report`"
+        );
+    }
+}
@@ -474,6 +474,161 @@ zkvm_status zkvm_secp256r1_verify(const zkvm_secp256r1_hash* msg,
                                   const zkvm_secp256r1_pubkey* pubkey,
                                   bool* verified);
 
+/* ============================================================================
+ * ZisK extensions
+ *
+ * The following accelerators are not part of the standard interface.
+ * ============================================================================ */
+
+/**
+ * BLS signature scheme variants
+ *
+ * - ZKVM_BLS_MIN_PK: public keys in G1 (48 bytes), signatures in G2 (96 bytes)
+ * - ZKVM_BLS_MIN_SIG: public keys in G2 (96 bytes), signatures in G1 (48 bytes)
+ *
+ * Public keys and signatures are in compressed form.
+ */
+typedef enum {
+    ZKVM_BLS_MIN_PK = 0,
+    ZKVM_BLS_MIN_SIG = 1
+} zkvm_bls_variant;
+
+/**
+ * BLS12-381 hash to G1
+ *
+ * RFC 9380, suite BLS12381G1_XMD:SHA-256_SSWU_RO_
+ *
+ * @param msg Pointer to message
+ * @param msg_len Length of message in bytes
+ * @param dst Pointer to domain separation tag
+ * @param dst_len Length of domain separation tag in bytes
+ * @param[out] result Pointer to output G1 point
+ * @return ZKVM_EOK on success, ZKVM_EFAIL on failure
+ */
+zkvm_status zkvm_bls12_hash_to_g1(const uint8_t* msg, size_t msg_len,
+                                  const uint8_t* dst, size_t dst_len,
+                                  zkvm_bls12_381_g1_point* result);
+
+/**
+ * BLS12-381 hash to G2
+ *
+ * RFC 9380, suite BLS12381G2_XMD:SHA-256_SSWU_RO_
+ *
+ * @param msg Pointer to message
+ * @param msg_len Length of message in bytes
+ * @param dst Pointer to domain separation tag
+ * @param dst_len Length of domain separation tag in bytes
+ * @param[out] result Pointer to output G2 point
+ * @return ZKVM_EOK on success, ZKVM_EFAIL on failure
+ */
+zkvm_status zkvm_bls12_hash_to_g2(const uint8_t* msg, size_t msg_len,
+                                  const uint8_t* dst, size_t dst_len,
+                                  zkvm_bls12_381_g2_point* result);
+
+/**
+ * BLS signature verification
+ *
+ * @param variant Signature scheme variant
+ * @param pubkey Pointer to compressed public key
+ * @param msg Pointer to message
+ * @param msg_len Length of message in bytes
+ * @param signature Pointer to compressed signature
+ * @param dst Pointer to domain separation tag
+ * @param dst_len Length of domain separation tag in bytes
+ * @param[out] verified Pointer to bool indicating if signature is valid
+ * @return ZKVM_EOK on success, ZKVM_EFAIL on failure
+ */
+zkvm_status zkvm_bls_verify(zkvm_bls_variant variant,
+                            const uint8_t* pubkey,
+                            const uint8_t* msg, size_t msg_len,
+                            const uint8_t* signature,
+                            const uint8_t* dst, size_t dst_len,
+                            bool* verified);
+
+/**
+ * BLS aggregate signature verification
+ *
+ * Each public key signs its own message. Messages are not required to be
+ * distinct, so this must only be used with the proof-of-possession scheme.
+ *
+ * @param variant Signature scheme variant
+ * @param pubkeys Pointer to concatenated compressed public keys
+ * @param num_pubkeys Number of public keys and messages
+ * @param msgs Pointer to concatenated messages
+ * @param msg_lens Pointer to array of message lengths in bytes
+ * @param signature Pointer to compressed aggregate signature
+ * @param dst Pointer to domain separation tag
+ * @param dst_len Length of domain separation tag in bytes
+ * @param[out] verified Pointer to bool indicating if signature is valid
+ * @return ZKVM_EOK on success, ZKVM_EFAIL on failure
+ */
+zkvm_status zkvm_bls_aggregate_verify(zkvm_bls_variant variant,
+                                      const uint8_t* pubkeys, size_t num_pubkeys,
+                                      const uint8_t* msgs, const size_t* msg_lens,
+                                      const uint8_t* signature,
+                                      const uint8_t* dst, size_t dst_len,
+                                      bool* verified);
+
+/**
+ * BLS fast aggregate signature verification
+ *
+ * All public keys sign the same message.
+ *
+ * @param variant Signature scheme variant
+ * @param pubkeys Pointer to concatenated compressed public keys
+ * @param num_pubkeys Number of public keys
+ * @param msg Pointer to message
+ * @param msg_len Length of message in bytes
+ * @param signature Pointer to compressed aggregate signature
+ * @param dst Pointer to domain separation tag
+ * @param dst_len Length of domain separation tag in bytes
+ * @param[out] verified Pointer to bool indicating if signature is valid
+ * @return ZKVM_EOK on success, ZKVM_EFAIL on failure
+ */
+zkvm_status zkvm_bls_fast_aggregate_verify(zkvm_bls_variant variant,
+                                           const uint8_t* pubkeys, size_t num_pubkeys,
+                                           const uint8_t* msg, size_t msg_len,
+                                           const uint8_t* signature,
+                                           const uint8_t* dst, size_t dst_len,
+                                           bool* verified);
+
+/**
+ * RSA signature schemes
+ *
+ * - ZKVM_RSA_PKCS1V15_SHA256: RSASSA-PKCS1-v1_5 with SHA-256
+ * - ZKVM_RSA_PSS_SHA256: RSASSA-PSS with SHA-256 and MGF1-SHA-256
+ */
+typedef enum {
+    ZKVM_RSA_PKCS1V15_SHA256 = 0,
+    ZKVM_RSA_PSS_SHA256 = 1
+} zkvm_rsa_scheme;
+
+/**
+ * RSA signature verification
+ *
+ * RFC 8017, for 2048 to 4096-bit moduli. Keys of other sizes, even moduli
+ * and exponents that are even or equal to one are reported as not verified.
+ *
+ * @param scheme Signature scheme
+ * @param modulus Pointer to modulus bytes (big-endian)
+ * @param mod_len Length of modulus in bytes
+ * @param exp Pointer to public exponent bytes (big-endian)
+ * @param exp_len Length of public exponent in bytes
+ * @param msg_hash Pointer to SHA-256 hash of the message
+ * @param signature Pointer to signature bytes (big-endian)
+ * @param sig_len Length of signature in bytes
+ * @param salt_len Length of the PSS salt in bytes, ignored for PKCS#1 v1.5
+ * @param[out] verified Pointer to bool indicating if signature is valid
+ * @return ZKVM_EOK on success, ZKVM_EFAIL on failure
+ */
+zkvm_status zkvm_rsa_verify(zkvm_rsa_scheme scheme,
+                            const uint8_t* modulus, size_t mod_len,
+                            const uint8_t* exp, size_t exp_len,
+                            const zkvm_sha256_hash* msg_hash,
+                            const uint8_t* signature, size_t sig_len,
+                            size_t salt_len,
+                            bool* verified);
+
 #ifdef __cplusplus
 }
 #endif